@@ -0,0 +1,169 @@
+use crate::state::ContractState;
+
+use contract_version_base::state::ContractVersionBase;
+use pbc_contract_common::{
+    address::Address,
+    context::{CallbackContext, ContractContext},
+    events::EventGroup,
+};
+
+use mpc1155_crafting_base::{
+    actions::{
+        execute_claim_refund, execute_craft, execute_init, execute_on_fee_paid,
+        execute_on_fee_transferred, execute_on_inputs_escrowed, execute_on_inputs_returned,
+        execute_on_outputs_minted, execute_remove_recipe, execute_set_fee_receiver,
+        execute_set_recipe,
+    },
+    msg::{
+        ClaimRefundMsg, CraftCallbackMsg, CraftMsg, FeeCallbackMsg, InitMsg, RefundCallbackMsg,
+        RemoveRecipeMsg, SetFeeReceiverMsg, SetRecipeMsg,
+    },
+    state::Recipe,
+};
+
+const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[init]
+pub fn initialize(ctx: ContractContext, msg: InitMsg) -> (ContractState, Vec<EventGroup>) {
+    let (crafting, events) = execute_init(&ctx, &msg);
+    let state = ContractState {
+        crafting,
+        version: ContractVersionBase::new(CONTRACT_NAME, CONTRACT_VERSION),
+    };
+
+    (state, events)
+}
+
+#[action(shortname = 0x01)]
+pub fn set_recipe(
+    ctx: ContractContext,
+    state: ContractState,
+    recipe_id: u128,
+    recipe: Recipe,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_set_recipe(
+        &ctx,
+        &mut state.crafting,
+        &SetRecipeMsg { recipe_id, recipe },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x03)]
+pub fn remove_recipe(
+    ctx: ContractContext,
+    state: ContractState,
+    recipe_id: u128,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_remove_recipe(&ctx, &mut state.crafting, &RemoveRecipeMsg { recipe_id });
+
+    (state, events)
+}
+
+#[action(shortname = 0x05)]
+pub fn set_fee_receiver(
+    ctx: ContractContext,
+    state: ContractState,
+    fee_receiver: Address,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_set_fee_receiver(
+        &ctx,
+        &mut state.crafting,
+        &SetFeeReceiverMsg { fee_receiver },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x07)]
+pub fn craft(
+    ctx: ContractContext,
+    state: ContractState,
+    recipe_id: u128,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_craft(&ctx, &mut state.crafting, &CraftMsg { recipe_id });
+
+    (state, events)
+}
+
+#[action(shortname = 0x09)]
+pub fn claim_refund(
+    ctx: ContractContext,
+    state: ContractState,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_claim_refund(&ctx, &mut state.crafting, &ClaimRefundMsg {});
+
+    (state, events)
+}
+
+#[callback(shortname = 0x31)]
+pub fn on_fee_paid(
+    ctx: ContractContext,
+    callback_ctx: CallbackContext,
+    state: ContractState,
+    msg: CraftCallbackMsg,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_on_fee_paid(&ctx, &callback_ctx, &mut state.crafting, &msg);
+
+    (state, events)
+}
+
+#[callback(shortname = 0x33)]
+pub fn on_inputs_escrowed(
+    ctx: ContractContext,
+    callback_ctx: CallbackContext,
+    state: ContractState,
+    msg: CraftCallbackMsg,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_on_inputs_escrowed(&ctx, &callback_ctx, &mut state.crafting, &msg);
+
+    (state, events)
+}
+
+#[callback(shortname = 0x35)]
+pub fn on_outputs_minted(
+    ctx: ContractContext,
+    callback_ctx: CallbackContext,
+    state: ContractState,
+    msg: CraftCallbackMsg,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_on_outputs_minted(&ctx, &callback_ctx, &mut state.crafting, &msg);
+
+    (state, events)
+}
+
+#[callback(shortname = 0x37)]
+pub fn on_inputs_returned(
+    ctx: ContractContext,
+    callback_ctx: CallbackContext,
+    state: ContractState,
+    msg: RefundCallbackMsg,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_on_inputs_returned(&ctx, &callback_ctx, &mut state.crafting, &msg);
+
+    (state, events)
+}
+
+#[callback(shortname = 0x39)]
+pub fn on_fee_transferred(
+    ctx: ContractContext,
+    callback_ctx: CallbackContext,
+    state: ContractState,
+    msg: FeeCallbackMsg,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_on_fee_transferred(&ctx, &callback_ctx, &mut state.crafting, &msg);
+
+    (state, events)
+}
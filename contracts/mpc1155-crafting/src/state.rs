@@ -0,0 +1,9 @@
+use contract_version_base::state::ContractVersionBase;
+use mpc1155_crafting_base::state::MPC1155CraftingContractState;
+
+#[state]
+#[derive(PartialEq, Eq, Debug)]
+pub struct ContractState {
+    pub crafting: MPC1155CraftingContractState,
+    pub version: ContractVersionBase,
+}
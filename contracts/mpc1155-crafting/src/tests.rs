@@ -0,0 +1,94 @@
+use mpc1155_crafting_base::{
+    msg::{ClaimRefundMsg, CraftMsg, SetRecipeMsg},
+    state::{Recipe, RecipeFee, RecipeItem},
+};
+use pbc_contract_common::{
+    address::{Address, AddressType, Shortname},
+    events::EventGroup,
+};
+use utils::events::IntoShortnameRPCEvent;
+
+fn mock_address(le: u8) -> Address {
+    Address {
+        address_type: AddressType::Account,
+        identifier: [
+            le, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8,
+        ],
+    }
+}
+
+const SET_RECIPE: u32 = 0x01;
+const CRAFT: u32 = 0x07;
+const CLAIM_REFUND: u32 = 0x09;
+
+#[test]
+fn proper_set_recipe_action_call() {
+    let dest = mock_address(30u8);
+
+    let recipe = Recipe {
+        inputs: vec![RecipeItem {
+            token_id: 1,
+            amount: 2,
+        }],
+        outputs: vec![RecipeItem {
+            token_id: 3,
+            amount: 1,
+        }],
+        fee: Some(RecipeFee {
+            token: mock_address(5u8),
+            amount: 10,
+        }),
+    };
+    let msg = SetRecipeMsg {
+        recipe_id: 1,
+        recipe: recipe.clone(),
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(SET_RECIPE))
+        .argument(1u128)
+        .argument(recipe)
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_craft_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = CraftMsg { recipe_id: 1 };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(CRAFT))
+        .argument(1u128)
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_claim_refund_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = ClaimRefundMsg {};
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(CLAIM_REFUND))
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
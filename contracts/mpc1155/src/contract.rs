@@ -1,19 +1,36 @@
 use crate::state::ContractState;
 
 use contract_version_base::state::ContractVersionBase;
-use pbc_contract_common::{address::Address, context::ContractContext, events::EventGroup};
+use pbc_contract_common::{
+    address::Address,
+    context::{CallbackContext, ContractContext},
+    events::EventGroup,
+};
 
 use mpc1155_base::{
     actions::{
-        execute_approve_for_all, execute_batch_burn, execute_batch_mint,
-        execute_batch_transfer_from, execute_burn, execute_check_balances, execute_init,
-        execute_mint, execute_revoke_for_all, execute_set_uri, execute_transfer_from,
+        execute_allowance, execute_approve, execute_approve_for_all, execute_balance_of,
+        execute_balance_of_batch, execute_batch_burn, execute_batch_mint,
+        execute_batch_transfer_from, execute_burn, execute_check_balances, execute_create,
+        execute_decrease_allowance, execute_freeze_token_uri, execute_increase_allowance,
+        execute_init, execute_mint, execute_on_safe_transfer, execute_revoke_for_all,
+        execute_royalty_info, execute_safe_batch_transfer_from, execute_safe_transfer_from,
+        execute_set_creator, execute_set_default_royalty, execute_set_public_creation,
+        execute_set_soulbound, execute_set_token_minter, execute_set_token_royalty,
+        execute_set_token_soulbound, execute_set_token_uri, execute_set_uri, execute_total_supply,
+        execute_transfer_from, execute_transfer_ownership, execute_update_minter,
     },
     msg::{
-        ApproveForAllMsg, BatchBurnMsg, BatchMintMsg, BatchTransferFromMsg, BurnMsg,
-        CheckBalancesMsg, InitMsg, MintMsg, RevokeForAllMsg, SetUriMsg, TokenMintInfoMsg,
-        TokenTransferInfoMsg, TransferFromMsg,
+        AllowanceMsg, ApproveForAllMsg, ApproveMsg, BalanceOfBatchMsg, BalanceOfMsg, BatchBurnMsg,
+        BatchMintMsg, BatchTransferFromMsg, BurnMsg, CheckBalancesMsg, CreateMsg,
+        DecreaseAllowanceMsg, FreezeTokenUriMsg, IncreaseAllowanceMsg, InitMsg, MintMsg,
+        RevokeForAllMsg, RoyaltyInfoMsg, SafeBatchTransferFromMsg, SafeTransferCallbackMsg,
+        SafeTransferFromMsg, SetCreatorMsg, SetDefaultRoyaltyMsg, SetPublicCreationMsg,
+        SetSoulboundMsg, SetTokenMinterMsg, SetTokenRoyaltyMsg, SetTokenSoulboundMsg,
+        SetTokenUriMsg, SetUriMsg, TokenMintInfoMsg, TokenTransferInfoMsg, TotalSupplyMsg,
+        TransferFromMsg, TransferOwnershipMsg, UpdateMinterMsg,
     },
+    state::RoyaltyInfo,
 };
 
 const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
@@ -186,3 +203,456 @@ pub fn check_balances(
 
     (state, events)
 }
+
+#[action(shortname = 0x19)]
+pub fn set_default_royalty(
+    ctx: ContractContext,
+    state: ContractState,
+    royalty: Option<RoyaltyInfo>,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events =
+        execute_set_default_royalty(&ctx, &mut state.mpc1155, &SetDefaultRoyaltyMsg { royalty });
+
+    (state, events)
+}
+
+#[action(shortname = 0x20)]
+pub fn set_token_royalty(
+    ctx: ContractContext,
+    state: ContractState,
+    token_id: u128,
+    royalty: Option<RoyaltyInfo>,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_set_token_royalty(
+        &ctx,
+        &mut state.mpc1155,
+        &SetTokenRoyaltyMsg { token_id, royalty },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x21)]
+pub fn royalty_info(
+    ctx: ContractContext,
+    state: ContractState,
+    token_id: u128,
+    sale_price: u128,
+    callback_action: u32,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_royalty_info(
+        &ctx,
+        &mut state.mpc1155,
+        &RoyaltyInfoMsg {
+            token_id,
+            sale_price,
+            callback_action,
+        },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x22)]
+pub fn set_soulbound(
+    ctx: ContractContext,
+    state: ContractState,
+    soulbound: bool,
+    holder_burn: bool,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_set_soulbound(
+        &ctx,
+        &mut state.mpc1155,
+        &SetSoulboundMsg {
+            soulbound,
+            holder_burn,
+        },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x23)]
+pub fn set_token_soulbound(
+    ctx: ContractContext,
+    state: ContractState,
+    token_id: u128,
+    soulbound: bool,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_set_token_soulbound(
+        &ctx,
+        &mut state.mpc1155,
+        &SetTokenSoulboundMsg {
+            token_id,
+            soulbound,
+        },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x24)]
+pub fn create(
+    ctx: ContractContext,
+    state: ContractState,
+    token_id: u128,
+    token_uri: Option<String>,
+    royalty: Option<RoyaltyInfo>,
+    max_supply: Option<u128>,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_create(
+        &ctx,
+        &mut state.mpc1155,
+        &CreateMsg {
+            token_id,
+            token_uri,
+            royalty,
+            max_supply,
+        },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x25)]
+pub fn set_token_uri(
+    ctx: ContractContext,
+    state: ContractState,
+    token_id: u128,
+    token_uri: Option<String>,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_set_token_uri(
+        &ctx,
+        &mut state.mpc1155,
+        &SetTokenUriMsg {
+            token_id,
+            token_uri,
+        },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x30)]
+pub fn freeze_token_uri(
+    ctx: ContractContext,
+    state: ContractState,
+    token_id: u128,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events =
+        execute_freeze_token_uri(&ctx, &mut state.mpc1155, &FreezeTokenUriMsg { token_id });
+
+    (state, events)
+}
+
+#[action(shortname = 0x26)]
+pub fn set_token_minter(
+    ctx: ContractContext,
+    state: ContractState,
+    token_id: u128,
+    new_minter: Address,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_set_token_minter(
+        &ctx,
+        &mut state.mpc1155,
+        &SetTokenMinterMsg {
+            token_id,
+            new_minter,
+        },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x27)]
+pub fn update_minter(
+    ctx: ContractContext,
+    state: ContractState,
+    new_minter: Address,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_update_minter(&ctx, &mut state.mpc1155, &UpdateMinterMsg { new_minter });
+
+    (state, events)
+}
+
+#[action(shortname = 0x28)]
+pub fn transfer_ownership(
+    ctx: ContractContext,
+    state: ContractState,
+    new_owner: Option<Address>,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_transfer_ownership(
+        &ctx,
+        &mut state.mpc1155,
+        &TransferOwnershipMsg { new_owner },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x29)]
+pub fn set_creator(
+    ctx: ContractContext,
+    state: ContractState,
+    creator: Address,
+    allowed: bool,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_set_creator(
+        &ctx,
+        &mut state.mpc1155,
+        &SetCreatorMsg { creator, allowed },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x2A)]
+pub fn set_public_creation(
+    ctx: ContractContext,
+    state: ContractState,
+    public_creation: bool,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_set_public_creation(
+        &ctx,
+        &mut state.mpc1155,
+        &SetPublicCreationMsg { public_creation },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x2B)]
+pub fn approve(
+    ctx: ContractContext,
+    state: ContractState,
+    spender: Address,
+    token_id: u128,
+    amount: u128,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_approve(
+        &ctx,
+        &mut state.mpc1155,
+        &ApproveMsg {
+            spender,
+            token_id,
+            amount,
+        },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x2C)]
+pub fn increase_allowance(
+    ctx: ContractContext,
+    state: ContractState,
+    spender: Address,
+    token_id: u128,
+    amount: u128,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_increase_allowance(
+        &ctx,
+        &mut state.mpc1155,
+        &IncreaseAllowanceMsg {
+            spender,
+            token_id,
+            amount,
+        },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x2D)]
+pub fn decrease_allowance(
+    ctx: ContractContext,
+    state: ContractState,
+    spender: Address,
+    token_id: u128,
+    amount: u128,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_decrease_allowance(
+        &ctx,
+        &mut state.mpc1155,
+        &DecreaseAllowanceMsg {
+            spender,
+            token_id,
+            amount,
+        },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x2E)]
+pub fn safe_transfer_from(
+    ctx: ContractContext,
+    state: ContractState,
+    from: Address,
+    to: Address,
+    token_info: TokenTransferInfoMsg,
+    data: Vec<u8>,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_safe_transfer_from(
+        &ctx,
+        &mut state.mpc1155,
+        &SafeTransferFromMsg {
+            from,
+            to,
+            token_info,
+            data,
+        },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x2F)]
+pub fn safe_batch_transfer_from(
+    ctx: ContractContext,
+    state: ContractState,
+    from: Address,
+    to: Address,
+    token_infos: Vec<TokenTransferInfoMsg>,
+    data: Vec<u8>,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_safe_batch_transfer_from(
+        &ctx,
+        &mut state.mpc1155,
+        &SafeBatchTransferFromMsg {
+            from,
+            to,
+            token_infos,
+            data,
+        },
+    );
+
+    (state, events)
+}
+
+#[callback(shortname = 0x31)]
+pub fn on_safe_transfer(
+    ctx: ContractContext,
+    callback_ctx: CallbackContext,
+    state: ContractState,
+    msg: SafeTransferCallbackMsg,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_on_safe_transfer(&ctx, &callback_ctx, &mut state.mpc1155, &msg);
+
+    (state, events)
+}
+
+#[action(shortname = 0x40)]
+pub fn balance_of(
+    ctx: ContractContext,
+    state: ContractState,
+    owner: Address,
+    token_id: u128,
+    callback_action: u32,
+    context: Vec<u8>,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_balance_of(
+        &ctx,
+        &mut state.mpc1155,
+        &BalanceOfMsg {
+            owner,
+            token_id,
+            callback_action,
+            context,
+        },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x41)]
+pub fn balance_of_batch(
+    ctx: ContractContext,
+    state: ContractState,
+    owners: Vec<Address>,
+    token_ids: Vec<u128>,
+    callback_action: u32,
+    context: Vec<u8>,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_balance_of_batch(
+        &ctx,
+        &mut state.mpc1155,
+        &BalanceOfBatchMsg {
+            owners,
+            token_ids,
+            callback_action,
+            context,
+        },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x42)]
+pub fn allowance(
+    ctx: ContractContext,
+    state: ContractState,
+    owner: Address,
+    spender: Address,
+    token_id: u128,
+    callback_action: u32,
+    context: Vec<u8>,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_allowance(
+        &ctx,
+        &mut state.mpc1155,
+        &AllowanceMsg {
+            owner,
+            spender,
+            token_id,
+            callback_action,
+            context,
+        },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x43)]
+pub fn total_supply(
+    ctx: ContractContext,
+    state: ContractState,
+    token_id: u128,
+    callback_action: u32,
+    context: Vec<u8>,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_total_supply(
+        &ctx,
+        &mut state.mpc1155,
+        &TotalSupplyMsg {
+            token_id,
+            callback_action,
+            context,
+        },
+    );
+
+    (state, events)
+}
@@ -1,6 +1,13 @@
-use mpc1155_base::msg::{
-    ApproveForAllMsg, BatchBurnMsg, BatchMintMsg, BatchTransferFromMsg, BurnMsg, CheckBalancesMsg,
-    MintMsg, RevokeForAllMsg, SetUriMsg, TokenMintInfoMsg, TokenTransferInfoMsg, TransferFromMsg,
+use mpc1155_base::{
+    msg::{
+        ApproveForAllMsg, ApproveMsg, BalanceOfBatchMsg, BatchBurnMsg, BatchMintMsg,
+        BatchTransferFromMsg, BurnMsg, CheckBalancesMsg, CreateMsg, FreezeTokenUriMsg, MintMsg,
+        OnMpc1155BatchReceivedMsg, OnMpc1155ReceivedMsg, RevokeForAllMsg, RoyaltyInfoMsg,
+        SafeBatchTransferFromMsg, SafeTransferFromMsg, SetDefaultRoyaltyMsg, SetSoulboundMsg,
+        SetTokenMinterMsg, SetTokenRoyaltyMsg, SetTokenSoulboundMsg, SetUriMsg, TokenMintInfoMsg,
+        TokenTransferInfoMsg, TotalSupplyMsg, TransferFromMsg,
+    },
+    state::RoyaltyInfo,
 };
 use pbc_contract_common::{
     address::{Address, AddressType, Shortname},
@@ -28,6 +35,21 @@ const BURN: u32 = 0x13;
 const BATCH_BURN: u32 = 0x15;
 const REVOKE_FOR_ALL: u32 = 0x17;
 const CHECK_BALANCES: u32 = 0x18;
+const SET_DEFAULT_ROYALTY: u32 = 0x19;
+const SET_TOKEN_ROYALTY: u32 = 0x20;
+const ROYALTY_INFO: u32 = 0x21;
+const SET_SOULBOUND: u32 = 0x22;
+const SET_TOKEN_SOULBOUND: u32 = 0x23;
+const CREATE: u32 = 0x24;
+const SET_TOKEN_MINTER: u32 = 0x26;
+const APPROVE: u32 = 0x2B;
+const SAFE_TRANSFER_FROM: u32 = 0x2E;
+const SAFE_BATCH_TRANSFER_FROM: u32 = 0x2F;
+const FREEZE_TOKEN_URI: u32 = 0x30;
+const BALANCE_OF_BATCH: u32 = 0x41;
+const TOTAL_SUPPLY: u32 = 0x43;
+const ON_MPC1155_RECEIVED: u32 = 0x51;
+const ON_MPC1155_BATCH_RECEIVED: u32 = 0x52;
 
 #[test]
 fn proper_transfer_from_action_call() {
@@ -139,6 +161,8 @@ fn proper_mint_action_call() {
             token_id: 1,
             amount: 100,
             token_uri: Some("uri".to_string()),
+            royalty: None,
+            max_supply: None,
         },
     };
 
@@ -153,6 +177,8 @@ fn proper_mint_action_call() {
             token_id: 1,
             amount: 100,
             token_uri: Some("uri".to_string()),
+            royalty: None,
+            max_supply: None,
         })
         .done();
 
@@ -169,6 +195,8 @@ fn proper_batch_mint_action_call() {
             token_id: 1,
             amount: 100,
             token_uri: Some("uri".to_string()),
+            royalty: None,
+            max_supply: None,
         }],
     };
 
@@ -183,6 +211,8 @@ fn proper_batch_mint_action_call() {
             token_id: 1,
             amount: 100,
             token_uri: Some("uri".to_string()),
+            royalty: None,
+            max_supply: None,
         }])
         .done();
 
@@ -285,3 +315,377 @@ fn proper_check_balances_call() {
 
     assert_eq!(event_group.build(), test_event_group.build());
 }
+
+#[test]
+fn proper_set_default_royalty_action_call() {
+    let dest = mock_address(30u8);
+
+    let royalty = RoyaltyInfo {
+        receiver: mock_address(1u8),
+        basis_points: 500,
+    };
+    let msg = SetDefaultRoyaltyMsg {
+        royalty: Some(royalty.clone()),
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(SET_DEFAULT_ROYALTY))
+        .argument(Some(royalty))
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_set_token_royalty_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = SetTokenRoyaltyMsg {
+        token_id: 1,
+        royalty: None,
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(SET_TOKEN_ROYALTY))
+        .argument(1u128)
+        .argument(None::<RoyaltyInfo>)
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_royalty_info_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = RoyaltyInfoMsg {
+        token_id: 1,
+        sale_price: 1_000,
+        callback_action: 0x41,
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(ROYALTY_INFO))
+        .argument(1u128)
+        .argument(1_000u128)
+        .argument(0x41u32)
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_set_soulbound_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = SetSoulboundMsg {
+        soulbound: true,
+        holder_burn: true,
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(SET_SOULBOUND))
+        .argument(true)
+        .argument(true)
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_set_token_soulbound_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = SetTokenSoulboundMsg {
+        token_id: 1,
+        soulbound: false,
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(SET_TOKEN_SOULBOUND))
+        .argument(1u128)
+        .argument(false)
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_create_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = CreateMsg {
+        token_id: 1,
+        token_uri: Some("1.json".to_string()),
+        royalty: None,
+        max_supply: Some(100),
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(CREATE))
+        .argument(1u128)
+        .argument(Some("1.json".to_string()))
+        .argument(None::<RoyaltyInfo>)
+        .argument(Some(100u128))
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_set_token_minter_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = SetTokenMinterMsg {
+        token_id: 1,
+        new_minter: mock_address(1u8),
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(SET_TOKEN_MINTER))
+        .argument(1u128)
+        .argument(mock_address(1u8))
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_approve_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = ApproveMsg {
+        spender: mock_address(1u8),
+        token_id: 1,
+        amount: 10,
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(APPROVE))
+        .argument(mock_address(1u8))
+        .argument(1u128)
+        .argument(10u128)
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_safe_transfer_from_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = SafeTransferFromMsg {
+        from: mock_address(1u8),
+        to: mock_address(2u8),
+        token_info: TokenTransferInfoMsg {
+            token_id: 1,
+            amount: 100,
+        },
+        data: vec![1u8, 2u8],
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(SAFE_TRANSFER_FROM))
+        .argument(mock_address(1u8))
+        .argument(mock_address(2u8))
+        .argument(TokenTransferInfoMsg {
+            token_id: 1,
+            amount: 100,
+        })
+        .argument(vec![1u8, 2u8])
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_safe_batch_transfer_from_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = SafeBatchTransferFromMsg {
+        from: mock_address(1u8),
+        to: mock_address(2u8),
+        token_infos: vec![TokenTransferInfoMsg {
+            token_id: 1,
+            amount: 100,
+        }],
+        data: vec![],
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(SAFE_BATCH_TRANSFER_FROM))
+        .argument(mock_address(1u8))
+        .argument(mock_address(2u8))
+        .argument(vec![TokenTransferInfoMsg {
+            token_id: 1,
+            amount: 100,
+        }])
+        .argument(Vec::<u8>::new())
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_on_mpc1155_received_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = OnMpc1155ReceivedMsg {
+        operator: mock_address(1u8),
+        from: mock_address(2u8),
+        token_id: 1,
+        amount: 10,
+        data: vec![],
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(ON_MPC1155_RECEIVED))
+        .argument(mock_address(1u8))
+        .argument(mock_address(2u8))
+        .argument(1u128)
+        .argument(10u128)
+        .argument(Vec::<u8>::new())
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_on_mpc1155_batch_received_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = OnMpc1155BatchReceivedMsg {
+        operator: mock_address(1u8),
+        from: mock_address(2u8),
+        token_ids: vec![1, 2],
+        amounts: vec![10, 20],
+        data: vec![],
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(ON_MPC1155_BATCH_RECEIVED))
+        .argument(mock_address(1u8))
+        .argument(mock_address(2u8))
+        .argument(vec![1u128, 2u128])
+        .argument(vec![10u128, 20u128])
+        .argument(Vec::<u8>::new())
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_freeze_token_uri_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = FreezeTokenUriMsg { token_id: 1 };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(FREEZE_TOKEN_URI))
+        .argument(1u128)
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_balance_of_batch_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = BalanceOfBatchMsg {
+        owners: vec![mock_address(1u8)],
+        token_ids: vec![1],
+        callback_action: 0x60,
+        context: vec![1, 2],
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(BALANCE_OF_BATCH))
+        .argument(vec![mock_address(1u8)])
+        .argument(vec![1u128])
+        .argument(0x60u32)
+        .argument(vec![1u8, 2u8])
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_total_supply_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = TotalSupplyMsg {
+        token_id: 1,
+        callback_action: 0x60,
+        context: vec![1, 2],
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(TOTAL_SUPPLY))
+        .argument(1u128)
+        .argument(0x60u32)
+        .argument(vec![1u8, 2u8])
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
@@ -1,11 +1,32 @@
 use crate::state::ContractState;
 
 use contract_version_base::state::ContractVersionBase;
-use pbc_contract_common::{address::Address, context::ContractContext, events::EventGroup};
+use pbc_contract_common::{
+    address::Address,
+    context::{CallbackContext, ContractContext},
+    events::EventGroup,
+};
 
 use mpc20_staking_base::{
-    actions::{execute_claim, execute_compound, execute_init, execute_stake, execute_unstake},
-    msg::{ClaimMsg, CompoundMsg, Mpc20StakingInitMsg, StakeMsg, UnstakeMsg},
+    actions::{
+        execute_add_reward_stream, execute_claim, execute_claim_stream, execute_compound,
+        execute_fund_reward_program, execute_fund_reward_stream, execute_init,
+        execute_instant_unstake, execute_on_claim, execute_on_claim_stream,
+        execute_on_fund_program, execute_on_fund_stream, execute_on_instant_unstake,
+        execute_on_penalty, execute_on_stake, execute_on_unstake, execute_on_withdraw,
+        execute_on_withdraw_program, execute_pay_penalties, execute_set_distribution_amount,
+        execute_set_unbonding, execute_stake, execute_unstake, execute_withdraw,
+        execute_withdraw_reward_program,
+    },
+    msg::{
+        AddRewardStreamMsg, ClaimCallbackMsg, ClaimMsg, ClaimStreamCallbackMsg, ClaimStreamMsg,
+        CompoundMsg, FundProgramCallbackMsg, FundRewardProgramMsg, FundRewardStreamMsg,
+        FundStreamCallbackMsg, InstantUnstakeCallbackMsg, InstantUnstakeMsg, Mpc20StakingInitMsg,
+        PayPenaltiesMsg, PenaltyCallbackMsg, SetDistributionAmountMsg, SetUnbondingMsg,
+        StakeCallbackMsg, StakeMsg, UnstakeCallbackMsg, UnstakeMsg, WithdrawCallbackMsg,
+        WithdrawMsg, WithdrawProgramCallbackMsg, WithdrawRewardProgramMsg,
+    },
+    state::UnstakePenalty,
 };
 
 use mpc20_base::{
@@ -90,6 +111,304 @@ pub fn compound(
     (state, events)
 }
 
+#[action(shortname = 0x25)]
+pub fn add_reward_stream(
+    ctx: ContractContext,
+    state: ContractState,
+    token: Address,
+    distribution_amount: u128,
+    distribution_epoch: u64,
+    end_time: u64,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_add_reward_stream(
+        &ctx,
+        &mut state.mpc20_staking,
+        &AddRewardStreamMsg {
+            token,
+            distribution_amount,
+            distribution_epoch,
+            end_time,
+        },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x27)]
+pub fn fund_reward_stream(
+    ctx: ContractContext,
+    state: ContractState,
+    token: Address,
+    amount: u128,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_fund_reward_stream(
+        &ctx,
+        &mut state.mpc20_staking,
+        &FundRewardStreamMsg { token, amount },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x29)]
+pub fn claim_stream(
+    ctx: ContractContext,
+    state: ContractState,
+    token: Address,
+    amount: Option<u128>,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_claim_stream(
+        &ctx,
+        &mut state.mpc20_staking,
+        &ClaimStreamMsg { token, amount },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x2B)]
+pub fn set_unbonding(
+    ctx: ContractContext,
+    state: ContractState,
+    unbonding_period: u64,
+    instant_unstake_penalty: Option<UnstakePenalty>,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_set_unbonding(
+        &ctx,
+        &mut state.mpc20_staking,
+        &SetUnbondingMsg {
+            unbonding_period,
+            instant_unstake_penalty,
+        },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x2D)]
+pub fn instant_unstake(
+    ctx: ContractContext,
+    state: ContractState,
+    amount: u128,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_instant_unstake(
+        &ctx,
+        &mut state.mpc20_staking,
+        &InstantUnstakeMsg { amount },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x2F)]
+pub fn withdraw(ctx: ContractContext, state: ContractState) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_withdraw(&ctx, &mut state.mpc20_staking, &WithdrawMsg {});
+
+    (state, events)
+}
+
+#[action(shortname = 0x51)]
+pub fn fund_reward_program(
+    ctx: ContractContext,
+    state: ContractState,
+    amount: u128,
+    end_time: u64,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_fund_reward_program(
+        &ctx,
+        &mut state.mpc20_staking,
+        &FundRewardProgramMsg { amount, end_time },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x53)]
+pub fn set_distribution_amount(
+    ctx: ContractContext,
+    state: ContractState,
+    distribution_amount: u128,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_set_distribution_amount(
+        &ctx,
+        &mut state.mpc20_staking,
+        &SetDistributionAmountMsg {
+            distribution_amount,
+        },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x55)]
+pub fn pay_penalties(
+    ctx: ContractContext,
+    state: ContractState,
+    treasury: Address,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_pay_penalties(
+        &ctx,
+        &mut state.mpc20_staking,
+        &PayPenaltiesMsg { treasury },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x57)]
+pub fn withdraw_reward_program(
+    ctx: ContractContext,
+    state: ContractState,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_withdraw_reward_program(
+        &ctx,
+        &mut state.mpc20_staking,
+        &WithdrawRewardProgramMsg {},
+    );
+
+    (state, events)
+}
+
+#[callback(shortname = 0x31)]
+pub fn on_stake(
+    ctx: ContractContext,
+    callback_ctx: CallbackContext,
+    state: ContractState,
+    msg: StakeCallbackMsg,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_on_stake(&ctx, &callback_ctx, &mut state.mpc20_staking, &msg);
+
+    (state, events)
+}
+
+#[callback(shortname = 0x33)]
+pub fn on_unstake(
+    ctx: ContractContext,
+    callback_ctx: CallbackContext,
+    state: ContractState,
+    msg: UnstakeCallbackMsg,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_on_unstake(&ctx, &callback_ctx, &mut state.mpc20_staking, &msg);
+
+    (state, events)
+}
+
+#[callback(shortname = 0x35)]
+pub fn on_fund_stream(
+    ctx: ContractContext,
+    callback_ctx: CallbackContext,
+    state: ContractState,
+    msg: FundStreamCallbackMsg,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_on_fund_stream(&ctx, &callback_ctx, &mut state.mpc20_staking, &msg);
+
+    (state, events)
+}
+
+#[callback(shortname = 0x37)]
+pub fn on_claim_stream(
+    ctx: ContractContext,
+    callback_ctx: CallbackContext,
+    state: ContractState,
+    msg: ClaimStreamCallbackMsg,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_on_claim_stream(&ctx, &callback_ctx, &mut state.mpc20_staking, &msg);
+
+    (state, events)
+}
+
+#[callback(shortname = 0x39)]
+pub fn on_withdraw(
+    ctx: ContractContext,
+    callback_ctx: CallbackContext,
+    state: ContractState,
+    msg: WithdrawCallbackMsg,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_on_withdraw(&ctx, &callback_ctx, &mut state.mpc20_staking, &msg);
+
+    (state, events)
+}
+
+#[callback(shortname = 0x3B)]
+pub fn on_instant_unstake(
+    ctx: ContractContext,
+    callback_ctx: CallbackContext,
+    state: ContractState,
+    msg: InstantUnstakeCallbackMsg,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_on_instant_unstake(&ctx, &callback_ctx, &mut state.mpc20_staking, &msg);
+
+    (state, events)
+}
+
+#[callback(shortname = 0x3D)]
+pub fn on_fund_program(
+    ctx: ContractContext,
+    callback_ctx: CallbackContext,
+    state: ContractState,
+    msg: FundProgramCallbackMsg,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_on_fund_program(&ctx, &callback_ctx, &mut state.mpc20_staking, &msg);
+
+    (state, events)
+}
+
+#[callback(shortname = 0x3F)]
+pub fn on_claim(
+    ctx: ContractContext,
+    callback_ctx: CallbackContext,
+    state: ContractState,
+    msg: ClaimCallbackMsg,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_on_claim(&ctx, &callback_ctx, &mut state.mpc20_staking, &msg);
+
+    (state, events)
+}
+
+#[callback(shortname = 0x41)]
+pub fn on_penalty(
+    ctx: ContractContext,
+    callback_ctx: CallbackContext,
+    state: ContractState,
+    msg: PenaltyCallbackMsg,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_on_penalty(&ctx, &callback_ctx, &mut state.mpc20_staking, &msg);
+
+    (state, events)
+}
+
+#[callback(shortname = 0x43)]
+pub fn on_withdraw_program(
+    ctx: ContractContext,
+    callback_ctx: CallbackContext,
+    state: ContractState,
+    msg: WithdrawProgramCallbackMsg,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_on_withdraw_program(&ctx, &callback_ctx, &mut state.mpc20_staking, &msg);
+
+    (state, events)
+}
+
 // ----- MPC20 Base Methods -----
 #[action(shortname = 0x01)]
 pub fn transfer(
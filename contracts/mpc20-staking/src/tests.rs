@@ -1,4 +1,11 @@
-use mpc20_staking_base::msg::{ClaimMsg, CompoundMsg, StakeMsg, UnstakeMsg};
+use mpc20_staking_base::{
+    msg::{
+        AddRewardStreamMsg, ClaimMsg, ClaimStreamMsg, CompoundMsg, FundRewardProgramMsg,
+        FundRewardStreamMsg, InstantUnstakeMsg, PayPenaltiesMsg, SetDistributionAmountMsg,
+        SetUnbondingMsg, StakeMsg, UnstakeMsg, WithdrawMsg,
+    },
+    state::UnstakePenalty,
+};
 use pbc_contract_common::{
     address::{Address, AddressType, Shortname},
     events::EventGroup,
@@ -19,6 +26,15 @@ const STAKE: u32 = 0x17;
 const UNSTAKE: u32 = 0x19;
 const CLAIM: u32 = 0x21;
 const COMPOUND: u32 = 0x23;
+const ADD_REWARD_STREAM: u32 = 0x25;
+const FUND_REWARD_STREAM: u32 = 0x27;
+const CLAIM_STREAM: u32 = 0x29;
+const SET_UNBONDING: u32 = 0x2B;
+const INSTANT_UNSTAKE: u32 = 0x2D;
+const WITHDRAW: u32 = 0x2F;
+const FUND_REWARD_PROGRAM: u32 = 0x51;
+const SET_DISTRIBUTION_AMOUNT: u32 = 0x53;
+const PAY_PENALTIES: u32 = 0x55;
 
 #[test]
 fn proper_stake_action_call() {
@@ -91,3 +107,198 @@ fn proper_compound_action_call() {
 
     assert_eq!(event_group.build(), test_event_group.build());
 }
+
+#[test]
+fn proper_add_reward_stream_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = AddRewardStreamMsg {
+        token: mock_address(1u8),
+        distribution_amount: 100,
+        distribution_epoch: 10,
+        end_time: 1000,
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(ADD_REWARD_STREAM))
+        .argument(mock_address(1u8))
+        .argument(100u128)
+        .argument(10u64)
+        .argument(1000u64)
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_fund_reward_stream_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = FundRewardStreamMsg {
+        token: mock_address(1u8),
+        amount: 100,
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(FUND_REWARD_STREAM))
+        .argument(mock_address(1u8))
+        .argument(100u128)
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_claim_stream_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = ClaimStreamMsg {
+        token: mock_address(1u8),
+        amount: Some(100),
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(CLAIM_STREAM))
+        .argument(mock_address(1u8))
+        .argument(Some(100u128))
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_set_unbonding_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = SetUnbondingMsg {
+        unbonding_period: 100,
+        instant_unstake_penalty: Some(UnstakePenalty {
+            basis_points: 500,
+            treasury: mock_address(1u8),
+        }),
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(SET_UNBONDING))
+        .argument(100u64)
+        .argument(Some(UnstakePenalty {
+            basis_points: 500,
+            treasury: mock_address(1u8),
+        }))
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_instant_unstake_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = InstantUnstakeMsg { amount: 100 };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(INSTANT_UNSTAKE))
+        .argument(100u128)
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_withdraw_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = WithdrawMsg {};
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(WITHDRAW))
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_fund_reward_program_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = FundRewardProgramMsg {
+        amount: 1000,
+        end_time: 2000,
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(FUND_REWARD_PROGRAM))
+        .argument(1000u128)
+        .argument(2000u64)
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_set_distribution_amount_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = SetDistributionAmountMsg {
+        distribution_amount: 100,
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(SET_DISTRIBUTION_AMOUNT))
+        .argument(100u128)
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_pay_penalties_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = PayPenaltiesMsg {
+        treasury: mock_address(5u8),
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(PAY_PENALTIES))
+        .argument(mock_address(5u8))
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
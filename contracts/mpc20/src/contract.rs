@@ -5,13 +5,13 @@ use pbc_contract_common::{address::Address, context::ContractContext, events::Ev
 
 use mpc20_base::{
     actions::{
-        execute_approve, execute_burn, execute_burn_from, execute_decrease_allowance,
-        execute_increase_allowance, execute_init, execute_mint, execute_transfer,
-        execute_transfer_from,
+        execute_allowance, execute_approve, execute_balance, execute_burn, execute_burn_from,
+        execute_decrease_allowance, execute_increase_allowance, execute_init, execute_mint,
+        execute_total_supply, execute_transfer, execute_transfer_from,
     },
     msg::{
-        ApproveMsg, BurnFromMsg, BurnMsg, DecreaseAllowanceMsg, IncreaseAllowanceMsg, MintMsg,
-        Mpc20InitMsg, TransferFromMsg, TransferMsg,
+        AllowanceMsg, ApproveMsg, BalanceMsg, BurnFromMsg, BurnMsg, DecreaseAllowanceMsg,
+        IncreaseAllowanceMsg, MintMsg, Mpc20InitMsg, TotalSupplyMsg, TransferFromMsg, TransferMsg,
     },
 };
 
@@ -144,3 +144,69 @@ pub fn decrease_allowance(
 
     (state, events)
 }
+
+#[action(shortname = 0x40)]
+pub fn balance(
+    ctx: ContractContext,
+    state: TokenState,
+    owner: Address,
+    callback_action: u32,
+    context: Vec<u8>,
+) -> (TokenState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_balance(
+        &ctx,
+        &mut state.mpc20,
+        &BalanceMsg {
+            owner,
+            callback_action,
+            context,
+        },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x41)]
+pub fn allowance(
+    ctx: ContractContext,
+    state: TokenState,
+    owner: Address,
+    spender: Address,
+    callback_action: u32,
+    context: Vec<u8>,
+) -> (TokenState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_allowance(
+        &ctx,
+        &mut state.mpc20,
+        &AllowanceMsg {
+            owner,
+            spender,
+            callback_action,
+            context,
+        },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x42)]
+pub fn total_supply(
+    ctx: ContractContext,
+    state: TokenState,
+    callback_action: u32,
+    context: Vec<u8>,
+) -> (TokenState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_total_supply(
+        &ctx,
+        &mut state.mpc20,
+        &TotalSupplyMsg {
+            callback_action,
+            context,
+        },
+    );
+
+    (state, events)
+}
@@ -1,6 +1,6 @@
 use mpc20_base::msg::{
-    ApproveMsg, BurnFromMsg, BurnMsg, DecreaseAllowanceMsg, IncreaseAllowanceMsg, MintMsg,
-    TransferFromMsg, TransferMsg,
+    AllowanceMsg, ApproveMsg, BalanceMsg, BurnFromMsg, BurnMsg, DecreaseAllowanceMsg,
+    IncreaseAllowanceMsg, MintMsg, TotalSupplyMsg, TransferFromMsg, TransferMsg,
 };
 use pbc_contract_common::{
     address::{Address, AddressType, Shortname},
@@ -26,6 +26,9 @@ const BURN: u32 = 0x09;
 const BURN_FROM: u32 = 0x11;
 const INCREASE_ALLOWANCE: u32 = 0x13;
 const DECREASE_ALLOWANCE: u32 = 0x15;
+const BALANCE: u32 = 0x40;
+const ALLOWANCE: u32 = 0x41;
+const TOTAL_SUPPLY: u32 = 0x42;
 
 #[test]
 fn proper_transfer_action_call() {
@@ -200,3 +203,75 @@ fn proper_decrease_allowance_action_call() {
 
     assert_eq!(event_group.build(), test_event_group.build());
 }
+
+#[test]
+fn proper_balance_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = BalanceMsg {
+        owner: mock_address(1u8),
+        callback_action: 0x50,
+        context: vec![1, 2],
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(BALANCE))
+        .argument(mock_address(1u8))
+        .argument(0x50u32)
+        .argument(vec![1u8, 2u8])
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_allowance_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = AllowanceMsg {
+        owner: mock_address(1u8),
+        spender: mock_address(2u8),
+        callback_action: 0x50,
+        context: vec![1, 2],
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(ALLOWANCE))
+        .argument(mock_address(1u8))
+        .argument(mock_address(2u8))
+        .argument(0x50u32)
+        .argument(vec![1u8, 2u8])
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_total_supply_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = TotalSupplyMsg {
+        callback_action: 0x50,
+        context: vec![1, 2],
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(TOTAL_SUPPLY))
+        .argument(0x50u32)
+        .argument(vec![1u8, 2u8])
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
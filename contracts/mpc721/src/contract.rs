@@ -1,18 +1,37 @@
 use crate::state::ContractState;
 
 use contract_version_base::state::ContractVersionBase;
-use pbc_contract_common::{address::Address, context::ContractContext, events::EventGroup};
+use pbc_contract_common::{
+    address::Address,
+    context::{CallbackContext, ContractContext},
+    events::EventGroup,
+};
 
 use mpc721_base::{
     actions::{
-        execute_approve, execute_approve_for_all, execute_burn, execute_init, execute_mint,
-        execute_multi_mint, execute_ownership_check, execute_revoke, execute_revoke_for_all,
-        execute_set_base_uri, execute_transfer, execute_transfer_from, execute_update_minter,
+        execute_approve, execute_approve_for_all, execute_balance_of, execute_burn,
+        execute_freeze_metadata, execute_init, execute_mint, execute_mint_consecutive,
+        execute_mint_next, execute_mint_next_n, execute_multi_mint, execute_on_phase_mint_payment,
+        execute_on_safe_transfer, execute_on_voucher_payment, execute_owner_of,
+        execute_ownership_check, execute_public_mint, execute_redeem_voucher, execute_revoke,
+        execute_revoke_for_all, execute_royalty_info, execute_safe_transfer_from,
+        execute_set_base_uri, execute_set_default_royalty, execute_set_max_supply,
+        execute_set_mint_phase, execute_set_minter_public_key, execute_set_soulbound,
+        execute_set_token_attributes, execute_set_token_royalty, execute_set_token_soulbound,
+        execute_set_token_uri, execute_set_user, execute_total_supply, execute_transfer,
+        execute_transfer_from, execute_update_minter,
     },
     msg::{
-        ApproveForAllMsg, ApproveMsg, BurnMsg, CheckOwnerMsg, InitMsg, MintMsg, MultiMintMsg,
-        RevokeForAllMsg, RevokeMsg, SetBaseUriMsg, TransferFromMsg, TransferMsg, UpdateMinterMsg,
+        ApproveForAllMsg, ApproveMsg, BalanceOfMsg, BurnMsg, CheckOwnerMsg, FreezeMetadataMsg,
+        InitMsg, MintConsecutiveMsg, MintMsg, MintNextMsg, MintNextNMsg, MintVoucher, MultiMintMsg,
+        OwnerOfMsg, PhaseMintCallbackMsg, PublicMintMsg, RedeemVoucherMsg, RevokeForAllMsg,
+        RevokeMsg, RoyaltyInfoMsg, SafeTransferCallbackMsg, SafeTransferFromMsg, SetBaseUriMsg,
+        SetDefaultRoyaltyMsg, SetMaxSupplyMsg, SetMintPhaseMsg, SetMinterPublicKeyMsg,
+        SetSoulboundMsg, SetTokenAttributesMsg, SetTokenRoyaltyMsg, SetTokenSoulboundMsg,
+        SetTokenUriMsg, SetUserMsg, TotalSupplyMsg, TransferFromMsg, TransferMsg, UpdateMinterMsg,
+        VoucherPaymentCallbackMsg,
     },
+    state::{MintPhase, RoyaltyInfo, TokenAttribute},
 };
 
 const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
@@ -189,3 +208,438 @@ pub fn multi_mint(
     execute_multi_mint(&ctx, &mut state.mpc721, &MultiMintMsg { mints });
     (state, vec![])
 }
+
+#[action(shortname = 0x21)]
+pub fn set_default_royalty(
+    ctx: ContractContext,
+    state: ContractState,
+    royalty: Option<RoyaltyInfo>,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events =
+        execute_set_default_royalty(&ctx, &mut state.mpc721, &SetDefaultRoyaltyMsg { royalty });
+
+    (state, events)
+}
+
+#[action(shortname = 0x22)]
+pub fn set_token_royalty(
+    ctx: ContractContext,
+    state: ContractState,
+    token_id: u128,
+    royalty: Option<RoyaltyInfo>,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_set_token_royalty(
+        &ctx,
+        &mut state.mpc721,
+        &SetTokenRoyaltyMsg { token_id, royalty },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x23)]
+pub fn royalty_info(
+    ctx: ContractContext,
+    state: ContractState,
+    token_id: u128,
+    sale_price: u128,
+    callback_action: u32,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_royalty_info(
+        &ctx,
+        &mut state.mpc721,
+        &RoyaltyInfoMsg {
+            token_id,
+            sale_price,
+            callback_action,
+        },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x24)]
+pub fn safe_transfer_from(
+    ctx: ContractContext,
+    state: ContractState,
+    from: Address,
+    to: Address,
+    token_id: u128,
+    data: Vec<u8>,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_safe_transfer_from(
+        &ctx,
+        &mut state.mpc721,
+        &SafeTransferFromMsg {
+            from,
+            to,
+            token_id,
+            data,
+        },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x25)]
+pub fn set_minter_public_key(
+    ctx: ContractContext,
+    state: ContractState,
+    public_key: Vec<u8>,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_set_minter_public_key(
+        &ctx,
+        &mut state.mpc721,
+        &SetMinterPublicKeyMsg { public_key },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x26)]
+pub fn redeem_voucher(
+    ctx: ContractContext,
+    state: ContractState,
+    voucher: MintVoucher,
+    signature: Vec<u8>,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_redeem_voucher(
+        &ctx,
+        &mut state.mpc721,
+        &RedeemVoucherMsg { voucher, signature },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x27)]
+pub fn mint_next(
+    ctx: ContractContext,
+    state: ContractState,
+    to: Address,
+    token_uri: Option<String>,
+    callback_action: Option<u32>,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_mint_next(
+        &ctx,
+        &mut state.mpc721,
+        &MintNextMsg {
+            to,
+            token_uri,
+            callback_action,
+        },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x28)]
+pub fn mint_next_n(
+    ctx: ContractContext,
+    state: ContractState,
+    to: Address,
+    amount: u32,
+    callback_action: Option<u32>,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_mint_next_n(
+        &ctx,
+        &mut state.mpc721,
+        &MintNextNMsg {
+            to,
+            amount,
+            callback_action,
+        },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x29)]
+pub fn set_max_supply(
+    ctx: ContractContext,
+    state: ContractState,
+    max_supply: Option<u128>,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_set_max_supply(&ctx, &mut state.mpc721, &SetMaxSupplyMsg { max_supply });
+
+    (state, events)
+}
+
+#[action(shortname = 0x2A)]
+pub fn set_mint_phase(
+    ctx: ContractContext,
+    state: ContractState,
+    phase_id: u32,
+    phase: Option<MintPhase>,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_set_mint_phase(
+        &ctx,
+        &mut state.mpc721,
+        &SetMintPhaseMsg { phase_id, phase },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x2B)]
+pub fn public_mint(
+    ctx: ContractContext,
+    state: ContractState,
+    phase_id: u32,
+    amount: u32,
+    merkle_proof: Vec<String>,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_public_mint(
+        &ctx,
+        &mut state.mpc721,
+        &PublicMintMsg {
+            phase_id,
+            amount,
+            merkle_proof,
+        },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x2C)]
+pub fn set_soulbound(
+    ctx: ContractContext,
+    state: ContractState,
+    soulbound: bool,
+    holder_burn: bool,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_set_soulbound(
+        &ctx,
+        &mut state.mpc721,
+        &SetSoulboundMsg {
+            soulbound,
+            holder_burn,
+        },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x2D)]
+pub fn set_token_soulbound(
+    ctx: ContractContext,
+    state: ContractState,
+    token_id: u128,
+    soulbound: bool,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_set_token_soulbound(
+        &ctx,
+        &mut state.mpc721,
+        &SetTokenSoulboundMsg {
+            token_id,
+            soulbound,
+        },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x2E)]
+pub fn set_token_uri(
+    ctx: ContractContext,
+    state: ContractState,
+    token_id: u128,
+    token_uri: Option<String>,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_set_token_uri(
+        &ctx,
+        &mut state.mpc721,
+        &SetTokenUriMsg {
+            token_id,
+            token_uri,
+        },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x2F)]
+pub fn set_token_attributes(
+    ctx: ContractContext,
+    state: ContractState,
+    token_id: u128,
+    attributes: Vec<TokenAttribute>,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_set_token_attributes(
+        &ctx,
+        &mut state.mpc721,
+        &SetTokenAttributesMsg {
+            token_id,
+            attributes,
+        },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x30)]
+pub fn freeze_metadata(
+    ctx: ContractContext,
+    state: ContractState,
+    token_id: Option<u128>,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_freeze_metadata(&ctx, &mut state.mpc721, &FreezeMetadataMsg { token_id });
+
+    (state, events)
+}
+
+#[action(shortname = 0x32)]
+pub fn set_user(
+    ctx: ContractContext,
+    state: ContractState,
+    token_id: u128,
+    user: Option<Address>,
+    expires_at: u64,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_set_user(
+        &ctx,
+        &mut state.mpc721,
+        &SetUserMsg {
+            token_id,
+            user,
+            expires_at,
+        },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x34)]
+pub fn mint_consecutive(
+    ctx: ContractContext,
+    state: ContractState,
+    to: Address,
+    amount: u32,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events =
+        execute_mint_consecutive(&ctx, &mut state.mpc721, &MintConsecutiveMsg { to, amount });
+
+    (state, events)
+}
+
+#[callback(shortname = 0x31)]
+pub fn on_safe_transfer(
+    ctx: ContractContext,
+    callback_ctx: CallbackContext,
+    state: ContractState,
+    msg: SafeTransferCallbackMsg,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_on_safe_transfer(&ctx, &callback_ctx, &mut state.mpc721, &msg);
+
+    (state, events)
+}
+
+#[callback(shortname = 0x33)]
+pub fn on_voucher_payment(
+    ctx: ContractContext,
+    callback_ctx: CallbackContext,
+    state: ContractState,
+    msg: VoucherPaymentCallbackMsg,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_on_voucher_payment(&ctx, &callback_ctx, &mut state.mpc721, &msg);
+
+    (state, events)
+}
+
+#[callback(shortname = 0x35)]
+pub fn on_phase_mint_payment(
+    ctx: ContractContext,
+    callback_ctx: CallbackContext,
+    state: ContractState,
+    msg: PhaseMintCallbackMsg,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_on_phase_mint_payment(&ctx, &callback_ctx, &mut state.mpc721, &msg);
+
+    (state, events)
+}
+
+#[action(shortname = 0x40)]
+pub fn balance_of(
+    ctx: ContractContext,
+    state: ContractState,
+    owner: Address,
+    callback_action: u32,
+    context: Vec<u8>,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_balance_of(
+        &ctx,
+        &mut state.mpc721,
+        &BalanceOfMsg {
+            owner,
+            callback_action,
+            context,
+        },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x41)]
+pub fn owner_of(
+    ctx: ContractContext,
+    state: ContractState,
+    token_id: u128,
+    callback_action: u32,
+    context: Vec<u8>,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_owner_of(
+        &ctx,
+        &mut state.mpc721,
+        &OwnerOfMsg {
+            token_id,
+            callback_action,
+            context,
+        },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x42)]
+pub fn total_supply(
+    ctx: ContractContext,
+    state: ContractState,
+    callback_action: u32,
+    context: Vec<u8>,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_total_supply(
+        &ctx,
+        &mut state.mpc721,
+        &TotalSupplyMsg {
+            callback_action,
+            context,
+        },
+    );
+
+    (state, events)
+}
@@ -1,6 +1,14 @@
-use mpc721_base::msg::{
-    ApproveForAllMsg, ApproveMsg, BurnMsg, CheckOwnerMsg, MintMsg, MultiMintMsg, RevokeForAllMsg,
-    RevokeMsg, SetBaseUriMsg, TransferFromMsg, TransferMsg, UpdateMinterMsg,
+use mpc721_base::{
+    msg::{
+        ApproveForAllMsg, ApproveMsg, BalanceOfMsg, BurnMsg, CheckOwnerMsg, FreezeMetadataMsg,
+        MintConsecutiveMsg, MintMsg, MintNextMsg, MintNextNMsg, MintVoucher, MultiMintMsg,
+        OnMpc721ReceivedMsg, OwnerOfMsg, PublicMintMsg, RedeemVoucherMsg, RevokeForAllMsg,
+        RevokeMsg, RoyaltyInfoMsg, SafeTransferFromMsg, SetBaseUriMsg, SetDefaultRoyaltyMsg,
+        SetMaxSupplyMsg, SetMintPhaseMsg, SetMinterPublicKeyMsg, SetSoulboundMsg,
+        SetTokenAttributesMsg, SetTokenRoyaltyMsg, SetTokenSoulboundMsg, SetTokenUriMsg,
+        SetUserMsg, TotalSupplyMsg, TransferFromMsg, TransferMsg, UpdateMinterMsg,
+    },
+    state::{MintPhase, RoyaltyInfo, TokenAttribute},
 };
 
 use pbc_contract_common::{
@@ -33,6 +41,28 @@ const BURN: u32 = 0x17;
 const MULTI_MINT: u32 = 0x20;
 const CHECKOWNER: u32 = 0x18;
 const UPDATE_MINTER: u32 = 0x19;
+const SET_DEFAULT_ROYALTY: u32 = 0x21;
+const SET_TOKEN_ROYALTY: u32 = 0x22;
+const ROYALTY_INFO: u32 = 0x23;
+const SAFE_TRANSFER_FROM: u32 = 0x24;
+const SET_MINTER_PUBLIC_KEY: u32 = 0x25;
+const REDEEM_VOUCHER: u32 = 0x26;
+const MINT_NEXT: u32 = 0x27;
+const MINT_NEXT_N: u32 = 0x28;
+const SET_MAX_SUPPLY: u32 = 0x29;
+const SET_MINT_PHASE: u32 = 0x2A;
+const PUBLIC_MINT: u32 = 0x2B;
+const SET_SOULBOUND: u32 = 0x2C;
+const SET_TOKEN_SOULBOUND: u32 = 0x2D;
+const SET_TOKEN_URI: u32 = 0x2E;
+const SET_TOKEN_ATTRIBUTES: u32 = 0x2F;
+const FREEZE_METADATA: u32 = 0x30;
+const SET_USER: u32 = 0x32;
+const MINT_CONSECUTIVE: u32 = 0x34;
+const BALANCE_OF: u32 = 0x40;
+const OWNER_OF: u32 = 0x41;
+const TOTAL_SUPPLY: u32 = 0x42;
+const ON_MPC721_RECEIVED: u32 = 0x50;
 #[test]
 fn proper_transfer_action_call() {
     let dest = mock_address(30u8);
@@ -309,3 +339,523 @@ fn proper_multi_mint_action_call() {
 
     assert_eq!(event_group.build(), test_event_group.build());
 }
+
+#[test]
+fn proper_set_default_royalty_action_call() {
+    let dest = mock_address(30u8);
+
+    let royalty = RoyaltyInfo {
+        receiver: mock_address(1u8),
+        basis_points: 500,
+    };
+    let msg = SetDefaultRoyaltyMsg {
+        royalty: Some(royalty.clone()),
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(SET_DEFAULT_ROYALTY))
+        .argument(Some(royalty))
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_set_token_royalty_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = SetTokenRoyaltyMsg {
+        token_id: 1,
+        royalty: None,
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(SET_TOKEN_ROYALTY))
+        .argument(1u128)
+        .argument(None::<RoyaltyInfo>)
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_royalty_info_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = RoyaltyInfoMsg {
+        token_id: 1,
+        sale_price: 1_000,
+        callback_action: 0x41,
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(ROYALTY_INFO))
+        .argument(1u128)
+        .argument(1_000u128)
+        .argument(0x41u32)
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_safe_transfer_from_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = SafeTransferFromMsg {
+        from: mock_address(1u8),
+        to: mock_address(2u8),
+        token_id: 1,
+        data: vec![1u8, 2u8],
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(SAFE_TRANSFER_FROM))
+        .argument(mock_address(1u8))
+        .argument(mock_address(2u8))
+        .argument(1u128)
+        .argument(vec![1u8, 2u8])
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_on_mpc721_received_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = OnMpc721ReceivedMsg {
+        operator: mock_address(1u8),
+        from: mock_address(2u8),
+        token_id: 1,
+        data: vec![],
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(ON_MPC721_RECEIVED))
+        .argument(mock_address(1u8))
+        .argument(mock_address(2u8))
+        .argument(1u128)
+        .argument(Vec::<u8>::new())
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_set_minter_public_key_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = SetMinterPublicKeyMsg {
+        public_key: vec![2u8; 33],
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(SET_MINTER_PUBLIC_KEY))
+        .argument(vec![2u8; 33])
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_redeem_voucher_action_call() {
+    let dest = mock_address(30u8);
+
+    let voucher = MintVoucher {
+        token_id: 1,
+        token_uri: Some("1.json".to_string()),
+        currency: mock_address(2u8),
+        price: 100,
+        recipient: None,
+        expires_at: 200,
+    };
+    let msg = RedeemVoucherMsg {
+        voucher: voucher.clone(),
+        signature: vec![1u8; 64],
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(REDEEM_VOUCHER))
+        .argument(voucher)
+        .argument(vec![1u8; 64])
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_mint_next_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = MintNextMsg {
+        to: mock_address(1u8),
+        token_uri: Some("uri".to_string()),
+        callback_action: Some(0x41),
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(MINT_NEXT))
+        .argument(mock_address(1u8))
+        .argument(Some("uri".to_string()))
+        .argument(Some(0x41u32))
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_mint_next_n_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = MintNextNMsg {
+        to: mock_address(1u8),
+        amount: 10,
+        callback_action: None,
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(MINT_NEXT_N))
+        .argument(mock_address(1u8))
+        .argument(10u32)
+        .argument(None::<u32>)
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_set_max_supply_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = SetMaxSupplyMsg {
+        max_supply: Some(1000),
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(SET_MAX_SUPPLY))
+        .argument(Some(1000u128))
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_set_mint_phase_action_call() {
+    let dest = mock_address(30u8);
+
+    let phase = MintPhase {
+        start_time: 100,
+        end_time: 200,
+        currency: mock_address(2u8),
+        price: 10,
+        wallet_limit: Some(2),
+        merkle_root: None,
+    };
+    let msg = SetMintPhaseMsg {
+        phase_id: 1,
+        phase: Some(phase.clone()),
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(SET_MINT_PHASE))
+        .argument(1u32)
+        .argument(Some(phase))
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_public_mint_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = PublicMintMsg {
+        phase_id: 1,
+        amount: 2,
+        merkle_proof: vec!["proof".to_string()],
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(PUBLIC_MINT))
+        .argument(1u32)
+        .argument(2u32)
+        .argument(vec!["proof".to_string()])
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_set_soulbound_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = SetSoulboundMsg {
+        soulbound: true,
+        holder_burn: false,
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(SET_SOULBOUND))
+        .argument(true)
+        .argument(false)
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_set_token_soulbound_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = SetTokenSoulboundMsg {
+        token_id: 1,
+        soulbound: true,
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(SET_TOKEN_SOULBOUND))
+        .argument(1u128)
+        .argument(true)
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_set_token_uri_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = SetTokenUriMsg {
+        token_id: 1,
+        token_uri: Some("uri".to_string()),
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(SET_TOKEN_URI))
+        .argument(1u128)
+        .argument(Some("uri".to_string()))
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_set_token_attributes_action_call() {
+    let dest = mock_address(30u8);
+
+    let attributes = vec![TokenAttribute {
+        key: "color".to_string(),
+        value: Some("red".to_string()),
+    }];
+    let msg = SetTokenAttributesMsg {
+        token_id: 1,
+        attributes: attributes.clone(),
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(SET_TOKEN_ATTRIBUTES))
+        .argument(1u128)
+        .argument(attributes)
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_freeze_metadata_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = FreezeMetadataMsg { token_id: None };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(FREEZE_METADATA))
+        .argument(None::<u128>)
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_set_user_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = SetUserMsg {
+        token_id: 1,
+        user: Some(mock_address(2u8)),
+        expires_at: 200,
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(SET_USER))
+        .argument(1u128)
+        .argument(Some(mock_address(2u8)))
+        .argument(200u64)
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_mint_consecutive_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = MintConsecutiveMsg {
+        to: mock_address(1u8),
+        amount: 10_000,
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(MINT_CONSECUTIVE))
+        .argument(mock_address(1u8))
+        .argument(10_000u32)
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_balance_of_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = BalanceOfMsg {
+        owner: mock_address(1u8),
+        callback_action: 0x60,
+        context: vec![1, 2],
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(BALANCE_OF))
+        .argument(mock_address(1u8))
+        .argument(0x60u32)
+        .argument(vec![1u8, 2u8])
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_owner_of_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = OwnerOfMsg {
+        token_id: 1,
+        callback_action: 0x60,
+        context: vec![1, 2],
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(OWNER_OF))
+        .argument(1u128)
+        .argument(0x60u32)
+        .argument(vec![1u8, 2u8])
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_total_supply_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = TotalSupplyMsg {
+        callback_action: 0x60,
+        context: vec![1, 2],
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(TOTAL_SUPPLY))
+        .argument(0x60u32)
+        .argument(vec![1u8, 2u8])
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
@@ -0,0 +1,255 @@
+use crate::state::ContractState;
+
+use contract_version_base::state::ContractVersionBase;
+use pbc_contract_common::{
+    address::Address,
+    context::{CallbackContext, ContractContext},
+    events::EventGroup,
+};
+
+use nft_fractional_vault_base::{
+    actions::{
+        execute_bid, execute_burn_shares, execute_burn_shares_from, execute_deposit,
+        execute_end_auction, execute_init, execute_on_bid, execute_on_deposit, execute_on_sale,
+        execute_redeem_nft, execute_redeem_proceeds,
+    },
+    msg::{BidCallbackMsg, BidMsg, InitMsg},
+    state::Auction,
+};
+
+use mpc20_base::{
+    actions::{
+        execute_approve as mpc20_execute_approve,
+        execute_decrease_allowance as mpc20_execute_decrease_allowance,
+        execute_increase_allowance as mpc20_execute_increase_allowance,
+        execute_transfer as mpc20_execute_transfer,
+        execute_transfer_from as mpc20_execute_transfer_from,
+    },
+    msg::{
+        ApproveMsg as Mpc20ApproveMsg, BurnFromMsg as Mpc20BurnFromMsg, BurnMsg as Mpc20BurnMsg,
+        DecreaseAllowanceMsg as Mpc20DecreaseAllowanceMsg,
+        IncreaseAllowanceMsg as Mpc20IncreaseAllowanceMsg, TransferFromMsg as Mpc20TransferFromMsg,
+        TransferMsg as Mpc20TransferMsg,
+    },
+};
+use mpc721_base::msg::TransferFromMsg as Mpc721TransferFromMsg;
+
+const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[init]
+pub fn initialize(ctx: ContractContext, msg: InitMsg) -> (ContractState, Vec<EventGroup>) {
+    let (nft_fractional_vault, events) = execute_init(&ctx, &msg);
+    let state = ContractState {
+        nft_fractional_vault,
+        version: ContractVersionBase::new(CONTRACT_NAME, CONTRACT_VERSION),
+    };
+
+    (state, events)
+}
+
+#[action(shortname = 0x17)]
+pub fn deposit(ctx: ContractContext, state: ContractState) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_deposit(&ctx, &mut state.nft_fractional_vault);
+
+    (state, events)
+}
+
+#[action(shortname = 0x19)]
+pub fn bid(
+    ctx: ContractContext,
+    state: ContractState,
+    amount: u128,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_bid(&ctx, &mut state.nft_fractional_vault, &BidMsg { amount });
+
+    (state, events)
+}
+
+#[action(shortname = 0x21)]
+pub fn end_auction(ctx: ContractContext, state: ContractState) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_end_auction(&ctx, &mut state.nft_fractional_vault);
+
+    (state, events)
+}
+
+#[action(shortname = 0x23)]
+pub fn redeem_proceeds(
+    ctx: ContractContext,
+    state: ContractState,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_redeem_proceeds(&ctx, &mut state.nft_fractional_vault);
+
+    (state, events)
+}
+
+#[action(shortname = 0x25)]
+pub fn redeem_nft(ctx: ContractContext, state: ContractState) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_redeem_nft(&ctx, &mut state.nft_fractional_vault);
+
+    (state, events)
+}
+
+#[callback(shortname = 0x31)]
+pub fn on_deposit(
+    ctx: ContractContext,
+    callback_ctx: CallbackContext,
+    state: ContractState,
+    msg: Mpc721TransferFromMsg,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_on_deposit(&ctx, &callback_ctx, &mut state.nft_fractional_vault, &msg);
+
+    (state, events)
+}
+
+#[callback(shortname = 0x33)]
+pub fn on_bid(
+    ctx: ContractContext,
+    callback_ctx: CallbackContext,
+    state: ContractState,
+    msg: BidCallbackMsg,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_on_bid(&ctx, &callback_ctx, &mut state.nft_fractional_vault, &msg);
+
+    (state, events)
+}
+
+#[callback(shortname = 0x35)]
+pub fn on_sale(
+    ctx: ContractContext,
+    callback_ctx: CallbackContext,
+    state: ContractState,
+    msg: Auction,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_on_sale(&ctx, &callback_ctx, &mut state.nft_fractional_vault, &msg);
+
+    (state, events)
+}
+
+// ----- MPC20 Base Methods -----
+#[action(shortname = 0x01)]
+pub fn transfer(
+    ctx: ContractContext,
+    state: ContractState,
+    to: Address,
+    amount: u128,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = mpc20_execute_transfer(
+        &ctx,
+        &mut state.nft_fractional_vault.mpc20,
+        &Mpc20TransferMsg { to, amount },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x03)]
+pub fn transfer_from(
+    ctx: ContractContext,
+    state: ContractState,
+    from: Address,
+    to: Address,
+    amount: u128,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = mpc20_execute_transfer_from(
+        &ctx,
+        &mut state.nft_fractional_vault.mpc20,
+        &Mpc20TransferFromMsg { from, to, amount },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x05)]
+pub fn approve(
+    ctx: ContractContext,
+    state: ContractState,
+    spender: Address,
+    amount: u128,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = mpc20_execute_approve(
+        &ctx,
+        &mut state.nft_fractional_vault.mpc20,
+        &Mpc20ApproveMsg { spender, amount },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x09)]
+pub fn burn(
+    ctx: ContractContext,
+    state: ContractState,
+    amount: u128,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_burn_shares(
+        &ctx,
+        &mut state.nft_fractional_vault,
+        &Mpc20BurnMsg { amount },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x11)]
+pub fn burn_from(
+    ctx: ContractContext,
+    state: ContractState,
+    owner: Address,
+    amount: u128,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_burn_shares_from(
+        &ctx,
+        &mut state.nft_fractional_vault,
+        &Mpc20BurnFromMsg { owner, amount },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x13)]
+pub fn increase_allowance(
+    ctx: ContractContext,
+    state: ContractState,
+    spender: Address,
+    amount: u128,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = mpc20_execute_increase_allowance(
+        &ctx,
+        &mut state.nft_fractional_vault.mpc20,
+        &Mpc20IncreaseAllowanceMsg { spender, amount },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x15)]
+pub fn decrease_allowance(
+    ctx: ContractContext,
+    state: ContractState,
+    spender: Address,
+    amount: u128,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = mpc20_execute_decrease_allowance(
+        &ctx,
+        &mut state.nft_fractional_vault.mpc20,
+        &Mpc20DecreaseAllowanceMsg { spender, amount },
+    );
+
+    (state, events)
+}
@@ -0,0 +1,9 @@
+use contract_version_base::state::ContractVersionBase;
+use nft_fractional_vault_base::state::NFTFractionalVaultContractState;
+
+#[state]
+#[derive(PartialEq, Eq, Debug)]
+pub struct ContractState {
+    pub nft_fractional_vault: NFTFractionalVaultContractState,
+    pub version: ContractVersionBase,
+}
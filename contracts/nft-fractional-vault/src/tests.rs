@@ -0,0 +1,36 @@
+use nft_fractional_vault_base::msg::BidMsg;
+use pbc_contract_common::{
+    address::{Address, AddressType, Shortname},
+    events::EventGroup,
+};
+use utils::events::IntoShortnameRPCEvent;
+
+fn mock_address(le: u8) -> Address {
+    Address {
+        address_type: AddressType::Account,
+        identifier: [
+            le, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8,
+        ],
+    }
+}
+
+const BID: u32 = 0x19;
+
+#[test]
+fn proper_bid_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = BidMsg { amount: 1_000 };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(BID))
+        .argument(1_000u128)
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
@@ -0,0 +1,266 @@
+use crate::state::ContractState;
+
+use contract_version_base::state::ContractVersionBase;
+use pbc_contract_common::{
+    address::Address,
+    context::{CallbackContext, ContractContext},
+    events::EventGroup,
+};
+
+use nft_marketplace_base::{
+    actions::{
+        execute_accept_offer, execute_buy, execute_cancel_listing, execute_cancel_offer,
+        execute_create_listing, execute_create_offer, execute_init, execute_on_ownership_checked,
+        execute_on_payment_received, execute_on_token_transferred, execute_remove_royalty,
+        execute_set_royalty, execute_update_fee,
+    },
+    msg::{
+        AcceptOfferMsg, BuyMsg, CancelListingMsg, CancelOfferMsg, CreateListingMsg, CreateOfferMsg,
+        FillMsg, InitMsg, RemoveRoyaltyMsg, SetRoyaltyMsg, UpdateFeeMsg,
+    },
+    state::TokenType,
+};
+
+const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[init]
+pub fn initialize(ctx: ContractContext, msg: InitMsg) -> (ContractState, Vec<EventGroup>) {
+    let (nft_marketplace, events) = execute_init(&ctx, &msg);
+    let state = ContractState {
+        nft_marketplace,
+        version: ContractVersionBase::new(CONTRACT_NAME, CONTRACT_VERSION),
+    };
+
+    (state, events)
+}
+
+#[action(shortname = 0x01)]
+#[allow(clippy::too_many_arguments)]
+pub fn create_listing(
+    ctx: ContractContext,
+    state: ContractState,
+    token_type: TokenType,
+    collection: Address,
+    token_id: u128,
+    amount: u128,
+    currency: Address,
+    unit_price: u128,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_create_listing(
+        &ctx,
+        &mut state.nft_marketplace,
+        &CreateListingMsg {
+            token_type,
+            collection,
+            token_id,
+            amount,
+            currency,
+            unit_price,
+        },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x03)]
+pub fn cancel_listing(
+    ctx: ContractContext,
+    state: ContractState,
+    listing_id: u64,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_cancel_listing(
+        &ctx,
+        &mut state.nft_marketplace,
+        &CancelListingMsg { listing_id },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x05)]
+pub fn buy(
+    ctx: ContractContext,
+    state: ContractState,
+    listing_id: u64,
+    amount: u128,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_buy(
+        &ctx,
+        &mut state.nft_marketplace,
+        &BuyMsg { listing_id, amount },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x07)]
+#[allow(clippy::too_many_arguments)]
+pub fn create_offer(
+    ctx: ContractContext,
+    state: ContractState,
+    token_type: TokenType,
+    collection: Address,
+    token_id: Option<u128>,
+    amount: u128,
+    currency: Address,
+    unit_price: u128,
+    expires_at: u64,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_create_offer(
+        &ctx,
+        &mut state.nft_marketplace,
+        &CreateOfferMsg {
+            token_type,
+            collection,
+            token_id,
+            amount,
+            currency,
+            unit_price,
+            expires_at,
+        },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x09)]
+pub fn cancel_offer(
+    ctx: ContractContext,
+    state: ContractState,
+    offer_id: u64,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_cancel_offer(
+        &ctx,
+        &mut state.nft_marketplace,
+        &CancelOfferMsg { offer_id },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x11)]
+pub fn accept_offer(
+    ctx: ContractContext,
+    state: ContractState,
+    offer_id: u64,
+    token_id: u128,
+    amount: u128,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_accept_offer(
+        &ctx,
+        &mut state.nft_marketplace,
+        &AcceptOfferMsg {
+            offer_id,
+            token_id,
+            amount,
+        },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x13)]
+pub fn set_royalty(
+    ctx: ContractContext,
+    state: ContractState,
+    collection: Address,
+    receiver: Address,
+    basis_points: u16,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_set_royalty(
+        &ctx,
+        &mut state.nft_marketplace,
+        &SetRoyaltyMsg {
+            collection,
+            receiver,
+            basis_points,
+        },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x15)]
+pub fn remove_royalty(
+    ctx: ContractContext,
+    state: ContractState,
+    collection: Address,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_remove_royalty(
+        &ctx,
+        &mut state.nft_marketplace,
+        &RemoveRoyaltyMsg { collection },
+    );
+
+    (state, events)
+}
+
+#[action(shortname = 0x17)]
+pub fn update_fee(
+    ctx: ContractContext,
+    state: ContractState,
+    fee_receiver: Address,
+    fee_basis_points: u16,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_update_fee(
+        &ctx,
+        &mut state.nft_marketplace,
+        &UpdateFeeMsg {
+            fee_receiver,
+            fee_basis_points,
+        },
+    );
+
+    (state, events)
+}
+
+#[callback(shortname = 0x31)]
+pub fn on_ownership_checked(
+    ctx: ContractContext,
+    callback_ctx: CallbackContext,
+    state: ContractState,
+    msg: FillMsg,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events =
+        execute_on_ownership_checked(&ctx, &callback_ctx, &mut state.nft_marketplace, &msg);
+
+    (state, events)
+}
+
+#[callback(shortname = 0x33)]
+pub fn on_payment_received(
+    ctx: ContractContext,
+    callback_ctx: CallbackContext,
+    state: ContractState,
+    msg: FillMsg,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events = execute_on_payment_received(&ctx, &callback_ctx, &mut state.nft_marketplace, &msg);
+
+    (state, events)
+}
+
+#[callback(shortname = 0x35)]
+pub fn on_token_transferred(
+    ctx: ContractContext,
+    callback_ctx: CallbackContext,
+    state: ContractState,
+    msg: FillMsg,
+) -> (ContractState, Vec<EventGroup>) {
+    let mut state = state;
+    let events =
+        execute_on_token_transferred(&ctx, &callback_ctx, &mut state.nft_marketplace, &msg);
+
+    (state, events)
+}
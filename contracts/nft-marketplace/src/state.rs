@@ -0,0 +1,9 @@
+use contract_version_base::state::ContractVersionBase;
+use nft_marketplace_base::state::NFTMarketplaceContractState;
+
+#[state]
+#[derive(PartialEq, Eq, Debug)]
+pub struct ContractState {
+    pub nft_marketplace: NFTMarketplaceContractState,
+    pub version: ContractVersionBase,
+}
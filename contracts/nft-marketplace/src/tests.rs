@@ -0,0 +1,242 @@
+use nft_marketplace_base::{
+    msg::{
+        AcceptOfferMsg, BuyMsg, CancelListingMsg, CancelOfferMsg, CreateListingMsg, CreateOfferMsg,
+        RemoveRoyaltyMsg, SetRoyaltyMsg, UpdateFeeMsg,
+    },
+    state::MPC721_TOKEN_TYPE,
+};
+use pbc_contract_common::{
+    address::{Address, AddressType, Shortname},
+    events::EventGroup,
+};
+use utils::events::IntoShortnameRPCEvent;
+
+fn mock_address(le: u8) -> Address {
+    Address {
+        address_type: AddressType::Account,
+        identifier: [
+            le, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8,
+        ],
+    }
+}
+
+const CREATE_LISTING: u32 = 0x01;
+const CANCEL_LISTING: u32 = 0x03;
+const BUY: u32 = 0x05;
+const CREATE_OFFER: u32 = 0x07;
+const CANCEL_OFFER: u32 = 0x09;
+const ACCEPT_OFFER: u32 = 0x11;
+const SET_ROYALTY: u32 = 0x13;
+const REMOVE_ROYALTY: u32 = 0x15;
+const UPDATE_FEE: u32 = 0x17;
+
+#[test]
+fn proper_create_listing_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = CreateListingMsg {
+        token_type: MPC721_TOKEN_TYPE,
+        collection: mock_address(1u8),
+        token_id: 1,
+        amount: 1,
+        currency: mock_address(2u8),
+        unit_price: 100,
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(CREATE_LISTING))
+        .argument(MPC721_TOKEN_TYPE)
+        .argument(mock_address(1u8))
+        .argument(1u128)
+        .argument(1u128)
+        .argument(mock_address(2u8))
+        .argument(100u128)
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_cancel_listing_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = CancelListingMsg { listing_id: 1 };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(CANCEL_LISTING))
+        .argument(1u64)
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_buy_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = BuyMsg {
+        listing_id: 1,
+        amount: 5,
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(BUY))
+        .argument(1u64)
+        .argument(5u128)
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_create_offer_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = CreateOfferMsg {
+        token_type: MPC721_TOKEN_TYPE,
+        collection: mock_address(1u8),
+        token_id: None,
+        amount: 1,
+        currency: mock_address(2u8),
+        unit_price: 100,
+        expires_at: 1000,
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(CREATE_OFFER))
+        .argument(MPC721_TOKEN_TYPE)
+        .argument(mock_address(1u8))
+        .argument(None::<u128>)
+        .argument(1u128)
+        .argument(mock_address(2u8))
+        .argument(100u128)
+        .argument(1000u64)
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_cancel_offer_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = CancelOfferMsg { offer_id: 1 };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(CANCEL_OFFER))
+        .argument(1u64)
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_accept_offer_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = AcceptOfferMsg {
+        offer_id: 1,
+        token_id: 2,
+        amount: 1,
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(ACCEPT_OFFER))
+        .argument(1u64)
+        .argument(2u128)
+        .argument(1u128)
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_set_royalty_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = SetRoyaltyMsg {
+        collection: mock_address(1u8),
+        receiver: mock_address(2u8),
+        basis_points: 500,
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(SET_ROYALTY))
+        .argument(mock_address(1u8))
+        .argument(mock_address(2u8))
+        .argument(500u16)
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_remove_royalty_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = RemoveRoyaltyMsg {
+        collection: mock_address(1u8),
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(REMOVE_ROYALTY))
+        .argument(mock_address(1u8))
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_update_fee_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = UpdateFeeMsg {
+        fee_receiver: mock_address(1u8),
+        fee_basis_points: 250,
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(UPDATE_FEE))
+        .argument(mock_address(1u8))
+        .argument(250u16)
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
@@ -0,0 +1,10 @@
+#![allow(unused_variables)]
+
+#[macro_use]
+extern crate pbc_contract_codegen;
+
+pub(crate) mod contract;
+pub mod state;
+
+#[cfg(test)]
+mod tests;
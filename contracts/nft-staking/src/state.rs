@@ -0,0 +1,9 @@
+use contract_version_base::state::ContractVersionBase;
+use nft_staking_base::state::NFTStakingContractState;
+
+#[state]
+#[derive(PartialEq, Eq, Debug)]
+pub struct ContractState {
+    pub nft_staking: NFTStakingContractState,
+    pub version: ContractVersionBase,
+}
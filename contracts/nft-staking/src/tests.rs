@@ -0,0 +1,63 @@
+use nft_staking_base::msg::{StakeMsg, UnstakeMsg};
+use pbc_contract_common::{
+    address::{Address, AddressType, Shortname},
+    events::EventGroup,
+};
+use utils::events::IntoShortnameRPCEvent;
+
+fn mock_address(le: u8) -> Address {
+    Address {
+        address_type: AddressType::Account,
+        identifier: [
+            le, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8,
+        ],
+    }
+}
+
+const STAKE: u32 = 0x17;
+const UNSTAKE: u32 = 0x19;
+
+#[test]
+fn proper_stake_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = StakeMsg {
+        collection: mock_address(1u8),
+        token_id: 7,
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(STAKE))
+        .argument(mock_address(1u8))
+        .argument(7u128)
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
+
+#[test]
+fn proper_unstake_action_call() {
+    let dest = mock_address(30u8);
+
+    let msg = UnstakeMsg {
+        collection: mock_address(1u8),
+        token_ids: vec![1, 2, 3],
+    };
+
+    let mut event_group = EventGroup::builder();
+    msg.as_interaction(&mut event_group, &dest);
+
+    let mut test_event_group = EventGroup::builder();
+    test_event_group
+        .call(dest, Shortname::from_u32(UNSTAKE))
+        .argument(mock_address(1u8))
+        .argument(vec![1u128, 2u128, 3u128])
+        .done();
+
+    assert_eq!(event_group.build(), test_event_group.build());
+}
@@ -1,11 +1,24 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
-use pbc_contract_common::{context::ContractContext, events::EventGroup};
+use pbc_contract_common::{
+    address::{Address, AddressType},
+    context::{CallbackContext, ContractContext},
+    events::EventGroup,
+};
+use utils::events::{build_msg_callback, build_msg_reply, IntoShortnameRPCEvent};
 
 use crate::{
     msg::{
-        ApproveForAllMsg, BatchBurnMsg, BatchMintMsg, BatchTransferFromMsg, BurnMsg,
-        CheckBalancesMsg, InitMsg, MintMsg, RevokeForAllMsg, SetUriMsg, TransferFromMsg,
+        AllowanceMsg, AllowanceReplyMsg, ApproveForAllMsg, ApproveMsg, BalanceOfBatchMsg,
+        BalanceOfBatchReplyMsg, BalanceOfMsg, BalanceOfReplyMsg, BatchBurnMsg, BatchMintMsg,
+        BatchTransferFromMsg, BurnMsg, CheckBalancesMsg, CreateMsg, DecreaseAllowanceMsg,
+        FreezeTokenUriMsg, IncreaseAllowanceMsg, InitMsg, MintMsg, OnMpc1155BatchReceivedMsg,
+        OnMpc1155ReceivedMsg, RevokeForAllMsg, RoyaltyInfoMsg, RoyaltyInfoReplyMsg,
+        SafeBatchTransferFromMsg, SafeTransferCallbackMsg, SafeTransferFromMsg, SetCreatorMsg,
+        SetDefaultRoyaltyMsg, SetPublicCreationMsg, SetSoulboundMsg, SetTokenMinterMsg,
+        SetTokenRoyaltyMsg, SetTokenSoulboundMsg, SetTokenUriMsg, SetUriMsg, TokenTransferInfoMsg,
+        TotalSupplyMsg, TotalSupplyReplyMsg, TransferFromMsg, TransferOwnershipMsg,
+        UpdateMinterMsg, ON_SAFE_TRANSFER_CALLBACK,
     },
     state::{MPC1155ContractState, TokenInfo},
     ContractError,
@@ -30,6 +43,16 @@ pub fn execute_init(
         balances: BTreeMap::new(),
         operator_approvals: BTreeMap::new(),
         tokens: BTreeMap::new(),
+        default_royalty: None,
+        token_royalties: BTreeMap::new(),
+        soulbound: false,
+        soulbound_holder_burn: false,
+        soulbound_tokens: BTreeSet::new(),
+        token_minters: BTreeMap::new(),
+        creators: BTreeSet::new(),
+        public_creation: false,
+        allowances: BTreeMap::new(),
+        frozen_tokens: BTreeSet::new(),
     };
 
     (state, vec![])
@@ -61,7 +84,7 @@ pub fn execute_set_uri(
 }
 
 /// ## Description
-/// Mint a new token. Can only be executed by minter account.
+/// Mint a new token. Can only be executed by minter account of the token id.
 /// Returns [`(MPC1155ContractState, Vec<EventGroup>)`] if operation was successful,
 /// otherwise panics with error message defined in [`ContractError`]
 /// ## Params
@@ -76,15 +99,20 @@ pub fn execute_mint(
     msg: &MintMsg,
 ) -> Vec<EventGroup> {
     assert!(
-        state.minter == ctx.sender,
+        state.token_minter(msg.token_info.token_id) == ctx.sender,
         "{}",
         ContractError::Unauthorized
     );
 
+    if state.token_info(msg.token_info.token_id).is_none() {
+        state.set_token_royalty(msg.token_info.token_id, &msg.token_info.royalty);
+    }
     state.store_token(
         msg.token_info.token_id,
         &TokenInfo {
             token_uri: msg.token_info.token_uri.clone(),
+            total_supply: 0,
+            max_supply: msg.token_info.max_supply,
         },
     );
     state.transfer(
@@ -98,7 +126,7 @@ pub fn execute_mint(
 }
 
 /// ## Description
-/// Batch mint a new token. Can only be executed by minter account.
+/// Batch mint a new token. Can only be executed by minter account of every token id.
 /// Returns [`(MPC1155ContractState, Vec<EventGroup>)`] if operation was successful,
 /// otherwise panics with error message defined in [`ContractError`]
 /// ## Params
@@ -112,17 +140,22 @@ pub fn execute_batch_mint(
     state: &mut MPC1155ContractState,
     msg: &BatchMintMsg,
 ) -> Vec<EventGroup> {
-    assert!(
-        state.minter == ctx.sender,
-        "{}",
-        ContractError::Unauthorized
-    );
-
     for token_info in msg.token_infos.iter() {
+        assert!(
+            state.token_minter(token_info.token_id) == ctx.sender,
+            "{}",
+            ContractError::Unauthorized
+        );
+
+        if state.token_info(token_info.token_id).is_none() {
+            state.set_token_royalty(token_info.token_id, &token_info.royalty);
+        }
         state.store_token(
             token_info.token_id,
             &TokenInfo {
                 token_uri: token_info.token_uri.clone(),
+                total_supply: 0,
+                max_supply: token_info.max_supply,
             },
         );
         state.transfer(None, Some(&msg.to), token_info.token_id, token_info.amount);
@@ -146,17 +179,12 @@ pub fn execute_transfer_from(
     state: &mut MPC1155ContractState,
     msg: &TransferFromMsg,
 ) -> Vec<EventGroup> {
-    assert!(
-        state.is_token_owner_or_operator(&msg.from, &ctx.sender),
-        "{}",
-        ContractError::Unauthorized,
-    );
-
-    state.transfer(
-        Some(&msg.from),
-        Some(&msg.to),
-        msg.token_info.token_id,
-        msg.token_info.amount,
+    transfer_from(
+        state,
+        &ctx.sender,
+        &msg.from,
+        &msg.to,
+        std::slice::from_ref(&msg.token_info),
     );
 
     vec![]
@@ -177,26 +205,15 @@ pub fn execute_batch_transfer_from(
     state: &mut MPC1155ContractState,
     msg: &BatchTransferFromMsg,
 ) -> Vec<EventGroup> {
-    assert!(
-        state.is_token_owner_or_operator(&msg.from, &ctx.sender),
-        "{}",
-        ContractError::Unauthorized,
-    );
-
-    for token_info in msg.token_infos.iter() {
-        state.transfer(
-            Some(&msg.from),
-            Some(&msg.to),
-            token_info.token_id,
-            token_info.amount,
-        );
-    }
+    transfer_from(state, &ctx.sender, &msg.from, &msg.to, &msg.token_infos);
 
     vec![]
 }
 
 /// ## Description
 /// Destroy your token forever.
+/// Soulbound tokens can be burned by minter account or,
+/// if holder burn is allowed, by the token owner.
 /// Returns [`(MPC1155ContractState, Vec<EventGroup>)`] if operation was successful,
 /// otherwise panics with error message defined in [`ContractError`]
 /// ## Params
@@ -210,10 +227,11 @@ pub fn execute_burn(
     state: &mut MPC1155ContractState,
     msg: &BurnMsg,
 ) -> Vec<EventGroup> {
-    assert!(
-        state.is_token_owner_or_operator(&msg.from, &ctx.sender),
-        "{}",
-        ContractError::Unauthorized,
+    state.assert_burnable(
+        &msg.from,
+        &ctx.sender,
+        msg.token_info.token_id,
+        msg.token_info.amount,
     );
 
     state.transfer(
@@ -228,6 +246,8 @@ pub fn execute_burn(
 
 /// ## Description
 /// Batch destroy your token forever.
+/// Soulbound tokens can be burned by minter account or,
+/// if holder burn is allowed, by the token owner.
 /// Returns [`(MPC1155ContractState, Vec<EventGroup>)`] if operation was successful,
 /// otherwise panics with error message defined in [`ContractError`]
 /// ## Params
@@ -241,13 +261,13 @@ pub fn execute_batch_burn(
     state: &mut MPC1155ContractState,
     msg: &BatchBurnMsg,
 ) -> Vec<EventGroup> {
-    assert!(
-        state.is_token_owner_or_operator(&msg.from, &ctx.sender),
-        "{}",
-        ContractError::Unauthorized,
-    );
-
     for token_info in msg.token_infos.iter() {
+        state.assert_burnable(
+            &msg.from,
+            &ctx.sender,
+            token_info.token_id,
+            token_info.amount,
+        );
         state.transfer(
             Some(&msg.from),
             None,
@@ -274,6 +294,8 @@ pub fn execute_approve_for_all(
     state: &mut MPC1155ContractState,
     msg: &ApproveForAllMsg,
 ) -> Vec<EventGroup> {
+    assert!(!state.soulbound, "{}", ContractError::SoulboundApproval);
+
     state.add_operator(&ctx.sender, &msg.operator);
     vec![]
 }
@@ -314,3 +336,742 @@ pub fn execute_check_balances(
     state.check_balances(msg.owner, msg.token_ids, msg.amounts);
     vec![]
 }
+
+/// ## Description
+/// Sets or removes contract-wide royalty. Can only be executed by owner account.
+/// Returns [`(MPC1155ContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC1155ContractState`]
+///
+/// * **msg** is an object of type [`SetDefaultRoyaltyMsg`]
+pub fn execute_set_default_royalty(
+    ctx: &ContractContext,
+    state: &mut MPC1155ContractState,
+    msg: &SetDefaultRoyaltyMsg,
+) -> Vec<EventGroup> {
+    assert!(
+        state.is_owner(&ctx.sender),
+        "{}",
+        ContractError::Unauthorized
+    );
+
+    state.set_default_royalty(&msg.royalty);
+    vec![]
+}
+
+/// ## Description
+/// Sets or removes royalty of the minted token id. Can only be executed by owner account.
+/// Returns [`(MPC1155ContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC1155ContractState`]
+///
+/// * **msg** is an object of type [`SetTokenRoyaltyMsg`]
+pub fn execute_set_token_royalty(
+    ctx: &ContractContext,
+    state: &mut MPC1155ContractState,
+    msg: &SetTokenRoyaltyMsg,
+) -> Vec<EventGroup> {
+    assert!(
+        state.is_owner(&ctx.sender),
+        "{}",
+        ContractError::Unauthorized
+    );
+    assert!(
+        state.token_info(msg.token_id).is_some(),
+        "{}",
+        ContractError::NotFound
+    );
+
+    state.set_token_royalty(msg.token_id, &msg.royalty);
+    vec![]
+}
+
+/// ## Description
+/// Reports royalty receiver and amount for the sale price back to the calling contract.
+/// Returns [`(MPC1155ContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC1155ContractState`]
+///
+/// * **msg** is an object of type [`RoyaltyInfoMsg`]
+pub fn execute_royalty_info(
+    ctx: &ContractContext,
+    state: &mut MPC1155ContractState,
+    msg: &RoyaltyInfoMsg,
+) -> Vec<EventGroup> {
+    assert!(
+        state.token_info(msg.token_id).is_some(),
+        "{}",
+        ContractError::NotFound
+    );
+
+    let royalty = state.royalty_info(msg.token_id, msg.sale_price);
+    let reply = RoyaltyInfoReplyMsg {
+        token_id: msg.token_id,
+        sale_price: msg.sale_price,
+        receiver: royalty.map(|(receiver, _)| receiver),
+        royalty_amount: royalty.map(|(_, amount)| amount).unwrap_or(0),
+    };
+
+    let mut event_group = EventGroup::builder();
+    build_msg_reply(&mut event_group, &ctx.sender, msg.callback_action, &reply);
+
+    vec![event_group.build()]
+}
+
+/// ## Description
+/// Reports balance of the holder for the token id back to the calling contract.
+/// Returns [`(MPC1155ContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC1155ContractState`]
+///
+/// * **msg** is an object of type [`BalanceOfMsg`]
+pub fn execute_balance_of(
+    ctx: &ContractContext,
+    state: &mut MPC1155ContractState,
+    msg: &BalanceOfMsg,
+) -> Vec<EventGroup> {
+    let reply = BalanceOfReplyMsg {
+        owner: msg.owner,
+        token_id: msg.token_id,
+        balance: state.balance_of(&msg.owner, msg.token_id),
+        context: msg.context.clone(),
+    };
+
+    let mut event_group = EventGroup::builder();
+    build_msg_reply(&mut event_group, &ctx.sender, msg.callback_action, &reply);
+
+    vec![event_group.build()]
+}
+
+/// ## Description
+/// Reports balances of the holders for the token ids back to the calling contract.
+/// Returns [`(MPC1155ContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC1155ContractState`]
+///
+/// * **msg** is an object of type [`BalanceOfBatchMsg`]
+pub fn execute_balance_of_batch(
+    ctx: &ContractContext,
+    state: &mut MPC1155ContractState,
+    msg: &BalanceOfBatchMsg,
+) -> Vec<EventGroup> {
+    let reply = BalanceOfBatchReplyMsg {
+        owners: msg.owners.clone(),
+        token_ids: msg.token_ids.clone(),
+        balances: state.balance_of_batch(&msg.owners, &msg.token_ids),
+        context: msg.context.clone(),
+    };
+
+    let mut event_group = EventGroup::builder();
+    build_msg_reply(&mut event_group, &ctx.sender, msg.callback_action, &reply);
+
+    vec![event_group.build()]
+}
+
+/// ## Description
+/// Reports amount of the token id spender is allowed to move from the owner back to the calling contract.
+/// Returns [`(MPC1155ContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC1155ContractState`]
+///
+/// * **msg** is an object of type [`AllowanceMsg`]
+pub fn execute_allowance(
+    ctx: &ContractContext,
+    state: &mut MPC1155ContractState,
+    msg: &AllowanceMsg,
+) -> Vec<EventGroup> {
+    let reply = AllowanceReplyMsg {
+        owner: msg.owner,
+        spender: msg.spender,
+        token_id: msg.token_id,
+        allowance: state.allowance(&msg.owner, &msg.spender, msg.token_id),
+        context: msg.context.clone(),
+    };
+
+    let mut event_group = EventGroup::builder();
+    build_msg_reply(&mut event_group, &ctx.sender, msg.callback_action, &reply);
+
+    vec![event_group.build()]
+}
+
+/// ## Description
+/// Reports current and maximum supply of the token id back to the calling contract.
+/// Returns [`(MPC1155ContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC1155ContractState`]
+///
+/// * **msg** is an object of type [`TotalSupplyMsg`]
+pub fn execute_total_supply(
+    ctx: &ContractContext,
+    state: &mut MPC1155ContractState,
+    msg: &TotalSupplyMsg,
+) -> Vec<EventGroup> {
+    let reply = TotalSupplyReplyMsg {
+        token_id: msg.token_id,
+        total_supply: state.total_supply(msg.token_id),
+        max_supply: state.max_supply(msg.token_id),
+        context: msg.context.clone(),
+    };
+
+    let mut event_group = EventGroup::builder();
+    build_msg_reply(&mut event_group, &ctx.sender, msg.callback_action, &reply);
+
+    vec![event_group.build()]
+}
+
+/// ## Description
+/// Enables or disables soulbound mode for all tokens. Can only be executed by owner.
+/// Returns [`(MPC1155ContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC1155ContractState`]
+///
+/// * **msg** is an object of type [`SetSoulboundMsg`]
+pub fn execute_set_soulbound(
+    ctx: &ContractContext,
+    state: &mut MPC1155ContractState,
+    msg: &SetSoulboundMsg,
+) -> Vec<EventGroup> {
+    assert!(
+        state.is_owner(&ctx.sender),
+        "{}",
+        ContractError::Unauthorized
+    );
+
+    state.soulbound = msg.soulbound;
+    state.soulbound_holder_burn = msg.holder_burn;
+    vec![]
+}
+
+/// ## Description
+/// Marks or unmarks token id as soulbound. Can only be executed by minter account of the token id or owner.
+/// Returns [`(MPC1155ContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC1155ContractState`]
+///
+/// * **msg** is an object of type [`SetTokenSoulboundMsg`]
+pub fn execute_set_token_soulbound(
+    ctx: &ContractContext,
+    state: &mut MPC1155ContractState,
+    msg: &SetTokenSoulboundMsg,
+) -> Vec<EventGroup> {
+    assert!(
+        state.is_token_admin(&ctx.sender, msg.token_id),
+        "{}",
+        ContractError::Unauthorized
+    );
+
+    if msg.soulbound {
+        state.soulbound_tokens.insert(msg.token_id);
+    } else {
+        state.soulbound_tokens.remove(&msg.token_id);
+    }
+    vec![]
+}
+
+/// ## Description
+/// Creates a new token id without minting any units.
+/// Sender becomes minter of the token id and is allowed to update its uri.
+/// Can only be executed by owner, minter, allowlisted creator or anyone when public creation is enabled.
+/// Returns [`(MPC1155ContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC1155ContractState`]
+///
+/// * **msg** is an object of type [`CreateMsg`]
+pub fn execute_create(
+    ctx: &ContractContext,
+    state: &mut MPC1155ContractState,
+    msg: &CreateMsg,
+) -> Vec<EventGroup> {
+    assert!(
+        state.can_create(&ctx.sender),
+        "{}",
+        ContractError::Unauthorized
+    );
+    assert!(
+        state.token_info(msg.token_id).is_none(),
+        "{}",
+        ContractError::TokenExists
+    );
+
+    state.set_token_royalty(msg.token_id, &msg.royalty);
+    state.store_token(
+        msg.token_id,
+        &TokenInfo {
+            token_uri: msg.token_uri.clone(),
+            total_supply: 0,
+            max_supply: msg.max_supply,
+        },
+    );
+    state.token_minters.insert(msg.token_id, ctx.sender);
+
+    vec![]
+}
+
+/// ## Description
+/// Sets or removes uri of the token id. Can only be executed by minter account of the token id or owner.
+/// Returns [`(MPC1155ContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC1155ContractState`]
+///
+/// * **msg** is an object of type [`SetTokenUriMsg`]
+pub fn execute_set_token_uri(
+    ctx: &ContractContext,
+    state: &mut MPC1155ContractState,
+    msg: &SetTokenUriMsg,
+) -> Vec<EventGroup> {
+    assert!(
+        state.is_token_admin(&ctx.sender, msg.token_id),
+        "{}",
+        ContractError::Unauthorized
+    );
+
+    state.set_token_uri(msg.token_id, &msg.token_uri);
+    vec![]
+}
+
+/// ## Description
+/// Permanently freezes uri of the token id. Can only be executed by minter account of the token id or owner.
+/// Returns [`(MPC1155ContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC1155ContractState`]
+///
+/// * **msg** is an object of type [`FreezeTokenUriMsg`]
+pub fn execute_freeze_token_uri(
+    ctx: &ContractContext,
+    state: &mut MPC1155ContractState,
+    msg: &FreezeTokenUriMsg,
+) -> Vec<EventGroup> {
+    assert!(
+        state.is_token_admin(&ctx.sender, msg.token_id),
+        "{}",
+        ContractError::Unauthorized
+    );
+
+    state.freeze_token_uri(msg.token_id);
+    vec![]
+}
+
+/// ## Description
+/// Transfers minting rights of the token id. Can only be executed by minter account of the token id or owner.
+/// Returns [`(MPC1155ContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC1155ContractState`]
+///
+/// * **msg** is an object of type [`SetTokenMinterMsg`]
+pub fn execute_set_token_minter(
+    ctx: &ContractContext,
+    state: &mut MPC1155ContractState,
+    msg: &SetTokenMinterMsg,
+) -> Vec<EventGroup> {
+    assert!(
+        state.is_token_admin(&ctx.sender, msg.token_id),
+        "{}",
+        ContractError::Unauthorized
+    );
+    assert!(
+        state.token_info(msg.token_id).is_some(),
+        "{}",
+        ContractError::NotFound
+    );
+
+    state.token_minters.insert(msg.token_id, msg.new_minter);
+    vec![]
+}
+
+/// ## Description
+/// Updates contract minter address. Can only be executed by owner account.
+/// Returns [`(MPC1155ContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC1155ContractState`]
+///
+/// * **msg** is an object of type [`UpdateMinterMsg`]
+pub fn execute_update_minter(
+    ctx: &ContractContext,
+    state: &mut MPC1155ContractState,
+    msg: &UpdateMinterMsg,
+) -> Vec<EventGroup> {
+    assert!(
+        state.is_owner(&ctx.sender),
+        "{}",
+        ContractError::Unauthorized
+    );
+
+    state.minter = msg.new_minter;
+    vec![]
+}
+
+/// ## Description
+/// Transfers or renounces contract ownership. Can only be executed by owner account.
+/// Returns [`(MPC1155ContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC1155ContractState`]
+///
+/// * **msg** is an object of type [`TransferOwnershipMsg`]
+pub fn execute_transfer_ownership(
+    ctx: &ContractContext,
+    state: &mut MPC1155ContractState,
+    msg: &TransferOwnershipMsg,
+) -> Vec<EventGroup> {
+    assert!(
+        state.is_owner(&ctx.sender),
+        "{}",
+        ContractError::Unauthorized
+    );
+
+    state.owner = msg.new_owner;
+    vec![]
+}
+
+/// ## Description
+/// Adds or removes address allowed to create new token ids. Can only be executed by owner account.
+/// Returns [`(MPC1155ContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC1155ContractState`]
+///
+/// * **msg** is an object of type [`SetCreatorMsg`]
+pub fn execute_set_creator(
+    ctx: &ContractContext,
+    state: &mut MPC1155ContractState,
+    msg: &SetCreatorMsg,
+) -> Vec<EventGroup> {
+    assert!(
+        state.is_owner(&ctx.sender),
+        "{}",
+        ContractError::Unauthorized
+    );
+
+    if msg.allowed {
+        state.creators.insert(msg.creator);
+    } else {
+        state.creators.remove(&msg.creator);
+    }
+    vec![]
+}
+
+/// ## Description
+/// Allows or disallows anyone to create new token ids. Can only be executed by owner account.
+/// Returns [`(MPC1155ContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC1155ContractState`]
+///
+/// * **msg** is an object of type [`SetPublicCreationMsg`]
+pub fn execute_set_public_creation(
+    ctx: &ContractContext,
+    state: &mut MPC1155ContractState,
+    msg: &SetPublicCreationMsg,
+) -> Vec<EventGroup> {
+    assert!(
+        state.is_owner(&ctx.sender),
+        "{}",
+        ContractError::Unauthorized
+    );
+
+    state.public_creation = msg.public_creation;
+    vec![]
+}
+
+/// ## Description
+/// Allows spender to move specified amount of the token id from sender account.
+/// Returns [`(MPC1155ContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC1155ContractState`]
+///
+/// * **msg** is an object of type [`ApproveMsg`]
+pub fn execute_approve(
+    ctx: &ContractContext,
+    state: &mut MPC1155ContractState,
+    msg: &ApproveMsg,
+) -> Vec<EventGroup> {
+    assert!(
+        ctx.sender != msg.spender,
+        "{}",
+        ContractError::CannotApproveToYourself
+    );
+    assert!(
+        !state.is_soulbound(msg.token_id),
+        "{}",
+        ContractError::SoulboundApproval
+    );
+
+    state.set_allowance(&ctx.sender, &msg.spender, msg.token_id, msg.amount);
+    vec![]
+}
+
+/// ## Description
+/// Allows spender to move an additional amount of the token id from sender account.
+/// Returns [`(MPC1155ContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC1155ContractState`]
+///
+/// * **msg** is an object of type [`IncreaseAllowanceMsg`]
+pub fn execute_increase_allowance(
+    ctx: &ContractContext,
+    state: &mut MPC1155ContractState,
+    msg: &IncreaseAllowanceMsg,
+) -> Vec<EventGroup> {
+    assert!(
+        ctx.sender != msg.spender,
+        "{}",
+        ContractError::CannotApproveToYourself
+    );
+    assert!(
+        !state.is_soulbound(msg.token_id),
+        "{}",
+        ContractError::SoulboundApproval
+    );
+
+    let allowance = state
+        .allowance(&ctx.sender, &msg.spender, msg.token_id)
+        .checked_add(msg.amount)
+        .unwrap();
+    state.set_allowance(&ctx.sender, &msg.spender, msg.token_id, allowance);
+    vec![]
+}
+
+/// ## Description
+/// Lowers the spender's access to the token id of sender account by amount.
+/// Returns [`(MPC1155ContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC1155ContractState`]
+///
+/// * **msg** is an object of type [`DecreaseAllowanceMsg`]
+pub fn execute_decrease_allowance(
+    ctx: &ContractContext,
+    state: &mut MPC1155ContractState,
+    msg: &DecreaseAllowanceMsg,
+) -> Vec<EventGroup> {
+    let allowance = state.allowance(&ctx.sender, &msg.spender, msg.token_id);
+    assert!(
+        allowance >= msg.amount,
+        "{}",
+        ContractError::InsufficientAllowance
+    );
+
+    state.set_allowance(
+        &ctx.sender,
+        &msg.spender,
+        msg.token_id,
+        allowance - msg.amount,
+    );
+    vec![]
+}
+
+/// ## Description
+/// Transfer token from owner to receiver. If receiver is a contract
+/// it is notified with [`OnMpc1155ReceivedMsg`] and the transfer is reverted if it fails.
+/// Returns [`(MPC1155ContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC1155ContractState`]
+///
+/// * **msg** is an object of type [`SafeTransferFromMsg`]
+pub fn execute_safe_transfer_from(
+    ctx: &ContractContext,
+    state: &mut MPC1155ContractState,
+    msg: &SafeTransferFromMsg,
+) -> Vec<EventGroup> {
+    let spent_allowances = transfer_from(
+        state,
+        &ctx.sender,
+        &msg.from,
+        &msg.to,
+        std::slice::from_ref(&msg.token_info),
+    );
+
+    if !is_contract(&msg.to) {
+        return vec![];
+    }
+
+    let mut event_group = EventGroup::builder();
+    OnMpc1155ReceivedMsg {
+        operator: ctx.sender,
+        from: msg.from,
+        token_id: msg.token_info.token_id,
+        amount: msg.token_info.amount,
+        data: msg.data.clone(),
+    }
+    .as_interaction(&mut event_group, &msg.to);
+    build_msg_callback(
+        &mut event_group,
+        ON_SAFE_TRANSFER_CALLBACK,
+        &SafeTransferCallbackMsg {
+            operator: ctx.sender,
+            from: msg.from,
+            to: msg.to,
+            token_infos: vec![msg.token_info.clone()],
+            spent_allowances,
+        },
+    );
+
+    vec![event_group.build()]
+}
+
+/// ## Description
+/// Batch transfer tokens from owner to receiver. If receiver is a contract
+/// it is notified with [`OnMpc1155BatchReceivedMsg`] and the transfer is reverted if it fails.
+/// Returns [`(MPC1155ContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC1155ContractState`]
+///
+/// * **msg** is an object of type [`SafeBatchTransferFromMsg`]
+pub fn execute_safe_batch_transfer_from(
+    ctx: &ContractContext,
+    state: &mut MPC1155ContractState,
+    msg: &SafeBatchTransferFromMsg,
+) -> Vec<EventGroup> {
+    let spent_allowances = transfer_from(state, &ctx.sender, &msg.from, &msg.to, &msg.token_infos);
+
+    if !is_contract(&msg.to) {
+        return vec![];
+    }
+
+    let mut event_group = EventGroup::builder();
+    OnMpc1155BatchReceivedMsg {
+        operator: ctx.sender,
+        from: msg.from,
+        token_ids: msg.token_infos.iter().map(|info| info.token_id).collect(),
+        amounts: msg.token_infos.iter().map(|info| info.amount).collect(),
+        data: msg.data.clone(),
+    }
+    .as_interaction(&mut event_group, &msg.to);
+    build_msg_callback(
+        &mut event_group,
+        ON_SAFE_TRANSFER_CALLBACK,
+        &SafeTransferCallbackMsg {
+            operator: ctx.sender,
+            from: msg.from,
+            to: msg.to,
+            token_infos: msg.token_infos.clone(),
+            spent_allowances,
+        },
+    );
+
+    vec![event_group.build()]
+}
+
+/// ## Description
+/// Handles receiver contract acknowledgement of the safe transfer.
+/// Reverts the transfer and restores spent allowances if receiver contract failed to handle the tokens.
+/// Returns [`(MPC1155ContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **callback_ctx** is an object of type [`CallbackContext`]
+///
+/// * **state** is an object of type [`MPC1155ContractState`]
+///
+/// * **msg** is an object of type [`SafeTransferCallbackMsg`]
+pub fn execute_on_safe_transfer(
+    ctx: &ContractContext,
+    callback_ctx: &CallbackContext,
+    state: &mut MPC1155ContractState,
+    msg: &SafeTransferCallbackMsg,
+) -> Vec<EventGroup> {
+    if !callback_ctx.success {
+        for token_info in msg.token_infos.iter() {
+            state.revert_transfer(&msg.from, &msg.to, token_info.token_id, token_info.amount);
+        }
+        for spent in msg.spent_allowances.iter() {
+            let allowance = state
+                .allowance(&msg.from, &msg.operator, spent.token_id)
+                .checked_add(spent.amount)
+                .unwrap();
+            state.set_allowance(&msg.from, &msg.operator, spent.token_id, allowance);
+        }
+    }
+
+    vec![]
+}
+
+fn transfer_from(
+    state: &mut MPC1155ContractState,
+    sender: &Address,
+    from: &Address,
+    to: &Address,
+    token_infos: &[TokenTransferInfoMsg],
+) -> Vec<TokenTransferInfoMsg> {
+    let mut spent_allowances = vec![];
+    for token_info in token_infos.iter() {
+        state.assert_transferable(token_info.token_id);
+        let spent = state.spend_allowance(from, sender, token_info.token_id, token_info.amount);
+        if spent > 0 {
+            spent_allowances.push(TokenTransferInfoMsg {
+                token_id: token_info.token_id,
+                amount: spent,
+            });
+        }
+        state.transfer(Some(from), Some(to), token_info.token_id, token_info.amount);
+    }
+
+    spent_allowances
+}
+
+fn is_contract(address: &Address) -> bool {
+    !matches!(address.address_type, AddressType::Account)
+}
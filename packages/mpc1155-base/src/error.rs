@@ -12,4 +12,34 @@ pub enum ContractError {
 
     #[error("Balance check has failed")]
     InadequateBalance,
+
+    #[error("Length of provided lists must be equal")]
+    LengthMismatch,
+
+    #[error("Royalty basis points must not exceed 10000")]
+    InvalidRoyalty,
+
+    #[error("Soulbound token can not be transferred")]
+    SoulboundTransfer,
+
+    #[error("Soulbound token can not be approved")]
+    SoulboundApproval,
+
+    #[error("Soulbound token can only be burned by minter or allowed holder")]
+    SoulboundBurn,
+
+    #[error("Max supply exceeded")]
+    MaxSupplyExceeded,
+
+    #[error("Token id already exists")]
+    TokenExists,
+
+    #[error("Cannot approve to yourself")]
+    CannotApproveToYourself,
+
+    #[error("Insufficient allowance")]
+    InsufficientAllowance,
+
+    #[error("Metadata is frozen")]
+    MetadataFrozen,
 }
@@ -5,6 +5,8 @@ use read_write_rpc_derive::ReadWriteRPC;
 use rpc_msg_derive::IntoShortnameRPCEvent;
 use utils::events::IntoShortnameRPCEvent;
 
+use crate::state::RoyaltyInfo;
+
 /// ## Description
 /// This structure describes fields for mpc1155 initialize msg
 #[derive(ReadWriteRPC, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
@@ -71,6 +73,10 @@ pub struct TokenMintInfoMsg {
     pub amount: u128,
     /// optional token uri
     pub token_uri: Option<String>,
+    /// optional token royalty, only applied when token id is minted for the first time
+    pub royalty: Option<RoyaltyInfo>,
+    /// optional max supply, only applied when token id is minted for the first time
+    pub max_supply: Option<u128>,
 }
 
 /// ## Description
@@ -145,3 +151,403 @@ pub struct CheckBalancesMsg {
     // vectore of token amounts being checked
     pub amounts: Vec<u128>,
 }
+
+/// ## Description
+/// This structure describes fields for mpc1155 set default royalty msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x19)]
+pub struct SetDefaultRoyaltyMsg {
+    /// new contract-wide royalty, None removes it
+    pub royalty: Option<RoyaltyInfo>,
+}
+
+/// ## Description
+/// This structure describes fields for mpc1155 set token royalty msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x20)]
+pub struct SetTokenRoyaltyMsg {
+    /// token id
+    pub token_id: u128,
+    /// new token royalty, None removes it
+    pub royalty: Option<RoyaltyInfo>,
+}
+
+/// ## Description
+/// This structure describes fields for mpc1155 royalty info msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x21)]
+pub struct RoyaltyInfoMsg {
+    /// token id
+    pub token_id: u128,
+    /// sale price
+    pub sale_price: u128,
+    /// action shortname of the calling contract which receives [`RoyaltyInfoReplyMsg`]
+    pub callback_action: u32,
+}
+
+/// ## Description
+/// This structure describes royalty information sent back to the calling contract
+#[derive(ReadWriteRPC, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct RoyaltyInfoReplyMsg {
+    /// token id
+    pub token_id: u128,
+    /// sale price
+    pub sale_price: u128,
+    /// optional royalty receiver address
+    pub receiver: Option<Address>,
+    /// royalty amount
+    pub royalty_amount: u128,
+}
+
+/// ## Description
+/// This structure describes fields for mpc1155 set soulbound msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x22)]
+pub struct SetSoulboundMsg {
+    /// whether all tokens of the contract are non-transferable
+    pub soulbound: bool,
+    /// whether holders are allowed to burn their soulbound tokens
+    pub holder_burn: bool,
+}
+
+/// ## Description
+/// This structure describes fields for mpc1155 set token soulbound msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x23)]
+pub struct SetTokenSoulboundMsg {
+    /// token id
+    pub token_id: u128,
+    /// whether token is non-transferable
+    pub soulbound: bool,
+}
+
+/// ## Description
+/// This structure describes fields for mpc1155 create msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x24)]
+pub struct CreateMsg {
+    /// new token id
+    pub token_id: u128,
+    /// optional token uri
+    pub token_uri: Option<String>,
+    /// optional token royalty
+    pub royalty: Option<RoyaltyInfo>,
+    /// optional max supply
+    pub max_supply: Option<u128>,
+}
+
+/// ## Description
+/// This structure describes fields for mpc1155 set token uri msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x25)]
+pub struct SetTokenUriMsg {
+    /// token id
+    pub token_id: u128,
+    /// new optional token uri
+    pub token_uri: Option<String>,
+}
+
+/// ## Description
+/// This structure describes fields for mpc1155 freeze token uri msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x30)]
+pub struct FreezeTokenUriMsg {
+    /// token id
+    pub token_id: u128,
+}
+
+/// ## Description
+/// This structure describes fields for mpc1155 set token minter msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x26)]
+pub struct SetTokenMinterMsg {
+    /// token id
+    pub token_id: u128,
+    /// new token minter address
+    pub new_minter: Address,
+}
+
+/// ## Description
+/// This structure describes fields for mpc1155 update minter msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x27)]
+pub struct UpdateMinterMsg {
+    /// new contract minter address
+    pub new_minter: Address,
+}
+
+/// ## Description
+/// This structure describes fields for mpc1155 transfer ownership msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x28)]
+pub struct TransferOwnershipMsg {
+    /// new optional owner address, if None ownership is renounced
+    pub new_owner: Option<Address>,
+}
+
+/// ## Description
+/// This structure describes fields for mpc1155 set creator msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x29)]
+pub struct SetCreatorMsg {
+    /// creator address
+    pub creator: Address,
+    /// whether creator is allowed to create new token ids
+    pub allowed: bool,
+}
+
+/// ## Description
+/// This structure describes fields for mpc1155 set public creation msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x2A)]
+pub struct SetPublicCreationMsg {
+    /// whether anyone is allowed to create new token ids
+    pub public_creation: bool,
+}
+
+/// ## Description
+/// This structure describes fields for mpc1155 approve msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x2B)]
+pub struct ApproveMsg {
+    /// approved address
+    pub spender: Address,
+    /// token id
+    pub token_id: u128,
+    /// approved amount, zero removes the approval
+    pub amount: u128,
+}
+
+/// ## Description
+/// This structure describes fields for mpc1155 increase allowance msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x2C)]
+pub struct IncreaseAllowanceMsg {
+    /// approved spender
+    pub spender: Address,
+    /// token id
+    pub token_id: u128,
+    /// approved amount to increase
+    pub amount: u128,
+}
+
+/// ## Description
+/// This structure describes fields for mpc1155 decrease allowance msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x2D)]
+pub struct DecreaseAllowanceMsg {
+    /// approved spender
+    pub spender: Address,
+    /// token id
+    pub token_id: u128,
+    /// approved amount to decrease
+    pub amount: u128,
+}
+
+/// ## Description
+/// This structure describes fields for mpc1155 safe transfer from msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x2E)]
+pub struct SafeTransferFromMsg {
+    /// owner address
+    pub from: Address,
+    /// receiver address
+    pub to: Address,
+    /// token info
+    pub token_info: TokenTransferInfoMsg,
+    /// additional data passed to the receiver contract
+    pub data: Vec<u8>,
+}
+
+/// ## Description
+/// This structure describes fields for mpc1155 safe batch transfer from msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x2F)]
+pub struct SafeBatchTransferFromMsg {
+    /// owner address
+    pub from: Address,
+    /// receiver address
+    pub to: Address,
+    /// list of tokens to transfer
+    pub token_infos: Vec<TokenTransferInfoMsg>,
+    /// additional data passed to the receiver contract
+    pub data: Vec<u8>,
+}
+
+/// ## Description
+/// This structure describes fields of the msg sent to the receiver contract on safe transfer.
+/// Contracts accepting mpc1155 tokens must implement action with shortname 0x51
+/// taking these fields, and fail if they can not handle received tokens
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x51)]
+pub struct OnMpc1155ReceivedMsg {
+    /// address which initiated the transfer
+    pub operator: Address,
+    /// previous owner address
+    pub from: Address,
+    /// token id
+    pub token_id: u128,
+    /// transferred amount
+    pub amount: u128,
+    /// additional data passed by the operator
+    pub data: Vec<u8>,
+}
+
+/// ## Description
+/// This structure describes fields of the msg sent to the receiver contract on safe batch transfer.
+/// Contracts accepting mpc1155 tokens must implement action with shortname 0x52
+/// taking these fields, and fail if they can not handle received tokens
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x52)]
+pub struct OnMpc1155BatchReceivedMsg {
+    /// address which initiated the transfer
+    pub operator: Address,
+    /// previous owner address
+    pub from: Address,
+    /// token ids
+    pub token_ids: Vec<u128>,
+    /// transferred amounts, i-th amount belongs to i-th token id
+    pub amounts: Vec<u128>,
+    /// additional data passed by the operator
+    pub data: Vec<u8>,
+}
+
+/// ## Description
+/// This structure describes safe transfer which is passed to the acknowledgement callback
+#[derive(ReadWriteRPC, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct SafeTransferCallbackMsg {
+    /// address which initiated the transfer
+    pub operator: Address,
+    /// previous owner address
+    pub from: Address,
+    /// receiver contract address
+    pub to: Address,
+    /// transferred tokens
+    pub token_infos: Vec<TokenTransferInfoMsg>,
+    /// allowances of the operator spent by the transfer
+    pub spent_allowances: Vec<TokenTransferInfoMsg>,
+}
+
+pub const ON_SAFE_TRANSFER_CALLBACK: u32 = 0x31;
+
+/// ## Description
+/// This structure describes fields for mpc1155 balance of query msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x40)]
+pub struct BalanceOfMsg {
+    /// token holder address
+    pub owner: Address,
+    /// token id
+    pub token_id: u128,
+    /// action shortname of the calling contract which receives [`BalanceOfReplyMsg`]
+    pub callback_action: u32,
+    /// opaque data echoed back in the reply, e.g. to match the reply with the query
+    pub context: Vec<u8>,
+}
+
+/// ## Description
+/// This structure describes balance sent back to the calling contract.
+/// Receiver must check that the reply is sent by the token contract
+#[derive(ReadWriteRPC, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct BalanceOfReplyMsg {
+    /// token holder address
+    pub owner: Address,
+    /// token id
+    pub token_id: u128,
+    /// balance of the holder
+    pub balance: u128,
+    /// opaque data of the query
+    pub context: Vec<u8>,
+}
+
+/// ## Description
+/// This structure describes fields for mpc1155 balance of batch query msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x41)]
+pub struct BalanceOfBatchMsg {
+    /// token holder addresses
+    pub owners: Vec<Address>,
+    /// token ids, i-th token id belongs to i-th owner
+    pub token_ids: Vec<u128>,
+    /// action shortname of the calling contract which receives [`BalanceOfBatchReplyMsg`]
+    pub callback_action: u32,
+    /// opaque data echoed back in the reply, e.g. to match the reply with the query
+    pub context: Vec<u8>,
+}
+
+/// ## Description
+/// This structure describes balances sent back to the calling contract.
+/// Receiver must check that the reply is sent by the token contract
+#[derive(ReadWriteRPC, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct BalanceOfBatchReplyMsg {
+    /// token holder addresses
+    pub owners: Vec<Address>,
+    /// token ids
+    pub token_ids: Vec<u128>,
+    /// balances, i-th balance belongs to i-th owner and token id
+    pub balances: Vec<u128>,
+    /// opaque data of the query
+    pub context: Vec<u8>,
+}
+
+/// ## Description
+/// This structure describes fields for mpc1155 allowance query msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x42)]
+pub struct AllowanceMsg {
+    /// token owner address
+    pub owner: Address,
+    /// spender address
+    pub spender: Address,
+    /// token id
+    pub token_id: u128,
+    /// action shortname of the calling contract which receives [`AllowanceReplyMsg`]
+    pub callback_action: u32,
+    /// opaque data echoed back in the reply, e.g. to match the reply with the query
+    pub context: Vec<u8>,
+}
+
+/// ## Description
+/// This structure describes allowance sent back to the calling contract.
+/// Receiver must check that the reply is sent by the token contract
+#[derive(ReadWriteRPC, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct AllowanceReplyMsg {
+    /// token owner address
+    pub owner: Address,
+    /// spender address
+    pub spender: Address,
+    /// token id
+    pub token_id: u128,
+    /// amount of the token id spender is allowed to move
+    pub allowance: u128,
+    /// opaque data of the query
+    pub context: Vec<u8>,
+}
+
+/// ## Description
+/// This structure describes fields for mpc1155 total supply query msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x43)]
+pub struct TotalSupplyMsg {
+    /// token id
+    pub token_id: u128,
+    /// action shortname of the calling contract which receives [`TotalSupplyReplyMsg`]
+    pub callback_action: u32,
+    /// opaque data echoed back in the reply, e.g. to match the reply with the query
+    pub context: Vec<u8>,
+}
+
+/// ## Description
+/// This structure describes token id supply sent back to the calling contract.
+/// Receiver must check that the reply is sent by the token contract
+#[derive(ReadWriteRPC, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct TotalSupplyReplyMsg {
+    /// token id
+    pub token_id: u128,
+    /// amount of existing units of the token id
+    pub total_supply: u128,
+    /// optional limit of existing units of the token id
+    pub max_supply: Option<u128>,
+    /// opaque data of the query
+    pub context: Vec<u8>,
+}
@@ -1,4 +1,4 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 use create_type_spec_derive::CreateTypeSpec;
 use pbc_contract_common::address::Address;
@@ -23,6 +23,26 @@ pub struct MPC1155ContractState {
     pub operator_approvals: BTreeMap<Address, BTreeMap<Address, bool>>,
     /// token info by token id
     pub tokens: BTreeMap<u128, TokenInfo>,
+    /// optional contract-wide royalty
+    pub default_royalty: Option<RoyaltyInfo>,
+    /// royalties overriding the default one by token id
+    pub token_royalties: BTreeMap<u128, RoyaltyInfo>,
+    /// whether all tokens of the contract are non-transferable
+    pub soulbound: bool,
+    /// whether holders are allowed to burn their soulbound tokens
+    pub soulbound_holder_burn: bool,
+    /// non-transferable token ids
+    pub soulbound_tokens: BTreeSet<u128>,
+    /// minters of creator-owned token ids
+    pub token_minters: BTreeMap<u128, Address>,
+    /// addresses allowed to create new token ids
+    pub creators: BTreeSet<Address>,
+    /// whether anyone is allowed to create new token ids
+    pub public_creation: bool,
+    /// per token id allowances by owner and spender
+    pub allowances: BTreeMap<Address, BTreeMap<Address, BTreeMap<u128, u128>>>,
+    /// token ids with permanently frozen uri
+    pub frozen_tokens: BTreeSet<u128>,
 }
 
 /// ## Description
@@ -31,8 +51,26 @@ pub struct MPC1155ContractState {
 pub struct TokenInfo {
     /// optional token uri
     pub token_uri: Option<String>,
+    /// amount of existing units of the token
+    pub total_supply: u128,
+    /// optional limit of existing units of the token
+    pub max_supply: Option<u128>,
 }
 
+/// ## Description
+/// This structure describes royalty receiver and its share of the sale price
+#[derive(ReadWriteRPC, ReadWriteState, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct RoyaltyInfo {
+    /// royalty receiver address
+    pub receiver: Address,
+    /// royalty in basis points
+    pub basis_points: u16,
+}
+
+/// ## Description
+/// Basis points representing 100% of the sale price
+pub const MAX_ROYALTY_BASIS_POINTS: u16 = 10_000;
+
 impl MPC1155ContractState {
     /// ## Description
     /// Sets new base uri
@@ -52,6 +90,116 @@ impl MPC1155ContractState {
         self.tokens.entry(token_id).or_insert_with(|| info.clone());
     }
 
+    /// ## Description
+    /// Sets or removes uri of the existing token id
+    /// ## Params
+    /// * **token_id** is a field of type [`u128`]
+    ///
+    /// * **token_uri** is an object of type [`Option<String>`]
+    pub fn set_token_uri(&mut self, token_id: u128, token_uri: &Option<String>) {
+        assert!(
+            !self.is_uri_frozen(token_id),
+            "{}",
+            ContractError::MetadataFrozen
+        );
+
+        let info = self
+            .tokens
+            .get_mut(&token_id)
+            .unwrap_or_else(|| panic!("{}", ContractError::NotFound));
+        info.token_uri = token_uri.clone();
+    }
+
+    /// ## Description
+    /// Permanently freezes uri of the existing token id.
+    /// Token id without own uri keeps contract uri it had at the moment of freezing
+    /// ## Params
+    /// * **token_id** is a field of type [`u128`]
+    pub fn freeze_token_uri(&mut self, token_id: u128) {
+        let uri = self.uri.clone();
+        let info = self
+            .tokens
+            .get_mut(&token_id)
+            .unwrap_or_else(|| panic!("{}", ContractError::NotFound));
+        info.token_uri.get_or_insert(uri);
+
+        self.frozen_tokens.insert(token_id);
+    }
+
+    /// ## Description
+    /// Returns whether uri of the token id is permanently frozen
+    /// ## Params
+    /// * **token_id** is a field of type [`u128`]
+    pub fn is_uri_frozen(&self, token_id: u128) -> bool {
+        self.frozen_tokens.contains(&token_id)
+    }
+
+    /// ## Description
+    /// Returns token uri, or base uri if token uri is not set.
+    /// Every `{id}` occurrence is replaced with lowercase hex token id, zero-padded to 64 characters
+    /// ## Params
+    /// * **token_id** is a field of type [`u128`]
+    pub fn uri(&self, token_id: u128) -> String {
+        let uri = self
+            .token_info(token_id)
+            .and_then(|info| info.token_uri.as_ref())
+            .unwrap_or(&self.uri);
+
+        uri.replace("{id}", &format!("{:064x}", token_id))
+    }
+
+    /// ## Description
+    /// Sets or removes contract-wide royalty
+    /// ## Params
+    /// * **royalty** is an object of type [`Option<RoyaltyInfo>`]
+    pub fn set_default_royalty(&mut self, royalty: &Option<RoyaltyInfo>) {
+        if let Some(royalty) = royalty {
+            Self::assert_valid_royalty(royalty);
+        }
+
+        self.default_royalty = royalty.clone();
+    }
+
+    /// ## Description
+    /// Sets or removes royalty for the specified token id
+    /// ## Params
+    /// * **token_id** is a field of type [`u128`]
+    ///
+    /// * **royalty** is an object of type [`Option<RoyaltyInfo>`]
+    pub fn set_token_royalty(&mut self, token_id: u128, royalty: &Option<RoyaltyInfo>) {
+        match royalty {
+            Some(royalty) => {
+                Self::assert_valid_royalty(royalty);
+                self.token_royalties.insert(token_id, royalty.clone());
+            }
+            None => {
+                self.token_royalties.remove(&token_id);
+            }
+        }
+    }
+
+    /// ## Description
+    /// Returns royalty receiver and royalty amount for the specified token id and sale price.
+    /// Token royalty takes precedence over the contract-wide one
+    /// ## Params
+    /// * **token_id** is a field of type [`u128`]
+    ///
+    /// * **sale_price** is a field of type [`u128`]
+    pub fn royalty_info(&self, token_id: u128, sale_price: u128) -> Option<(Address, u128)> {
+        self.token_royalties
+            .get(&token_id)
+            .or(self.default_royalty.as_ref())
+            .map(|royalty| {
+                let amount = sale_price
+                    .checked_mul(royalty.basis_points as u128)
+                    .unwrap()
+                    .checked_div(MAX_ROYALTY_BASIS_POINTS as u128)
+                    .unwrap();
+
+                (royalty.receiver, amount)
+            })
+    }
+
     /// ## Description
     /// Transfers token from owner to spender
     /// ## Params
@@ -70,36 +218,89 @@ impl MPC1155ContractState {
         amount: u128,
     ) {
         if let Some(from) = from {
-            self.balances.entry(token_id).and_modify(|token_balances| {
-                token_balances
-                    .entry(*from)
-                    .and_modify(|balance| *balance = balance.checked_sub(amount).unwrap());
-            });
+            let balance = self.balance_of(from, token_id);
+            assert!(balance >= amount, "{}", ContractError::InadequateBalance);
+            self.set_balance(from, token_id, balance - amount);
         }
 
         if let Some(to) = to {
-            self.balances
-                .entry(token_id)
-                .and_modify(|token_balances| {
-                    token_balances
-                        .entry(*to)
-                        .and_modify(|balance| *balance = balance.checked_add(amount).unwrap())
-                        .or_insert(amount);
-                })
-                .or_insert_with(|| BTreeMap::from([(*to, amount)]));
+            let balance = self.balance_of(to, token_id).checked_add(amount).unwrap();
+            self.set_balance(to, token_id, balance);
+        }
+
+        match (from, to) {
+            (None, Some(_)) => self.increase_supply(token_id, amount),
+            (Some(_), None) => self.decrease_supply(token_id, amount),
+            _ => {}
         }
     }
+
+    /// ## Description
+    /// Returns tokens back to the previous owner, receiver must still hold them
+    /// ## Params
+    /// * **from** is an object of type [`Address`]
+    ///
+    /// * **to** is an object of type [`Address`]
+    ///
+    /// * **token_id** is a field of type [`u128`]
+    ///
+    /// * **amount** is a field of type [`u128`]
+    pub fn revert_transfer(&mut self, from: &Address, to: &Address, token_id: u128, amount: u128) {
+        self.transfer(Some(to), Some(from), token_id, amount);
+    }
+
+    /// ## Description
+    /// Returns token balance of the owner
+    /// ## Params
+    /// * **owner** is an object of type [`Address`]
+    ///
+    /// * **token_id** is a field of type [`u128`]
+    pub fn balance_of(&self, owner: &Address, token_id: u128) -> u128 {
+        self.balances
+            .get(&token_id)
+            .and_then(|token_balances| token_balances.get(owner))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// ## Description
+    /// Returns token balances of the owners, i-th balance is a balance of i-th owner for i-th token id
+    /// ## Params
+    /// * **owners** is an object of type [`[Address]`]
+    ///
+    /// * **token_ids** is an object of type [`[u128]`]
+    pub fn balance_of_batch(&self, owners: &[Address], token_ids: &[u128]) -> Vec<u128> {
+        assert!(
+            owners.len() == token_ids.len(),
+            "{}",
+            ContractError::LengthMismatch
+        );
+
+        owners
+            .iter()
+            .zip(token_ids.iter())
+            .map(|(owner, token_id)| self.balance_of(owner, *token_id))
+            .collect()
+    }
+
     /// ## Description
     /// checks if an address possesses at least a given balance of tokens
     pub fn check_balances(&mut self, owner: Address, token_ids: Vec<u128>, amounts: Vec<u128>) {
-        token_ids.into_iter().enumerate().for_each(|(n, id)| {
+        assert!(
+            token_ids.len() == amounts.len(),
+            "{}",
+            ContractError::LengthMismatch
+        );
+
+        token_ids.into_iter().zip(amounts).for_each(|(id, amount)| {
             assert!(
-                self.balances.get(&id).unwrap().get(&owner).unwrap() >= &amounts[n],
+                self.balance_of(&owner, id) >= amount,
                 "{}",
                 ContractError::InadequateBalance
             )
         });
     }
+
     /// ## Description
     /// Adds new operator approval
     /// ## Params
@@ -146,6 +347,38 @@ impl MPC1155ContractState {
         }
     }
 
+    /// ## Description
+    /// Returns minter of the token id. Token ids without own minter are minted by contract minter
+    /// ## Params
+    /// * **token_id** is a field of type [`u128`]
+    pub fn token_minter(&self, token_id: u128) -> Address {
+        self.token_minters
+            .get(&token_id)
+            .copied()
+            .unwrap_or(self.minter)
+    }
+
+    /// ## Description
+    /// Checks that address is allowed to manage the token id, e.g. it is token minter or owner
+    /// ## Params
+    /// * **address** is an object of type [`Address`]
+    ///
+    /// * **token_id** is a field of type [`u128`]
+    pub fn is_token_admin(&self, address: &Address, token_id: u128) -> bool {
+        self.is_owner(address) || self.token_minter(token_id) == *address
+    }
+
+    /// ## Description
+    /// Checks that address is allowed to create new token ids
+    /// ## Params
+    /// * **address** is an object of type [`Address`]
+    pub fn can_create(&self, address: &Address) -> bool {
+        self.public_creation
+            || self.is_owner(address)
+            || self.minter == *address
+            || self.creators.contains(address)
+    }
+
     /// ## Description
     /// Checks approval
     /// ## Params
@@ -166,6 +399,142 @@ impl MPC1155ContractState {
         false
     }
 
+    /// ## Description
+    /// Says is token id non-transferable or not
+    /// ## Params
+    /// * **token_id** is an object of type [`u128`]
+    pub fn is_soulbound(&self, token_id: u128) -> bool {
+        self.soulbound || self.soulbound_tokens.contains(&token_id)
+    }
+
+    /// ## Description
+    /// Checks that token id is not soulbound
+    /// ## Params
+    /// * **token_id** is an object of type [`u128`]
+    pub fn assert_transferable(&self, token_id: u128) {
+        assert!(
+            !self.is_soulbound(token_id),
+            "{}",
+            ContractError::SoulboundTransfer
+        );
+    }
+
+    /// ## Description
+    /// Checks that sender is allowed to burn tokens of the owner.
+    /// Soulbound tokens can be burned by minter of the token id, owner or, if allowed, by the holder,
+    /// other tokens consume allowance of the sender if it is not an operator
+    /// ## Params
+    /// * **owner** is an object of type [`Address`]
+    ///
+    /// * **sender** is an object of type [`Address`]
+    ///
+    /// * **token_id** is an object of type [`u128`]
+    ///
+    /// * **amount** is a field of type [`u128`]
+    pub fn assert_burnable(
+        &mut self,
+        owner: &Address,
+        sender: &Address,
+        token_id: u128,
+        amount: u128,
+    ) {
+        if self.is_soulbound(token_id) {
+            assert!(
+                self.is_token_admin(sender, token_id)
+                    || (self.soulbound_holder_burn && owner == sender),
+                "{}",
+                ContractError::SoulboundBurn
+            );
+        } else {
+            self.spend_allowance(owner, sender, token_id, amount);
+        }
+    }
+
+    /// ## Description
+    /// Checks that sender is allowed to move tokens of the owner.
+    /// Allowance of the token id is consumed when sender is not an owner or operator.
+    /// Returns spent allowance
+    /// ## Params
+    /// * **owner** is an object of type [`Address`]
+    ///
+    /// * **sender** is an object of type [`Address`]
+    ///
+    /// * **token_id** is a field of type [`u128`]
+    ///
+    /// * **amount** is a field of type [`u128`]
+    pub fn spend_allowance(
+        &mut self,
+        owner: &Address,
+        sender: &Address,
+        token_id: u128,
+        amount: u128,
+    ) -> u128 {
+        if self.is_token_owner_or_operator(owner, sender) {
+            return 0;
+        }
+
+        let allowance = self.allowance(owner, sender, token_id);
+        assert!(allowance > 0, "{}", ContractError::Unauthorized);
+        assert!(
+            allowance >= amount,
+            "{}",
+            ContractError::InsufficientAllowance
+        );
+
+        self.set_allowance(owner, sender, token_id, allowance - amount);
+        amount
+    }
+
+    /// ## Description
+    /// Returns amount of the token id which spender is allowed to move from owner account
+    /// ## Params
+    /// * **owner** is an object of type [`Address`]
+    ///
+    /// * **spender** is an object of type [`Address`]
+    ///
+    /// * **token_id** is a field of type [`u128`]
+    pub fn allowance(&self, owner: &Address, spender: &Address, token_id: u128) -> u128 {
+        self.allowances
+            .get(owner)
+            .and_then(|owner_allowances| owner_allowances.get(spender))
+            .and_then(|spender_allowances| spender_allowances.get(&token_id))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// ## Description
+    /// Sets allowance of the token id for spender, zero amount removes the allowance
+    /// ## Params
+    /// * **owner** is an object of type [`Address`]
+    ///
+    /// * **spender** is an object of type [`Address`]
+    ///
+    /// * **token_id** is a field of type [`u128`]
+    ///
+    /// * **amount** is a field of type [`u128`]
+    pub fn set_allowance(
+        &mut self,
+        owner: &Address,
+        spender: &Address,
+        token_id: u128,
+        amount: u128,
+    ) {
+        let owner_allowances = self.allowances.entry(*owner).or_default();
+        let spender_allowances = owner_allowances.entry(*spender).or_default();
+        if amount == 0 {
+            spender_allowances.remove(&token_id);
+        } else {
+            spender_allowances.insert(token_id, amount);
+        }
+
+        if spender_allowances.is_empty() {
+            owner_allowances.remove(spender);
+        }
+        if owner_allowances.is_empty() {
+            self.allowances.remove(owner);
+        }
+    }
+
     /// ## Description
     /// Returns token info by specified token id
     /// ## Params
@@ -173,4 +542,77 @@ impl MPC1155ContractState {
     pub fn token_info(&self, token_id: u128) -> Option<&TokenInfo> {
         self.tokens.get(&token_id)
     }
+
+    /// ## Description
+    /// Returns amount of existing units of the token
+    /// ## Params
+    /// * **token_id** is an object of type [`u128`]
+    pub fn total_supply(&self, token_id: u128) -> u128 {
+        self.tokens
+            .get(&token_id)
+            .map(|info| info.total_supply)
+            .unwrap_or_default()
+    }
+
+    /// ## Description
+    /// Returns optional limit of existing units of the token
+    /// ## Params
+    /// * **token_id** is an object of type [`u128`]
+    pub fn max_supply(&self, token_id: u128) -> Option<u128> {
+        self.tokens.get(&token_id).and_then(|info| info.max_supply)
+    }
+
+    /// ## Description
+    /// Says is there any existing unit of the token or not
+    /// ## Params
+    /// * **token_id** is an object of type [`u128`]
+    pub fn exists(&self, token_id: u128) -> bool {
+        self.total_supply(token_id) > 0
+    }
+
+    fn set_balance(&mut self, owner: &Address, token_id: u128, balance: u128) {
+        let token_balances = self.balances.entry(token_id).or_default();
+        if balance == 0 {
+            token_balances.remove(owner);
+        } else {
+            token_balances.insert(*owner, balance);
+        }
+
+        if token_balances.is_empty() {
+            self.balances.remove(&token_id);
+        }
+    }
+
+    fn increase_supply(&mut self, token_id: u128, amount: u128) {
+        let info = self
+            .tokens
+            .get_mut(&token_id)
+            .unwrap_or_else(|| panic!("{}", ContractError::NotFound));
+
+        info.total_supply = info.total_supply.checked_add(amount).unwrap();
+        if let Some(max_supply) = info.max_supply {
+            assert!(
+                info.total_supply <= max_supply,
+                "{}",
+                ContractError::MaxSupplyExceeded
+            );
+        }
+    }
+
+    fn decrease_supply(&mut self, token_id: u128, amount: u128) {
+        let info = self
+            .tokens
+            .get_mut(&token_id)
+            .unwrap_or_else(|| panic!("{}", ContractError::NotFound));
+
+        info.total_supply = info.total_supply.checked_sub(amount).unwrap();
+    }
+
+    fn assert_valid_royalty(royalty: &RoyaltyInfo) {
+        assert!(
+            royalty.basis_points <= MAX_ROYALTY_BASIS_POINTS,
+            "{}",
+            ContractError::InvalidRoyalty
+        );
+    }
 }
@@ -1,22 +1,38 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 use pbc_contract_common::{
     address::{Address, AddressType},
-    context::ContractContext,
+    context::{CallbackContext, ContractContext},
+    events::EventGroup,
 };
+use utils::events::{build_msg_callback, build_msg_reply, IntoShortnameRPCEvent};
 
 use crate::{
     actions::{
-        execute_approve_for_all, execute_batch_burn, execute_batch_mint,
-        execute_batch_transfer_from, execute_burn, execute_check_balances, execute_init,
-        execute_mint, execute_revoke_for_all, execute_set_uri, execute_transfer_from,
+        execute_allowance, execute_approve, execute_approve_for_all, execute_balance_of,
+        execute_balance_of_batch, execute_batch_burn, execute_batch_mint,
+        execute_batch_transfer_from, execute_burn, execute_check_balances, execute_create,
+        execute_decrease_allowance, execute_freeze_token_uri, execute_increase_allowance,
+        execute_init, execute_mint, execute_on_safe_transfer, execute_revoke_for_all,
+        execute_royalty_info, execute_safe_batch_transfer_from, execute_safe_transfer_from,
+        execute_set_creator, execute_set_default_royalty, execute_set_public_creation,
+        execute_set_soulbound, execute_set_token_minter, execute_set_token_royalty,
+        execute_set_token_soulbound, execute_set_token_uri, execute_set_uri, execute_total_supply,
+        execute_transfer_from, execute_transfer_ownership, execute_update_minter,
     },
     msg::{
-        ApproveForAllMsg, BatchBurnMsg, BatchMintMsg, BatchTransferFromMsg, BurnMsg,
-        CheckBalancesMsg, InitMsg, MintMsg, RevokeForAllMsg, SetUriMsg, TokenMintInfoMsg,
-        TokenTransferInfoMsg, TransferFromMsg,
+        AllowanceMsg, AllowanceReplyMsg, ApproveForAllMsg, ApproveMsg, BalanceOfBatchMsg,
+        BalanceOfBatchReplyMsg, BalanceOfMsg, BalanceOfReplyMsg, BatchBurnMsg, BatchMintMsg,
+        BatchTransferFromMsg, BurnMsg, CheckBalancesMsg, CreateMsg, DecreaseAllowanceMsg,
+        FreezeTokenUriMsg, IncreaseAllowanceMsg, InitMsg, MintMsg, OnMpc1155BatchReceivedMsg,
+        OnMpc1155ReceivedMsg, RevokeForAllMsg, RoyaltyInfoMsg, RoyaltyInfoReplyMsg,
+        SafeBatchTransferFromMsg, SafeTransferCallbackMsg, SafeTransferFromMsg, SetCreatorMsg,
+        SetDefaultRoyaltyMsg, SetPublicCreationMsg, SetSoulboundMsg, SetTokenMinterMsg,
+        SetTokenRoyaltyMsg, SetTokenSoulboundMsg, SetTokenUriMsg, SetUriMsg, TokenMintInfoMsg,
+        TokenTransferInfoMsg, TotalSupplyMsg, TotalSupplyReplyMsg, TransferFromMsg,
+        TransferOwnershipMsg, UpdateMinterMsg, ON_SAFE_TRANSFER_CALLBACK,
     },
-    state::{MPC1155ContractState, TokenInfo},
+    state::{MPC1155ContractState, RoyaltyInfo, TokenInfo},
 };
 
 fn mock_address(le: u8) -> Address {
@@ -29,6 +45,23 @@ fn mock_address(le: u8) -> Address {
     }
 }
 
+fn mock_contract_address(le: u8) -> Address {
+    Address {
+        address_type: AddressType::PublicContract,
+        identifier: [
+            le, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8,
+        ],
+    }
+}
+
+fn mock_callback_context(success: bool) -> CallbackContext {
+    CallbackContext {
+        success,
+        results: vec![],
+    }
+}
+
 fn mock_contract_context(sender: u8) -> ContractContext {
     ContractContext {
         contract_address: mock_address(1u8),
@@ -65,6 +98,16 @@ fn proper_execute_init() {
             balances: BTreeMap::new(),
             operator_approvals: BTreeMap::new(),
             tokens: BTreeMap::new(),
+            default_royalty: None,
+            token_royalties: BTreeMap::new(),
+            soulbound: false,
+            soulbound_holder_burn: false,
+            soulbound_tokens: BTreeSet::new(),
+            token_minters: BTreeMap::new(),
+            creators: BTreeSet::new(),
+            public_creation: false,
+            allowances: BTreeMap::new(),
+            frozen_tokens: BTreeSet::new(),
         }
     );
 }
@@ -151,6 +194,8 @@ fn proper_mint() {
             token_id: 1,
             amount: 10,
             token_uri: Some("1.json".to_string()),
+            royalty: None,
+            max_supply: None,
         },
     };
 
@@ -161,6 +206,8 @@ fn proper_mint() {
             1,
             TokenInfo {
                 token_uri: Some("1.json".to_string()),
+                total_supply: 10,
+                max_supply: None,
             }
         )])
     );
@@ -177,6 +224,8 @@ fn proper_mint() {
                 token_id: 2,
                 amount: 20,
                 token_uri: Some("2.json".to_string()),
+                royalty: None,
+                max_supply: None,
             },
         },
         MintMsg {
@@ -185,6 +234,8 @@ fn proper_mint() {
                 token_id: 1,
                 amount: 50,
                 token_uri: None,
+                royalty: None,
+                max_supply: None,
             },
         },
         MintMsg {
@@ -193,6 +244,8 @@ fn proper_mint() {
                 token_id: 1,
                 amount: 1,
                 token_uri: None,
+                royalty: None,
+                max_supply: None,
             },
         },
     ]
@@ -208,12 +261,16 @@ fn proper_mint() {
                 1,
                 TokenInfo {
                     token_uri: Some("1.json".to_string()),
+                    total_supply: 61,
+                    max_supply: None,
                 }
             ),
             (
                 2,
                 TokenInfo {
                     token_uri: Some("2.json".to_string()),
+                    total_supply: 20,
+                    max_supply: None,
                 }
             )
         ])
@@ -251,6 +308,8 @@ fn sender_is_not_minter_on_mint() {
             token_id: 1,
             amount: 10,
             token_uri: Some("1.json".to_string()),
+            royalty: None,
+            max_supply: None,
         },
     };
 
@@ -280,11 +339,15 @@ fn proper_batch_mint() {
                     token_id: 1,
                     amount: 10,
                     token_uri: None,
+                    royalty: None,
+                    max_supply: None,
                 },
                 TokenMintInfoMsg {
                     token_id: 2,
                     amount: 20,
                     token_uri: Some("2.json".to_string()),
+                    royalty: None,
+                    max_supply: None,
                 },
             ],
         },
@@ -295,11 +358,15 @@ fn proper_batch_mint() {
                     token_id: 1,
                     amount: 100,
                     token_uri: None,
+                    royalty: None,
+                    max_supply: None,
                 },
                 TokenMintInfoMsg {
                     token_id: 3,
                     amount: 30,
                     token_uri: Some("3.json".to_string()),
+                    royalty: None,
+                    max_supply: None,
                 },
             ],
         },
@@ -312,17 +379,28 @@ fn proper_batch_mint() {
     assert_eq!(
         state.tokens,
         BTreeMap::from([
-            (1, TokenInfo { token_uri: None }),
+            (
+                1,
+                TokenInfo {
+                    token_uri: None,
+                    total_supply: 110,
+                    max_supply: None,
+                }
+            ),
             (
                 2,
                 TokenInfo {
                     token_uri: Some("2.json".to_string()),
+                    total_supply: 20,
+                    max_supply: None,
                 }
             ),
             (
                 3,
                 TokenInfo {
                     token_uri: Some("3.json".to_string()),
+                    total_supply: 30,
+                    max_supply: None,
                 }
             )
         ])
@@ -363,11 +441,15 @@ fn check_balances() {
                     token_id: 1,
                     amount: 10,
                     token_uri: None,
+                    royalty: None,
+                    max_supply: None,
                 },
                 TokenMintInfoMsg {
                     token_id: 2,
                     amount: 20,
                     token_uri: Some("2.json".to_string()),
+                    royalty: None,
+                    max_supply: None,
                 },
             ],
         },
@@ -378,11 +460,15 @@ fn check_balances() {
                     token_id: 1,
                     amount: 100,
                     token_uri: None,
+                    royalty: None,
+                    max_supply: None,
                 },
                 TokenMintInfoMsg {
                     token_id: 3,
                     amount: 30,
                     token_uri: Some("3.json".to_string()),
+                    royalty: None,
+                    max_supply: None,
                 },
             ],
         },
@@ -430,11 +516,15 @@ fn check_balances_fail() {
                     token_id: 1,
                     amount: 10,
                     token_uri: None,
+                    royalty: None,
+                    max_supply: None,
                 },
                 TokenMintInfoMsg {
                     token_id: 2,
                     amount: 20,
                     token_uri: Some("2.json".to_string()),
+                    royalty: None,
+                    max_supply: None,
                 },
             ],
         },
@@ -445,11 +535,15 @@ fn check_balances_fail() {
                     token_id: 1,
                     amount: 100,
                     token_uri: None,
+                    royalty: None,
+                    max_supply: None,
                 },
                 TokenMintInfoMsg {
                     token_id: 3,
                     amount: 30,
                     token_uri: Some("3.json".to_string()),
+                    royalty: None,
+                    max_supply: None,
                 },
             ],
         },
@@ -489,11 +583,15 @@ fn sender_is_not_minter_on_batch_mint() {
                 token_id: 1,
                 amount: 100,
                 token_uri: None,
+                royalty: None,
+                max_supply: None,
             },
             TokenMintInfoMsg {
                 token_id: 3,
                 amount: 30,
                 token_uri: Some("3.json".to_string()),
+                royalty: None,
+                max_supply: None,
             },
         ],
     };
@@ -634,11 +732,15 @@ fn proper_transfer_from() {
                     token_id: 1,
                     amount: 10,
                     token_uri: None,
+                    royalty: None,
+                    max_supply: None,
                 },
                 TokenMintInfoMsg {
                     token_id: 2,
                     amount: 20,
                     token_uri: Some("2.json".to_string()),
+                    royalty: None,
+                    max_supply: None,
                 },
             ],
         },
@@ -649,11 +751,15 @@ fn proper_transfer_from() {
                     token_id: 1,
                     amount: 100,
                     token_uri: None,
+                    royalty: None,
+                    max_supply: None,
                 },
                 TokenMintInfoMsg {
                     token_id: 3,
                     amount: 30,
                     token_uri: Some("3.json".to_string()),
+                    royalty: None,
+                    max_supply: None,
                 },
             ],
         },
@@ -757,11 +863,7 @@ fn proper_transfer_from() {
         BTreeMap::from([
             (
                 1,
-                BTreeMap::from([
-                    (mock_address(alice), 0),
-                    (mock_address(bob), 106),
-                    (mock_address(jack), 4)
-                ])
+                BTreeMap::from([(mock_address(bob), 106), (mock_address(jack), 4)])
             ),
             (
                 2,
@@ -797,6 +899,8 @@ fn transfer_not_owned_token() {
             token_id: 1,
             amount: 10,
             token_uri: Some("1.json".to_string()),
+            royalty: None,
+            max_supply: None,
         },
     };
 
@@ -814,7 +918,7 @@ fn transfer_not_owned_token() {
 }
 
 #[test]
-#[should_panic]
+#[should_panic(expected = "Balance check has failed")]
 fn transfer_more_than_balance() {
     let owner = 1u8;
     let minter = 2u8;
@@ -835,6 +939,8 @@ fn transfer_more_than_balance() {
             token_id: 1,
             amount: 10,
             token_uri: Some("1.json".to_string()),
+            royalty: None,
+            max_supply: None,
         },
     };
 
@@ -877,11 +983,15 @@ fn proper_batch_transfer_from() {
                 token_id: 1,
                 amount: 10,
                 token_uri: None,
+                royalty: None,
+                max_supply: None,
             },
             TokenMintInfoMsg {
                 token_id: 2,
                 amount: 20,
                 token_uri: Some("2.json".to_string()),
+                royalty: None,
+                max_supply: None,
             },
         ],
     };
@@ -944,6 +1054,8 @@ fn batch_transfer_not_owned_token() {
             token_id: 1,
             amount: 10,
             token_uri: Some("1.json".to_string()),
+            royalty: None,
+            max_supply: None,
         },
     };
 
@@ -965,7 +1077,7 @@ fn batch_transfer_not_owned_token() {
 }
 
 #[test]
-#[should_panic]
+#[should_panic(expected = "Balance check has failed")]
 fn batch_transfer_more_than_balance() {
     let owner = 1u8;
     let minter = 2u8;
@@ -986,6 +1098,8 @@ fn batch_transfer_more_than_balance() {
             token_id: 1,
             amount: 10,
             token_uri: Some("1.json".to_string()),
+            royalty: None,
+            max_supply: None,
         },
     };
 
@@ -1026,6 +1140,8 @@ fn proper_burn() {
             token_id: 1,
             amount: 10,
             token_uri: Some("1.json".to_string()),
+            royalty: None,
+            max_supply: None,
         },
     };
 
@@ -1043,6 +1159,7 @@ fn proper_burn() {
         state.balances,
         BTreeMap::from([(1, BTreeMap::from([(mock_address(alice), 9)]))])
     );
+    assert_eq!(state.total_supply(1), 9);
 }
 
 #[test]
@@ -1067,6 +1184,8 @@ fn burn_not_owned_token() {
             token_id: 1,
             amount: 10,
             token_uri: Some("1.json".to_string()),
+            royalty: None,
+            max_supply: None,
         },
     };
 
@@ -1083,7 +1202,7 @@ fn burn_not_owned_token() {
 }
 
 #[test]
-#[should_panic]
+#[should_panic(expected = "Balance check has failed")]
 fn burn_more_than_balance() {
     let owner = 1u8;
     let minter = 2u8;
@@ -1104,6 +1223,8 @@ fn burn_more_than_balance() {
             token_id: 1,
             amount: 10,
             token_uri: Some("1.json".to_string()),
+            royalty: None,
+            max_supply: None,
         },
     };
 
@@ -1139,6 +1260,8 @@ fn proper_batch_burn() {
             token_id: 1,
             amount: 10,
             token_uri: Some("1.json".to_string()),
+            royalty: None,
+            max_supply: None,
         },
     };
 
@@ -1150,6 +1273,8 @@ fn proper_batch_burn() {
             token_id: 2,
             amount: 10,
             token_uri: None,
+            royalty: None,
+            max_supply: None,
         },
     };
 
@@ -1176,6 +1301,8 @@ fn proper_batch_burn() {
             (2, BTreeMap::from([(mock_address(alice), 8)]))
         ])
     );
+    assert_eq!(state.total_supply(1), 9);
+    assert_eq!(state.total_supply(2), 8);
 }
 
 #[test]
@@ -1200,6 +1327,8 @@ fn batch_burn_not_owned_token() {
             token_id: 1,
             amount: 10,
             token_uri: Some("1.json".to_string()),
+            royalty: None,
+            max_supply: None,
         },
     };
 
@@ -1211,6 +1340,8 @@ fn batch_burn_not_owned_token() {
             token_id: 2,
             amount: 10,
             token_uri: None,
+            royalty: None,
+            max_supply: None,
         },
     };
 
@@ -1254,6 +1385,8 @@ fn batch_burn_more_than_balance() {
             token_id: 1,
             amount: 10,
             token_uri: Some("1.json".to_string()),
+            royalty: None,
+            max_supply: None,
         },
     };
 
@@ -1268,3 +1401,3819 @@ fn batch_burn_more_than_balance() {
     };
     let _ = execute_batch_burn(&mock_contract_context(bob), &mut state, &batch_burn_msg);
 }
+
+#[test]
+fn proper_royalties() {
+    let owner = 1u8;
+    let minter = 2u8;
+    let alice = 10u8;
+    let creator = 11u8;
+    let artist = 12u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(owner)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(owner), &msg);
+
+    let artist_royalty = RoyaltyInfo {
+        receiver: mock_address(artist),
+        basis_points: 1_000,
+    };
+    let _ = execute_batch_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &BatchMintMsg {
+            to: mock_address(alice),
+            token_infos: vec![
+                TokenMintInfoMsg {
+                    token_id: 1,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: Some(artist_royalty.clone()),
+                    max_supply: None,
+                },
+                TokenMintInfoMsg {
+                    token_id: 2,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+            ],
+        },
+    );
+    assert_eq!(
+        state.token_royalties,
+        BTreeMap::from([(1, artist_royalty.clone())])
+    );
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            to: mock_address(alice),
+            token_info: TokenMintInfoMsg {
+                token_id: 1,
+                amount: 5,
+                token_uri: None,
+                royalty: Some(RoyaltyInfo {
+                    receiver: mock_address(minter),
+                    basis_points: 5_000,
+                }),
+                max_supply: None,
+            },
+        },
+    );
+    assert_eq!(state.token_royalties, BTreeMap::from([(1, artist_royalty)]));
+    assert_eq!(
+        state.royalty_info(1, 1_000),
+        Some((mock_address(artist), 100))
+    );
+    assert_eq!(state.royalty_info(2, 1_000), None);
+
+    let _ = execute_set_default_royalty(
+        &mock_contract_context(owner),
+        &mut state,
+        &SetDefaultRoyaltyMsg {
+            royalty: Some(RoyaltyInfo {
+                receiver: mock_address(creator),
+                basis_points: 250,
+            }),
+        },
+    );
+    assert_eq!(
+        state.royalty_info(2, 1_000),
+        Some((mock_address(creator), 25))
+    );
+
+    let _ = execute_set_token_royalty(
+        &mock_contract_context(owner),
+        &mut state,
+        &SetTokenRoyaltyMsg {
+            token_id: 1,
+            royalty: None,
+        },
+    );
+    assert_eq!(state.token_royalties, BTreeMap::new());
+    assert_eq!(
+        state.royalty_info(1, 1_000),
+        Some((mock_address(creator), 25))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn set_token_royalty_not_owner() {
+    let owner = 1u8;
+    let minter = 2u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(owner)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(owner), &msg);
+
+    let _ = execute_set_token_royalty(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetTokenRoyaltyMsg {
+            token_id: 1,
+            royalty: None,
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Royalty basis points must not exceed 10000")]
+fn set_too_high_default_royalty() {
+    let owner = 1u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(owner)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(owner),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(owner), &msg);
+
+    let _ = execute_set_default_royalty(
+        &mock_contract_context(owner),
+        &mut state,
+        &SetDefaultRoyaltyMsg {
+            royalty: Some(RoyaltyInfo {
+                receiver: mock_address(owner),
+                basis_points: 10_001,
+            }),
+        },
+    );
+}
+
+#[test]
+fn proper_royalty_info_reply() {
+    let owner = 1u8;
+    let marketplace = 20u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(owner)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(owner),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(owner), &msg);
+
+    let _ = execute_mint(
+        &mock_contract_context(owner),
+        &mut state,
+        &MintMsg {
+            to: mock_address(owner),
+            token_info: TokenMintInfoMsg {
+                token_id: 1,
+                amount: 10,
+                token_uri: None,
+                royalty: None,
+                max_supply: None,
+            },
+        },
+    );
+
+    let events = execute_royalty_info(
+        &mock_contract_context(marketplace),
+        &mut state,
+        &RoyaltyInfoMsg {
+            token_id: 1,
+            sale_price: 2_000,
+            callback_action: 0x41,
+        },
+    );
+
+    let mut event_group = EventGroup::builder();
+    build_msg_reply(
+        &mut event_group,
+        &mock_address(marketplace),
+        0x41,
+        &RoyaltyInfoReplyMsg {
+            token_id: 1,
+            sale_price: 2_000,
+            receiver: None,
+            royalty_amount: 0,
+        },
+    );
+    assert_eq!(events, vec![event_group.build()]);
+}
+
+#[test]
+fn proper_set_soulbound() {
+    let minter = 1u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(minter)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(minter), &msg);
+
+    let _ = execute_batch_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &BatchMintMsg {
+            to: mock_address(alice),
+            token_infos: vec![
+                TokenMintInfoMsg {
+                    token_id: 1,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+                TokenMintInfoMsg {
+                    token_id: 2,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+            ],
+        },
+    );
+
+    // allow holder burns without making the whole collection soulbound
+    let _ = execute_set_soulbound(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetSoulboundMsg {
+            soulbound: false,
+            holder_burn: true,
+        },
+    );
+
+    let _ = execute_set_token_soulbound(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetTokenSoulboundMsg {
+            token_id: 1,
+            soulbound: true,
+        },
+    );
+    assert!(!state.soulbound);
+    assert!(state.soulbound_holder_burn);
+    assert_eq!(state.soulbound_tokens, BTreeSet::from([1]));
+    assert!(state.is_soulbound(1));
+    assert!(!state.is_soulbound(2));
+
+    let _ = execute_set_soulbound(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetSoulboundMsg {
+            soulbound: true,
+            holder_burn: false,
+        },
+    );
+    assert!(state.is_soulbound(2));
+
+    let _ = execute_set_token_soulbound(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetTokenSoulboundMsg {
+            token_id: 1,
+            soulbound: false,
+        },
+    );
+    assert_eq!(state.soulbound_tokens, BTreeSet::new());
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn set_token_soulbound_not_minter() {
+    let minter = 1u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(minter)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(minter), &msg);
+
+    let _ = execute_batch_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &BatchMintMsg {
+            to: mock_address(alice),
+            token_infos: vec![
+                TokenMintInfoMsg {
+                    token_id: 1,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+                TokenMintInfoMsg {
+                    token_id: 2,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+            ],
+        },
+    );
+
+    let _ = execute_set_token_soulbound(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetTokenSoulboundMsg {
+            token_id: 1,
+            soulbound: true,
+        },
+    );
+    let _ = execute_set_token_soulbound(
+        &mock_contract_context(alice),
+        &mut state,
+        &SetTokenSoulboundMsg {
+            token_id: 2,
+            soulbound: true,
+        },
+    );
+}
+
+#[test]
+fn transfer_not_soulbound_token() {
+    let minter = 1u8;
+    let alice = 10u8;
+    let bob = 11u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(minter)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(minter), &msg);
+
+    let _ = execute_batch_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &BatchMintMsg {
+            to: mock_address(alice),
+            token_infos: vec![
+                TokenMintInfoMsg {
+                    token_id: 1,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+                TokenMintInfoMsg {
+                    token_id: 2,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+            ],
+        },
+    );
+
+    let _ = execute_set_token_soulbound(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetTokenSoulboundMsg {
+            token_id: 1,
+            soulbound: true,
+        },
+    );
+    let _ = execute_transfer_from(
+        &mock_contract_context(alice),
+        &mut state,
+        &TransferFromMsg {
+            from: mock_address(alice),
+            to: mock_address(bob),
+            token_info: TokenTransferInfoMsg {
+                token_id: 2,
+                amount: 1,
+            },
+        },
+    );
+    assert_eq!(state.balances[&2][&mock_address(bob)], 1);
+}
+
+#[test]
+#[should_panic(expected = "Soulbound token can not be transferred")]
+fn transfer_soulbound_token() {
+    let minter = 1u8;
+    let alice = 10u8;
+    let bob = 11u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(minter)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(minter), &msg);
+
+    let _ = execute_batch_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &BatchMintMsg {
+            to: mock_address(alice),
+            token_infos: vec![
+                TokenMintInfoMsg {
+                    token_id: 1,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+                TokenMintInfoMsg {
+                    token_id: 2,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+            ],
+        },
+    );
+
+    let _ = execute_set_token_soulbound(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetTokenSoulboundMsg {
+            token_id: 1,
+            soulbound: true,
+        },
+    );
+    let _ = execute_transfer_from(
+        &mock_contract_context(alice),
+        &mut state,
+        &TransferFromMsg {
+            from: mock_address(alice),
+            to: mock_address(bob),
+            token_info: TokenTransferInfoMsg {
+                token_id: 1,
+                amount: 1,
+            },
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Soulbound token can not be transferred")]
+fn batch_transfer_soulbound_token() {
+    let minter = 1u8;
+    let alice = 10u8;
+    let bob = 11u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(minter)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(minter), &msg);
+
+    let _ = execute_batch_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &BatchMintMsg {
+            to: mock_address(alice),
+            token_infos: vec![
+                TokenMintInfoMsg {
+                    token_id: 1,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+                TokenMintInfoMsg {
+                    token_id: 2,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+            ],
+        },
+    );
+
+    let _ = execute_set_token_soulbound(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetTokenSoulboundMsg {
+            token_id: 1,
+            soulbound: true,
+        },
+    );
+    let _ = execute_batch_transfer_from(
+        &mock_contract_context(alice),
+        &mut state,
+        &BatchTransferFromMsg {
+            from: mock_address(alice),
+            to: mock_address(bob),
+            token_infos: vec![
+                TokenTransferInfoMsg {
+                    token_id: 2,
+                    amount: 1,
+                },
+                TokenTransferInfoMsg {
+                    token_id: 1,
+                    amount: 1,
+                },
+            ],
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Soulbound token can not be approved")]
+fn approve_for_all_soulbound() {
+    let minter = 1u8;
+    let alice = 10u8;
+    let bob = 11u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(minter)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(minter), &msg);
+
+    let _ = execute_batch_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &BatchMintMsg {
+            to: mock_address(alice),
+            token_infos: vec![
+                TokenMintInfoMsg {
+                    token_id: 1,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+                TokenMintInfoMsg {
+                    token_id: 2,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+            ],
+        },
+    );
+
+    let _ = execute_set_token_soulbound(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetTokenSoulboundMsg {
+            token_id: 1,
+            soulbound: true,
+        },
+    );
+    let _ = execute_set_soulbound(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetSoulboundMsg {
+            soulbound: true,
+            holder_burn: false,
+        },
+    );
+    let _ = execute_approve_for_all(
+        &mock_contract_context(alice),
+        &mut state,
+        &ApproveForAllMsg {
+            operator: mock_address(bob),
+        },
+    );
+}
+
+#[test]
+fn minter_burns_soulbound_token() {
+    let minter = 1u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(minter)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(minter), &msg);
+
+    let _ = execute_batch_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &BatchMintMsg {
+            to: mock_address(alice),
+            token_infos: vec![
+                TokenMintInfoMsg {
+                    token_id: 1,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+                TokenMintInfoMsg {
+                    token_id: 2,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+            ],
+        },
+    );
+
+    let _ = execute_set_token_soulbound(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetTokenSoulboundMsg {
+            token_id: 1,
+            soulbound: true,
+        },
+    );
+    let _ = execute_batch_burn(
+        &mock_contract_context(minter),
+        &mut state,
+        &BatchBurnMsg {
+            from: mock_address(alice),
+            token_infos: vec![TokenTransferInfoMsg {
+                token_id: 1,
+                amount: 4,
+            }],
+        },
+    );
+    assert_eq!(state.balances[&1][&mock_address(alice)], 6);
+}
+
+#[test]
+fn holder_burns_soulbound_token() {
+    let minter = 1u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(minter)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(minter), &msg);
+
+    let _ = execute_batch_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &BatchMintMsg {
+            to: mock_address(alice),
+            token_infos: vec![
+                TokenMintInfoMsg {
+                    token_id: 1,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+                TokenMintInfoMsg {
+                    token_id: 2,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+            ],
+        },
+    );
+
+    // allow holder burns without making the whole collection soulbound
+    let _ = execute_set_soulbound(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetSoulboundMsg {
+            soulbound: false,
+            holder_burn: true,
+        },
+    );
+
+    let _ = execute_set_token_soulbound(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetTokenSoulboundMsg {
+            token_id: 1,
+            soulbound: true,
+        },
+    );
+    let _ = execute_burn(
+        &mock_contract_context(alice),
+        &mut state,
+        &BurnMsg {
+            from: mock_address(alice),
+            token_info: TokenTransferInfoMsg {
+                token_id: 1,
+                amount: 10,
+            },
+        },
+    );
+    assert_eq!(state.balance_of(&mock_address(alice), 1), 0);
+}
+
+#[test]
+#[should_panic(expected = "Soulbound token can only be burned by minter or allowed holder")]
+fn holder_burn_soulbound_token_not_allowed() {
+    let minter = 1u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(minter)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(minter), &msg);
+
+    let _ = execute_batch_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &BatchMintMsg {
+            to: mock_address(alice),
+            token_infos: vec![
+                TokenMintInfoMsg {
+                    token_id: 1,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+                TokenMintInfoMsg {
+                    token_id: 2,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+            ],
+        },
+    );
+
+    let _ = execute_set_token_soulbound(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetTokenSoulboundMsg {
+            token_id: 1,
+            soulbound: true,
+        },
+    );
+    let _ = execute_burn(
+        &mock_contract_context(alice),
+        &mut state,
+        &BurnMsg {
+            from: mock_address(alice),
+            token_info: TokenTransferInfoMsg {
+                token_id: 1,
+                amount: 1,
+            },
+        },
+    );
+}
+
+#[test]
+fn creator_manages_soulbound_token() {
+    let owner = 1u8;
+    let minter = 2u8;
+    let creator = 3u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(owner)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(minter), &msg);
+    let _ = execute_set_creator(
+        &mock_contract_context(owner),
+        &mut state,
+        &SetCreatorMsg {
+            creator: mock_address(creator),
+            allowed: true,
+        },
+    );
+    let _ = execute_create(&mock_contract_context(creator), &mut state, &create_msg(1));
+    let _ = execute_mint(
+        &mock_contract_context(creator),
+        &mut state,
+        &mint_msg(alice, 1, 10),
+    );
+
+    let _ = execute_set_token_soulbound(
+        &mock_contract_context(creator),
+        &mut state,
+        &SetTokenSoulboundMsg {
+            token_id: 1,
+            soulbound: true,
+        },
+    );
+    assert!(state.is_soulbound(1));
+
+    let _ = execute_burn(
+        &mock_contract_context(creator),
+        &mut state,
+        &BurnMsg {
+            from: mock_address(alice),
+            token_info: TokenTransferInfoMsg {
+                token_id: 1,
+                amount: 4,
+            },
+        },
+    );
+    assert_eq!(state.balance_of(&mock_address(alice), 1), 6);
+}
+
+#[test]
+#[should_panic(expected = "Soulbound token can only be burned by minter or allowed holder")]
+fn contract_minter_burns_creator_soulbound_token() {
+    let owner = 1u8;
+    let minter = 2u8;
+    let creator = 3u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(owner)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(minter), &msg);
+    let _ = execute_set_creator(
+        &mock_contract_context(owner),
+        &mut state,
+        &SetCreatorMsg {
+            creator: mock_address(creator),
+            allowed: true,
+        },
+    );
+    let _ = execute_create(&mock_contract_context(creator), &mut state, &create_msg(1));
+    let _ = execute_mint(
+        &mock_contract_context(creator),
+        &mut state,
+        &mint_msg(alice, 1, 10),
+    );
+    let _ = execute_set_token_soulbound(
+        &mock_contract_context(creator),
+        &mut state,
+        &SetTokenSoulboundMsg {
+            token_id: 1,
+            soulbound: true,
+        },
+    );
+
+    let _ = execute_burn(
+        &mock_contract_context(minter),
+        &mut state,
+        &BurnMsg {
+            from: mock_address(alice),
+            token_info: TokenTransferInfoMsg {
+                token_id: 1,
+                amount: 1,
+            },
+        },
+    );
+}
+
+#[test]
+fn owner_manages_soulbound_tokens() {
+    let owner = 1u8;
+    let minter = 2u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(owner)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(minter), &msg);
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &mint_msg(alice, 1, 10),
+    );
+
+    let _ = execute_set_soulbound(
+        &mock_contract_context(owner),
+        &mut state,
+        &SetSoulboundMsg {
+            soulbound: true,
+            holder_burn: false,
+        },
+    );
+    assert!(state.is_soulbound(1));
+
+    let _ = execute_set_token_soulbound(
+        &mock_contract_context(owner),
+        &mut state,
+        &SetTokenSoulboundMsg {
+            token_id: 2,
+            soulbound: true,
+        },
+    );
+    assert_eq!(state.soulbound_tokens, BTreeSet::from([2]));
+
+    let _ = execute_burn(
+        &mock_contract_context(owner),
+        &mut state,
+        &BurnMsg {
+            from: mock_address(alice),
+            token_info: TokenTransferInfoMsg {
+                token_id: 1,
+                amount: 10,
+            },
+        },
+    );
+    assert_eq!(state.balance_of(&mock_address(alice), 1), 0);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn set_soulbound_not_owner() {
+    let owner = 1u8;
+    let minter = 2u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(owner)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(minter), &msg);
+    let _ = execute_set_soulbound(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetSoulboundMsg {
+            soulbound: true,
+            holder_burn: false,
+        },
+    );
+}
+
+#[test]
+fn proper_supply_tracking() {
+    let minter = 2u8;
+    let alice = 10u8;
+    let bob = 11u8;
+
+    let msg = InitMsg {
+        owner: None,
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(minter), &msg);
+
+    let _ = execute_batch_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &BatchMintMsg {
+            to: mock_address(alice),
+            token_infos: vec![TokenMintInfoMsg {
+                token_id: 1,
+                amount: 10,
+                token_uri: None,
+                royalty: None,
+                max_supply: Some(15),
+            }],
+        },
+    );
+    assert!(state.exists(1));
+    assert!(!state.exists(2));
+    assert_eq!(state.total_supply(1), 10);
+    assert_eq!(state.max_supply(1), Some(15));
+    assert_eq!(state.total_supply(2), 0);
+    assert_eq!(state.max_supply(2), None);
+
+    let batch_mint_msg = BatchMintMsg {
+        to: mock_address(bob),
+        token_infos: vec![
+            TokenMintInfoMsg {
+                token_id: 1,
+                amount: 5,
+                token_uri: None,
+                royalty: None,
+                max_supply: Some(100),
+            },
+            TokenMintInfoMsg {
+                token_id: 2,
+                amount: 20,
+                token_uri: None,
+                royalty: None,
+                max_supply: None,
+            },
+        ],
+    };
+    let _ = execute_batch_mint(&mock_contract_context(minter), &mut state, &batch_mint_msg);
+    assert_eq!(state.total_supply(1), 15);
+    assert_eq!(state.max_supply(1), Some(15));
+    assert_eq!(state.total_supply(2), 20);
+
+    let transfer_msg = TransferFromMsg {
+        from: mock_address(bob),
+        to: mock_address(alice),
+        token_info: TokenTransferInfoMsg {
+            token_id: 2,
+            amount: 5,
+        },
+    };
+    let _ = execute_transfer_from(&mock_contract_context(bob), &mut state, &transfer_msg);
+    assert_eq!(state.total_supply(2), 20);
+
+    let batch_burn_msg = BatchBurnMsg {
+        from: mock_address(alice),
+        token_infos: vec![
+            TokenTransferInfoMsg {
+                token_id: 1,
+                amount: 10,
+            },
+            TokenTransferInfoMsg {
+                token_id: 2,
+                amount: 5,
+            },
+        ],
+    };
+    let _ = execute_batch_burn(&mock_contract_context(alice), &mut state, &batch_burn_msg);
+    assert_eq!(state.total_supply(1), 5);
+    assert_eq!(state.total_supply(2), 15);
+
+    let burn_msg = BurnMsg {
+        from: mock_address(bob),
+        token_info: TokenTransferInfoMsg {
+            token_id: 1,
+            amount: 5,
+        },
+    };
+    let _ = execute_burn(&mock_contract_context(bob), &mut state, &burn_msg);
+    assert_eq!(state.total_supply(1), 0);
+    assert!(!state.exists(1));
+    assert_eq!(state.max_supply(1), Some(15));
+
+    let mint_msg = MintMsg {
+        to: mock_address(alice),
+        token_info: TokenMintInfoMsg {
+            token_id: 1,
+            amount: 15,
+            token_uri: None,
+            royalty: None,
+            max_supply: None,
+        },
+    };
+    let _ = execute_mint(&mock_contract_context(minter), &mut state, &mint_msg);
+    assert_eq!(state.total_supply(1), 15);
+}
+
+#[test]
+#[should_panic(expected = "Max supply exceeded")]
+fn mint_more_than_max_supply() {
+    let minter = 2u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: None,
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(minter), &msg);
+
+    let _ = execute_batch_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &BatchMintMsg {
+            to: mock_address(alice),
+            token_infos: vec![TokenMintInfoMsg {
+                token_id: 1,
+                amount: 10,
+                token_uri: None,
+                royalty: None,
+                max_supply: Some(15),
+            }],
+        },
+    );
+
+    let mint_msg = MintMsg {
+        to: mock_address(alice),
+        token_info: TokenMintInfoMsg {
+            token_id: 1,
+            amount: 6,
+            token_uri: None,
+            royalty: None,
+            max_supply: None,
+        },
+    };
+    let _ = execute_mint(&mock_contract_context(minter), &mut state, &mint_msg);
+}
+
+#[test]
+#[should_panic(expected = "Max supply exceeded")]
+fn batch_mint_more_than_max_supply() {
+    let minter = 2u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: None,
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(minter), &msg);
+
+    let _ = execute_batch_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &BatchMintMsg {
+            to: mock_address(alice),
+            token_infos: vec![TokenMintInfoMsg {
+                token_id: 1,
+                amount: 10,
+                token_uri: None,
+                royalty: None,
+                max_supply: Some(15),
+            }],
+        },
+    );
+
+    let batch_mint_msg = BatchMintMsg {
+        to: mock_address(alice),
+        token_infos: vec![
+            TokenMintInfoMsg {
+                token_id: 1,
+                amount: 5,
+                token_uri: None,
+                royalty: None,
+                max_supply: None,
+            },
+            TokenMintInfoMsg {
+                token_id: 1,
+                amount: 1,
+                token_uri: None,
+                royalty: None,
+                max_supply: None,
+            },
+        ],
+    };
+    let _ = execute_batch_mint(&mock_contract_context(minter), &mut state, &batch_mint_msg);
+}
+
+#[test]
+#[should_panic(expected = "Max supply exceeded")]
+fn first_mint_more_than_max_supply() {
+    let minter = 2u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: None,
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(minter), &msg);
+
+    let mint_msg = MintMsg {
+        to: mock_address(alice),
+        token_info: TokenMintInfoMsg {
+            token_id: 1,
+            amount: 2,
+            token_uri: None,
+            royalty: None,
+            max_supply: Some(1),
+        },
+    };
+    let _ = execute_mint(&mock_contract_context(minter), &mut state, &mint_msg);
+}
+
+#[test]
+fn proper_balance_of() {
+    let minter = 2u8;
+    let alice = 10u8;
+    let bob = 11u8;
+
+    let msg = InitMsg {
+        owner: None,
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(minter), &msg);
+
+    let _ = execute_batch_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &BatchMintMsg {
+            to: mock_address(alice),
+            token_infos: vec![TokenMintInfoMsg {
+                token_id: 1,
+                amount: 10,
+                token_uri: None,
+                royalty: None,
+                max_supply: Some(15),
+            }],
+        },
+    );
+    let mint_msg = MintMsg {
+        to: mock_address(bob),
+        token_info: TokenMintInfoMsg {
+            token_id: 2,
+            amount: 20,
+            token_uri: None,
+            royalty: None,
+            max_supply: None,
+        },
+    };
+    let _ = execute_mint(&mock_contract_context(minter), &mut state, &mint_msg);
+
+    assert_eq!(state.balance_of(&mock_address(alice), 1), 10);
+    assert_eq!(state.balance_of(&mock_address(bob), 1), 0);
+    assert_eq!(state.balance_of(&mock_address(alice), 3), 0);
+    assert_eq!(
+        state.balance_of_batch(
+            &[mock_address(alice), mock_address(bob), mock_address(bob)],
+            &[1, 1, 2]
+        ),
+        vec![10, 0, 20]
+    );
+
+    let transfer_from_msg = TransferFromMsg {
+        from: mock_address(alice),
+        to: mock_address(bob),
+        token_info: TokenTransferInfoMsg {
+            token_id: 1,
+            amount: 10,
+        },
+    };
+    let _ = execute_transfer_from(
+        &mock_contract_context(alice),
+        &mut state,
+        &transfer_from_msg,
+    );
+    assert_eq!(
+        state.balances,
+        BTreeMap::from([
+            (1, BTreeMap::from([(mock_address(bob), 10)])),
+            (2, BTreeMap::from([(mock_address(bob), 20)]))
+        ])
+    );
+
+    let batch_burn_msg = BatchBurnMsg {
+        from: mock_address(bob),
+        token_infos: vec![TokenTransferInfoMsg {
+            token_id: 2,
+            amount: 20,
+        }],
+    };
+    let _ = execute_batch_burn(&mock_contract_context(bob), &mut state, &batch_burn_msg);
+    assert_eq!(
+        state.balances,
+        BTreeMap::from([(1, BTreeMap::from([(mock_address(bob), 10)]))])
+    );
+}
+
+#[test]
+#[should_panic(expected = "Length of provided lists must be equal")]
+fn balance_of_batch_length_mismatch() {
+    let minter = 2u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: None,
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(minter), &msg);
+
+    let _ = execute_batch_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &BatchMintMsg {
+            to: mock_address(alice),
+            token_infos: vec![TokenMintInfoMsg {
+                token_id: 1,
+                amount: 10,
+                token_uri: None,
+                royalty: None,
+                max_supply: Some(15),
+            }],
+        },
+    );
+    let _ = state.balance_of_batch(&[mock_address(alice)], &[1, 2]);
+}
+
+#[test]
+#[should_panic(expected = "Balance check has failed")]
+fn transfer_without_balance() {
+    let minter = 2u8;
+    let alice = 10u8;
+    let bob = 11u8;
+
+    let msg = InitMsg {
+        owner: None,
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(minter), &msg);
+
+    let _ = execute_batch_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &BatchMintMsg {
+            to: mock_address(alice),
+            token_infos: vec![TokenMintInfoMsg {
+                token_id: 1,
+                amount: 10,
+                token_uri: None,
+                royalty: None,
+                max_supply: Some(15),
+            }],
+        },
+    );
+
+    let transfer_from_msg = TransferFromMsg {
+        from: mock_address(bob),
+        to: mock_address(alice),
+        token_info: TokenTransferInfoMsg {
+            token_id: 1,
+            amount: 1,
+        },
+    };
+    let _ = execute_transfer_from(&mock_contract_context(bob), &mut state, &transfer_from_msg);
+}
+
+#[test]
+#[should_panic(expected = "Balance check has failed")]
+fn batch_transfer_without_balance() {
+    let minter = 2u8;
+    let alice = 10u8;
+    let bob = 11u8;
+
+    let msg = InitMsg {
+        owner: None,
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(minter), &msg);
+
+    let _ = execute_batch_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &BatchMintMsg {
+            to: mock_address(alice),
+            token_infos: vec![TokenMintInfoMsg {
+                token_id: 1,
+                amount: 10,
+                token_uri: None,
+                royalty: None,
+                max_supply: Some(15),
+            }],
+        },
+    );
+
+    let batch_transfer_from_msg = BatchTransferFromMsg {
+        from: mock_address(bob),
+        to: mock_address(alice),
+        token_infos: vec![TokenTransferInfoMsg {
+            token_id: 7,
+            amount: 1,
+        }],
+    };
+    let _ = execute_batch_transfer_from(
+        &mock_contract_context(bob),
+        &mut state,
+        &batch_transfer_from_msg,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Balance check has failed")]
+fn burn_without_balance() {
+    let minter = 2u8;
+    let alice = 10u8;
+    let bob = 11u8;
+
+    let msg = InitMsg {
+        owner: None,
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(minter), &msg);
+
+    let _ = execute_batch_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &BatchMintMsg {
+            to: mock_address(alice),
+            token_infos: vec![TokenMintInfoMsg {
+                token_id: 1,
+                amount: 10,
+                token_uri: None,
+                royalty: None,
+                max_supply: Some(15),
+            }],
+        },
+    );
+
+    let burn_msg = BurnMsg {
+        from: mock_address(bob),
+        token_info: TokenTransferInfoMsg {
+            token_id: 1,
+            amount: 1,
+        },
+    };
+    let _ = execute_burn(&mock_contract_context(bob), &mut state, &burn_msg);
+}
+
+#[test]
+#[should_panic(expected = "Balance check has failed")]
+fn check_balances_of_unknown_token() {
+    let minter = 2u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: None,
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(minter), &msg);
+
+    let _ = execute_batch_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &BatchMintMsg {
+            to: mock_address(alice),
+            token_infos: vec![TokenMintInfoMsg {
+                token_id: 1,
+                amount: 10,
+                token_uri: None,
+                royalty: None,
+                max_supply: Some(15),
+            }],
+        },
+    );
+
+    let msg = CheckBalancesMsg {
+        owner: mock_address(alice),
+        token_ids: vec![1, 2],
+        amounts: vec![1, 1],
+    };
+    execute_check_balances(&mock_contract_context(minter), &mut state, msg);
+}
+
+fn create_msg(token_id: u128) -> CreateMsg {
+    CreateMsg {
+        token_id,
+        token_uri: Some(format!("{}.json", token_id)),
+        royalty: None,
+        max_supply: None,
+    }
+}
+
+fn mint_msg(to: u8, token_id: u128, amount: u128) -> MintMsg {
+    MintMsg {
+        to: mock_address(to),
+        token_info: TokenMintInfoMsg {
+            token_id,
+            amount,
+            token_uri: None,
+            royalty: None,
+            max_supply: None,
+        },
+    }
+}
+
+#[test]
+fn proper_create() {
+    let owner = 1u8;
+    let minter = 2u8;
+    let creator = 3u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(owner)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(minter), &msg);
+
+    let _ = execute_set_creator(
+        &mock_contract_context(owner),
+        &mut state,
+        &SetCreatorMsg {
+            creator: mock_address(creator),
+            allowed: true,
+        },
+    );
+    assert_eq!(state.creators, BTreeSet::from([mock_address(creator)]));
+
+    let _ = execute_create(
+        &mock_contract_context(creator),
+        &mut state,
+        &CreateMsg {
+            token_id: 5,
+            token_uri: Some("5.json".to_string()),
+            royalty: Some(RoyaltyInfo {
+                receiver: mock_address(creator),
+                basis_points: 500,
+            }),
+            max_supply: Some(100),
+        },
+    );
+    assert_eq!(
+        state.token_info(5),
+        Some(&TokenInfo {
+            token_uri: Some("5.json".to_string()),
+            total_supply: 0,
+            max_supply: Some(100),
+        })
+    );
+    assert_eq!(state.token_minter(5), mock_address(creator));
+    assert_eq!(state.token_minter(6), mock_address(minter));
+    assert_eq!(
+        state.royalty_info(5, 1_000),
+        Some((mock_address(creator), 50))
+    );
+    assert!(!state.exists(5));
+
+    let _ = execute_mint(
+        &mock_contract_context(creator),
+        &mut state,
+        &mint_msg(alice, 5, 10),
+    );
+    assert_eq!(state.balance_of(&mock_address(alice), 5), 10);
+    assert_eq!(
+        state.token_info(5).unwrap().token_uri,
+        Some("5.json".to_string())
+    );
+
+    let _ = execute_batch_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &BatchMintMsg {
+            to: mock_address(alice),
+            token_infos: vec![TokenMintInfoMsg {
+                token_id: 6,
+                amount: 1,
+                token_uri: None,
+                royalty: None,
+                max_supply: None,
+            }],
+        },
+    );
+    assert_eq!(state.balance_of(&mock_address(alice), 6), 1);
+    assert_eq!(
+        state.token_minters,
+        BTreeMap::from([(5, mock_address(creator))])
+    );
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn create_not_allowed() {
+    let owner = 1u8;
+    let minter = 2u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(owner)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(minter), &msg);
+    let _ = execute_create(&mock_contract_context(alice), &mut state, &create_msg(1));
+}
+
+#[test]
+#[should_panic(expected = "Token id already exists")]
+fn create_existing_token() {
+    let owner = 1u8;
+    let minter = 2u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(owner)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(minter), &msg);
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &mint_msg(alice, 1, 1),
+    );
+
+    let _ = execute_set_public_creation(
+        &mock_contract_context(owner),
+        &mut state,
+        &SetPublicCreationMsg {
+            public_creation: true,
+        },
+    );
+    let _ = execute_create(&mock_contract_context(alice), &mut state, &create_msg(1));
+}
+
+#[test]
+fn proper_public_creation() {
+    let owner = 1u8;
+    let minter = 2u8;
+    let alice = 10u8;
+    let bob = 11u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(owner)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(minter), &msg);
+    let _ = execute_set_public_creation(
+        &mock_contract_context(owner),
+        &mut state,
+        &SetPublicCreationMsg {
+            public_creation: true,
+        },
+    );
+    assert!(state.public_creation);
+
+    let _ = execute_create(&mock_contract_context(alice), &mut state, &create_msg(1));
+    let _ = execute_create(&mock_contract_context(bob), &mut state, &create_msg(2));
+    assert_eq!(state.token_minter(1), mock_address(alice));
+    assert_eq!(state.token_minter(2), mock_address(bob));
+
+    let _ = execute_mint(
+        &mock_contract_context(bob),
+        &mut state,
+        &mint_msg(alice, 2, 3),
+    );
+    assert_eq!(state.balance_of(&mock_address(alice), 2), 3);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn mint_creator_token_by_contract_minter() {
+    let owner = 1u8;
+    let minter = 2u8;
+    let creator = 3u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(owner)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(minter), &msg);
+    let _ = execute_set_creator(
+        &mock_contract_context(owner),
+        &mut state,
+        &SetCreatorMsg {
+            creator: mock_address(creator),
+            allowed: true,
+        },
+    );
+    let _ = execute_create(&mock_contract_context(creator), &mut state, &create_msg(1));
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &mint_msg(alice, 1, 1),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn batch_mint_foreign_token() {
+    let owner = 1u8;
+    let minter = 2u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(owner)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(minter), &msg);
+    let _ = execute_create(&mock_contract_context(owner), &mut state, &create_msg(1));
+
+    let _ = execute_batch_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &BatchMintMsg {
+            to: mock_address(alice),
+            token_infos: vec![
+                TokenMintInfoMsg {
+                    token_id: 2,
+                    amount: 1,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+                TokenMintInfoMsg {
+                    token_id: 1,
+                    amount: 1,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+            ],
+        },
+    );
+}
+
+#[test]
+fn proper_set_token_uri() {
+    let owner = 1u8;
+    let minter = 2u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(owner)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(minter), &msg);
+    let _ = execute_set_public_creation(
+        &mock_contract_context(owner),
+        &mut state,
+        &SetPublicCreationMsg {
+            public_creation: true,
+        },
+    );
+    let _ = execute_create(&mock_contract_context(alice), &mut state, &create_msg(1));
+
+    let _ = execute_set_token_uri(
+        &mock_contract_context(alice),
+        &mut state,
+        &SetTokenUriMsg {
+            token_id: 1,
+            token_uri: Some("new.json".to_string()),
+        },
+    );
+    assert_eq!(
+        state.token_info(1).unwrap().token_uri,
+        Some("new.json".to_string())
+    );
+
+    let _ = execute_set_token_uri(
+        &mock_contract_context(owner),
+        &mut state,
+        &SetTokenUriMsg {
+            token_id: 1,
+            token_uri: None,
+        },
+    );
+    assert_eq!(state.token_info(1).unwrap().token_uri, None);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn set_token_uri_not_token_minter() {
+    let owner = 1u8;
+    let minter = 2u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(owner)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(minter), &msg);
+    let _ = execute_create(&mock_contract_context(owner), &mut state, &create_msg(1));
+
+    let _ = execute_set_token_uri(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetTokenUriMsg {
+            token_id: 1,
+            token_uri: None,
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Not found")]
+fn set_token_uri_unknown_token() {
+    let owner = 1u8;
+    let minter = 2u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(owner)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(minter), &msg);
+
+    let _ = execute_set_token_uri(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetTokenUriMsg {
+            token_id: 1,
+            token_uri: None,
+        },
+    );
+}
+
+#[test]
+fn proper_set_token_minter() {
+    let owner = 1u8;
+    let minter = 2u8;
+    let creator = 3u8;
+    let alice = 10u8;
+    let bob = 11u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(owner)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(minter), &msg);
+    let _ = execute_set_creator(
+        &mock_contract_context(owner),
+        &mut state,
+        &SetCreatorMsg {
+            creator: mock_address(creator),
+            allowed: true,
+        },
+    );
+    let _ = execute_create(&mock_contract_context(creator), &mut state, &create_msg(1));
+
+    let _ = execute_set_token_minter(
+        &mock_contract_context(creator),
+        &mut state,
+        &SetTokenMinterMsg {
+            token_id: 1,
+            new_minter: mock_address(alice),
+        },
+    );
+    assert_eq!(state.token_minter(1), mock_address(alice));
+
+    let _ = execute_set_token_minter(
+        &mock_contract_context(owner),
+        &mut state,
+        &SetTokenMinterMsg {
+            token_id: 1,
+            new_minter: mock_address(bob),
+        },
+    );
+    let _ = execute_mint(
+        &mock_contract_context(bob),
+        &mut state,
+        &mint_msg(bob, 1, 1),
+    );
+    assert_eq!(state.balance_of(&mock_address(bob), 1), 1);
+
+    let _ = execute_set_creator(
+        &mock_contract_context(owner),
+        &mut state,
+        &SetCreatorMsg {
+            creator: mock_address(creator),
+            allowed: false,
+        },
+    );
+    assert!(state.creators.is_empty());
+    assert!(!state.can_create(&mock_address(creator)));
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn set_token_minter_by_previous_minter() {
+    let owner = 1u8;
+    let minter = 2u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(owner)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(minter), &msg);
+    let _ = execute_create(&mock_contract_context(minter), &mut state, &create_msg(1));
+    for _ in 0..2 {
+        let _ = execute_set_token_minter(
+            &mock_contract_context(minter),
+            &mut state,
+            &SetTokenMinterMsg {
+                token_id: 1,
+                new_minter: mock_address(alice),
+            },
+        );
+    }
+}
+
+#[test]
+fn proper_update_minter_and_transfer_ownership() {
+    let owner = 1u8;
+    let minter = 2u8;
+    let alice = 10u8;
+    let bob = 11u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(owner)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(minter), &msg);
+    let _ = execute_update_minter(
+        &mock_contract_context(owner),
+        &mut state,
+        &UpdateMinterMsg {
+            new_minter: mock_address(alice),
+        },
+    );
+    assert_eq!(state.minter, mock_address(alice));
+    assert_eq!(state.token_minter(1), mock_address(alice));
+
+    let _ = execute_transfer_ownership(
+        &mock_contract_context(owner),
+        &mut state,
+        &TransferOwnershipMsg {
+            new_owner: Some(mock_address(bob)),
+        },
+    );
+    assert_eq!(state.owner, Some(mock_address(bob)));
+    assert!(state.can_create(&mock_address(bob)));
+
+    let _ = execute_transfer_ownership(
+        &mock_contract_context(bob),
+        &mut state,
+        &TransferOwnershipMsg { new_owner: None },
+    );
+    assert_eq!(state.owner, None);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn update_minter_not_owner() {
+    let owner = 1u8;
+    let minter = 2u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(owner)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(minter), &msg);
+    let _ = execute_update_minter(
+        &mock_contract_context(minter),
+        &mut state,
+        &UpdateMinterMsg {
+            new_minter: mock_address(minter),
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn transfer_ownership_not_owner() {
+    let owner = 1u8;
+    let minter = 2u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(owner)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(minter), &msg);
+    let _ = execute_transfer_ownership(
+        &mock_contract_context(minter),
+        &mut state,
+        &TransferOwnershipMsg {
+            new_owner: Some(mock_address(minter)),
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn set_creator_not_owner() {
+    let owner = 1u8;
+    let minter = 2u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(owner)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(minter), &msg);
+    let _ = execute_set_creator(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetCreatorMsg {
+            creator: mock_address(minter),
+            allowed: true,
+        },
+    );
+}
+
+#[test]
+fn proper_allowances() {
+    let alice = 10u8;
+    let bob = 11u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(1)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(2),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_batch_mint(
+        &mock_contract_context(2),
+        &mut state,
+        &BatchMintMsg {
+            to: mock_address(alice),
+            token_infos: vec![
+                TokenMintInfoMsg {
+                    token_id: 1,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+                TokenMintInfoMsg {
+                    token_id: 2,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+            ],
+        },
+    );
+
+    let _ = execute_approve(
+        &mock_contract_context(alice),
+        &mut state,
+        &ApproveMsg {
+            spender: mock_address(bob),
+            token_id: 1,
+            amount: 5,
+        },
+    );
+    assert_eq!(
+        state.allowances,
+        BTreeMap::from([(
+            mock_address(alice),
+            BTreeMap::from([(mock_address(bob), BTreeMap::from([(1, 5)]))])
+        )])
+    );
+
+    let _ = execute_increase_allowance(
+        &mock_contract_context(alice),
+        &mut state,
+        &IncreaseAllowanceMsg {
+            spender: mock_address(bob),
+            token_id: 1,
+            amount: 3,
+        },
+    );
+    assert_eq!(
+        state.allowance(&mock_address(alice), &mock_address(bob), 1),
+        8
+    );
+
+    let _ = execute_decrease_allowance(
+        &mock_contract_context(alice),
+        &mut state,
+        &DecreaseAllowanceMsg {
+            spender: mock_address(bob),
+            token_id: 1,
+            amount: 2,
+        },
+    );
+    assert_eq!(
+        state.allowance(&mock_address(alice), &mock_address(bob), 1),
+        6
+    );
+    assert_eq!(
+        state.allowance(&mock_address(alice), &mock_address(bob), 2),
+        0
+    );
+
+    let _ = execute_approve(
+        &mock_contract_context(alice),
+        &mut state,
+        &ApproveMsg {
+            spender: mock_address(bob),
+            token_id: 1,
+            amount: 0,
+        },
+    );
+    assert!(state.allowances.is_empty());
+}
+
+#[test]
+fn transfer_and_burn_consume_allowance() {
+    let alice = 10u8;
+    let bob = 11u8;
+    let jack = 12u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(1)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(2),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_batch_mint(
+        &mock_contract_context(2),
+        &mut state,
+        &BatchMintMsg {
+            to: mock_address(alice),
+            token_infos: vec![
+                TokenMintInfoMsg {
+                    token_id: 1,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+                TokenMintInfoMsg {
+                    token_id: 2,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+            ],
+        },
+    );
+
+    let _ = execute_approve(
+        &mock_contract_context(alice),
+        &mut state,
+        &ApproveMsg {
+            spender: mock_address(bob),
+            token_id: 1,
+            amount: 5,
+        },
+    );
+
+    let transfer_from_msg = TransferFromMsg {
+        from: mock_address(alice),
+        to: mock_address(jack),
+        token_info: TokenTransferInfoMsg {
+            token_id: 1,
+            amount: 2,
+        },
+    };
+    let _ = execute_transfer_from(&mock_contract_context(bob), &mut state, &transfer_from_msg);
+    assert_eq!(state.balance_of(&mock_address(jack), 1), 2);
+    assert_eq!(
+        state.allowance(&mock_address(alice), &mock_address(bob), 1),
+        3
+    );
+
+    let batch_transfer_from_msg = BatchTransferFromMsg {
+        from: mock_address(alice),
+        to: mock_address(jack),
+        token_infos: vec![TokenTransferInfoMsg {
+            token_id: 1,
+            amount: 1,
+        }],
+    };
+    let _ = execute_batch_transfer_from(
+        &mock_contract_context(bob),
+        &mut state,
+        &batch_transfer_from_msg,
+    );
+    assert_eq!(state.balance_of(&mock_address(jack), 1), 3);
+    assert_eq!(
+        state.allowance(&mock_address(alice), &mock_address(bob), 1),
+        2
+    );
+
+    let burn_msg = BurnMsg {
+        from: mock_address(alice),
+        token_info: TokenTransferInfoMsg {
+            token_id: 1,
+            amount: 2,
+        },
+    };
+    let _ = execute_burn(&mock_contract_context(bob), &mut state, &burn_msg);
+    assert_eq!(state.balance_of(&mock_address(alice), 1), 5);
+    assert_eq!(state.total_supply(1), 8);
+    assert!(state.allowances.is_empty());
+
+    let _ = execute_approve_for_all(
+        &mock_contract_context(alice),
+        &mut state,
+        &ApproveForAllMsg {
+            operator: mock_address(bob),
+        },
+    );
+    let _ = execute_transfer_from(&mock_contract_context(bob), &mut state, &transfer_from_msg);
+    assert_eq!(state.balance_of(&mock_address(jack), 1), 5);
+    assert!(state.allowances.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "Insufficient allowance")]
+fn transfer_more_than_allowance() {
+    let alice = 10u8;
+    let bob = 11u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(1)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(2),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_batch_mint(
+        &mock_contract_context(2),
+        &mut state,
+        &BatchMintMsg {
+            to: mock_address(alice),
+            token_infos: vec![
+                TokenMintInfoMsg {
+                    token_id: 1,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+                TokenMintInfoMsg {
+                    token_id: 2,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+            ],
+        },
+    );
+
+    let _ = execute_approve(
+        &mock_contract_context(alice),
+        &mut state,
+        &ApproveMsg {
+            spender: mock_address(bob),
+            token_id: 1,
+            amount: 5,
+        },
+    );
+
+    let transfer_from_msg = TransferFromMsg {
+        from: mock_address(alice),
+        to: mock_address(bob),
+        token_info: TokenTransferInfoMsg {
+            token_id: 1,
+            amount: 6,
+        },
+    };
+    let _ = execute_transfer_from(&mock_contract_context(bob), &mut state, &transfer_from_msg);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn batch_transfer_not_approved_token() {
+    let alice = 10u8;
+    let bob = 11u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(1)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(2),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_batch_mint(
+        &mock_contract_context(2),
+        &mut state,
+        &BatchMintMsg {
+            to: mock_address(alice),
+            token_infos: vec![
+                TokenMintInfoMsg {
+                    token_id: 1,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+                TokenMintInfoMsg {
+                    token_id: 2,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+            ],
+        },
+    );
+
+    let _ = execute_approve(
+        &mock_contract_context(alice),
+        &mut state,
+        &ApproveMsg {
+            spender: mock_address(bob),
+            token_id: 1,
+            amount: 5,
+        },
+    );
+
+    let batch_transfer_from_msg = BatchTransferFromMsg {
+        from: mock_address(alice),
+        to: mock_address(bob),
+        token_infos: vec![
+            TokenTransferInfoMsg {
+                token_id: 1,
+                amount: 1,
+            },
+            TokenTransferInfoMsg {
+                token_id: 2,
+                amount: 1,
+            },
+        ],
+    };
+    let _ = execute_batch_transfer_from(
+        &mock_contract_context(bob),
+        &mut state,
+        &batch_transfer_from_msg,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Insufficient allowance")]
+fn batch_burn_more_than_allowance() {
+    let alice = 10u8;
+    let bob = 11u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(1)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(2),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_batch_mint(
+        &mock_contract_context(2),
+        &mut state,
+        &BatchMintMsg {
+            to: mock_address(alice),
+            token_infos: vec![
+                TokenMintInfoMsg {
+                    token_id: 1,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+                TokenMintInfoMsg {
+                    token_id: 2,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+            ],
+        },
+    );
+
+    let _ = execute_approve(
+        &mock_contract_context(alice),
+        &mut state,
+        &ApproveMsg {
+            spender: mock_address(bob),
+            token_id: 1,
+            amount: 5,
+        },
+    );
+
+    let batch_burn_msg = BatchBurnMsg {
+        from: mock_address(alice),
+        token_infos: vec![
+            TokenTransferInfoMsg {
+                token_id: 1,
+                amount: 3,
+            },
+            TokenTransferInfoMsg {
+                token_id: 1,
+                amount: 3,
+            },
+        ],
+    };
+    let _ = execute_batch_burn(&mock_contract_context(bob), &mut state, &batch_burn_msg);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient allowance")]
+fn decrease_allowance_below_zero() {
+    let alice = 10u8;
+    let bob = 11u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(1)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(2),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_batch_mint(
+        &mock_contract_context(2),
+        &mut state,
+        &BatchMintMsg {
+            to: mock_address(alice),
+            token_infos: vec![
+                TokenMintInfoMsg {
+                    token_id: 1,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+                TokenMintInfoMsg {
+                    token_id: 2,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+            ],
+        },
+    );
+
+    let _ = execute_approve(
+        &mock_contract_context(alice),
+        &mut state,
+        &ApproveMsg {
+            spender: mock_address(bob),
+            token_id: 1,
+            amount: 5,
+        },
+    );
+
+    let _ = execute_decrease_allowance(
+        &mock_contract_context(alice),
+        &mut state,
+        &DecreaseAllowanceMsg {
+            spender: mock_address(bob),
+            token_id: 1,
+            amount: 6,
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Cannot approve to yourself")]
+fn approve_to_yourself() {
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(1)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(2),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_batch_mint(
+        &mock_contract_context(2),
+        &mut state,
+        &BatchMintMsg {
+            to: mock_address(alice),
+            token_infos: vec![
+                TokenMintInfoMsg {
+                    token_id: 1,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+                TokenMintInfoMsg {
+                    token_id: 2,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+            ],
+        },
+    );
+
+    let _ = execute_approve(
+        &mock_contract_context(alice),
+        &mut state,
+        &ApproveMsg {
+            spender: mock_address(alice),
+            token_id: 1,
+            amount: 5,
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Soulbound token can not be approved")]
+fn approve_soulbound_token() {
+    let minter = 1u8;
+    let alice = 10u8;
+    let bob = 11u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(minter)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(minter), &msg);
+
+    let _ = execute_batch_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &BatchMintMsg {
+            to: mock_address(alice),
+            token_infos: vec![
+                TokenMintInfoMsg {
+                    token_id: 1,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+                TokenMintInfoMsg {
+                    token_id: 2,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+            ],
+        },
+    );
+
+    let _ = execute_set_token_soulbound(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetTokenSoulboundMsg {
+            token_id: 1,
+            soulbound: true,
+        },
+    );
+    let _ = execute_increase_allowance(
+        &mock_contract_context(alice),
+        &mut state,
+        &IncreaseAllowanceMsg {
+            spender: mock_address(bob),
+            token_id: 1,
+            amount: 1,
+        },
+    );
+}
+
+#[test]
+fn proper_safe_transfer_to_account() {
+    let alice = 10u8;
+    let bob = 11u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(1)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(2),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_batch_mint(
+        &mock_contract_context(2),
+        &mut state,
+        &BatchMintMsg {
+            to: mock_address(alice),
+            token_infos: vec![
+                TokenMintInfoMsg {
+                    token_id: 1,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+                TokenMintInfoMsg {
+                    token_id: 2,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+            ],
+        },
+    );
+
+    let _ = execute_approve(
+        &mock_contract_context(alice),
+        &mut state,
+        &ApproveMsg {
+            spender: mock_address(bob),
+            token_id: 1,
+            amount: 5,
+        },
+    );
+
+    let events = execute_safe_transfer_from(
+        &mock_contract_context(alice),
+        &mut state,
+        &SafeTransferFromMsg {
+            from: mock_address(alice),
+            to: mock_address(bob),
+            token_info: TokenTransferInfoMsg {
+                token_id: 1,
+                amount: 4,
+            },
+            data: vec![],
+        },
+    );
+    assert_eq!(events, vec![]);
+    assert_eq!(state.balance_of(&mock_address(alice), 1), 6);
+    assert_eq!(state.balance_of(&mock_address(bob), 1), 4);
+}
+
+#[test]
+fn proper_safe_transfer_to_contract() {
+    let alice = 10u8;
+    let bob = 11u8;
+    let receiver = 20u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(1)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(2),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_batch_mint(
+        &mock_contract_context(2),
+        &mut state,
+        &BatchMintMsg {
+            to: mock_address(alice),
+            token_infos: vec![
+                TokenMintInfoMsg {
+                    token_id: 1,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+                TokenMintInfoMsg {
+                    token_id: 2,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+            ],
+        },
+    );
+
+    let _ = execute_approve(
+        &mock_contract_context(alice),
+        &mut state,
+        &ApproveMsg {
+            spender: mock_address(bob),
+            token_id: 1,
+            amount: 5,
+        },
+    );
+
+    let events = execute_safe_transfer_from(
+        &mock_contract_context(bob),
+        &mut state,
+        &SafeTransferFromMsg {
+            from: mock_address(alice),
+            to: mock_contract_address(receiver),
+            token_info: TokenTransferInfoMsg {
+                token_id: 1,
+                amount: 4,
+            },
+            data: vec![7u8],
+        },
+    );
+
+    let mut event_group = EventGroup::builder();
+    OnMpc1155ReceivedMsg {
+        operator: mock_address(bob),
+        from: mock_address(alice),
+        token_id: 1,
+        amount: 4,
+        data: vec![7u8],
+    }
+    .as_interaction(&mut event_group, &mock_contract_address(receiver));
+    build_msg_callback(
+        &mut event_group,
+        ON_SAFE_TRANSFER_CALLBACK,
+        &SafeTransferCallbackMsg {
+            operator: mock_address(bob),
+            from: mock_address(alice),
+            to: mock_contract_address(receiver),
+            token_infos: vec![TokenTransferInfoMsg {
+                token_id: 1,
+                amount: 4,
+            }],
+            spent_allowances: vec![TokenTransferInfoMsg {
+                token_id: 1,
+                amount: 4,
+            }],
+        },
+    );
+    assert_eq!(events, vec![event_group.build()]);
+    assert_eq!(state.balance_of(&mock_address(alice), 1), 6);
+    assert_eq!(state.balance_of(&mock_contract_address(receiver), 1), 4);
+    assert_eq!(
+        state.allowance(&mock_address(alice), &mock_address(bob), 1),
+        1
+    );
+
+    let _ = execute_on_safe_transfer(
+        &mock_contract_context(1),
+        &mock_callback_context(true),
+        &mut state,
+        &SafeTransferCallbackMsg {
+            operator: mock_address(bob),
+            from: mock_address(alice),
+            to: mock_contract_address(receiver),
+            token_infos: vec![TokenTransferInfoMsg {
+                token_id: 1,
+                amount: 4,
+            }],
+            spent_allowances: vec![TokenTransferInfoMsg {
+                token_id: 1,
+                amount: 4,
+            }],
+        },
+    );
+    assert_eq!(state.balance_of(&mock_contract_address(receiver), 1), 4);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn safe_transfer_not_owner_or_approved() {
+    let alice = 10u8;
+    let bob = 11u8;
+    let receiver = 20u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(1)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(2),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_batch_mint(
+        &mock_contract_context(2),
+        &mut state,
+        &BatchMintMsg {
+            to: mock_address(alice),
+            token_infos: vec![
+                TokenMintInfoMsg {
+                    token_id: 1,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+                TokenMintInfoMsg {
+                    token_id: 2,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+            ],
+        },
+    );
+
+    let _ = execute_approve(
+        &mock_contract_context(alice),
+        &mut state,
+        &ApproveMsg {
+            spender: mock_address(bob),
+            token_id: 1,
+            amount: 5,
+        },
+    );
+
+    let _ = execute_safe_transfer_from(
+        &mock_contract_context(bob),
+        &mut state,
+        &SafeTransferFromMsg {
+            from: mock_address(alice),
+            to: mock_contract_address(receiver),
+            token_info: TokenTransferInfoMsg {
+                token_id: 2,
+                amount: 1,
+            },
+            data: vec![],
+        },
+    );
+}
+
+#[test]
+fn safe_transfer_reverted_on_failed_acknowledgement() {
+    let alice = 10u8;
+    let receiver = 20u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(1)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(2),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_batch_mint(
+        &mock_contract_context(2),
+        &mut state,
+        &BatchMintMsg {
+            to: mock_address(alice),
+            token_infos: vec![
+                TokenMintInfoMsg {
+                    token_id: 1,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+                TokenMintInfoMsg {
+                    token_id: 2,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+            ],
+        },
+    );
+
+    let _ = execute_approve(
+        &mock_contract_context(alice),
+        &mut state,
+        &ApproveMsg {
+            spender: mock_address(11u8),
+            token_id: 1,
+            amount: 5,
+        },
+    );
+
+    let _ = execute_safe_transfer_from(
+        &mock_contract_context(alice),
+        &mut state,
+        &SafeTransferFromMsg {
+            from: mock_address(alice),
+            to: mock_contract_address(receiver),
+            token_info: TokenTransferInfoMsg {
+                token_id: 1,
+                amount: 4,
+            },
+            data: vec![],
+        },
+    );
+    assert_eq!(state.balance_of(&mock_contract_address(receiver), 1), 4);
+
+    let _ = execute_on_safe_transfer(
+        &mock_contract_context(1),
+        &mock_callback_context(false),
+        &mut state,
+        &SafeTransferCallbackMsg {
+            operator: mock_address(alice),
+            from: mock_address(alice),
+            to: mock_contract_address(receiver),
+            token_infos: vec![TokenTransferInfoMsg {
+                token_id: 1,
+                amount: 4,
+            }],
+            spent_allowances: vec![],
+        },
+    );
+    assert_eq!(state.balance_of(&mock_address(alice), 1), 10);
+    assert_eq!(state.balance_of(&mock_contract_address(receiver), 1), 0);
+    assert_eq!(state.total_supply(1), 10);
+}
+
+#[test]
+fn safe_transfer_revert_restores_allowance() {
+    let alice = 10u8;
+    let bob = 11u8;
+    let receiver = 20u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(1)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(2),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_batch_mint(
+        &mock_contract_context(2),
+        &mut state,
+        &BatchMintMsg {
+            to: mock_address(alice),
+            token_infos: vec![
+                TokenMintInfoMsg {
+                    token_id: 1,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+                TokenMintInfoMsg {
+                    token_id: 2,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+            ],
+        },
+    );
+
+    let _ = execute_approve(
+        &mock_contract_context(alice),
+        &mut state,
+        &ApproveMsg {
+            spender: mock_address(bob),
+            token_id: 1,
+            amount: 5,
+        },
+    );
+
+    let _ = execute_safe_transfer_from(
+        &mock_contract_context(bob),
+        &mut state,
+        &SafeTransferFromMsg {
+            from: mock_address(alice),
+            to: mock_contract_address(receiver),
+            token_info: TokenTransferInfoMsg {
+                token_id: 1,
+                amount: 4,
+            },
+            data: vec![],
+        },
+    );
+    assert_eq!(
+        state.allowance(&mock_address(alice), &mock_address(bob), 1),
+        1
+    );
+
+    let _ = execute_on_safe_transfer(
+        &mock_contract_context(1),
+        &mock_callback_context(false),
+        &mut state,
+        &SafeTransferCallbackMsg {
+            operator: mock_address(bob),
+            from: mock_address(alice),
+            to: mock_contract_address(receiver),
+            token_infos: vec![TokenTransferInfoMsg {
+                token_id: 1,
+                amount: 4,
+            }],
+            spent_allowances: vec![TokenTransferInfoMsg {
+                token_id: 1,
+                amount: 4,
+            }],
+        },
+    );
+    assert_eq!(state.balance_of(&mock_address(alice), 1), 10);
+    assert_eq!(state.balance_of(&mock_contract_address(receiver), 1), 0);
+    assert_eq!(
+        state.allowance(&mock_address(alice), &mock_address(bob), 1),
+        5
+    );
+}
+
+#[test]
+#[should_panic(expected = "Balance check has failed")]
+fn safe_transfer_revert_without_receiver_balance() {
+    let alice = 10u8;
+    let bob = 11u8;
+    let receiver = 20u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(1)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(2),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_batch_mint(
+        &mock_contract_context(2),
+        &mut state,
+        &BatchMintMsg {
+            to: mock_address(alice),
+            token_infos: vec![
+                TokenMintInfoMsg {
+                    token_id: 1,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+                TokenMintInfoMsg {
+                    token_id: 2,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+            ],
+        },
+    );
+
+    let _ = execute_approve(
+        &mock_contract_context(alice),
+        &mut state,
+        &ApproveMsg {
+            spender: mock_address(bob),
+            token_id: 1,
+            amount: 5,
+        },
+    );
+
+    let _ = execute_safe_transfer_from(
+        &mock_contract_context(alice),
+        &mut state,
+        &SafeTransferFromMsg {
+            from: mock_address(alice),
+            to: mock_contract_address(receiver),
+            token_info: TokenTransferInfoMsg {
+                token_id: 1,
+                amount: 4,
+            },
+            data: vec![],
+        },
+    );
+    let mut receiver_ctx = mock_contract_context(receiver);
+    receiver_ctx.sender = mock_contract_address(receiver);
+    let _ = execute_transfer_from(
+        &receiver_ctx,
+        &mut state,
+        &TransferFromMsg {
+            from: mock_contract_address(receiver),
+            to: mock_address(bob),
+            token_info: TokenTransferInfoMsg {
+                token_id: 1,
+                amount: 3,
+            },
+        },
+    );
+
+    let _ = execute_on_safe_transfer(
+        &mock_contract_context(1),
+        &mock_callback_context(false),
+        &mut state,
+        &SafeTransferCallbackMsg {
+            operator: mock_address(alice),
+            from: mock_address(alice),
+            to: mock_contract_address(receiver),
+            token_infos: vec![TokenTransferInfoMsg {
+                token_id: 1,
+                amount: 4,
+            }],
+            spent_allowances: vec![],
+        },
+    );
+}
+
+#[test]
+fn proper_safe_batch_transfer_to_contract() {
+    let alice = 10u8;
+    let receiver = 20u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(1)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(2),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_batch_mint(
+        &mock_contract_context(2),
+        &mut state,
+        &BatchMintMsg {
+            to: mock_address(alice),
+            token_infos: vec![
+                TokenMintInfoMsg {
+                    token_id: 1,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+                TokenMintInfoMsg {
+                    token_id: 2,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+            ],
+        },
+    );
+
+    let _ = execute_approve(
+        &mock_contract_context(alice),
+        &mut state,
+        &ApproveMsg {
+            spender: mock_address(11u8),
+            token_id: 1,
+            amount: 5,
+        },
+    );
+    let token_infos = vec![
+        TokenTransferInfoMsg {
+            token_id: 1,
+            amount: 2,
+        },
+        TokenTransferInfoMsg {
+            token_id: 2,
+            amount: 3,
+        },
+    ];
+
+    let events = execute_safe_batch_transfer_from(
+        &mock_contract_context(alice),
+        &mut state,
+        &SafeBatchTransferFromMsg {
+            from: mock_address(alice),
+            to: mock_contract_address(receiver),
+            token_infos: token_infos.clone(),
+            data: vec![1u8, 2u8],
+        },
+    );
+
+    let mut event_group = EventGroup::builder();
+    OnMpc1155BatchReceivedMsg {
+        operator: mock_address(alice),
+        from: mock_address(alice),
+        token_ids: vec![1, 2],
+        amounts: vec![2, 3],
+        data: vec![1u8, 2u8],
+    }
+    .as_interaction(&mut event_group, &mock_contract_address(receiver));
+    build_msg_callback(
+        &mut event_group,
+        ON_SAFE_TRANSFER_CALLBACK,
+        &SafeTransferCallbackMsg {
+            operator: mock_address(alice),
+            from: mock_address(alice),
+            to: mock_contract_address(receiver),
+            token_infos: token_infos.clone(),
+            spent_allowances: vec![],
+        },
+    );
+    assert_eq!(events, vec![event_group.build()]);
+    assert_eq!(state.balance_of(&mock_contract_address(receiver), 1), 2);
+    assert_eq!(state.balance_of(&mock_contract_address(receiver), 2), 3);
+
+    let _ = execute_on_safe_transfer(
+        &mock_contract_context(1),
+        &mock_callback_context(false),
+        &mut state,
+        &SafeTransferCallbackMsg {
+            operator: mock_address(alice),
+            from: mock_address(alice),
+            to: mock_contract_address(receiver),
+            token_infos,
+            spent_allowances: vec![],
+        },
+    );
+    assert_eq!(state.balance_of(&mock_address(alice), 1), 10);
+    assert_eq!(state.balance_of(&mock_address(alice), 2), 10);
+    assert_eq!(state.balance_of(&mock_contract_address(receiver), 1), 0);
+    assert_eq!(state.balance_of(&mock_contract_address(receiver), 2), 0);
+}
+
+#[test]
+fn proper_safe_batch_transfer_to_account() {
+    let alice = 10u8;
+    let bob = 11u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(1)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(2),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_batch_mint(
+        &mock_contract_context(2),
+        &mut state,
+        &BatchMintMsg {
+            to: mock_address(alice),
+            token_infos: vec![
+                TokenMintInfoMsg {
+                    token_id: 1,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+                TokenMintInfoMsg {
+                    token_id: 2,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+            ],
+        },
+    );
+
+    let _ = execute_approve(
+        &mock_contract_context(alice),
+        &mut state,
+        &ApproveMsg {
+            spender: mock_address(bob),
+            token_id: 1,
+            amount: 5,
+        },
+    );
+
+    let events = execute_safe_batch_transfer_from(
+        &mock_contract_context(alice),
+        &mut state,
+        &SafeBatchTransferFromMsg {
+            from: mock_address(alice),
+            to: mock_address(bob),
+            token_infos: vec![TokenTransferInfoMsg {
+                token_id: 2,
+                amount: 10,
+            }],
+            data: vec![],
+        },
+    );
+    assert_eq!(events, vec![]);
+    assert_eq!(state.balance_of(&mock_address(alice), 2), 0);
+    assert_eq!(state.balance_of(&mock_address(bob), 2), 10);
+}
+
+#[test]
+fn proper_uri_resolution() {
+    let owner = 1u8;
+    let minter = 2u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(owner)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(minter), &msg);
+    let _ = execute_set_uri(
+        &mock_contract_context(owner),
+        &mut state,
+        &SetUriMsg {
+            new_uri: "ipfs://base/{id}.json".to_string(),
+        },
+    );
+    let _ = execute_create(
+        &mock_contract_context(owner),
+        &mut state,
+        &CreateMsg {
+            token_id: 1,
+            token_uri: None,
+            royalty: None,
+            max_supply: None,
+        },
+    );
+    let _ = execute_create(
+        &mock_contract_context(owner),
+        &mut state,
+        &CreateMsg {
+            token_id: 314592,
+            token_uri: Some("ipfs://custom/{id}".to_string()),
+            royalty: None,
+            max_supply: None,
+        },
+    );
+
+    assert_eq!(
+        state.uri(1),
+        "ipfs://base/0000000000000000000000000000000000000000000000000000000000000001.json"
+    );
+    assert_eq!(
+        state.uri(314592),
+        "ipfs://custom/000000000000000000000000000000000000000000000000000000000004cce0"
+    );
+    assert_eq!(
+        state.uri(2),
+        "ipfs://base/0000000000000000000000000000000000000000000000000000000000000002.json"
+    );
+
+    let _ = execute_set_uri(
+        &mock_contract_context(owner),
+        &mut state,
+        &SetUriMsg {
+            new_uri: "ipfs://static".to_string(),
+        },
+    );
+    assert_eq!(state.uri(1), "ipfs://static");
+}
+
+#[test]
+fn proper_freeze_token_uri() {
+    let owner = 1u8;
+    let minter = 2u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(owner)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(minter), &msg);
+    let _ = execute_set_public_creation(
+        &mock_contract_context(owner),
+        &mut state,
+        &SetPublicCreationMsg {
+            public_creation: true,
+        },
+    );
+    let _ = execute_create(&mock_contract_context(alice), &mut state, &create_msg(1));
+    let _ = execute_create(&mock_contract_context(alice), &mut state, &create_msg(2));
+
+    let _ = execute_freeze_token_uri(
+        &mock_contract_context(alice),
+        &mut state,
+        &FreezeTokenUriMsg { token_id: 1 },
+    );
+    assert!(state.is_uri_frozen(1));
+    assert!(!state.is_uri_frozen(2));
+    assert_eq!(state.frozen_tokens, BTreeSet::from([1]));
+
+    let _ = execute_set_token_uri(
+        &mock_contract_context(alice),
+        &mut state,
+        &SetTokenUriMsg {
+            token_id: 2,
+            token_uri: Some("new.json".to_string()),
+        },
+    );
+    assert_eq!(state.uri(2), "new.json");
+}
+
+#[test]
+#[should_panic(expected = "Metadata is frozen")]
+fn set_token_uri_when_frozen() {
+    let owner = 1u8;
+    let minter = 2u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(owner)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(minter), &msg);
+    let _ = execute_create(&mock_contract_context(owner), &mut state, &create_msg(1));
+    let _ = execute_freeze_token_uri(
+        &mock_contract_context(owner),
+        &mut state,
+        &FreezeTokenUriMsg { token_id: 1 },
+    );
+
+    let _ = execute_set_token_uri(
+        &mock_contract_context(owner),
+        &mut state,
+        &SetTokenUriMsg {
+            token_id: 1,
+            token_uri: None,
+        },
+    );
+}
+
+#[test]
+fn set_uri_after_freeze_token_uri() {
+    let owner = 1u8;
+    let minter = 2u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(owner)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(minter), &msg);
+    let _ = execute_create(
+        &mock_contract_context(owner),
+        &mut state,
+        &CreateMsg {
+            token_id: 1,
+            token_uri: None,
+            royalty: None,
+            max_supply: None,
+        },
+    );
+    let _ = execute_freeze_token_uri(
+        &mock_contract_context(owner),
+        &mut state,
+        &FreezeTokenUriMsg { token_id: 1 },
+    );
+    assert_eq!(
+        state.token_info(1).unwrap().token_uri,
+        Some("ipfs://random".to_string())
+    );
+
+    let _ = execute_set_uri(
+        &mock_contract_context(owner),
+        &mut state,
+        &SetUriMsg {
+            new_uri: "ipfs://changed".to_string(),
+        },
+    );
+    assert_eq!(state.uri(1), "ipfs://random");
+    assert_eq!(state.uri(2), "ipfs://changed");
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn freeze_token_uri_not_token_minter() {
+    let owner = 1u8;
+    let minter = 2u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(owner)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(minter), &msg);
+    let _ = execute_create(&mock_contract_context(owner), &mut state, &create_msg(1));
+
+    let _ = execute_freeze_token_uri(
+        &mock_contract_context(minter),
+        &mut state,
+        &FreezeTokenUriMsg { token_id: 1 },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Not found")]
+fn freeze_unknown_token_uri() {
+    let owner = 1u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(owner)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(2u8),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2u8), &msg);
+
+    let _ = execute_freeze_token_uri(
+        &mock_contract_context(owner),
+        &mut state,
+        &FreezeTokenUriMsg { token_id: 1 },
+    );
+}
+
+#[test]
+fn proper_balance_of_and_allowance_queries() {
+    let alice = 10u8;
+    let bob = 11u8;
+    let caller = 20u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(1)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(2),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_batch_mint(
+        &mock_contract_context(2),
+        &mut state,
+        &BatchMintMsg {
+            to: mock_address(alice),
+            token_infos: vec![
+                TokenMintInfoMsg {
+                    token_id: 1,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+                TokenMintInfoMsg {
+                    token_id: 2,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+            ],
+        },
+    );
+
+    let _ = execute_approve(
+        &mock_contract_context(alice),
+        &mut state,
+        &ApproveMsg {
+            spender: mock_address(bob),
+            token_id: 1,
+            amount: 5,
+        },
+    );
+
+    let events = execute_balance_of(
+        &mock_contract_context(caller),
+        &mut state,
+        &BalanceOfMsg {
+            owner: mock_address(alice),
+            token_id: 1,
+            callback_action: 0x41,
+            context: vec![1, 2],
+        },
+    );
+
+    let mut event_group = EventGroup::builder();
+    build_msg_reply(
+        &mut event_group,
+        &mock_address(caller),
+        0x41,
+        &BalanceOfReplyMsg {
+            owner: mock_address(alice),
+            token_id: 1,
+            balance: 10,
+            context: vec![1, 2],
+        },
+    );
+    assert_eq!(events, vec![event_group.build()]);
+
+    let events = execute_allowance(
+        &mock_contract_context(caller),
+        &mut state,
+        &AllowanceMsg {
+            owner: mock_address(alice),
+            spender: mock_address(bob),
+            token_id: 1,
+            callback_action: 0x42,
+            context: vec![1, 2],
+        },
+    );
+
+    let mut event_group = EventGroup::builder();
+    build_msg_reply(
+        &mut event_group,
+        &mock_address(caller),
+        0x42,
+        &AllowanceReplyMsg {
+            owner: mock_address(alice),
+            spender: mock_address(bob),
+            token_id: 1,
+            allowance: 5,
+            context: vec![1, 2],
+        },
+    );
+    assert_eq!(events, vec![event_group.build()]);
+}
+
+#[test]
+fn proper_balance_of_batch_query() {
+    let alice = 10u8;
+    let bob = 11u8;
+    let caller = 20u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(1)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(2),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_batch_mint(
+        &mock_contract_context(2),
+        &mut state,
+        &BatchMintMsg {
+            to: mock_address(alice),
+            token_infos: vec![
+                TokenMintInfoMsg {
+                    token_id: 1,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+                TokenMintInfoMsg {
+                    token_id: 2,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+            ],
+        },
+    );
+
+    let _ = execute_approve(
+        &mock_contract_context(alice),
+        &mut state,
+        &ApproveMsg {
+            spender: mock_address(bob),
+            token_id: 1,
+            amount: 5,
+        },
+    );
+
+    let events = execute_balance_of_batch(
+        &mock_contract_context(caller),
+        &mut state,
+        &BalanceOfBatchMsg {
+            owners: vec![mock_address(alice), mock_address(bob), mock_address(alice)],
+            token_ids: vec![1, 1, 3],
+            callback_action: 0x41,
+            context: vec![1, 2],
+        },
+    );
+
+    let mut event_group = EventGroup::builder();
+    build_msg_reply(
+        &mut event_group,
+        &mock_address(caller),
+        0x41,
+        &BalanceOfBatchReplyMsg {
+            owners: vec![mock_address(alice), mock_address(bob), mock_address(alice)],
+            token_ids: vec![1, 1, 3],
+            balances: vec![10, 0, 0],
+            context: vec![1, 2],
+        },
+    );
+    assert_eq!(events, vec![event_group.build()]);
+}
+
+#[test]
+#[should_panic(expected = "Length of provided lists must be equal")]
+fn balance_of_batch_query_length_mismatch() {
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(1)),
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(2),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_batch_mint(
+        &mock_contract_context(2),
+        &mut state,
+        &BatchMintMsg {
+            to: mock_address(alice),
+            token_infos: vec![
+                TokenMintInfoMsg {
+                    token_id: 1,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+                TokenMintInfoMsg {
+                    token_id: 2,
+                    amount: 10,
+                    token_uri: None,
+                    royalty: None,
+                    max_supply: None,
+                },
+            ],
+        },
+    );
+
+    let _ = execute_approve(
+        &mock_contract_context(alice),
+        &mut state,
+        &ApproveMsg {
+            spender: mock_address(11u8),
+            token_id: 1,
+            amount: 5,
+        },
+    );
+
+    let _ = execute_balance_of_batch(
+        &mock_contract_context(20u8),
+        &mut state,
+        &BalanceOfBatchMsg {
+            owners: vec![mock_address(alice)],
+            token_ids: vec![1, 2],
+            callback_action: 0x41,
+            context: vec![1, 2],
+        },
+    );
+}
+
+#[test]
+fn proper_total_supply_query() {
+    let minter = 1u8;
+    let holder = 10u8;
+    let caller = 20u8;
+
+    let msg = InitMsg {
+        owner: None,
+        uri: "ipfs://random".to_string(),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(minter), &msg);
+
+    let _ = execute_batch_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &BatchMintMsg {
+            to: mock_address(holder),
+            token_infos: vec![TokenMintInfoMsg {
+                token_id: 1,
+                amount: 10,
+                token_uri: None,
+                royalty: None,
+                max_supply: Some(15),
+            }],
+        },
+    );
+
+    let events = execute_total_supply(
+        &mock_contract_context(caller),
+        &mut state,
+        &TotalSupplyMsg {
+            token_id: 1,
+            callback_action: 0x43,
+            context: vec![1, 2],
+        },
+    );
+
+    let mut event_group = EventGroup::builder();
+    build_msg_reply(
+        &mut event_group,
+        &mock_address(caller),
+        0x43,
+        &TotalSupplyReplyMsg {
+            token_id: 1,
+            total_supply: 10,
+            max_supply: Some(15),
+            context: vec![1, 2],
+        },
+    );
+    assert_eq!(events, vec![event_group.build()]);
+
+    let events = execute_total_supply(
+        &mock_contract_context(caller),
+        &mut state,
+        &TotalSupplyMsg {
+            token_id: 2,
+            callback_action: 0x43,
+            context: vec![1, 2],
+        },
+    );
+
+    let mut event_group = EventGroup::builder();
+    build_msg_reply(
+        &mut event_group,
+        &mock_address(caller),
+        0x43,
+        &TotalSupplyReplyMsg {
+            token_id: 2,
+            total_supply: 0,
+            max_supply: None,
+            context: vec![1, 2],
+        },
+    );
+    assert_eq!(events, vec![event_group.build()]);
+}
@@ -0,0 +1,466 @@
+use std::collections::BTreeMap;
+
+use pbc_contract_common::{
+    address::Address,
+    context::{CallbackContext, ContractContext},
+    events::{EventGroup, EventGroupBuilder},
+};
+
+use crate::{
+    msg::{
+        ClaimRefundMsg, CraftCallbackMsg, CraftMsg, FeeCallbackMsg, InitMsg, RefundCallbackMsg,
+        RemoveRecipeMsg, SetFeeReceiverMsg, SetRecipeMsg, ON_FEE_PAID_CALLBACK,
+        ON_FEE_TRANSFERRED_CALLBACK, ON_INPUTS_ESCROWED_CALLBACK, ON_INPUTS_RETURNED_CALLBACK,
+        ON_OUTPUTS_MINTED_CALLBACK,
+    },
+    state::{MPC1155CraftingContractState, RecipeFee, RecipeItem},
+    ContractError,
+};
+
+use mpc1155_base::msg::{
+    BatchBurnMsg as Mpc1155BatchBurnMsg, BatchMintMsg as Mpc1155BatchMintMsg,
+    BatchTransferFromMsg as Mpc1155BatchTransferFromMsg, TokenMintInfoMsg, TokenTransferInfoMsg,
+};
+use mpc20_base::msg::{TransferFromMsg as Mpc20TransferFromMsg, TransferMsg as Mpc20TransferMsg};
+use utils::events::{build_msg_callback, IntoShortnameRPCEvent};
+
+/// ## Description
+/// Inits contract state.
+/// Returns [`(MPC1155CraftingContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **msg** is an object of type [`InitMsg`]
+pub fn execute_init(
+    ctx: &ContractContext,
+    msg: &InitMsg,
+) -> (MPC1155CraftingContractState, Vec<EventGroup>) {
+    let state = MPC1155CraftingContractState {
+        owner: ctx.sender,
+        collection: msg.collection,
+        fee_receiver: msg.fee_receiver.unwrap_or(ctx.sender),
+        recipes: BTreeMap::new(),
+        pending_refunds: BTreeMap::new(),
+        pending_fees: BTreeMap::new(),
+    };
+
+    (state, vec![])
+}
+
+/// ## Description
+/// Registers new or replaces existing recipe. Can only be executed by owner account.
+/// Returns [`(MPC1155CraftingContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC1155CraftingContractState`]
+///
+/// * **msg** is an object of type [`SetRecipeMsg`]
+pub fn execute_set_recipe(
+    ctx: &ContractContext,
+    state: &mut MPC1155CraftingContractState,
+    msg: &SetRecipeMsg,
+) -> Vec<EventGroup> {
+    assert!(
+        state.is_owner(&ctx.sender),
+        "{}",
+        ContractError::Unauthorized
+    );
+
+    state.set_recipe(msg.recipe_id, &msg.recipe);
+    vec![]
+}
+
+/// ## Description
+/// Removes existing recipe. Can only be executed by owner account.
+/// Already started craftings are completed with the removed recipe.
+/// Returns [`(MPC1155CraftingContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC1155CraftingContractState`]
+///
+/// * **msg** is an object of type [`RemoveRecipeMsg`]
+pub fn execute_remove_recipe(
+    ctx: &ContractContext,
+    state: &mut MPC1155CraftingContractState,
+    msg: &RemoveRecipeMsg,
+) -> Vec<EventGroup> {
+    assert!(
+        state.is_owner(&ctx.sender),
+        "{}",
+        ContractError::Unauthorized
+    );
+
+    state.remove_recipe(msg.recipe_id);
+    vec![]
+}
+
+/// ## Description
+/// Sets receiver of the crafting fees. Can only be executed by owner account.
+/// Returns [`(MPC1155CraftingContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC1155CraftingContractState`]
+///
+/// * **msg** is an object of type [`SetFeeReceiverMsg`]
+pub fn execute_set_fee_receiver(
+    ctx: &ContractContext,
+    state: &mut MPC1155CraftingContractState,
+    msg: &SetFeeReceiverMsg,
+) -> Vec<EventGroup> {
+    assert!(
+        state.is_owner(&ctx.sender),
+        "{}",
+        ContractError::Unauthorized
+    );
+
+    state.fee_receiver = msg.fee_receiver;
+    vec![]
+}
+
+/// ## Description
+/// Craft outputs of the recipe from the inputs.
+/// Player must approve contract as mpc1155 operator and, if recipe has a fee, allow it to spend the fee.
+/// Fee and inputs are escrowed first, then outputs are minted and finally escrowed inputs are burned.
+/// Returns [`(MPC1155CraftingContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC1155CraftingContractState`]
+///
+/// * **msg** is an object of type [`CraftMsg`]
+pub fn execute_craft(
+    ctx: &ContractContext,
+    state: &mut MPC1155CraftingContractState,
+    msg: &CraftMsg,
+) -> Vec<EventGroup> {
+    let craft = CraftCallbackMsg {
+        player: ctx.sender,
+        recipe: state.get_recipe(msg.recipe_id),
+    };
+
+    let mut event_group = EventGroup::builder();
+    match &craft.recipe.fee {
+        Some(fee) => {
+            Mpc20TransferFromMsg {
+                from: craft.player,
+                to: ctx.contract_address,
+                amount: fee.amount,
+            }
+            .as_interaction(&mut event_group, &fee.token);
+            build_msg_callback(&mut event_group, ON_FEE_PAID_CALLBACK, &craft);
+        }
+        None => escrow_inputs(
+            &mut event_group,
+            &state.collection,
+            &ctx.contract_address,
+            &craft,
+        ),
+    }
+
+    vec![event_group.build()]
+}
+
+/// ## Description
+/// Transfers escrowed inputs and fees which previously failed to be transferred to the sender.
+/// Returns [`(MPC1155CraftingContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC1155CraftingContractState`]
+///
+/// * **_msg** is an object of type [`ClaimRefundMsg`]
+pub fn execute_claim_refund(
+    ctx: &ContractContext,
+    state: &mut MPC1155CraftingContractState,
+    _msg: &ClaimRefundMsg,
+) -> Vec<EventGroup> {
+    let (items, fees) = state.take_pending_refund(&ctx.sender);
+
+    let mut events = vec![];
+    if !items.is_empty() {
+        let mut event_group = EventGroup::builder();
+        return_inputs(
+            &mut event_group,
+            &state.collection,
+            &ctx.contract_address,
+            &RefundCallbackMsg {
+                player: ctx.sender,
+                items,
+            },
+        );
+        events.push(event_group.build());
+    }
+    events.extend(fees.iter().map(|fee| transfer_fee(&ctx.sender, fee)));
+
+    events
+}
+
+/// ## Description
+/// Handles result of the fee escrow and escrows recipe inputs on success.
+/// Returns [`(MPC1155CraftingContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **callback_ctx** is an object of type [`CallbackContext`]
+///
+/// * **state** is an object of type [`MPC1155CraftingContractState`]
+///
+/// * **msg** is an object of type [`CraftCallbackMsg`]
+pub fn execute_on_fee_paid(
+    ctx: &ContractContext,
+    callback_ctx: &CallbackContext,
+    state: &mut MPC1155CraftingContractState,
+    msg: &CraftCallbackMsg,
+) -> Vec<EventGroup> {
+    if !callback_ctx.success {
+        return vec![];
+    }
+
+    let mut event_group = EventGroup::builder();
+    escrow_inputs(
+        &mut event_group,
+        &state.collection,
+        &ctx.contract_address,
+        msg,
+    );
+
+    vec![event_group.build()]
+}
+
+/// ## Description
+/// Handles result of the inputs escrow. Mints recipe outputs on success, refunds escrowed fee on failure.
+/// Returns [`(MPC1155CraftingContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **callback_ctx** is an object of type [`CallbackContext`]
+///
+/// * **state** is an object of type [`MPC1155CraftingContractState`]
+///
+/// * **msg** is an object of type [`CraftCallbackMsg`]
+pub fn execute_on_inputs_escrowed(
+    ctx: &ContractContext,
+    callback_ctx: &CallbackContext,
+    state: &mut MPC1155CraftingContractState,
+    msg: &CraftCallbackMsg,
+) -> Vec<EventGroup> {
+    if !callback_ctx.success {
+        return refund_fee(msg);
+    }
+
+    let mut event_group = EventGroup::builder();
+    mint_items(
+        &mut event_group,
+        &state.collection,
+        &msg.player,
+        &msg.recipe.outputs,
+    );
+    build_msg_callback(&mut event_group, ON_OUTPUTS_MINTED_CALLBACK, msg);
+
+    vec![event_group.build()]
+}
+
+/// ## Description
+/// Handles result of the outputs mint. Burns escrowed inputs and forwards escrowed fee
+/// to the fee receiver on success, returns escrowed inputs and refunds escrowed fee on failure.
+/// Returns [`(MPC1155CraftingContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **callback_ctx** is an object of type [`CallbackContext`]
+///
+/// * **state** is an object of type [`MPC1155CraftingContractState`]
+///
+/// * **msg** is an object of type [`CraftCallbackMsg`]
+pub fn execute_on_outputs_minted(
+    ctx: &ContractContext,
+    callback_ctx: &CallbackContext,
+    state: &mut MPC1155CraftingContractState,
+    msg: &CraftCallbackMsg,
+) -> Vec<EventGroup> {
+    let mut event_group = EventGroup::builder();
+    if !callback_ctx.success {
+        return_inputs(
+            &mut event_group,
+            &state.collection,
+            &ctx.contract_address,
+            &RefundCallbackMsg {
+                player: msg.player,
+                items: msg.recipe.inputs.clone(),
+            },
+        );
+
+        let mut events = vec![event_group.build()];
+        events.extend(refund_fee(msg));
+        return events;
+    }
+
+    Mpc1155BatchBurnMsg {
+        from: ctx.contract_address,
+        token_infos: transfer_infos(&msg.recipe.inputs),
+    }
+    .as_interaction(&mut event_group, &state.collection);
+
+    let mut events = vec![event_group.build()];
+    events.extend(
+        msg.recipe
+            .fee
+            .iter()
+            .map(|fee| transfer_fee(&state.fee_receiver, fee)),
+    );
+    events
+}
+
+/// ## Description
+/// Handles result of the escrowed inputs return. Records inputs as pending refund on failure,
+/// so that player can claim them later.
+/// Returns [`(MPC1155CraftingContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **callback_ctx** is an object of type [`CallbackContext`]
+///
+/// * **state** is an object of type [`MPC1155CraftingContractState`]
+///
+/// * **msg** is an object of type [`RefundCallbackMsg`]
+pub fn execute_on_inputs_returned(
+    ctx: &ContractContext,
+    callback_ctx: &CallbackContext,
+    state: &mut MPC1155CraftingContractState,
+    msg: &RefundCallbackMsg,
+) -> Vec<EventGroup> {
+    if !callback_ctx.success {
+        state.add_pending_refund(&msg.player, &msg.items);
+    }
+
+    vec![]
+}
+
+/// ## Description
+/// Handles result of the escrowed fee transfer. Records fee as pending on failure,
+/// so that receiver can claim it later.
+/// Returns [`(MPC1155CraftingContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **callback_ctx** is an object of type [`CallbackContext`]
+///
+/// * **state** is an object of type [`MPC1155CraftingContractState`]
+///
+/// * **msg** is an object of type [`FeeCallbackMsg`]
+pub fn execute_on_fee_transferred(
+    ctx: &ContractContext,
+    callback_ctx: &CallbackContext,
+    state: &mut MPC1155CraftingContractState,
+    msg: &FeeCallbackMsg,
+) -> Vec<EventGroup> {
+    if !callback_ctx.success {
+        state.add_pending_fee(&msg.receiver, &msg.fee);
+    }
+
+    vec![]
+}
+
+fn escrow_inputs(
+    event_group: &mut EventGroupBuilder,
+    collection: &Address,
+    escrow: &Address,
+    craft: &CraftCallbackMsg,
+) {
+    Mpc1155BatchTransferFromMsg {
+        from: craft.player,
+        to: *escrow,
+        token_infos: transfer_infos(&craft.recipe.inputs),
+    }
+    .as_interaction(event_group, collection);
+    build_msg_callback(event_group, ON_INPUTS_ESCROWED_CALLBACK, craft);
+}
+
+fn return_inputs(
+    event_group: &mut EventGroupBuilder,
+    collection: &Address,
+    escrow: &Address,
+    refund: &RefundCallbackMsg,
+) {
+    Mpc1155BatchTransferFromMsg {
+        from: *escrow,
+        to: refund.player,
+        token_infos: transfer_infos(&refund.items),
+    }
+    .as_interaction(event_group, collection);
+    build_msg_callback(event_group, ON_INPUTS_RETURNED_CALLBACK, refund);
+}
+
+fn transfer_infos(items: &[RecipeItem]) -> Vec<TokenTransferInfoMsg> {
+    items
+        .iter()
+        .map(|item| TokenTransferInfoMsg {
+            token_id: item.token_id,
+            amount: item.amount,
+        })
+        .collect()
+}
+
+fn mint_items(
+    event_group: &mut EventGroupBuilder,
+    collection: &Address,
+    to: &Address,
+    items: &[RecipeItem],
+) {
+    Mpc1155BatchMintMsg {
+        to: *to,
+        token_infos: items
+            .iter()
+            .map(|item| TokenMintInfoMsg {
+                token_id: item.token_id,
+                amount: item.amount,
+                token_uri: None,
+                royalty: None,
+                max_supply: None,
+            })
+            .collect(),
+    }
+    .as_interaction(event_group, collection);
+}
+
+fn refund_fee(craft: &CraftCallbackMsg) -> Vec<EventGroup> {
+    craft
+        .recipe
+        .fee
+        .iter()
+        .map(|fee| transfer_fee(&craft.player, fee))
+        .collect()
+}
+
+fn transfer_fee(receiver: &Address, fee: &RecipeFee) -> EventGroup {
+    let mut event_group = EventGroup::builder();
+    Mpc20TransferMsg {
+        to: *receiver,
+        amount: fee.amount,
+    }
+    .as_interaction(&mut event_group, &fee.token);
+    build_msg_callback(
+        &mut event_group,
+        ON_FEE_TRANSFERRED_CALLBACK,
+        &FeeCallbackMsg {
+            receiver: *receiver,
+            fee: fee.clone(),
+        },
+    );
+
+    event_group.build()
+}
@@ -0,0 +1,18 @@
+use thiserror::Error;
+
+/// ## Description
+/// This enum describes mpc1155-crafting contract errors
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("Unauthorized")]
+    Unauthorized,
+
+    #[error("Recipe not found")]
+    RecipeNotFound,
+
+    #[error("Recipe must have inputs and outputs with positive amounts")]
+    InvalidRecipe,
+
+    #[error("Refund not found")]
+    RefundNotFound,
+}
@@ -0,0 +1,101 @@
+use create_type_spec_derive::CreateTypeSpec;
+use pbc_contract_common::address::{Address, Shortname};
+use read_write_rpc_derive::ReadWriteRPC;
+
+use rpc_msg_derive::IntoShortnameRPCEvent;
+use utils::events::IntoShortnameRPCEvent;
+
+use crate::state::{Recipe, RecipeFee, RecipeItem};
+
+/// ## Description
+/// This structure describes fields for mpc1155-crafting initialize msg
+#[derive(ReadWriteRPC, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct InitMsg {
+    /// mpc1155 collection address, contract must be its minter
+    pub collection: Address,
+    /// optional receiver of the crafting fees, if None then owner receives the fees
+    pub fee_receiver: Option<Address>,
+}
+
+/// ## Description
+/// This structure describes fields for mpc1155-crafting set recipe msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x01)]
+pub struct SetRecipeMsg {
+    /// recipe id
+    pub recipe_id: u128,
+    /// new or replaced recipe
+    pub recipe: Recipe,
+}
+
+/// ## Description
+/// This structure describes fields for mpc1155-crafting remove recipe msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x03)]
+pub struct RemoveRecipeMsg {
+    /// recipe id
+    pub recipe_id: u128,
+}
+
+/// ## Description
+/// This structure describes fields for mpc1155-crafting set fee receiver msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x05)]
+pub struct SetFeeReceiverMsg {
+    /// new receiver of the crafting fees
+    pub fee_receiver: Address,
+}
+
+/// ## Description
+/// This structure describes fields for mpc1155-crafting craft msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x07)]
+pub struct CraftMsg {
+    /// recipe id
+    pub recipe_id: u128,
+}
+
+/// ## Description
+/// This structure describes fields for mpc1155-crafting claim refund msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x09)]
+pub struct ClaimRefundMsg {}
+
+/// ## Description
+/// This structure describes crafting which is passed between crafting stage callbacks.
+/// Recipe is copied so that changes of the registered recipe do not affect started crafting
+#[derive(ReadWriteRPC, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct CraftCallbackMsg {
+    /// player address
+    pub player: Address,
+    /// crafted recipe
+    pub recipe: Recipe,
+}
+
+/// ## Description
+/// This structure describes escrowed inputs which are returned to the player
+#[derive(ReadWriteRPC, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct RefundCallbackMsg {
+    /// player address
+    pub player: Address,
+    /// returned tokens
+    pub items: Vec<RecipeItem>,
+}
+
+/// ## Description
+/// This structure describes escrowed fee which is transferred to the player or fee receiver
+#[derive(ReadWriteRPC, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct FeeCallbackMsg {
+    /// fee receiver address
+    pub receiver: Address,
+    /// transferred fee
+    pub fee: RecipeFee,
+}
+
+/// ## Description
+/// Callback shortnames used after fee escrow, inputs escrow, outputs mint, inputs return and fee transfer
+pub const ON_FEE_PAID_CALLBACK: u32 = 0x31;
+pub const ON_INPUTS_ESCROWED_CALLBACK: u32 = 0x33;
+pub const ON_OUTPUTS_MINTED_CALLBACK: u32 = 0x35;
+pub const ON_INPUTS_RETURNED_CALLBACK: u32 = 0x37;
+pub const ON_FEE_TRANSFERRED_CALLBACK: u32 = 0x39;
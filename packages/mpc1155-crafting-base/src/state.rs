@@ -0,0 +1,164 @@
+use std::collections::BTreeMap;
+
+use create_type_spec_derive::CreateTypeSpec;
+use pbc_contract_common::address::Address;
+use read_write_rpc_derive::ReadWriteRPC;
+use read_write_state_derive::ReadWriteState;
+
+use crate::ContractError;
+
+/// ## Description
+/// This structure describes main mpc1155-crafting contract state.
+#[derive(ReadWriteState, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct MPC1155CraftingContractState {
+    /// owner address
+    pub owner: Address,
+    /// mpc1155 collection address
+    pub collection: Address,
+    /// receiver of the crafting fees
+    pub fee_receiver: Address,
+    /// registered recipes by recipe id
+    pub recipes: BTreeMap<u128, Recipe>,
+    /// escrowed inputs which failed to be returned, by player address
+    pub pending_refunds: BTreeMap<Address, Vec<RecipeItem>>,
+    /// escrowed fees which failed to be transferred, by receiver address
+    pub pending_fees: BTreeMap<Address, Vec<RecipeFee>>,
+}
+
+/// ## Description
+/// This structure describes crafting recipe
+#[derive(ReadWriteRPC, ReadWriteState, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct Recipe {
+    /// tokens burned from the player
+    pub inputs: Vec<RecipeItem>,
+    /// tokens minted to the player
+    pub outputs: Vec<RecipeItem>,
+    /// optional mpc20 fee paid by the player
+    pub fee: Option<RecipeFee>,
+}
+
+/// ## Description
+/// This structure describes amount of the mpc1155 token id used by recipe
+#[derive(ReadWriteRPC, ReadWriteState, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct RecipeItem {
+    /// token id
+    pub token_id: u128,
+    /// amount of tokens
+    pub amount: u128,
+}
+
+/// ## Description
+/// This structure describes mpc20 crafting fee
+#[derive(ReadWriteRPC, ReadWriteState, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct RecipeFee {
+    /// mpc20 token address
+    pub token: Address,
+    /// fee amount
+    pub amount: u128,
+}
+
+impl Recipe {
+    /// ## Description
+    /// Validates that recipe has inputs and outputs and all amounts are positive
+    pub fn validate(&self) {
+        assert!(
+            !self.inputs.is_empty()
+                && !self.outputs.is_empty()
+                && self
+                    .inputs
+                    .iter()
+                    .chain(self.outputs.iter())
+                    .all(|item| item.amount > 0)
+                && self.fee.iter().all(|fee| fee.amount > 0),
+            "{}",
+            ContractError::InvalidRecipe
+        );
+    }
+}
+
+impl MPC1155CraftingContractState {
+    /// ## Description
+    /// Registers new or replaces existing recipe
+    /// ## Params
+    /// * **recipe_id** is a field of type [`u128`]
+    ///
+    /// * **recipe** is an object of type [`Recipe`]
+    pub fn set_recipe(&mut self, recipe_id: u128, recipe: &Recipe) {
+        recipe.validate();
+        self.recipes.insert(recipe_id, recipe.clone());
+    }
+
+    /// ## Description
+    /// Removes existing recipe
+    /// ## Params
+    /// * **recipe_id** is a field of type [`u128`]
+    pub fn remove_recipe(&mut self, recipe_id: u128) {
+        assert!(
+            self.recipes.remove(&recipe_id).is_some(),
+            "{}",
+            ContractError::RecipeNotFound
+        );
+    }
+
+    /// ## Description
+    /// Returns recipe by id or panics if recipe does not exist
+    /// ## Params
+    /// * **recipe_id** is a field of type [`u128`]
+    pub fn get_recipe(&self, recipe_id: u128) -> Recipe {
+        self.recipes
+            .get(&recipe_id)
+            .cloned()
+            .unwrap_or_else(|| panic!("{}", ContractError::RecipeNotFound))
+    }
+
+    /// ## Description
+    /// Records escrowed inputs which failed to be returned to the player
+    /// ## Params
+    /// * **player** is an object of type [`Address`]
+    ///
+    /// * **items** is an object of type [`[RecipeItem]`]
+    pub fn add_pending_refund(&mut self, player: &Address, items: &[RecipeItem]) {
+        self.pending_refunds
+            .entry(*player)
+            .or_default()
+            .extend_from_slice(items);
+    }
+
+    /// ## Description
+    /// Records escrowed fee which failed to be transferred to the receiver
+    /// ## Params
+    /// * **receiver** is an object of type [`Address`]
+    ///
+    /// * **fee** is an object of type [`RecipeFee`]
+    pub fn add_pending_fee(&mut self, receiver: &Address, fee: &RecipeFee) {
+        self.pending_fees
+            .entry(*receiver)
+            .or_default()
+            .push(fee.clone());
+    }
+
+    /// ## Description
+    /// Removes and returns escrowed inputs and fees pending transfer to the address
+    /// or panics if there are none
+    /// ## Params
+    /// * **address** is an object of type [`Address`]
+    pub fn take_pending_refund(&mut self, address: &Address) -> (Vec<RecipeItem>, Vec<RecipeFee>) {
+        let items = self.pending_refunds.remove(address).unwrap_or_default();
+        let fees = self.pending_fees.remove(address).unwrap_or_default();
+        assert!(
+            !items.is_empty() || !fees.is_empty(),
+            "{}",
+            ContractError::RefundNotFound
+        );
+
+        (items, fees)
+    }
+
+    /// ## Description
+    /// Checks that address is owner or not
+    /// ## Params
+    /// * **address** is an object of type [`Address`]
+    pub fn is_owner(&self, address: &Address) -> bool {
+        self.owner == *address
+    }
+}
@@ -0,0 +1,941 @@
+use std::collections::BTreeMap;
+
+use pbc_contract_common::{
+    address::{Address, AddressType},
+    context::{CallbackContext, ContractContext},
+    events::{EventGroup, EventGroupBuilder},
+};
+
+use crate::{
+    actions::{
+        execute_claim_refund, execute_craft, execute_init, execute_on_fee_paid,
+        execute_on_fee_transferred, execute_on_inputs_escrowed, execute_on_inputs_returned,
+        execute_on_outputs_minted, execute_remove_recipe, execute_set_fee_receiver,
+        execute_set_recipe,
+    },
+    msg::{
+        ClaimRefundMsg, CraftCallbackMsg, CraftMsg, FeeCallbackMsg, InitMsg, RefundCallbackMsg,
+        RemoveRecipeMsg, SetFeeReceiverMsg, SetRecipeMsg, ON_FEE_PAID_CALLBACK,
+        ON_FEE_TRANSFERRED_CALLBACK, ON_INPUTS_ESCROWED_CALLBACK, ON_INPUTS_RETURNED_CALLBACK,
+        ON_OUTPUTS_MINTED_CALLBACK,
+    },
+    state::{MPC1155CraftingContractState, Recipe, RecipeFee, RecipeItem},
+};
+
+use mpc1155_base::msg::{
+    BatchBurnMsg as Mpc1155BatchBurnMsg, BatchMintMsg as Mpc1155BatchMintMsg,
+    BatchTransferFromMsg as Mpc1155BatchTransferFromMsg, TokenMintInfoMsg, TokenTransferInfoMsg,
+};
+use mpc20_base::msg::{TransferFromMsg as Mpc20TransferFromMsg, TransferMsg as Mpc20TransferMsg};
+use utils::events::{build_msg_callback, IntoShortnameRPCEvent};
+
+const CRAFTING: u8 = 1;
+const OWNER: u8 = 2;
+const COLLECTION: u8 = 4;
+const FEE_TOKEN: u8 = 5;
+const TREASURY: u8 = 6;
+const ALICE: u8 = 10;
+
+fn mock_address(le: u8) -> Address {
+    Address {
+        address_type: AddressType::Account,
+        identifier: [
+            le, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8,
+        ],
+    }
+}
+
+fn mock_contract_context(sender: u8) -> ContractContext {
+    ContractContext {
+        contract_address: mock_address(CRAFTING),
+        sender: mock_address(sender),
+        block_time: 100,
+        block_production_time: 100,
+        current_transaction: [
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+        ],
+        original_transaction: [
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+        ],
+    }
+}
+
+fn mock_callback_context(success: bool) -> CallbackContext {
+    CallbackContext {
+        success,
+        results: vec![],
+    }
+}
+
+fn recipe(fee: Option<u128>) -> Recipe {
+    Recipe {
+        inputs: vec![
+            RecipeItem {
+                token_id: 1,
+                amount: 2,
+            },
+            RecipeItem {
+                token_id: 2,
+                amount: 1,
+            },
+        ],
+        outputs: vec![RecipeItem {
+            token_id: 3,
+            amount: 1,
+        }],
+        fee: fee.map(|amount| RecipeFee {
+            token: mock_address(FEE_TOKEN),
+            amount,
+        }),
+    }
+}
+
+fn craft_msg(fee: Option<u128>) -> CraftCallbackMsg {
+    CraftCallbackMsg {
+        player: mock_address(ALICE),
+        recipe: recipe(fee),
+    }
+}
+
+fn input_infos() -> Vec<TokenTransferInfoMsg> {
+    vec![
+        TokenTransferInfoMsg {
+            token_id: 1,
+            amount: 2,
+        },
+        TokenTransferInfoMsg {
+            token_id: 2,
+            amount: 1,
+        },
+    ]
+}
+
+fn escrow_inputs_event(event_group: &mut EventGroupBuilder) {
+    Mpc1155BatchTransferFromMsg {
+        from: mock_address(ALICE),
+        to: mock_address(CRAFTING),
+        token_infos: input_infos(),
+    }
+    .as_interaction(event_group, &mock_address(COLLECTION));
+}
+
+fn refund_msg() -> RefundCallbackMsg {
+    RefundCallbackMsg {
+        player: mock_address(ALICE),
+        items: recipe(None).inputs,
+    }
+}
+
+fn return_inputs_event(event_group: &mut EventGroupBuilder) {
+    Mpc1155BatchTransferFromMsg {
+        from: mock_address(CRAFTING),
+        to: mock_address(ALICE),
+        token_infos: input_infos(),
+    }
+    .as_interaction(event_group, &mock_address(COLLECTION));
+    build_msg_callback(event_group, ON_INPUTS_RETURNED_CALLBACK, &refund_msg());
+}
+
+fn fee_msg(receiver: u8) -> FeeCallbackMsg {
+    FeeCallbackMsg {
+        receiver: mock_address(receiver),
+        fee: RecipeFee {
+            token: mock_address(FEE_TOKEN),
+            amount: 10,
+        },
+    }
+}
+
+fn transfer_fee_event(receiver: u8) -> EventGroup {
+    let mut event_group = EventGroup::builder();
+    Mpc20TransferMsg {
+        to: mock_address(receiver),
+        amount: 10,
+    }
+    .as_interaction(&mut event_group, &mock_address(FEE_TOKEN));
+    build_msg_callback(
+        &mut event_group,
+        ON_FEE_TRANSFERRED_CALLBACK,
+        &fee_msg(receiver),
+    );
+
+    event_group.build()
+}
+
+fn mint_info(token_id: u128, amount: u128) -> TokenMintInfoMsg {
+    TokenMintInfoMsg {
+        token_id,
+        amount,
+        token_uri: None,
+        royalty: None,
+        max_supply: None,
+    }
+}
+
+#[test]
+fn proper_execute_init() {
+    let (state, events) = execute_init(
+        &mock_contract_context(OWNER),
+        &InitMsg {
+            collection: mock_address(COLLECTION),
+            fee_receiver: None,
+        },
+    );
+    assert_eq!(events, vec![]);
+    assert_eq!(
+        state,
+        MPC1155CraftingContractState {
+            owner: mock_address(OWNER),
+            collection: mock_address(COLLECTION),
+            fee_receiver: mock_address(OWNER),
+            recipes: BTreeMap::new(),
+            pending_refunds: BTreeMap::new(),
+            pending_fees: BTreeMap::new(),
+        }
+    );
+}
+
+#[test]
+fn proper_set_and_remove_recipe() {
+    let (mut state, _) = execute_init(
+        &mock_contract_context(OWNER),
+        &InitMsg {
+            collection: mock_address(COLLECTION),
+            fee_receiver: Some(mock_address(TREASURY)),
+        },
+    );
+    let _ = execute_set_recipe(
+        &mock_contract_context(OWNER),
+        &mut state,
+        &SetRecipeMsg {
+            recipe_id: 1,
+            recipe: recipe(None),
+        },
+    );
+    assert_eq!(state.get_recipe(1), recipe(None));
+
+    let _ = execute_set_recipe(
+        &mock_contract_context(OWNER),
+        &mut state,
+        &SetRecipeMsg {
+            recipe_id: 1,
+            recipe: recipe(Some(10)),
+        },
+    );
+    assert_eq!(state.get_recipe(1), recipe(Some(10)));
+
+    let _ = execute_remove_recipe(
+        &mock_contract_context(OWNER),
+        &mut state,
+        &RemoveRecipeMsg { recipe_id: 1 },
+    );
+    assert_eq!(state.recipes, BTreeMap::new());
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn set_recipe_not_owner() {
+    let (mut state, _) = execute_init(
+        &mock_contract_context(OWNER),
+        &InitMsg {
+            collection: mock_address(COLLECTION),
+            fee_receiver: Some(mock_address(TREASURY)),
+        },
+    );
+    let _ = execute_set_recipe(
+        &mock_contract_context(OWNER),
+        &mut state,
+        &SetRecipeMsg {
+            recipe_id: 1,
+            recipe: recipe(None),
+        },
+    );
+
+    let _ = execute_set_recipe(
+        &mock_contract_context(ALICE),
+        &mut state,
+        &SetRecipeMsg {
+            recipe_id: 2,
+            recipe: recipe(None),
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Recipe must have inputs and outputs with positive amounts")]
+fn set_recipe_without_outputs() {
+    let (mut state, _) = execute_init(
+        &mock_contract_context(OWNER),
+        &InitMsg {
+            collection: mock_address(COLLECTION),
+            fee_receiver: Some(mock_address(TREASURY)),
+        },
+    );
+    let _ = execute_set_recipe(
+        &mock_contract_context(OWNER),
+        &mut state,
+        &SetRecipeMsg {
+            recipe_id: 1,
+            recipe: recipe(None),
+        },
+    );
+
+    let mut invalid = recipe(None);
+    invalid.outputs = vec![];
+    let _ = execute_set_recipe(
+        &mock_contract_context(OWNER),
+        &mut state,
+        &SetRecipeMsg {
+            recipe_id: 2,
+            recipe: invalid,
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Recipe must have inputs and outputs with positive amounts")]
+fn set_recipe_with_zero_fee() {
+    let (mut state, _) = execute_init(
+        &mock_contract_context(OWNER),
+        &InitMsg {
+            collection: mock_address(COLLECTION),
+            fee_receiver: Some(mock_address(TREASURY)),
+        },
+    );
+    let _ = execute_set_recipe(
+        &mock_contract_context(OWNER),
+        &mut state,
+        &SetRecipeMsg {
+            recipe_id: 1,
+            recipe: recipe(None),
+        },
+    );
+
+    let _ = execute_set_recipe(
+        &mock_contract_context(OWNER),
+        &mut state,
+        &SetRecipeMsg {
+            recipe_id: 2,
+            recipe: recipe(Some(0)),
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Recipe not found")]
+fn remove_unknown_recipe() {
+    let (mut state, _) = execute_init(
+        &mock_contract_context(OWNER),
+        &InitMsg {
+            collection: mock_address(COLLECTION),
+            fee_receiver: Some(mock_address(TREASURY)),
+        },
+    );
+    let _ = execute_set_recipe(
+        &mock_contract_context(OWNER),
+        &mut state,
+        &SetRecipeMsg {
+            recipe_id: 1,
+            recipe: recipe(None),
+        },
+    );
+
+    let _ = execute_remove_recipe(
+        &mock_contract_context(OWNER),
+        &mut state,
+        &RemoveRecipeMsg { recipe_id: 2 },
+    );
+}
+
+#[test]
+fn proper_set_fee_receiver() {
+    let (mut state, _) = execute_init(
+        &mock_contract_context(OWNER),
+        &InitMsg {
+            collection: mock_address(COLLECTION),
+            fee_receiver: Some(mock_address(TREASURY)),
+        },
+    );
+    let _ = execute_set_recipe(
+        &mock_contract_context(OWNER),
+        &mut state,
+        &SetRecipeMsg {
+            recipe_id: 1,
+            recipe: recipe(None),
+        },
+    );
+
+    let _ = execute_set_fee_receiver(
+        &mock_contract_context(OWNER),
+        &mut state,
+        &SetFeeReceiverMsg {
+            fee_receiver: mock_address(ALICE),
+        },
+    );
+    assert_eq!(state.fee_receiver, mock_address(ALICE));
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn set_fee_receiver_not_owner() {
+    let (mut state, _) = execute_init(
+        &mock_contract_context(OWNER),
+        &InitMsg {
+            collection: mock_address(COLLECTION),
+            fee_receiver: Some(mock_address(TREASURY)),
+        },
+    );
+    let _ = execute_set_recipe(
+        &mock_contract_context(OWNER),
+        &mut state,
+        &SetRecipeMsg {
+            recipe_id: 1,
+            recipe: recipe(None),
+        },
+    );
+
+    let _ = execute_set_fee_receiver(
+        &mock_contract_context(ALICE),
+        &mut state,
+        &SetFeeReceiverMsg {
+            fee_receiver: mock_address(ALICE),
+        },
+    );
+}
+
+#[test]
+fn proper_craft_without_fee() {
+    let (mut state, _) = execute_init(
+        &mock_contract_context(OWNER),
+        &InitMsg {
+            collection: mock_address(COLLECTION),
+            fee_receiver: Some(mock_address(TREASURY)),
+        },
+    );
+    let _ = execute_set_recipe(
+        &mock_contract_context(OWNER),
+        &mut state,
+        &SetRecipeMsg {
+            recipe_id: 1,
+            recipe: recipe(None),
+        },
+    );
+
+    let events = execute_craft(
+        &mock_contract_context(ALICE),
+        &mut state,
+        &CraftMsg { recipe_id: 1 },
+    );
+
+    let mut event_group = EventGroup::builder();
+    escrow_inputs_event(&mut event_group);
+    build_msg_callback(
+        &mut event_group,
+        ON_INPUTS_ESCROWED_CALLBACK,
+        &craft_msg(None),
+    );
+    assert_eq!(events, vec![event_group.build()]);
+}
+
+#[test]
+fn proper_craft_with_fee() {
+    let (mut state, _) = execute_init(
+        &mock_contract_context(OWNER),
+        &InitMsg {
+            collection: mock_address(COLLECTION),
+            fee_receiver: Some(mock_address(TREASURY)),
+        },
+    );
+    let _ = execute_set_recipe(
+        &mock_contract_context(OWNER),
+        &mut state,
+        &SetRecipeMsg {
+            recipe_id: 1,
+            recipe: recipe(Some(10)),
+        },
+    );
+
+    let events = execute_craft(
+        &mock_contract_context(ALICE),
+        &mut state,
+        &CraftMsg { recipe_id: 1 },
+    );
+
+    let mut event_group = EventGroup::builder();
+    Mpc20TransferFromMsg {
+        from: mock_address(ALICE),
+        to: mock_address(CRAFTING),
+        amount: 10,
+    }
+    .as_interaction(&mut event_group, &mock_address(FEE_TOKEN));
+    build_msg_callback(&mut event_group, ON_FEE_PAID_CALLBACK, &craft_msg(Some(10)));
+    assert_eq!(events, vec![event_group.build()]);
+}
+
+#[test]
+#[should_panic(expected = "Recipe not found")]
+fn craft_unknown_recipe() {
+    let (mut state, _) = execute_init(
+        &mock_contract_context(OWNER),
+        &InitMsg {
+            collection: mock_address(COLLECTION),
+            fee_receiver: Some(mock_address(TREASURY)),
+        },
+    );
+    let _ = execute_set_recipe(
+        &mock_contract_context(OWNER),
+        &mut state,
+        &SetRecipeMsg {
+            recipe_id: 1,
+            recipe: recipe(None),
+        },
+    );
+
+    let _ = execute_craft(
+        &mock_contract_context(ALICE),
+        &mut state,
+        &CraftMsg { recipe_id: 2 },
+    );
+}
+
+#[test]
+fn proper_on_fee_paid() {
+    let (mut state, _) = execute_init(
+        &mock_contract_context(OWNER),
+        &InitMsg {
+            collection: mock_address(COLLECTION),
+            fee_receiver: Some(mock_address(TREASURY)),
+        },
+    );
+    let _ = execute_set_recipe(
+        &mock_contract_context(OWNER),
+        &mut state,
+        &SetRecipeMsg {
+            recipe_id: 1,
+            recipe: recipe(Some(10)),
+        },
+    );
+
+    let events = execute_on_fee_paid(
+        &mock_contract_context(CRAFTING),
+        &mock_callback_context(true),
+        &mut state,
+        &craft_msg(Some(10)),
+    );
+
+    let mut event_group = EventGroup::builder();
+    escrow_inputs_event(&mut event_group);
+    build_msg_callback(
+        &mut event_group,
+        ON_INPUTS_ESCROWED_CALLBACK,
+        &craft_msg(Some(10)),
+    );
+    assert_eq!(events, vec![event_group.build()]);
+
+    let events = execute_on_fee_paid(
+        &mock_contract_context(CRAFTING),
+        &mock_callback_context(false),
+        &mut state,
+        &craft_msg(Some(10)),
+    );
+    assert_eq!(events, vec![]);
+}
+
+#[test]
+fn proper_on_inputs_escrowed() {
+    let (mut state, _) = execute_init(
+        &mock_contract_context(OWNER),
+        &InitMsg {
+            collection: mock_address(COLLECTION),
+            fee_receiver: Some(mock_address(TREASURY)),
+        },
+    );
+    let _ = execute_set_recipe(
+        &mock_contract_context(OWNER),
+        &mut state,
+        &SetRecipeMsg {
+            recipe_id: 1,
+            recipe: recipe(Some(10)),
+        },
+    );
+
+    let events = execute_on_inputs_escrowed(
+        &mock_contract_context(CRAFTING),
+        &mock_callback_context(true),
+        &mut state,
+        &craft_msg(Some(10)),
+    );
+
+    let mut event_group = EventGroup::builder();
+    Mpc1155BatchMintMsg {
+        to: mock_address(ALICE),
+        token_infos: vec![mint_info(3, 1)],
+    }
+    .as_interaction(&mut event_group, &mock_address(COLLECTION));
+    build_msg_callback(
+        &mut event_group,
+        ON_OUTPUTS_MINTED_CALLBACK,
+        &craft_msg(Some(10)),
+    );
+    assert_eq!(events, vec![event_group.build()]);
+}
+
+#[test]
+fn on_inputs_escrow_failed_refunds_fee() {
+    let (mut state, _) = execute_init(
+        &mock_contract_context(OWNER),
+        &InitMsg {
+            collection: mock_address(COLLECTION),
+            fee_receiver: Some(mock_address(TREASURY)),
+        },
+    );
+    let _ = execute_set_recipe(
+        &mock_contract_context(OWNER),
+        &mut state,
+        &SetRecipeMsg {
+            recipe_id: 1,
+            recipe: recipe(Some(10)),
+        },
+    );
+
+    let events = execute_on_inputs_escrowed(
+        &mock_contract_context(CRAFTING),
+        &mock_callback_context(false),
+        &mut state,
+        &craft_msg(Some(10)),
+    );
+
+    assert_eq!(events, vec![transfer_fee_event(ALICE)]);
+
+    let events = execute_on_inputs_escrowed(
+        &mock_contract_context(CRAFTING),
+        &mock_callback_context(false),
+        &mut state,
+        &craft_msg(None),
+    );
+    assert_eq!(events, vec![]);
+}
+
+#[test]
+fn proper_on_outputs_minted() {
+    let (mut state, _) = execute_init(
+        &mock_contract_context(OWNER),
+        &InitMsg {
+            collection: mock_address(COLLECTION),
+            fee_receiver: Some(mock_address(TREASURY)),
+        },
+    );
+    let _ = execute_set_recipe(
+        &mock_contract_context(OWNER),
+        &mut state,
+        &SetRecipeMsg {
+            recipe_id: 1,
+            recipe: recipe(Some(10)),
+        },
+    );
+
+    let events = execute_on_outputs_minted(
+        &mock_contract_context(CRAFTING),
+        &mock_callback_context(true),
+        &mut state,
+        &craft_msg(Some(10)),
+    );
+
+    let mut event_group = EventGroup::builder();
+    Mpc1155BatchBurnMsg {
+        from: mock_address(CRAFTING),
+        token_infos: input_infos(),
+    }
+    .as_interaction(&mut event_group, &mock_address(COLLECTION));
+    assert_eq!(
+        events,
+        vec![event_group.build(), transfer_fee_event(TREASURY)]
+    );
+
+    let events = execute_on_outputs_minted(
+        &mock_contract_context(CRAFTING),
+        &mock_callback_context(true),
+        &mut state,
+        &craft_msg(None),
+    );
+
+    let mut event_group = EventGroup::builder();
+    Mpc1155BatchBurnMsg {
+        from: mock_address(CRAFTING),
+        token_infos: input_infos(),
+    }
+    .as_interaction(&mut event_group, &mock_address(COLLECTION));
+    assert_eq!(events, vec![event_group.build()]);
+}
+
+#[test]
+fn on_outputs_mint_failed_returns_inputs_and_refunds_fee() {
+    let (mut state, _) = execute_init(
+        &mock_contract_context(OWNER),
+        &InitMsg {
+            collection: mock_address(COLLECTION),
+            fee_receiver: Some(mock_address(TREASURY)),
+        },
+    );
+    let _ = execute_set_recipe(
+        &mock_contract_context(OWNER),
+        &mut state,
+        &SetRecipeMsg {
+            recipe_id: 1,
+            recipe: recipe(Some(10)),
+        },
+    );
+
+    let events = execute_on_outputs_minted(
+        &mock_contract_context(CRAFTING),
+        &mock_callback_context(false),
+        &mut state,
+        &craft_msg(Some(10)),
+    );
+
+    let mut event_group = EventGroup::builder();
+    return_inputs_event(&mut event_group);
+    assert_eq!(events, vec![event_group.build(), transfer_fee_event(ALICE)]);
+}
+
+#[test]
+fn on_inputs_return_failed_records_refund() {
+    let (mut state, _) = execute_init(
+        &mock_contract_context(OWNER),
+        &InitMsg {
+            collection: mock_address(COLLECTION),
+            fee_receiver: Some(mock_address(TREASURY)),
+        },
+    );
+    let _ = execute_set_recipe(
+        &mock_contract_context(OWNER),
+        &mut state,
+        &SetRecipeMsg {
+            recipe_id: 1,
+            recipe: recipe(None),
+        },
+    );
+
+    let events = execute_on_inputs_returned(
+        &mock_contract_context(CRAFTING),
+        &mock_callback_context(true),
+        &mut state,
+        &refund_msg(),
+    );
+    assert_eq!(events, vec![]);
+    assert_eq!(state.pending_refunds, BTreeMap::new());
+
+    for _ in 0..2 {
+        let _ = execute_on_inputs_returned(
+            &mock_contract_context(CRAFTING),
+            &mock_callback_context(false),
+            &mut state,
+            &refund_msg(),
+        );
+    }
+    let mut items = recipe(None).inputs;
+    items.extend(recipe(None).inputs);
+    assert_eq!(
+        state.pending_refunds,
+        BTreeMap::from([(mock_address(ALICE), items)])
+    );
+}
+
+#[test]
+fn proper_claim_refund() {
+    let (mut state, _) = execute_init(
+        &mock_contract_context(OWNER),
+        &InitMsg {
+            collection: mock_address(COLLECTION),
+            fee_receiver: Some(mock_address(TREASURY)),
+        },
+    );
+    let _ = execute_set_recipe(
+        &mock_contract_context(OWNER),
+        &mut state,
+        &SetRecipeMsg {
+            recipe_id: 1,
+            recipe: recipe(None),
+        },
+    );
+    let _ = execute_on_inputs_returned(
+        &mock_contract_context(CRAFTING),
+        &mock_callback_context(false),
+        &mut state,
+        &refund_msg(),
+    );
+
+    let events = execute_claim_refund(
+        &mock_contract_context(ALICE),
+        &mut state,
+        &ClaimRefundMsg {},
+    );
+
+    let mut event_group = EventGroup::builder();
+    return_inputs_event(&mut event_group);
+    assert_eq!(events, vec![event_group.build()]);
+    assert_eq!(state.pending_refunds, BTreeMap::new());
+}
+
+#[test]
+fn on_fee_transfer_failed_records_fee() {
+    let (mut state, _) = execute_init(
+        &mock_contract_context(OWNER),
+        &InitMsg {
+            collection: mock_address(COLLECTION),
+            fee_receiver: Some(mock_address(TREASURY)),
+        },
+    );
+    let _ = execute_set_recipe(
+        &mock_contract_context(OWNER),
+        &mut state,
+        &SetRecipeMsg {
+            recipe_id: 1,
+            recipe: recipe(Some(10)),
+        },
+    );
+
+    let events = execute_on_fee_transferred(
+        &mock_contract_context(CRAFTING),
+        &mock_callback_context(true),
+        &mut state,
+        &fee_msg(ALICE),
+    );
+    assert_eq!(events, vec![]);
+    assert_eq!(state.pending_fees, BTreeMap::new());
+
+    for receiver in [ALICE, TREASURY] {
+        let _ = execute_on_fee_transferred(
+            &mock_contract_context(CRAFTING),
+            &mock_callback_context(false),
+            &mut state,
+            &fee_msg(receiver),
+        );
+    }
+    assert_eq!(
+        state.pending_fees,
+        BTreeMap::from([
+            (mock_address(ALICE), vec![fee_msg(ALICE).fee]),
+            (mock_address(TREASURY), vec![fee_msg(TREASURY).fee]),
+        ])
+    );
+}
+
+#[test]
+fn proper_claim_refund_with_fee() {
+    let (mut state, _) = execute_init(
+        &mock_contract_context(OWNER),
+        &InitMsg {
+            collection: mock_address(COLLECTION),
+            fee_receiver: Some(mock_address(TREASURY)),
+        },
+    );
+    let _ = execute_set_recipe(
+        &mock_contract_context(OWNER),
+        &mut state,
+        &SetRecipeMsg {
+            recipe_id: 1,
+            recipe: recipe(Some(10)),
+        },
+    );
+    let _ = execute_on_inputs_returned(
+        &mock_contract_context(CRAFTING),
+        &mock_callback_context(false),
+        &mut state,
+        &refund_msg(),
+    );
+    for receiver in [ALICE, TREASURY] {
+        let _ = execute_on_fee_transferred(
+            &mock_contract_context(CRAFTING),
+            &mock_callback_context(false),
+            &mut state,
+            &fee_msg(receiver),
+        );
+    }
+
+    let events = execute_claim_refund(
+        &mock_contract_context(ALICE),
+        &mut state,
+        &ClaimRefundMsg {},
+    );
+
+    let mut event_group = EventGroup::builder();
+    return_inputs_event(&mut event_group);
+    assert_eq!(events, vec![event_group.build(), transfer_fee_event(ALICE)]);
+    assert_eq!(state.pending_refunds, BTreeMap::new());
+
+    let events = execute_claim_refund(
+        &mock_contract_context(TREASURY),
+        &mut state,
+        &ClaimRefundMsg {},
+    );
+    assert_eq!(events, vec![transfer_fee_event(TREASURY)]);
+    assert_eq!(state.pending_fees, BTreeMap::new());
+}
+
+#[test]
+#[should_panic(expected = "Refund not found")]
+fn claim_refund_without_pending_refund() {
+    let (mut state, _) = execute_init(
+        &mock_contract_context(OWNER),
+        &InitMsg {
+            collection: mock_address(COLLECTION),
+            fee_receiver: Some(mock_address(TREASURY)),
+        },
+    );
+    let _ = execute_set_recipe(
+        &mock_contract_context(OWNER),
+        &mut state,
+        &SetRecipeMsg {
+            recipe_id: 1,
+            recipe: recipe(None),
+        },
+    );
+
+    let _ = execute_claim_refund(
+        &mock_contract_context(ALICE),
+        &mut state,
+        &ClaimRefundMsg {},
+    );
+}
+
+#[test]
+fn craft_uses_recipe_snapshot() {
+    let (mut state, _) = execute_init(
+        &mock_contract_context(OWNER),
+        &InitMsg {
+            collection: mock_address(COLLECTION),
+            fee_receiver: Some(mock_address(TREASURY)),
+        },
+    );
+    let _ = execute_set_recipe(
+        &mock_contract_context(OWNER),
+        &mut state,
+        &SetRecipeMsg {
+            recipe_id: 1,
+            recipe: recipe(None),
+        },
+    );
+
+    let _ = execute_craft(
+        &mock_contract_context(ALICE),
+        &mut state,
+        &CraftMsg { recipe_id: 1 },
+    );
+    let _ = execute_remove_recipe(
+        &mock_contract_context(OWNER),
+        &mut state,
+        &RemoveRecipeMsg { recipe_id: 1 },
+    );
+
+    let events = execute_on_inputs_escrowed(
+        &mock_contract_context(CRAFTING),
+        &mock_callback_context(true),
+        &mut state,
+        &craft_msg(None),
+    );
+    assert_eq!(events.len(), 1);
+}
@@ -1,9 +1,11 @@
 use pbc_contract_common::{context::ContractContext, events::EventGroup};
+use utils::events::build_msg_reply;
 
 use crate::{
     msg::{
-        ApproveMsg, BurnFromMsg, BurnMsg, DecreaseAllowanceMsg, IncreaseAllowanceMsg, MintMsg,
-        Mpc20InitMsg, TransferFromMsg, TransferMsg,
+        AllowanceMsg, AllowanceReplyMsg, ApproveMsg, BalanceMsg, BalanceReplyMsg, BurnFromMsg,
+        BurnMsg, DecreaseAllowanceMsg, IncreaseAllowanceMsg, MintMsg, Mpc20InitMsg, TotalSupplyMsg,
+        TotalSupplyReplyMsg, TransferFromMsg, TransferMsg,
     },
     state::MPC20ContractState,
     ContractError,
@@ -275,3 +277,84 @@ pub fn execute_decrease_allowance(
     state.decrease_allowance(&ctx.sender, &msg.spender, msg.amount);
     vec![]
 }
+
+/// ## Description
+/// Reports balance of the holder back to the calling contract.
+/// Returns [`(MPC20ContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC20ContractState`]
+///
+/// * **msg** is an object of type [`BalanceMsg`]
+pub fn execute_balance(
+    ctx: &ContractContext,
+    state: &mut MPC20ContractState,
+    msg: &BalanceMsg,
+) -> Vec<EventGroup> {
+    let reply = BalanceReplyMsg {
+        owner: msg.owner,
+        balance: state.balance_of(&msg.owner),
+        context: msg.context.clone(),
+    };
+
+    let mut event_group = EventGroup::builder();
+    build_msg_reply(&mut event_group, &ctx.sender, msg.callback_action, &reply);
+
+    vec![event_group.build()]
+}
+
+/// ## Description
+/// Reports amount spender is allowed to transfer from the owner back to the calling contract.
+/// Returns [`(MPC20ContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC20ContractState`]
+///
+/// * **msg** is an object of type [`AllowanceMsg`]
+pub fn execute_allowance(
+    ctx: &ContractContext,
+    state: &mut MPC20ContractState,
+    msg: &AllowanceMsg,
+) -> Vec<EventGroup> {
+    let reply = AllowanceReplyMsg {
+        owner: msg.owner,
+        spender: msg.spender,
+        allowance: state.allowance(&msg.owner, &msg.spender),
+        context: msg.context.clone(),
+    };
+
+    let mut event_group = EventGroup::builder();
+    build_msg_reply(&mut event_group, &ctx.sender, msg.callback_action, &reply);
+
+    vec![event_group.build()]
+}
+
+/// ## Description
+/// Reports total supply back to the calling contract.
+/// Returns [`(MPC20ContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC20ContractState`]
+///
+/// * **msg** is an object of type [`TotalSupplyMsg`]
+pub fn execute_total_supply(
+    ctx: &ContractContext,
+    state: &mut MPC20ContractState,
+    msg: &TotalSupplyMsg,
+) -> Vec<EventGroup> {
+    let reply = TotalSupplyReplyMsg {
+        total_supply: state.total_supply,
+        context: msg.context.clone(),
+    };
+
+    let mut event_group = EventGroup::builder();
+    build_msg_reply(&mut event_group, &ctx.sender, msg.callback_action, &reply);
+
+    vec![event_group.build()]
+}
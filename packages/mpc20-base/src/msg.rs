@@ -168,3 +168,81 @@ pub struct DecreaseAllowanceMsg {
     /// approved amount to decrease
     pub amount: u128,
 }
+
+/// ## Description
+/// This structure describes fields for mpc20 balance query msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x40)]
+pub struct BalanceMsg {
+    /// token holder address
+    pub owner: Address,
+    /// action shortname of the calling contract which receives [`BalanceReplyMsg`]
+    pub callback_action: u32,
+    /// opaque data echoed back in the reply, e.g. to match the reply with the query
+    pub context: Vec<u8>,
+}
+
+/// ## Description
+/// This structure describes balance sent back to the calling contract.
+/// Receiver must check that the reply is sent by the token contract
+#[derive(ReadWriteRPC, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct BalanceReplyMsg {
+    /// token holder address
+    pub owner: Address,
+    /// balance of the holder
+    pub balance: u128,
+    /// opaque data of the query
+    pub context: Vec<u8>,
+}
+
+/// ## Description
+/// This structure describes fields for mpc20 allowance query msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x41)]
+pub struct AllowanceMsg {
+    /// token owner address
+    pub owner: Address,
+    /// spender address
+    pub spender: Address,
+    /// action shortname of the calling contract which receives [`AllowanceReplyMsg`]
+    pub callback_action: u32,
+    /// opaque data echoed back in the reply, e.g. to match the reply with the query
+    pub context: Vec<u8>,
+}
+
+/// ## Description
+/// This structure describes allowance sent back to the calling contract.
+/// Receiver must check that the reply is sent by the token contract
+#[derive(ReadWriteRPC, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct AllowanceReplyMsg {
+    /// token owner address
+    pub owner: Address,
+    /// spender address
+    pub spender: Address,
+    /// amount spender is allowed to transfer
+    pub allowance: u128,
+    /// opaque data of the query
+    pub context: Vec<u8>,
+}
+
+/// ## Description
+/// This structure describes fields for mpc20 total supply query msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x42)]
+pub struct TotalSupplyMsg {
+    /// action shortname of the calling contract which receives [`TotalSupplyReplyMsg`]
+    pub callback_action: u32,
+    /// opaque data echoed back in the reply, e.g. to match the reply with the query
+    pub context: Vec<u8>,
+}
+
+/// ## Description
+/// This structure describes total supply sent back to the calling contract.
+/// Receiver must check that the reply is sent by the token contract
+#[derive(ReadWriteRPC, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct TotalSupplyReplyMsg {
+    /// amount of existing tokens
+    pub total_supply: u128,
+    /// opaque data of the query
+    pub context: Vec<u8>,
+}
@@ -3,17 +3,20 @@ use std::collections::BTreeMap;
 use pbc_contract_common::{
     address::{Address, AddressType},
     context::ContractContext,
+    events::EventGroup,
 };
+use utils::events::build_msg_reply;
 
 use crate::{
     actions::{
-        execute_approve, execute_burn, execute_burn_from, execute_decrease_allowance,
-        execute_increase_allowance, execute_init, execute_mint, execute_transfer,
-        execute_transfer_from,
+        execute_allowance, execute_approve, execute_balance, execute_burn, execute_burn_from,
+        execute_decrease_allowance, execute_increase_allowance, execute_init, execute_mint,
+        execute_total_supply, execute_transfer, execute_transfer_from,
     },
     msg::{
-        ApproveMsg, BurnFromMsg, BurnMsg, DecreaseAllowanceMsg, IncreaseAllowanceMsg,
-        InitialBalance, MintMsg, Mpc20InitMsg, TransferFromMsg, TransferMsg,
+        AllowanceMsg, AllowanceReplyMsg, ApproveMsg, BalanceMsg, BalanceReplyMsg, BurnFromMsg,
+        BurnMsg, DecreaseAllowanceMsg, IncreaseAllowanceMsg, InitialBalance, MintMsg, Mpc20InitMsg,
+        TotalSupplyMsg, TotalSupplyReplyMsg, TransferFromMsg, TransferMsg,
     },
     state::{MPC20ContractState, Minter, TokenInfo},
 };
@@ -985,3 +988,135 @@ fn decrease_more_than_approved() {
         &decrease_allowance_msg,
     );
 }
+
+#[test]
+fn proper_balance_and_total_supply_queries() {
+    let alice = 10u8;
+    let caller = 20u8;
+
+    let msg = Mpc20InitMsg {
+        info: mock_token_info(),
+        initial_balances: vec![InitialBalance {
+            address: mock_address(alice),
+            amount: 100,
+        }],
+        minter: None,
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2u8), &msg);
+
+    let events = execute_balance(
+        &mock_contract_context(caller),
+        &mut state,
+        &BalanceMsg {
+            owner: mock_address(alice),
+            callback_action: 0x41,
+            context: vec![1, 2],
+        },
+    );
+
+    let mut event_group = EventGroup::builder();
+    build_msg_reply(
+        &mut event_group,
+        &mock_address(caller),
+        0x41,
+        &BalanceReplyMsg {
+            owner: mock_address(alice),
+            balance: 100,
+            context: vec![1, 2],
+        },
+    );
+    assert_eq!(events, vec![event_group.build()]);
+
+    let events = execute_balance(
+        &mock_contract_context(caller),
+        &mut state,
+        &BalanceMsg {
+            owner: mock_address(11u8),
+            callback_action: 0x41,
+            context: vec![1, 2],
+        },
+    );
+
+    let mut event_group = EventGroup::builder();
+    build_msg_reply(
+        &mut event_group,
+        &mock_address(caller),
+        0x41,
+        &BalanceReplyMsg {
+            owner: mock_address(11u8),
+            balance: 0,
+            context: vec![1, 2],
+        },
+    );
+    assert_eq!(events, vec![event_group.build()]);
+
+    let events = execute_total_supply(
+        &mock_contract_context(caller),
+        &mut state,
+        &TotalSupplyMsg {
+            callback_action: 0x43,
+            context: vec![1, 2],
+        },
+    );
+
+    let mut event_group = EventGroup::builder();
+    build_msg_reply(
+        &mut event_group,
+        &mock_address(caller),
+        0x43,
+        &TotalSupplyReplyMsg {
+            total_supply: 100,
+            context: vec![1, 2],
+        },
+    );
+    assert_eq!(events, vec![event_group.build()]);
+}
+
+#[test]
+fn proper_allowance_query() {
+    let alice = 10u8;
+    let bob = 11u8;
+    let caller = 20u8;
+
+    let msg = Mpc20InitMsg {
+        info: mock_token_info(),
+        initial_balances: vec![],
+        minter: None,
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2u8), &msg);
+    let _ = execute_approve(
+        &mock_contract_context(alice),
+        &mut state,
+        &ApproveMsg {
+            spender: mock_address(bob),
+            amount: 50,
+        },
+    );
+
+    let events = execute_allowance(
+        &mock_contract_context(caller),
+        &mut state,
+        &AllowanceMsg {
+            owner: mock_address(alice),
+            spender: mock_address(bob),
+            callback_action: 0x42,
+            context: vec![1, 2],
+        },
+    );
+
+    let mut event_group = EventGroup::builder();
+    build_msg_reply(
+        &mut event_group,
+        &mock_address(caller),
+        0x42,
+        &AllowanceReplyMsg {
+            owner: mock_address(alice),
+            spender: mock_address(bob),
+            allowance: 50,
+            context: vec![1, 2],
+        },
+    );
+    assert_eq!(events, vec![event_group.build()]);
+}
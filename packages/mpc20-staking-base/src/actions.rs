@@ -1,11 +1,25 @@
 use std::collections::BTreeMap;
 
-use pbc_contract_common::{context::ContractContext, events::EventGroup};
+use pbc_contract_common::{
+    address::Address,
+    context::{CallbackContext, ContractContext},
+    events::{EventGroup, EventGroupBuilder},
+};
 use rust_decimal::prelude::*;
 
 use crate::{
-    msg::{ClaimMsg, CompoundMsg, Mpc20StakingInitMsg, StakeMsg, UnstakeMsg},
-    state::MPC20StakingContractState,
+    msg::{
+        AddRewardStreamMsg, ClaimCallbackMsg, ClaimMsg, ClaimStreamCallbackMsg, ClaimStreamMsg,
+        CompoundMsg, FundProgramCallbackMsg, FundRewardProgramMsg, FundRewardStreamMsg,
+        FundStreamCallbackMsg, InstantUnstakeCallbackMsg, InstantUnstakeMsg, Mpc20StakingInitMsg,
+        PayPenaltiesMsg, PenaltyCallbackMsg, SetDistributionAmountMsg, SetUnbondingMsg,
+        StakeCallbackMsg, StakeMsg, UnstakeCallbackMsg, UnstakeMsg, WithdrawCallbackMsg,
+        WithdrawMsg, WithdrawProgramCallbackMsg, WithdrawRewardProgramMsg, ON_CLAIM_CALLBACK,
+        ON_CLAIM_STREAM_CALLBACK, ON_FUND_PROGRAM_CALLBACK, ON_FUND_STREAM_CALLBACK,
+        ON_INSTANT_UNSTAKE_CALLBACK, ON_PENALTY_CALLBACK, ON_STAKE_CALLBACK, ON_UNSTAKE_CALLBACK,
+        ON_WITHDRAW_CALLBACK, ON_WITHDRAW_PROGRAM_CALLBACK,
+    },
+    state::{MPC20StakingContractState, RewardProgram, RewardStream, MAX_BASIS_POINTS},
     ContractError,
 };
 
@@ -14,7 +28,10 @@ use mpc20_base::{
     msg::{Mpc20InitMsg, TransferFromMsg as Mpc20TransferFromMsg, TransferMsg as Mpc20TransferMsg},
     state::Minter as Mpc20Minter,
 };
-use utils::{decimal::DecimalRatio, events::IntoShortnameRPCEvent};
+use utils::{
+    decimal::DecimalRatio,
+    events::{build_msg_callback, IntoShortnameRPCEvent},
+};
 
 /// ## Description
 /// Inits contract state.
@@ -38,6 +55,13 @@ pub fn execute_init(
 
     let last_distributed = ctx.block_production_time as u64;
 
+    let reward_program = msg.reward_token.map(|token| RewardProgram {
+        token,
+        budget: 0,
+        distributed: 0,
+        end_time: last_distributed,
+    });
+
     let minter = msg.minter.map(|minter_addr| Mpc20Minter {
         minter: minter_addr,
         capacity: None,
@@ -56,11 +80,18 @@ pub fn execute_init(
         deposit_token,
         distribution_amount: msg.distribution_amount,
         distribution_epoch: msg.distribution_epoch,
+        reward_program,
         global_index: DecimalRatio::zero(),
         total_staked: 0,
         last_distributed,
         stakers: BTreeMap::new(),
         compound_frequency: msg.compound_frequency,
+        owner: ctx.sender,
+        reward_streams: BTreeMap::new(),
+        unbonding_period: msg.unbonding_period,
+        instant_unstake_penalty: None,
+        withdrawals: BTreeMap::new(),
+        pending_penalties: BTreeMap::new(),
         mpc20,
     };
 
@@ -69,6 +100,7 @@ pub fn execute_init(
 
 /// ## Description
 /// Stake specified amount of tokens to earn rewards.
+/// Stake is recorded once the deposit token transfer succeeds.
 /// Returns [`(MPC20StakingContractState, Vec<EventGroup>)`] if operation was successful,
 /// otherwise panics with error message defined in [`ContractError`]
 /// ## Params
@@ -82,12 +114,6 @@ pub fn execute_stake(
     state: &mut MPC20StakingContractState,
     msg: &StakeMsg,
 ) -> Vec<EventGroup> {
-    let mut staker = state.get_staker(&ctx.sender);
-
-    state.distribute_rewards(ctx.block_production_time as u64);
-    staker.compute_reward(state.global_index);
-    state.increase_stake_amount(&ctx.sender, &mut staker, msg.amount);
-
     let mut event_group = EventGroup::builder();
     Mpc20TransferFromMsg {
         from: ctx.sender,
@@ -95,12 +121,49 @@ pub fn execute_stake(
         amount: msg.amount,
     }
     .as_interaction(&mut event_group, &state.deposit_token);
+    build_msg_callback(
+        &mut event_group,
+        ON_STAKE_CALLBACK,
+        &StakeCallbackMsg {
+            staker: ctx.sender,
+            amount: msg.amount,
+        },
+    );
 
     vec![event_group.build()]
 }
 
+/// ## Description
+/// Handles result of the deposit token transfer and records the stake on success.
+/// Returns [`(MPC20StakingContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **callback_ctx** is an object of type [`CallbackContext`]
+///
+/// * **state** is an object of type [`MPC20StakingContractState`]
+///
+/// * **msg** is an object of type [`StakeCallbackMsg`]
+pub fn execute_on_stake(
+    ctx: &ContractContext,
+    callback_ctx: &CallbackContext,
+    state: &mut MPC20StakingContractState,
+    msg: &StakeCallbackMsg,
+) -> Vec<EventGroup> {
+    if !callback_ctx.success {
+        return vec![];
+    }
+
+    credit_stake(ctx, state, &msg.staker, msg.amount);
+
+    vec![]
+}
+
 /// ## Description
 /// Withdraw staked tokens.
+/// Stake is debited immediately. When unbonding period is set amount is queued until release time,
+/// otherwise it is transferred and stake is restored if the transfer fails.
 /// Returns [`(MPC20StakingContractState, Vec<EventGroup>)`] if operation was successful,
 /// otherwise panics with error message defined in [`ContractError`]
 /// ## Params
@@ -114,17 +177,13 @@ pub fn execute_unstake(
     state: &mut MPC20StakingContractState,
     msg: &UnstakeMsg,
 ) -> Vec<EventGroup> {
-    let mut staker = state.get_staker(&ctx.sender);
-
-    assert!(
-        staker.staked_amount >= msg.amount,
-        "{}",
-        ContractError::CannotUnstakeMoreThenStaked,
-    );
+    debit_stake(ctx, state, msg.amount);
 
-    state.distribute_rewards(ctx.block_production_time as u64);
-    staker.compute_reward(state.global_index);
-    state.decrease_stake_amount(&ctx.sender, &mut staker, msg.amount);
+    if !state.unbonding_period.is_zero() {
+        let release_time = (ctx.block_production_time as u64) + state.unbonding_period;
+        state.queue_withdrawal(&ctx.sender, msg.amount, release_time);
+        return vec![];
+    }
 
     let mut event_group = EventGroup::builder();
     Mpc20TransferMsg {
@@ -132,12 +191,48 @@ pub fn execute_unstake(
         amount: msg.amount,
     }
     .as_interaction(&mut event_group, &state.deposit_token);
+    build_msg_callback(
+        &mut event_group,
+        ON_UNSTAKE_CALLBACK,
+        &UnstakeCallbackMsg {
+            staker: ctx.sender,
+            amount: msg.amount,
+        },
+    );
 
     vec![event_group.build()]
 }
 
+/// ## Description
+/// Handles result of the withdraw transfer and restores the stake on failure.
+/// Returns [`(MPC20StakingContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **callback_ctx** is an object of type [`CallbackContext`]
+///
+/// * **state** is an object of type [`MPC20StakingContractState`]
+///
+/// * **msg** is an object of type [`UnstakeCallbackMsg`]
+pub fn execute_on_unstake(
+    ctx: &ContractContext,
+    callback_ctx: &CallbackContext,
+    state: &mut MPC20StakingContractState,
+    msg: &UnstakeCallbackMsg,
+) -> Vec<EventGroup> {
+    if callback_ctx.success {
+        return vec![];
+    }
+
+    credit_stake(ctx, state, &msg.staker, msg.amount);
+
+    vec![]
+}
+
 /// ## Description
 /// Claim earned rewards.
+/// Rewards are minted when reward program is not set, otherwise they are transferred from the funded pool.
 /// Returns [`(MPC20StakingContractState, Vec<EventGroup>)`] if operation was successful,
 /// otherwise panics with error message defined in [`ContractError`]
 /// ## Params
@@ -175,14 +270,65 @@ pub fn execute_claim(
 
     staker.pending_reward = staker.pending_reward.checked_sub(claim_amount).unwrap();
     state.store_staker(&ctx.sender, &staker);
-    state.mpc20.mint_to(&ctx.sender, claim_amount);
+
+    let program = match &state.reward_program {
+        Some(program) => program,
+        None => {
+            state.mpc20.mint_to(&ctx.sender, claim_amount);
+            return vec![];
+        }
+    };
+
+    let mut event_group = EventGroup::builder();
+    Mpc20TransferMsg {
+        to: ctx.sender,
+        amount: claim_amount,
+    }
+    .as_interaction(&mut event_group, &program.token);
+    build_msg_callback(
+        &mut event_group,
+        ON_CLAIM_CALLBACK,
+        &ClaimCallbackMsg {
+            staker: ctx.sender,
+            amount: claim_amount,
+        },
+    );
+
+    vec![event_group.build()]
+}
+
+/// ## Description
+/// Handles result of the reward payout and restores pending reward on failure.
+/// Returns [`(MPC20StakingContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **callback_ctx** is an object of type [`CallbackContext`]
+///
+/// * **state** is an object of type [`MPC20StakingContractState`]
+///
+/// * **msg** is an object of type [`ClaimCallbackMsg`]
+pub fn execute_on_claim(
+    ctx: &ContractContext,
+    callback_ctx: &CallbackContext,
+    state: &mut MPC20StakingContractState,
+    msg: &ClaimCallbackMsg,
+) -> Vec<EventGroup> {
+    if callback_ctx.success {
+        return vec![];
+    }
+
+    let mut staker = state.get_staker(&msg.staker);
+    staker.pending_reward = staker.pending_reward.checked_add(msg.amount).unwrap();
+    state.store_staker(&msg.staker, &staker);
 
     vec![]
 }
 
 /// ## Description
 /// Compound earned rewards(e.g. stake them).
-/// Only works when deposit token is reward token.
+/// Only works when deposit token is reward token. Funded rewards are already held by the contract and are not minted.
 /// Returns [`(MPC20StakingContractState, Vec<EventGroup>)`] if operation was successful,
 /// otherwise panics with error message defined in [`ContractError`]
 /// ## Params
@@ -202,7 +348,11 @@ pub fn execute_compound(
     staker.compute_reward(state.global_index);
 
     assert!(
-        state.deposit_token == ctx.contract_address,
+        state.deposit_token == ctx.contract_address
+            && state
+                .reward_program
+                .iter()
+                .all(|program| program.token == state.deposit_token),
         "{}",
         ContractError::CompoundOnlyWorksWithSelfToken
     );
@@ -224,11 +374,757 @@ pub fn execute_compound(
         staker.pending_reward
     };
 
+    state.compute_stream_rewards(&ctx.sender, staker.staked_amount);
     staker.last_compound = ctx.block_production_time as u64;
     staker.pending_reward = staker.pending_reward.checked_sub(compound_amount).unwrap();
     state.increase_stake_amount(&ctx.sender, &mut staker, compound_amount);
 
-    state.mpc20.mint_to(&ctx.contract_address, compound_amount);
+    if state.mints_rewards() {
+        state.mpc20.mint_to(&ctx.contract_address, compound_amount);
+    }
+
+    vec![]
+}
+
+/// ## Description
+/// Adds new reward stream paid in external mpc20 token. Can only be executed by owner account.
+/// Returns [`(MPC20StakingContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC20StakingContractState`]
+///
+/// * **msg** is an object of type [`AddRewardStreamMsg`]
+pub fn execute_add_reward_stream(
+    ctx: &ContractContext,
+    state: &mut MPC20StakingContractState,
+    msg: &AddRewardStreamMsg,
+) -> Vec<EventGroup> {
+    assert!(
+        state.is_owner(&ctx.sender),
+        "{}",
+        ContractError::Unauthorized
+    );
+    assert!(
+        !state.reward_streams.contains_key(&msg.token),
+        "{}",
+        ContractError::RewardStreamExists
+    );
+
+    let block_time = ctx.block_production_time as u64;
+    assert!(
+        msg.distribution_amount > 0 && msg.distribution_epoch > 0 && msg.end_time > block_time,
+        "{}",
+        ContractError::InvalidRewardStream
+    );
+    assert!(
+        msg.token != state.deposit_token
+            && state
+                .reward_program
+                .iter()
+                .all(|program| program.token != msg.token),
+        "{}",
+        ContractError::InvalidRewardStream
+    );
+
+    state.distribute_rewards(block_time);
+    state.reward_streams.insert(
+        msg.token,
+        RewardStream {
+            distribution_amount: msg.distribution_amount,
+            distribution_epoch: msg.distribution_epoch,
+            end_time: msg.end_time,
+            global_index: DecimalRatio::zero(),
+            last_distributed: block_time,
+            funded: 0,
+            distributed: 0,
+            stakers: BTreeMap::new(),
+        },
+    );
+
+    vec![]
+}
+
+/// ## Description
+/// Funds reward stream by transferring reward tokens to the contract. Can only be executed by owner account.
+/// Funding is recorded once the tokens are received.
+/// Returns [`(MPC20StakingContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC20StakingContractState`]
+///
+/// * **msg** is an object of type [`FundRewardStreamMsg`]
+pub fn execute_fund_reward_stream(
+    ctx: &ContractContext,
+    state: &mut MPC20StakingContractState,
+    msg: &FundRewardStreamMsg,
+) -> Vec<EventGroup> {
+    assert!(
+        state.is_owner(&ctx.sender),
+        "{}",
+        ContractError::Unauthorized
+    );
+    assert!(
+        state.reward_streams.contains_key(&msg.token),
+        "{}",
+        ContractError::RewardStreamNotFound
+    );
+
+    let mut event_group = EventGroup::builder();
+    Mpc20TransferFromMsg {
+        from: ctx.sender,
+        to: ctx.contract_address,
+        amount: msg.amount,
+    }
+    .as_interaction(&mut event_group, &msg.token);
+    build_msg_callback(
+        &mut event_group,
+        ON_FUND_STREAM_CALLBACK,
+        &FundStreamCallbackMsg {
+            token: msg.token,
+            amount: msg.amount,
+        },
+    );
+
+    vec![event_group.build()]
+}
+
+/// ## Description
+/// Handles result of the reward stream funding and records funded amount on success.
+/// Returns [`(MPC20StakingContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **callback_ctx** is an object of type [`CallbackContext`]
+///
+/// * **state** is an object of type [`MPC20StakingContractState`]
+///
+/// * **msg** is an object of type [`FundStreamCallbackMsg`]
+pub fn execute_on_fund_stream(
+    ctx: &ContractContext,
+    callback_ctx: &CallbackContext,
+    state: &mut MPC20StakingContractState,
+    msg: &FundStreamCallbackMsg,
+) -> Vec<EventGroup> {
+    if !callback_ctx.success {
+        return vec![];
+    }
+
+    state.distribute_rewards(ctx.block_production_time as u64);
+
+    let stream = state.reward_streams.get_mut(&msg.token).unwrap();
+    stream.funded = stream.funded.checked_add(msg.amount).unwrap();
+
+    vec![]
+}
+
+/// ## Description
+/// Claim earned rewards of the reward stream.
+/// Returns [`(MPC20StakingContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC20StakingContractState`]
+///
+/// * **msg** is an object of type [`ClaimStreamMsg`]
+pub fn execute_claim_stream(
+    ctx: &ContractContext,
+    state: &mut MPC20StakingContractState,
+    msg: &ClaimStreamMsg,
+) -> Vec<EventGroup> {
+    assert!(
+        state.reward_streams.contains_key(&msg.token),
+        "{}",
+        ContractError::RewardStreamNotFound
+    );
+
+    let staker = state.get_staker(&ctx.sender);
+
+    state.distribute_rewards(ctx.block_production_time as u64);
+    state.compute_stream_rewards(&ctx.sender, staker.staked_amount);
+
+    let stream_staker = state
+        .reward_streams
+        .get_mut(&msg.token)
+        .unwrap()
+        .stakers
+        .get_mut(&ctx.sender)
+        .unwrap();
+
+    assert!(
+        !stream_staker.pending_reward.is_zero(),
+        "{}",
+        ContractError::NothingToClaim
+    );
+
+    let claim_amount = if let Some(amount) = msg.amount {
+        assert!(
+            amount <= stream_staker.pending_reward && !amount.is_zero(),
+            "{}",
+            ContractError::CannotClaimMoreThenRewarded
+        );
+        amount
+    } else {
+        stream_staker.pending_reward
+    };
+
+    stream_staker.pending_reward = stream_staker
+        .pending_reward
+        .checked_sub(claim_amount)
+        .unwrap();
+
+    let mut event_group = EventGroup::builder();
+    Mpc20TransferMsg {
+        to: ctx.sender,
+        amount: claim_amount,
+    }
+    .as_interaction(&mut event_group, &msg.token);
+    build_msg_callback(
+        &mut event_group,
+        ON_CLAIM_STREAM_CALLBACK,
+        &ClaimStreamCallbackMsg {
+            staker: ctx.sender,
+            token: msg.token,
+            amount: claim_amount,
+        },
+    );
+
+    vec![event_group.build()]
+}
+
+/// ## Description
+/// Handles result of the reward stream payout and restores pending reward on failure.
+/// Returns [`(MPC20StakingContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **callback_ctx** is an object of type [`CallbackContext`]
+///
+/// * **state** is an object of type [`MPC20StakingContractState`]
+///
+/// * **msg** is an object of type [`ClaimStreamCallbackMsg`]
+pub fn execute_on_claim_stream(
+    ctx: &ContractContext,
+    callback_ctx: &CallbackContext,
+    state: &mut MPC20StakingContractState,
+    msg: &ClaimStreamCallbackMsg,
+) -> Vec<EventGroup> {
+    if callback_ctx.success {
+        return vec![];
+    }
+
+    let stream_staker = state
+        .reward_streams
+        .get_mut(&msg.token)
+        .unwrap()
+        .stakers
+        .get_mut(&msg.staker)
+        .unwrap();
+    stream_staker.pending_reward = stream_staker
+        .pending_reward
+        .checked_add(msg.amount)
+        .unwrap();
+
+    vec![]
+}
+
+/// ## Description
+/// Sets unbonding period and instant unstake penalty. Can only be executed by owner account.
+/// New unbonding period only applies to following unstakes.
+/// Returns [`(MPC20StakingContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC20StakingContractState`]
+///
+/// * **msg** is an object of type [`SetUnbondingMsg`]
+pub fn execute_set_unbonding(
+    ctx: &ContractContext,
+    state: &mut MPC20StakingContractState,
+    msg: &SetUnbondingMsg,
+) -> Vec<EventGroup> {
+    assert!(
+        state.is_owner(&ctx.sender),
+        "{}",
+        ContractError::Unauthorized
+    );
+    assert!(
+        msg.instant_unstake_penalty
+            .iter()
+            .all(|penalty| penalty.basis_points <= MAX_BASIS_POINTS),
+        "{}",
+        ContractError::InvalidBasisPoints
+    );
+
+    state.unbonding_period = msg.unbonding_period;
+    state.instant_unstake_penalty = msg.instant_unstake_penalty.clone();
 
     vec![]
 }
+
+/// ## Description
+/// Withdraw staked tokens without unbonding period. Penalty is sent to the treasury.
+/// Stake is restored if transfer of the deposit token fails.
+/// Returns [`(MPC20StakingContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC20StakingContractState`]
+///
+/// * **msg** is an object of type [`InstantUnstakeMsg`]
+pub fn execute_instant_unstake(
+    ctx: &ContractContext,
+    state: &mut MPC20StakingContractState,
+    msg: &InstantUnstakeMsg,
+) -> Vec<EventGroup> {
+    let penalty = state
+        .instant_unstake_penalty
+        .clone()
+        .unwrap_or_else(|| panic!("{}", ContractError::InstantUnstakeDisabled));
+
+    debit_stake(ctx, state, msg.amount);
+
+    let penalty_amount = penalty.penalty_of(msg.amount);
+
+    let mut event_group = EventGroup::builder();
+    Mpc20TransferMsg {
+        to: ctx.sender,
+        amount: msg.amount.checked_sub(penalty_amount).unwrap(),
+    }
+    .as_interaction(&mut event_group, &state.deposit_token);
+    build_msg_callback(
+        &mut event_group,
+        ON_INSTANT_UNSTAKE_CALLBACK,
+        &InstantUnstakeCallbackMsg {
+            staker: ctx.sender,
+            amount: msg.amount,
+            penalty: penalty_amount,
+            treasury: penalty.treasury,
+        },
+    );
+
+    vec![event_group.build()]
+}
+
+/// ## Description
+/// Handles result of the instant unstake transfer.
+/// Sends penalty to the treasury on success and restores the stake on failure.
+/// Returns [`(MPC20StakingContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **callback_ctx** is an object of type [`CallbackContext`]
+///
+/// * **state** is an object of type [`MPC20StakingContractState`]
+///
+/// * **msg** is an object of type [`InstantUnstakeCallbackMsg`]
+pub fn execute_on_instant_unstake(
+    ctx: &ContractContext,
+    callback_ctx: &CallbackContext,
+    state: &mut MPC20StakingContractState,
+    msg: &InstantUnstakeCallbackMsg,
+) -> Vec<EventGroup> {
+    if !callback_ctx.success {
+        credit_stake(ctx, state, &msg.staker, msg.amount);
+        return vec![];
+    }
+
+    if msg.penalty.is_zero() {
+        return vec![];
+    }
+
+    let mut event_group = EventGroup::builder();
+    send_penalty(
+        &mut event_group,
+        &state.deposit_token,
+        &PenaltyCallbackMsg {
+            treasury: msg.treasury,
+            amount: msg.penalty,
+        },
+    );
+
+    vec![event_group.build()]
+}
+
+/// ## Description
+/// Send penalties which previously failed to be sent to the treasury.
+/// Returns [`(MPC20StakingContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **_ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC20StakingContractState`]
+///
+/// * **msg** is an object of type [`PayPenaltiesMsg`]
+pub fn execute_pay_penalties(
+    _ctx: &ContractContext,
+    state: &mut MPC20StakingContractState,
+    msg: &PayPenaltiesMsg,
+) -> Vec<EventGroup> {
+    let amount = state
+        .pending_penalties
+        .remove(&msg.treasury)
+        .unwrap_or_else(|| panic!("{}", ContractError::NothingToPay));
+
+    let mut event_group = EventGroup::builder();
+    send_penalty(
+        &mut event_group,
+        &state.deposit_token,
+        &PenaltyCallbackMsg {
+            treasury: msg.treasury,
+            amount,
+        },
+    );
+
+    vec![event_group.build()]
+}
+
+/// ## Description
+/// Handles result of the penalty transfer and records penalty as pending on failure.
+/// Returns [`(MPC20StakingContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **callback_ctx** is an object of type [`CallbackContext`]
+///
+/// * **state** is an object of type [`MPC20StakingContractState`]
+///
+/// * **msg** is an object of type [`PenaltyCallbackMsg`]
+pub fn execute_on_penalty(
+    ctx: &ContractContext,
+    callback_ctx: &CallbackContext,
+    state: &mut MPC20StakingContractState,
+    msg: &PenaltyCallbackMsg,
+) -> Vec<EventGroup> {
+    if !callback_ctx.success {
+        state.add_pending_penalty(&msg.treasury, msg.amount);
+    }
+
+    vec![]
+}
+
+/// ## Description
+/// Withdraw every unstaked amount which unbonding period is over.
+/// Amount is returned to the queue if transfer of the deposit token fails.
+/// Returns [`(MPC20StakingContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC20StakingContractState`]
+///
+/// * **msg** is an object of type [`WithdrawMsg`]
+pub fn execute_withdraw(
+    ctx: &ContractContext,
+    state: &mut MPC20StakingContractState,
+    msg: &WithdrawMsg,
+) -> Vec<EventGroup> {
+    let amount = state.take_matured_withdrawals(&ctx.sender, ctx.block_production_time as u64);
+    assert!(!amount.is_zero(), "{}", ContractError::NothingToWithdraw);
+
+    let mut event_group = EventGroup::builder();
+    Mpc20TransferMsg {
+        to: ctx.sender,
+        amount,
+    }
+    .as_interaction(&mut event_group, &state.deposit_token);
+    build_msg_callback(
+        &mut event_group,
+        ON_WITHDRAW_CALLBACK,
+        &WithdrawCallbackMsg {
+            staker: ctx.sender,
+            amount,
+        },
+    );
+
+    vec![event_group.build()]
+}
+
+/// ## Description
+/// Handles result of the withdraw transfer and returns amount to the queue on failure.
+/// Returns [`(MPC20StakingContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **callback_ctx** is an object of type [`CallbackContext`]
+///
+/// * **state** is an object of type [`MPC20StakingContractState`]
+///
+/// * **msg** is an object of type [`WithdrawCallbackMsg`]
+pub fn execute_on_withdraw(
+    ctx: &ContractContext,
+    callback_ctx: &CallbackContext,
+    state: &mut MPC20StakingContractState,
+    msg: &WithdrawCallbackMsg,
+) -> Vec<EventGroup> {
+    if callback_ctx.success {
+        return vec![];
+    }
+
+    state.queue_withdrawal(&msg.staker, msg.amount, ctx.block_production_time as u64);
+
+    vec![]
+}
+
+/// ## Description
+/// Deposits reward budget to the funded pool and sets program end time. Can only be executed by owner account.
+/// Deposit is recorded once the tokens are received.
+/// Returns [`(MPC20StakingContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC20StakingContractState`]
+///
+/// * **msg** is an object of type [`FundRewardProgramMsg`]
+pub fn execute_fund_reward_program(
+    ctx: &ContractContext,
+    state: &mut MPC20StakingContractState,
+    msg: &FundRewardProgramMsg,
+) -> Vec<EventGroup> {
+    assert!(
+        state.is_owner(&ctx.sender),
+        "{}",
+        ContractError::Unauthorized
+    );
+
+    let program = state
+        .reward_program
+        .as_ref()
+        .unwrap_or_else(|| panic!("{}", ContractError::RewardProgramDisabled));
+
+    assert!(
+        msg.end_time > ctx.block_production_time as u64,
+        "{}",
+        ContractError::InvalidRewardProgram
+    );
+    assert!(
+        !msg.amount.is_zero(),
+        "{}",
+        ContractError::InvalidFundAmount
+    );
+
+    let mut event_group = EventGroup::builder();
+    Mpc20TransferFromMsg {
+        from: ctx.sender,
+        to: ctx.contract_address,
+        amount: msg.amount,
+    }
+    .as_interaction(&mut event_group, &program.token);
+    build_msg_callback(
+        &mut event_group,
+        ON_FUND_PROGRAM_CALLBACK,
+        &FundProgramCallbackMsg {
+            amount: msg.amount,
+            end_time: msg.end_time,
+        },
+    );
+
+    vec![event_group.build()]
+}
+
+/// ## Description
+/// Handles result of the reward program deposit and extends the program on success.
+/// End time is never moved backwards, and time which passed after the end is not distributed.
+/// Returns [`(MPC20StakingContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **callback_ctx** is an object of type [`CallbackContext`]
+///
+/// * **state** is an object of type [`MPC20StakingContractState`]
+///
+/// * **msg** is an object of type [`FundProgramCallbackMsg`]
+pub fn execute_on_fund_program(
+    ctx: &ContractContext,
+    callback_ctx: &CallbackContext,
+    state: &mut MPC20StakingContractState,
+    msg: &FundProgramCallbackMsg,
+) -> Vec<EventGroup> {
+    if !callback_ctx.success {
+        return vec![];
+    }
+
+    let block_time = ctx.block_production_time as u64;
+    state.distribute_rewards(block_time);
+
+    let program = state.reward_program.as_mut().unwrap();
+    if program.end_time <= block_time {
+        // ended program must not distribute for the time it was inactive
+        state.last_distributed = state.last_distributed.max(block_time);
+    }
+    program.budget = program.budget.checked_add(msg.amount).unwrap();
+    program.end_time = program.end_time.max(msg.end_time);
+
+    vec![]
+}
+
+/// ## Description
+/// Withdraw budget of the ended reward program which was not distributed. Can only be executed by owner account.
+/// Returns [`(MPC20StakingContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC20StakingContractState`]
+///
+/// * **_msg** is an object of type [`WithdrawRewardProgramMsg`]
+pub fn execute_withdraw_reward_program(
+    ctx: &ContractContext,
+    state: &mut MPC20StakingContractState,
+    _msg: &WithdrawRewardProgramMsg,
+) -> Vec<EventGroup> {
+    assert!(
+        state.is_owner(&ctx.sender),
+        "{}",
+        ContractError::Unauthorized
+    );
+    assert!(
+        state.reward_program.is_some(),
+        "{}",
+        ContractError::RewardProgramDisabled
+    );
+
+    let block_time = ctx.block_production_time as u64;
+    state.distribute_rewards(block_time);
+
+    let program = state.reward_program.as_mut().unwrap();
+    assert!(
+        program.end_time <= block_time,
+        "{}",
+        ContractError::RewardProgramNotEnded
+    );
+
+    let amount = program.budget - program.distributed;
+    assert!(!amount.is_zero(), "{}", ContractError::NothingToWithdraw);
+    program.budget = program.distributed;
+
+    let mut event_group = EventGroup::builder();
+    Mpc20TransferMsg {
+        to: ctx.sender,
+        amount,
+    }
+    .as_interaction(&mut event_group, &program.token);
+    build_msg_callback(
+        &mut event_group,
+        ON_WITHDRAW_PROGRAM_CALLBACK,
+        &WithdrawProgramCallbackMsg { amount },
+    );
+
+    vec![event_group.build()]
+}
+
+/// ## Description
+/// Handles result of the reward program withdrawal and restores the budget on failure.
+/// Returns [`(MPC20StakingContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **callback_ctx** is an object of type [`CallbackContext`]
+///
+/// * **state** is an object of type [`MPC20StakingContractState`]
+///
+/// * **msg** is an object of type [`WithdrawProgramCallbackMsg`]
+pub fn execute_on_withdraw_program(
+    _ctx: &ContractContext,
+    callback_ctx: &CallbackContext,
+    state: &mut MPC20StakingContractState,
+    msg: &WithdrawProgramCallbackMsg,
+) -> Vec<EventGroup> {
+    if callback_ctx.success {
+        return vec![];
+    }
+
+    let program = state.reward_program.as_mut().unwrap();
+    program.budget = program.budget.checked_add(msg.amount).unwrap();
+
+    vec![]
+}
+
+/// ## Description
+/// Sets per epoch distribution amount. Can only be executed by owner account.
+/// Rewards are distributed with previous amount before the change.
+/// Returns [`(MPC20StakingContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC20StakingContractState`]
+///
+/// * **msg** is an object of type [`SetDistributionAmountMsg`]
+pub fn execute_set_distribution_amount(
+    ctx: &ContractContext,
+    state: &mut MPC20StakingContractState,
+    msg: &SetDistributionAmountMsg,
+) -> Vec<EventGroup> {
+    assert!(
+        state.is_owner(&ctx.sender),
+        "{}",
+        ContractError::Unauthorized
+    );
+    assert!(
+        msg.distribution_amount > 0,
+        "{}",
+        ContractError::InvalidDistributionAmount
+    );
+
+    state.distribute_rewards(ctx.block_production_time as u64);
+    state.distribution_amount = msg.distribution_amount;
+
+    vec![]
+}
+
+fn send_penalty(
+    event_group: &mut EventGroupBuilder,
+    deposit_token: &Address,
+    penalty: &PenaltyCallbackMsg,
+) {
+    Mpc20TransferMsg {
+        to: penalty.treasury,
+        amount: penalty.amount,
+    }
+    .as_interaction(event_group, deposit_token);
+    build_msg_callback(event_group, ON_PENALTY_CALLBACK, penalty);
+}
+
+fn credit_stake(
+    ctx: &ContractContext,
+    state: &mut MPC20StakingContractState,
+    address: &Address,
+    amount: u128,
+) {
+    let mut staker = state.get_staker(address);
+
+    state.distribute_rewards(ctx.block_production_time as u64);
+    state.compute_stream_rewards(address, staker.staked_amount);
+    staker.compute_reward(state.global_index);
+    state.increase_stake_amount(address, &mut staker, amount);
+}
+
+fn debit_stake(ctx: &ContractContext, state: &mut MPC20StakingContractState, amount: u128) {
+    let mut staker = state.get_staker(&ctx.sender);
+
+    assert!(
+        staker.staked_amount >= amount,
+        "{}",
+        ContractError::CannotUnstakeMoreThenStaked,
+    );
+
+    state.distribute_rewards(ctx.block_production_time as u64);
+    state.compute_stream_rewards(&ctx.sender, staker.staked_amount);
+    staker.compute_reward(state.global_index);
+    state.decrease_stake_amount(&ctx.sender, &mut staker, amount);
+}
@@ -21,4 +21,43 @@ pub enum ContractError {
 
     #[error("Forbidden to compound to often")]
     ForbiddenToCompoundToOften,
+
+    #[error("Unauthorized")]
+    Unauthorized,
+
+    #[error("Reward stream already exists")]
+    RewardStreamExists,
+
+    #[error("Reward stream not found")]
+    RewardStreamNotFound,
+
+    #[error("Invalid reward stream")]
+    InvalidRewardStream,
+
+    #[error("Nothing to withdraw")]
+    NothingToWithdraw,
+
+    #[error("Instant unstake is disabled")]
+    InstantUnstakeDisabled,
+
+    #[error("Nothing to pay")]
+    NothingToPay,
+
+    #[error("Invalid basis points")]
+    InvalidBasisPoints,
+
+    #[error("Reward program is disabled")]
+    RewardProgramDisabled,
+
+    #[error("Invalid reward program")]
+    InvalidRewardProgram,
+
+    #[error("Distribution amount must be higher then 0")]
+    InvalidDistributionAmount,
+
+    #[error("Fund amount must be higher then 0")]
+    InvalidFundAmount,
+
+    #[error("Reward program has not ended")]
+    RewardProgramNotEnded,
 }
@@ -3,6 +3,8 @@ use pbc_contract_common::address::{Address, Shortname};
 use read_write_rpc_derive::ReadWriteRPC;
 
 use mpc20_base::{msg::InitialBalance, state::TokenInfo};
+
+use crate::state::UnstakePenalty;
 use rpc_msg_derive::IntoShortnameRPCEvent;
 use utils::events::IntoShortnameRPCEvent;
 
@@ -12,12 +14,16 @@ use utils::events::IntoShortnameRPCEvent;
 pub struct Mpc20StakingInitMsg {
     /// deposit token address, if None then deposit token will contract address
     pub deposit_token: Option<Address>,
+    /// reward token address, if None rewards are minted, otherwise transferred from the funded pool
+    pub reward_token: Option<Address>,
     /// per epoch distribution amount
     pub distribution_amount: u128,
     /// UTC timestamp
     pub distribution_epoch: u64,
     /// compounding limit
     pub compound_frequency: u64,
+    /// unbonding period in seconds, 0 means tokens are released immediately
+    pub unbonding_period: u64,
     /// mpc20 base token info
     pub info: TokenInfo,
     /// mpc20 base initial balances
@@ -74,3 +80,218 @@ pub struct CompoundMsg {
     /// optional amount to claim, if None everything will be compounded
     pub amount: Option<u128>,
 }
+
+/// ## Description
+/// This structure describes fields for mpc20-staking add reward stream msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x25)]
+pub struct AddRewardStreamMsg {
+    /// reward token address
+    pub token: Address,
+    /// per epoch distribution amount
+    pub distribution_amount: u128,
+    /// UTC timestamp
+    pub distribution_epoch: u64,
+    /// UTC timestamp after which nothing is distributed
+    pub end_time: u64,
+}
+
+/// ## Description
+/// This structure describes fields for mpc20-staking fund reward stream msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x27)]
+pub struct FundRewardStreamMsg {
+    /// reward token address
+    pub token: Address,
+    /// amount of reward tokens to transfer
+    pub amount: u128,
+}
+
+/// ## Description
+/// This structure describes fields for mpc20-staking claim reward stream msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x29)]
+pub struct ClaimStreamMsg {
+    /// reward token address
+    pub token: Address,
+    /// optional amount to claim, if None everything will be claimed
+    pub amount: Option<u128>,
+}
+
+/// ## Description
+/// This structure describes fields for mpc20-staking set unbonding msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x2B)]
+pub struct SetUnbondingMsg {
+    /// unbonding period in seconds, 0 means tokens are released immediately
+    pub unbonding_period: u64,
+    /// optional penalty for unstaking without unbonding period, if None instant unstake is disabled
+    pub instant_unstake_penalty: Option<UnstakePenalty>,
+}
+
+/// ## Description
+/// This structure describes fields for mpc20-staking instant unstake msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x2D)]
+pub struct InstantUnstakeMsg {
+    /// amount to unstake
+    pub amount: u128,
+}
+
+/// ## Description
+/// This structure describes fields for mpc20-staking withdraw msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x2F)]
+pub struct WithdrawMsg {}
+
+/// ## Description
+/// This structure describes fields for mpc20-staking pay penalties msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x55)]
+pub struct PayPenaltiesMsg {
+    /// penalty receiver address
+    pub treasury: Address,
+}
+
+/// ## Description
+/// This structure describes fields for mpc20-staking fund reward program msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x51)]
+pub struct FundRewardProgramMsg {
+    /// amount of reward tokens to deposit
+    pub amount: u128,
+    /// UTC timestamp after which nothing is distributed
+    pub end_time: u64,
+}
+
+/// ## Description
+/// This structure describes fields for mpc20-staking withdraw reward program msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x57)]
+pub struct WithdrawRewardProgramMsg {}
+
+/// ## Description
+/// This structure describes fields for mpc20-staking set distribution amount msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x53)]
+pub struct SetDistributionAmountMsg {
+    /// per epoch distribution amount
+    pub distribution_amount: u128,
+}
+
+/// ## Description
+/// This structure describes a stake which is passed to the deposit transfer callback
+#[derive(ReadWriteRPC, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct StakeCallbackMsg {
+    /// staker address
+    pub staker: Address,
+    /// staked amount
+    pub amount: u128,
+}
+
+/// ## Description
+/// This structure describes an unstake which is passed to the withdraw transfer callback
+#[derive(ReadWriteRPC, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct UnstakeCallbackMsg {
+    /// staker address
+    pub staker: Address,
+    /// unstaked amount
+    pub amount: u128,
+}
+
+/// ## Description
+/// This structure describes a funding which is passed to the reward stream transfer callback
+#[derive(ReadWriteRPC, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct FundStreamCallbackMsg {
+    /// reward token address
+    pub token: Address,
+    /// funded amount
+    pub amount: u128,
+}
+
+/// ## Description
+/// This structure describes a reward stream payout which is passed to the transfer callback
+#[derive(ReadWriteRPC, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct ClaimStreamCallbackMsg {
+    /// staker address
+    pub staker: Address,
+    /// reward token address
+    pub token: Address,
+    /// claimed amount
+    pub amount: u128,
+}
+
+/// ## Description
+/// This structure describes a withdrawal which is passed to the withdraw transfer callback
+#[derive(ReadWriteRPC, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct WithdrawCallbackMsg {
+    /// staker address
+    pub staker: Address,
+    /// withdrawn amount
+    pub amount: u128,
+}
+
+/// ## Description
+/// This structure describes an instant unstake which is passed to the transfer callback
+#[derive(ReadWriteRPC, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct InstantUnstakeCallbackMsg {
+    /// staker address
+    pub staker: Address,
+    /// unstaked amount including penalty
+    pub amount: u128,
+    /// penalty amount
+    pub penalty: u128,
+    /// penalty receiver address
+    pub treasury: Address,
+}
+
+/// ## Description
+/// This structure describes a penalty payout which is passed to the treasury transfer callback
+#[derive(ReadWriteRPC, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct PenaltyCallbackMsg {
+    /// penalty receiver address
+    pub treasury: Address,
+    /// penalty amount
+    pub amount: u128,
+}
+
+/// ## Description
+/// This structure describes a deposit which is passed to the reward program transfer callback
+#[derive(ReadWriteRPC, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct FundProgramCallbackMsg {
+    /// deposited amount
+    pub amount: u128,
+    /// UTC timestamp after which nothing is distributed
+    pub end_time: u64,
+}
+
+/// ## Description
+/// This structure describes a withdrawal which is passed to the reward program transfer callback
+#[derive(ReadWriteRPC, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct WithdrawProgramCallbackMsg {
+    /// withdrawn amount
+    pub amount: u128,
+}
+
+/// ## Description
+/// This structure describes a reward payout which is passed to the transfer callback
+#[derive(ReadWriteRPC, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct ClaimCallbackMsg {
+    /// staker address
+    pub staker: Address,
+    /// claimed amount
+    pub amount: u128,
+}
+
+/// ## Description
+/// Callback shortnames used during staking, unstaking, withdrawals and reward transfers
+pub const ON_STAKE_CALLBACK: u32 = 0x31;
+pub const ON_UNSTAKE_CALLBACK: u32 = 0x33;
+pub const ON_FUND_STREAM_CALLBACK: u32 = 0x35;
+pub const ON_CLAIM_STREAM_CALLBACK: u32 = 0x37;
+pub const ON_WITHDRAW_CALLBACK: u32 = 0x39;
+pub const ON_INSTANT_UNSTAKE_CALLBACK: u32 = 0x3B;
+pub const ON_FUND_PROGRAM_CALLBACK: u32 = 0x3D;
+pub const ON_CLAIM_CALLBACK: u32 = 0x3F;
+pub const ON_PENALTY_CALLBACK: u32 = 0x41;
+pub const ON_WITHDRAW_PROGRAM_CALLBACK: u32 = 0x43;
@@ -19,6 +19,8 @@ pub struct MPC20StakingContractState {
     pub distribution_amount: u128,
     /// UTC timestamp
     pub distribution_epoch: u64,
+    /// funded reward program, if None rewards are minted
+    pub reward_program: Option<RewardProgram>,
 
     /// global index for calculating users share
     pub global_index: DecimalRatio,
@@ -31,32 +33,147 @@ pub struct MPC20StakingContractState {
     pub stakers: BTreeMap<Address, Staker>,
     /// compounding limit
     pub compound_frequency: u64,
+    /// owner address
+    pub owner: Address,
+    /// additional reward streams by reward token address
+    pub reward_streams: BTreeMap<Address, RewardStream>,
+    /// unbonding period in seconds, 0 means tokens are released immediately
+    pub unbonding_period: u64,
+    /// optional penalty for unstaking without unbonding period
+    pub instant_unstake_penalty: Option<UnstakePenalty>,
+    /// pending withdrawals by staker
+    pub withdrawals: BTreeMap<Address, Vec<PendingWithdrawal>>,
+    /// instant unstake penalties which failed to be sent, by treasury address
+    pub pending_penalties: BTreeMap<Address, u128>,
     /// mpc20 base state
     pub mpc20: MPC20ContractState,
 }
 
 impl MPC20StakingContractState {
     /// ## Description
-    /// Distributes rewards by recalculting global index
+    /// Distributes rewards by recalculting global index of main reward and every reward stream.
+    /// Funded main reward is limited by program end time and budget
     /// ## Params
     /// * **block_time** is an object of type [`u64`]
     pub fn distribute_rewards(&mut self, block_time: u64) {
+        for stream in self.reward_streams.values_mut() {
+            stream.distribute(block_time, self.total_staked);
+        }
+
         if self.total_staked.is_zero() {
             self.last_distributed = block_time;
             return;
         }
 
+        let block_time = match &self.reward_program {
+            Some(program) => block_time.min(program.end_time),
+            None => block_time,
+        };
+        if block_time <= self.last_distributed {
+            return;
+        }
+
         let passed_distributions = (block_time - self.last_distributed) / self.distribution_epoch;
         if passed_distributions.is_zero() {
             return;
         }
 
-        let distributed_amount = self.distribution_amount * (passed_distributions as u128);
-        self.global_index =
-            self.global_index + DecimalRatio::from_ratio(distributed_amount, self.total_staked);
+        let mut distributed_amount = self.distribution_amount * (passed_distributions as u128);
+        if let Some(program) = self.reward_program.as_mut() {
+            distributed_amount = distributed_amount.min(program.budget - program.distributed);
+            program.distributed += distributed_amount;
+        }
+
+        if !distributed_amount.is_zero() {
+            self.global_index =
+                self.global_index + DecimalRatio::from_ratio(distributed_amount, self.total_staked);
+        }
         self.last_distributed += self.distribution_epoch * passed_distributions;
     }
 
+    /// ## Description
+    /// Says are rewards minted by this contract or transferred from the funded pool
+    pub fn mints_rewards(&self) -> bool {
+        self.reward_program.is_none()
+    }
+
+    /// ## Description
+    /// Computes staker rewards of every reward stream
+    /// ## Params
+    /// * **address** is an object of type [`Address`]
+    ///
+    /// * **staked_amount** is a field of type [`u128`]
+    pub fn compute_stream_rewards(&mut self, address: &Address, staked_amount: u128) {
+        for stream in self.reward_streams.values_mut() {
+            stream.compute_reward(address, staked_amount);
+        }
+    }
+
+    /// ## Description
+    /// Adds amount to the staker withdrawal queue
+    /// ## Params
+    /// * **address** is an object of type [`Address`]
+    ///
+    /// * **amount** is a field of type [`u128`]
+    ///
+    /// * **release_time** is a field of type [`u64`]
+    pub fn queue_withdrawal(&mut self, address: &Address, amount: u128, release_time: u64) {
+        self.withdrawals
+            .entry(*address)
+            .or_default()
+            .push(PendingWithdrawal {
+                amount,
+                release_time,
+            });
+    }
+
+    /// ## Description
+    /// Removes matured withdrawals from the staker queue and returns their total amount
+    /// ## Params
+    /// * **address** is an object of type [`Address`]
+    ///
+    /// * **block_time** is a field of type [`u64`]
+    pub fn take_matured_withdrawals(&mut self, address: &Address, block_time: u64) -> u128 {
+        let queue = match self.withdrawals.get_mut(address) {
+            Some(queue) => queue,
+            None => return 0,
+        };
+
+        let mut amount = 0u128;
+        queue.retain(|withdrawal| {
+            if withdrawal.release_time <= block_time {
+                amount = amount.checked_add(withdrawal.amount).unwrap();
+                return false;
+            }
+            true
+        });
+
+        if queue.is_empty() {
+            self.withdrawals.remove(address);
+        }
+
+        amount
+    }
+
+    /// ## Description
+    /// Records penalty which failed to be sent to the treasury
+    /// ## Params
+    /// * **treasury** is an object of type [`Address`]
+    ///
+    /// * **amount** is a field of type [`u128`]
+    pub fn add_pending_penalty(&mut self, treasury: &Address, amount: u128) {
+        let pending = self.pending_penalties.entry(*treasury).or_insert(0);
+        *pending = pending.checked_add(amount).unwrap();
+    }
+
+    /// ## Description
+    /// Checks that address is owner or not
+    /// ## Params
+    /// * **address** is an object of type [`Address`]
+    pub fn is_owner(&self, address: &Address) -> bool {
+        self.owner == *address
+    }
+
     /// ## Description
     /// Increases total staked amount and staked amount by staker
     /// ## Params
@@ -150,3 +267,154 @@ impl Staker {
             .unwrap();
     }
 }
+
+/// ## Description
+/// This structure describes funded program of the main reward
+#[derive(ReadWriteState, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct RewardProgram {
+    /// reward token address
+    pub token: Address,
+    /// total amount of deposited reward tokens
+    pub budget: u128,
+    /// total amount of distributed reward tokens
+    pub distributed: u128,
+    /// UTC timestamp after which nothing is distributed
+    pub end_time: u64,
+}
+
+/// ## Description
+/// This structure describes additional reward stream paid in external mpc20 token
+#[derive(ReadWriteState, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct RewardStream {
+    /// per epoch distribution amount
+    pub distribution_amount: u128,
+    /// UTC timestamp
+    pub distribution_epoch: u64,
+    /// UTC timestamp after which nothing is distributed
+    pub end_time: u64,
+    /// global index for calculating users share
+    pub global_index: DecimalRatio,
+    /// UTC timestamp of last distribution
+    pub last_distributed: u64,
+    /// total amount of funded reward tokens
+    pub funded: u128,
+    /// total amount of distributed reward tokens
+    pub distributed: u128,
+    /// information about stakers rewards
+    pub stakers: BTreeMap<Address, StreamStaker>,
+}
+
+impl RewardStream {
+    /// ## Description
+    /// Distributes rewards by recalculting global index.
+    /// Distribution is limited by stream end time and funded amount
+    /// ## Params
+    /// * **block_time** is an object of type [`u64`]
+    ///
+    /// * **total_staked** is a field of type [`u128`]
+    pub fn distribute(&mut self, block_time: u64, total_staked: u128) {
+        let block_time = block_time.min(self.end_time);
+        if block_time <= self.last_distributed {
+            return;
+        }
+
+        if total_staked.is_zero() {
+            self.last_distributed = block_time;
+            return;
+        }
+
+        let passed_distributions = (block_time - self.last_distributed) / self.distribution_epoch;
+        if passed_distributions.is_zero() {
+            return;
+        }
+
+        let distributed_amount = (self.distribution_amount * (passed_distributions as u128))
+            .min(self.funded - self.distributed);
+        if !distributed_amount.is_zero() {
+            self.global_index =
+                self.global_index + DecimalRatio::from_ratio(distributed_amount, total_staked);
+            self.distributed += distributed_amount;
+        }
+        self.last_distributed += self.distribution_epoch * passed_distributions;
+    }
+
+    /// ## Description
+    /// Computes current staker reward
+    /// ## Params
+    /// * **address** is an object of type [`Address`]
+    ///
+    /// * **staked_amount** is a field of type [`u128`]
+    pub fn compute_reward(&mut self, address: &Address, staked_amount: u128) {
+        let global_index = self.global_index;
+        let staker = self.stakers.entry(*address).or_insert(StreamStaker {
+            reward_index: DecimalRatio::zero(),
+            pending_reward: 0,
+        });
+
+        let staked_amount = DecimalRatio::new(staked_amount, 0);
+        let pending_reward = (staked_amount * global_index) - (staked_amount * staker.reward_index);
+
+        staker.reward_index = global_index;
+        staker.pending_reward = staker
+            .pending_reward
+            .checked_add(pending_reward.to_u128())
+            .unwrap();
+    }
+
+    /// ## Description
+    /// Returns pending reward of the staker
+    /// ## Params
+    /// * **address** is an object of type [`Address`]
+    pub fn pending_reward(&self, address: &Address) -> u128 {
+        self.stakers
+            .get(address)
+            .map(|s| s.pending_reward)
+            .unwrap_or_default()
+    }
+}
+
+/// ## Description
+/// This structure describes information about staker reward in the reward stream
+#[derive(ReadWriteState, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct StreamStaker {
+    /// personal reward index
+    pub reward_index: DecimalRatio,
+    /// pending rewards
+    pub pending_reward: u128,
+}
+
+/// ## Description
+/// This structure describes amount waiting for the end of unbonding period
+#[derive(ReadWriteState, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct PendingWithdrawal {
+    /// unstaked amount
+    pub amount: u128,
+    /// UTC timestamp after which amount can be withdrawn
+    pub release_time: u64,
+}
+
+/// ## Description
+/// This structure describes penalty for unstaking without unbonding period
+#[derive(ReadWriteRPC, ReadWriteState, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct UnstakePenalty {
+    /// penalty in basis points
+    pub basis_points: u16,
+    /// penalty receiver address
+    pub treasury: Address,
+}
+
+pub const MAX_BASIS_POINTS: u16 = 10_000;
+
+impl UnstakePenalty {
+    /// ## Description
+    /// Returns penalty amount of the unstaked amount
+    /// ## Params
+    /// * **amount** is a field of type [`u128`]
+    pub fn penalty_of(&self, amount: u128) -> u128 {
+        amount
+            .checked_mul(self.basis_points as u128)
+            .unwrap()
+            .checked_div(MAX_BASIS_POINTS as u128)
+            .unwrap()
+    }
+}
@@ -5,16 +5,41 @@ use mpc20_base::{
     state::{MPC20ContractState, Minter, TokenInfo},
 };
 use pbc_contract_common::{
-    address::{Address, AddressType, Shortname},
-    context::ContractContext,
+    address::{Address, AddressType},
+    context::{CallbackContext, ContractContext},
     events::EventGroup,
 };
-use utils::{decimal::DecimalRatio, events::IntoShortnameRPCEvent};
+use utils::{
+    decimal::DecimalRatio,
+    events::{build_msg_callback, IntoShortnameRPCEvent},
+};
 
 use crate::{
-    actions::{execute_claim, execute_compound, execute_init, execute_stake, execute_unstake},
-    msg::{ClaimMsg, CompoundMsg, Mpc20StakingInitMsg, StakeMsg, UnstakeMsg},
-    state::{MPC20StakingContractState, Staker},
+    actions::{
+        execute_add_reward_stream, execute_claim, execute_claim_stream, execute_compound,
+        execute_fund_reward_program, execute_fund_reward_stream, execute_init,
+        execute_instant_unstake, execute_on_claim, execute_on_claim_stream,
+        execute_on_fund_program, execute_on_fund_stream, execute_on_instant_unstake,
+        execute_on_penalty, execute_on_stake, execute_on_unstake, execute_on_withdraw,
+        execute_on_withdraw_program, execute_pay_penalties, execute_set_distribution_amount,
+        execute_set_unbonding, execute_stake, execute_unstake, execute_withdraw,
+        execute_withdraw_reward_program,
+    },
+    msg::{
+        AddRewardStreamMsg, ClaimCallbackMsg, ClaimMsg, ClaimStreamCallbackMsg, ClaimStreamMsg,
+        CompoundMsg, FundProgramCallbackMsg, FundRewardProgramMsg, FundRewardStreamMsg,
+        FundStreamCallbackMsg, InstantUnstakeCallbackMsg, InstantUnstakeMsg, Mpc20StakingInitMsg,
+        PayPenaltiesMsg, PenaltyCallbackMsg, SetDistributionAmountMsg, SetUnbondingMsg,
+        StakeCallbackMsg, StakeMsg, UnstakeCallbackMsg, UnstakeMsg, WithdrawCallbackMsg,
+        WithdrawMsg, WithdrawProgramCallbackMsg, WithdrawRewardProgramMsg, ON_CLAIM_CALLBACK,
+        ON_CLAIM_STREAM_CALLBACK, ON_FUND_PROGRAM_CALLBACK, ON_FUND_STREAM_CALLBACK,
+        ON_INSTANT_UNSTAKE_CALLBACK, ON_PENALTY_CALLBACK, ON_STAKE_CALLBACK, ON_UNSTAKE_CALLBACK,
+        ON_WITHDRAW_CALLBACK, ON_WITHDRAW_PROGRAM_CALLBACK,
+    },
+    state::{
+        MPC20StakingContractState, PendingWithdrawal, RewardProgram, RewardStream, Staker,
+        StreamStaker, UnstakePenalty,
+    },
 };
 
 fn mock_address(le: u8) -> Address {
@@ -44,6 +69,13 @@ fn mock_contract_context(sender: u8, block_time: i64) -> ContractContext {
     }
 }
 
+fn mock_callback_context(success: bool) -> CallbackContext {
+    CallbackContext {
+        success,
+        results: vec![],
+    }
+}
+
 #[test]
 fn test_staking() {
     const DEPOSIT_TOKEN: u8 = 1;
@@ -56,9 +88,11 @@ fn test_staking() {
 
     let msg = Mpc20StakingInitMsg {
         deposit_token: None,
+        reward_token: None,
         distribution_amount: 1_000,
         distribution_epoch: 10,
         compound_frequency: 100,
+        unbonding_period: 0,
         info: TokenInfo {
             name: "Staking Token".to_string(),
             symbol: "STKN".to_string(),
@@ -76,11 +110,18 @@ fn test_staking() {
             deposit_token: mock_address(DEPOSIT_TOKEN),
             distribution_amount: 1_000,
             distribution_epoch: 10,
+            reward_program: None,
             global_index: DecimalRatio::new(0, 0),
             total_staked: 0,
             last_distributed: 100,
             stakers: BTreeMap::new(),
             compound_frequency: 100,
+            owner: mock_address(MINTER),
+            reward_streams: BTreeMap::new(),
+            unbonding_period: 0,
+            instant_unstake_penalty: None,
+            withdrawals: BTreeMap::new(),
+            pending_penalties: BTreeMap::new(),
             mpc20: MPC20ContractState {
                 info: TokenInfo {
                     name: "Staking Token".to_string(),
@@ -110,23 +151,34 @@ fn test_staking() {
 
     assert_eq!(events.len(), 1);
 
-    let transfer_from_msg = TransferFromMsg {
+    let mut eg = EventGroup::builder();
+    TransferFromMsg {
         from: mock_address(ALICE),
         to: mock_address(1u8),
         amount: 100,
-    };
-
-    let mut eg = EventGroup::builder();
-    eg.call(
-        mock_address(DEPOSIT_TOKEN),
-        Shortname::from_u32(transfer_from_msg.action_shortname()),
-    )
-    .argument(mock_address(ALICE))
-    .argument(mock_address(1u8))
-    .argument(100u128)
-    .done();
+    }
+    .as_interaction(&mut eg, &mock_address(DEPOSIT_TOKEN));
+    build_msg_callback(
+        &mut eg,
+        ON_STAKE_CALLBACK,
+        &StakeCallbackMsg {
+            staker: mock_address(ALICE),
+            amount: 100,
+        },
+    );
 
     assert_eq!(events[0], eg.build());
+    assert_eq!(state.total_staked, 0);
+
+    let _ = execute_on_stake(
+        &mock_contract_context(DEPOSIT_TOKEN, block_production_time),
+        &mock_callback_context(true),
+        &mut state,
+        &StakeCallbackMsg {
+            staker: mock_address(ALICE),
+            amount: 100,
+        },
+    );
 
     assert_eq!(
         state,
@@ -134,6 +186,7 @@ fn test_staking() {
             deposit_token: mock_address(DEPOSIT_TOKEN),
             distribution_amount: 1_000,
             distribution_epoch: 10,
+            reward_program: None,
             global_index: DecimalRatio::new(0, 0),
             total_staked: 100,
             last_distributed: 105,
@@ -147,6 +200,12 @@ fn test_staking() {
                 }
             )]),
             compound_frequency: 100,
+            owner: mock_address(MINTER),
+            reward_streams: BTreeMap::new(),
+            unbonding_period: 0,
+            instant_unstake_penalty: None,
+            withdrawals: BTreeMap::new(),
+            pending_penalties: BTreeMap::new(),
             mpc20: MPC20ContractState {
                 info: TokenInfo {
                     name: "Staking Token".to_string(),
@@ -168,17 +227,27 @@ fn test_staking() {
     block_production_time = 114;
 
     let msg = StakeMsg { amount: 100 };
-    let events = execute_stake(
+    let _ = execute_stake(
         &mock_contract_context(BOB, block_production_time),
         &mut state,
         &msg,
     );
+    let _ = execute_on_stake(
+        &mock_contract_context(DEPOSIT_TOKEN, block_production_time),
+        &mock_callback_context(true),
+        &mut state,
+        &StakeCallbackMsg {
+            staker: mock_address(BOB),
+            amount: 100,
+        },
+    );
     assert_eq!(
         state,
         MPC20StakingContractState {
             deposit_token: mock_address(DEPOSIT_TOKEN),
             distribution_amount: 1_000,
             distribution_epoch: 10,
+            reward_program: None,
             global_index: DecimalRatio::new(0, 0),
             total_staked: 200,
             last_distributed: 105,
@@ -203,6 +272,12 @@ fn test_staking() {
                 )
             ]),
             compound_frequency: 100,
+            owner: mock_address(MINTER),
+            reward_streams: BTreeMap::new(),
+            unbonding_period: 0,
+            instant_unstake_penalty: None,
+            withdrawals: BTreeMap::new(),
+            pending_penalties: BTreeMap::new(),
             mpc20: MPC20ContractState {
                 info: TokenInfo {
                     name: "Staking Token".to_string(),
@@ -235,6 +310,7 @@ fn test_staking() {
             deposit_token: mock_address(DEPOSIT_TOKEN),
             distribution_amount: 1_000,
             distribution_epoch: 10,
+            reward_program: None,
             global_index: DecimalRatio::new(5, 0),
             total_staked: 200,
             last_distributed: 115,
@@ -259,6 +335,12 @@ fn test_staking() {
                 )
             ]),
             compound_frequency: 100,
+            owner: mock_address(MINTER),
+            reward_streams: BTreeMap::new(),
+            unbonding_period: 0,
+            instant_unstake_penalty: None,
+            withdrawals: BTreeMap::new(),
+            pending_penalties: BTreeMap::new(),
             mpc20: MPC20ContractState {
                 info: TokenInfo {
                     name: "Staking Token".to_string(),
@@ -289,6 +371,7 @@ fn test_staking() {
             deposit_token: mock_address(DEPOSIT_TOKEN),
             distribution_amount: 1_000,
             distribution_epoch: 10,
+            reward_program: None,
             global_index: DecimalRatio::new(5, 0),
             total_staked: 200,
             last_distributed: 115,
@@ -313,6 +396,12 @@ fn test_staking() {
                 )
             ]),
             compound_frequency: 100,
+            owner: mock_address(MINTER),
+            reward_streams: BTreeMap::new(),
+            unbonding_period: 0,
+            instant_unstake_penalty: None,
+            withdrawals: BTreeMap::new(),
+            pending_penalties: BTreeMap::new(),
             mpc20: MPC20ContractState {
                 info: TokenInfo {
                     name: "Staking Token".to_string(),
@@ -341,28 +430,40 @@ fn test_staking() {
 
     assert_eq!(unstake_events.len(), 1);
 
-    let transfer_msg = TransferMsg {
+    let mut eg = EventGroup::builder();
+    TransferMsg {
         to: mock_address(BOB),
         amount: 50,
-    };
-
-    let mut eg = EventGroup::builder();
-    eg.call(
-        mock_address(DEPOSIT_TOKEN),
-        Shortname::from_u32(transfer_msg.action_shortname()),
-    )
-    .argument(mock_address(BOB))
-    .argument(50u128)
-    .done();
+    }
+    .as_interaction(&mut eg, &mock_address(DEPOSIT_TOKEN));
+    build_msg_callback(
+        &mut eg,
+        ON_UNSTAKE_CALLBACK,
+        &UnstakeCallbackMsg {
+            staker: mock_address(BOB),
+            amount: 50,
+        },
+    );
 
     assert_eq!(unstake_events[0], eg.build());
 
+    let _ = execute_on_unstake(
+        &mock_contract_context(DEPOSIT_TOKEN, block_production_time),
+        &mock_callback_context(true),
+        &mut state,
+        &UnstakeCallbackMsg {
+            staker: mock_address(BOB),
+            amount: 50,
+        },
+    );
+
     assert_eq!(
         state,
         MPC20StakingContractState {
             deposit_token: mock_address(DEPOSIT_TOKEN),
             distribution_amount: 1_000,
             distribution_epoch: 10,
+            reward_program: None,
             global_index: DecimalRatio::new(5, 0),
             total_staked: 150,
             last_distributed: 115,
@@ -387,6 +488,12 @@ fn test_staking() {
                 )
             ]),
             compound_frequency: 100,
+            owner: mock_address(MINTER),
+            reward_streams: BTreeMap::new(),
+            unbonding_period: 0,
+            instant_unstake_penalty: None,
+            withdrawals: BTreeMap::new(),
+            pending_penalties: BTreeMap::new(),
             mpc20: MPC20ContractState {
                 info: TokenInfo {
                     name: "Staking Token".to_string(),
@@ -424,6 +531,7 @@ fn test_staking() {
             deposit_token: mock_address(DEPOSIT_TOKEN),
             distribution_amount: 1_000,
             distribution_epoch: 10,
+            reward_program: None,
             global_index: DecimalRatio::new(11666666666666666666666666667, 27),
             total_staked: 150,
             last_distributed: 125,
@@ -448,6 +556,12 @@ fn test_staking() {
                 )
             ]),
             compound_frequency: 100,
+            owner: mock_address(MINTER),
+            reward_streams: BTreeMap::new(),
+            unbonding_period: 0,
+            instant_unstake_penalty: None,
+            withdrawals: BTreeMap::new(),
+            pending_penalties: BTreeMap::new(),
             mpc20: MPC20ContractState {
                 info: TokenInfo {
                     name: "Staking Token".to_string(),
@@ -469,17 +583,27 @@ fn test_staking() {
     block_production_time = 134;
 
     let msg = StakeMsg { amount: 50 };
-    let events = execute_stake(
+    let _ = execute_stake(
         &mock_contract_context(JACK, block_production_time),
         &mut state,
         &msg,
     );
+    let _ = execute_on_stake(
+        &mock_contract_context(DEPOSIT_TOKEN, block_production_time),
+        &mock_callback_context(true),
+        &mut state,
+        &StakeCallbackMsg {
+            staker: mock_address(JACK),
+            amount: 50,
+        },
+    );
     assert_eq!(
         state,
         MPC20StakingContractState {
             deposit_token: mock_address(DEPOSIT_TOKEN),
             distribution_amount: 1_000,
             distribution_epoch: 10,
+            reward_program: None,
             global_index: DecimalRatio::new(11666666666666666666666666667, 27),
             total_staked: 200,
             last_distributed: 125,
@@ -513,6 +637,12 @@ fn test_staking() {
                 )
             ]),
             compound_frequency: 100,
+            owner: mock_address(MINTER),
+            reward_streams: BTreeMap::new(),
+            unbonding_period: 0,
+            instant_unstake_penalty: None,
+            withdrawals: BTreeMap::new(),
+            pending_penalties: BTreeMap::new(),
             mpc20: MPC20ContractState {
                 info: TokenInfo {
                     name: "Staking Token".to_string(),
@@ -555,6 +685,7 @@ fn test_staking() {
             deposit_token: mock_address(DEPOSIT_TOKEN),
             distribution_amount: 1_000,
             distribution_epoch: 10,
+            reward_program: None,
             global_index: DecimalRatio::new(16666666666666666666666666667, 27),
             total_staked: 200,
             last_distributed: 135,
@@ -588,6 +719,12 @@ fn test_staking() {
                 )
             ]),
             compound_frequency: 100,
+            owner: mock_address(MINTER),
+            reward_streams: BTreeMap::new(),
+            unbonding_period: 0,
+            instant_unstake_penalty: None,
+            withdrawals: BTreeMap::new(),
+            pending_penalties: BTreeMap::new(),
             mpc20: MPC20ContractState {
                 info: TokenInfo {
                     name: "Staking Token".to_string(),
@@ -613,7 +750,7 @@ fn test_staking() {
     block_production_time = 144;
 
     let msg = CompoundMsg { amount: Some(100) };
-    let events = execute_compound(
+    let _ = execute_compound(
         &mock_contract_context(JACK, block_production_time),
         &mut state,
         &msg,
@@ -624,6 +761,7 @@ fn test_staking() {
             deposit_token: mock_address(DEPOSIT_TOKEN),
             distribution_amount: 1_000,
             distribution_epoch: 10,
+            reward_program: None,
             global_index: DecimalRatio::new(16666666666666666666666666667, 27),
             total_staked: 300,
             last_distributed: 135,
@@ -657,6 +795,12 @@ fn test_staking() {
                 )
             ]),
             compound_frequency: 100,
+            owner: mock_address(MINTER),
+            reward_streams: BTreeMap::new(),
+            unbonding_period: 0,
+            instant_unstake_penalty: None,
+            withdrawals: BTreeMap::new(),
+            pending_penalties: BTreeMap::new(),
             mpc20: MPC20ContractState {
                 info: TokenInfo {
                     name: "Staking Token".to_string(),
@@ -689,9 +833,11 @@ fn invalid_distribution_amount() {
 
     let msg = Mpc20StakingInitMsg {
         deposit_token: None,
+        reward_token: None,
         distribution_amount: 0,
         distribution_epoch: 10,
         compound_frequency: 100,
+        unbonding_period: 0,
         info: TokenInfo {
             name: "Staking Token".to_string(),
             symbol: "STKN".to_string(),
@@ -712,9 +858,11 @@ fn invalid_distribution_epoch() {
 
     let msg = Mpc20StakingInitMsg {
         deposit_token: None,
+        reward_token: None,
         distribution_amount: 1_000,
         distribution_epoch: 0,
         compound_frequency: 100,
+        unbonding_period: 0,
         info: TokenInfo {
             name: "Staking Token".to_string(),
             symbol: "STKN".to_string(),
@@ -741,6 +889,7 @@ fn unstake_more_then_staked() {
         deposit_token: mock_address(DEPOSIT_TOKEN),
         distribution_amount: 1_000,
         distribution_epoch: 10,
+        reward_program: None,
         global_index: DecimalRatio::new(16666666666666666666666666667, 27),
         total_staked: 300,
         last_distributed: 135,
@@ -774,6 +923,12 @@ fn unstake_more_then_staked() {
             ),
         ]),
         compound_frequency: 100,
+        owner: mock_address(MINTER),
+        reward_streams: BTreeMap::new(),
+        unbonding_period: 0,
+        instant_unstake_penalty: None,
+        withdrawals: BTreeMap::new(),
+        pending_penalties: BTreeMap::new(),
         mpc20: MPC20ContractState {
             info: TokenInfo {
                 name: "Staking Token".to_string(),
@@ -818,6 +973,7 @@ fn claim_with_zero_rewards() {
         deposit_token: mock_address(DEPOSIT_TOKEN),
         distribution_amount: 1_000,
         distribution_epoch: 10,
+        reward_program: None,
         global_index: DecimalRatio::new(16666666666666666666666666667, 27),
         total_staked: 300,
         last_distributed: 135,
@@ -851,6 +1007,12 @@ fn claim_with_zero_rewards() {
             ),
         ]),
         compound_frequency: 100,
+        owner: mock_address(MINTER),
+        reward_streams: BTreeMap::new(),
+        unbonding_period: 0,
+        instant_unstake_penalty: None,
+        withdrawals: BTreeMap::new(),
+        pending_penalties: BTreeMap::new(),
         mpc20: MPC20ContractState {
             info: TokenInfo {
                 name: "Staking Token".to_string(),
@@ -895,6 +1057,7 @@ fn claim_more_then_rewarded() {
         deposit_token: mock_address(DEPOSIT_TOKEN),
         distribution_amount: 1_000,
         distribution_epoch: 10,
+        reward_program: None,
         global_index: DecimalRatio::new(16666666666666666666666666667, 27),
         total_staked: 300,
         last_distributed: 135,
@@ -928,6 +1091,12 @@ fn claim_more_then_rewarded() {
             ),
         ]),
         compound_frequency: 100,
+        owner: mock_address(MINTER),
+        reward_streams: BTreeMap::new(),
+        unbonding_period: 0,
+        instant_unstake_penalty: None,
+        withdrawals: BTreeMap::new(),
+        pending_penalties: BTreeMap::new(),
         mpc20: MPC20ContractState {
             info: TokenInfo {
                 name: "Staking Token".to_string(),
@@ -972,6 +1141,7 @@ fn compound_when_disabled() {
         deposit_token: mock_address(DEPOSIT_TOKEN),
         distribution_amount: 1_000,
         distribution_epoch: 10,
+        reward_program: None,
         global_index: DecimalRatio::new(16666666666666666666666666667, 27),
         total_staked: 300,
         last_distributed: 135,
@@ -1005,6 +1175,12 @@ fn compound_when_disabled() {
             ),
         ]),
         compound_frequency: 100,
+        owner: mock_address(MINTER),
+        reward_streams: BTreeMap::new(),
+        unbonding_period: 0,
+        instant_unstake_penalty: None,
+        withdrawals: BTreeMap::new(),
+        pending_penalties: BTreeMap::new(),
         mpc20: MPC20ContractState {
             info: TokenInfo {
                 name: "Staking Token".to_string(),
@@ -1049,6 +1225,7 @@ fn compound_to_often() {
         deposit_token: mock_address(DEPOSIT_TOKEN),
         distribution_amount: 1_000,
         distribution_epoch: 10,
+        reward_program: None,
         global_index: DecimalRatio::new(16666666666666666666666666667, 27),
         total_staked: 300,
         last_distributed: 135,
@@ -1082,6 +1259,12 @@ fn compound_to_often() {
             ),
         ]),
         compound_frequency: 100,
+        owner: mock_address(MINTER),
+        reward_streams: BTreeMap::new(),
+        unbonding_period: 0,
+        instant_unstake_penalty: None,
+        withdrawals: BTreeMap::new(),
+        pending_penalties: BTreeMap::new(),
         mpc20: MPC20ContractState {
             info: TokenInfo {
                 name: "Staking Token".to_string(),
@@ -1126,6 +1309,7 @@ fn compound_more_then_rewarded() {
         deposit_token: mock_address(DEPOSIT_TOKEN),
         distribution_amount: 1_000,
         distribution_epoch: 10,
+        reward_program: None,
         global_index: DecimalRatio::new(16666666666666666666666666667, 27),
         total_staked: 300,
         last_distributed: 135,
@@ -1159,6 +1343,12 @@ fn compound_more_then_rewarded() {
             ),
         ]),
         compound_frequency: 100,
+        owner: mock_address(MINTER),
+        reward_streams: BTreeMap::new(),
+        unbonding_period: 0,
+        instant_unstake_penalty: None,
+        withdrawals: BTreeMap::new(),
+        pending_penalties: BTreeMap::new(),
         mpc20: MPC20ContractState {
             info: TokenInfo {
                 name: "Staking Token".to_string(),
@@ -1187,3 +1377,1128 @@ fn compound_more_then_rewarded() {
         &msg,
     );
 }
+
+fn staked_state() -> MPC20StakingContractState {
+    const DEPOSIT_TOKEN: u8 = 2;
+    const MINTER: u8 = 9;
+
+    let msg = Mpc20StakingInitMsg {
+        deposit_token: Some(mock_address(DEPOSIT_TOKEN)),
+        reward_token: None,
+        distribution_amount: 1_000,
+        distribution_epoch: 10,
+        compound_frequency: 100,
+        unbonding_period: 0,
+        info: TokenInfo {
+            name: "Staking Token".to_string(),
+            symbol: "STKN".to_string(),
+            decimals: 18,
+        },
+        initial_balances: vec![],
+        minter: Some(mock_address(MINTER)),
+    };
+    let (state, _) = execute_init(&mock_contract_context(MINTER, 100), &msg);
+    state
+}
+
+#[test]
+fn failed_stake_transfer_is_not_credited() {
+    const DEPOSIT_TOKEN: u8 = 2;
+    const ALICE: u8 = 10;
+
+    let mut state = staked_state();
+
+    let _ = execute_stake(
+        &mock_contract_context(ALICE, 105),
+        &mut state,
+        &StakeMsg { amount: 100 },
+    );
+    let _ = execute_on_stake(
+        &mock_contract_context(DEPOSIT_TOKEN, 105),
+        &mock_callback_context(false),
+        &mut state,
+        &StakeCallbackMsg {
+            staker: mock_address(ALICE),
+            amount: 100,
+        },
+    );
+
+    assert_eq!(state.total_staked, 0);
+    assert_eq!(state.last_distributed, 100);
+    assert!(state.stakers.is_empty());
+}
+
+#[test]
+fn failed_unstake_transfer_restores_stake() {
+    const DEPOSIT_TOKEN: u8 = 2;
+    const ALICE: u8 = 10;
+    const BOB: u8 = 11;
+
+    let mut state = staked_state();
+
+    for (staker, amount) in [(ALICE, 100), (BOB, 100)] {
+        let _ = execute_stake(
+            &mock_contract_context(staker, 105),
+            &mut state,
+            &StakeMsg { amount },
+        );
+        let _ = execute_on_stake(
+            &mock_contract_context(DEPOSIT_TOKEN, 105),
+            &mock_callback_context(true),
+            &mut state,
+            &StakeCallbackMsg {
+                staker: mock_address(staker),
+                amount,
+            },
+        );
+    }
+    assert_eq!(state.total_staked, 200);
+
+    let _ = execute_unstake(
+        &mock_contract_context(ALICE, 115),
+        &mut state,
+        &UnstakeMsg { amount: 60 },
+    );
+    assert_eq!(state.total_staked, 140);
+    assert_eq!(state.get_staker(&mock_address(ALICE)).staked_amount, 40);
+
+    let _ = execute_on_unstake(
+        &mock_contract_context(DEPOSIT_TOKEN, 125),
+        &mock_callback_context(false),
+        &mut state,
+        &UnstakeCallbackMsg {
+            staker: mock_address(ALICE),
+            amount: 60,
+        },
+    );
+    assert_eq!(state.total_staked, 200);
+    assert_eq!(
+        state.get_staker(&mock_address(ALICE)),
+        Staker {
+            reward_index: DecimalRatio::new(12142857142857142857142857143, 27),
+            staked_amount: 100,
+            pending_reward: 785, // no rewards for amount in transit
+            last_compound: 0,
+        }
+    );
+}
+
+fn stake(state: &mut MPC20StakingContractState, staker: u8, amount: u128, block_time: i64) {
+    let _ = execute_stake(
+        &mock_contract_context(staker, block_time),
+        state,
+        &StakeMsg { amount },
+    );
+    let _ = execute_on_stake(
+        &mock_contract_context(2, block_time),
+        &mock_callback_context(true),
+        state,
+        &StakeCallbackMsg {
+            staker: mock_address(staker),
+            amount,
+        },
+    );
+}
+
+fn add_reward_stream(state: &mut MPC20StakingContractState, token: u8, end_time: u64) {
+    let _ = execute_add_reward_stream(
+        &mock_contract_context(9, 100),
+        state,
+        &AddRewardStreamMsg {
+            token: mock_address(token),
+            distribution_amount: 100,
+            distribution_epoch: 10,
+            end_time,
+        },
+    );
+}
+
+#[test]
+fn proper_reward_stream() {
+    const OWNER: u8 = 9;
+    const REWARD_TOKEN: u8 = 3;
+    const ALICE: u8 = 10;
+    const BOB: u8 = 11;
+
+    let mut state = staked_state();
+    add_reward_stream(&mut state, REWARD_TOKEN, 200);
+    assert_eq!(
+        state.reward_streams.get(&mock_address(REWARD_TOKEN)),
+        Some(&RewardStream {
+            distribution_amount: 100,
+            distribution_epoch: 10,
+            end_time: 200,
+            global_index: DecimalRatio::zero(),
+            last_distributed: 100,
+            funded: 0,
+            distributed: 0,
+            stakers: BTreeMap::new(),
+        })
+    );
+
+    let events = execute_fund_reward_stream(
+        &mock_contract_context(OWNER, 100),
+        &mut state,
+        &FundRewardStreamMsg {
+            token: mock_address(REWARD_TOKEN),
+            amount: 500,
+        },
+    );
+    let mut eg = EventGroup::builder();
+    TransferFromMsg {
+        from: mock_address(OWNER),
+        to: mock_address(1u8),
+        amount: 500,
+    }
+    .as_interaction(&mut eg, &mock_address(REWARD_TOKEN));
+    build_msg_callback(
+        &mut eg,
+        ON_FUND_STREAM_CALLBACK,
+        &FundStreamCallbackMsg {
+            token: mock_address(REWARD_TOKEN),
+            amount: 500,
+        },
+    );
+    assert_eq!(events, vec![eg.build()]);
+
+    let callback_msg = FundStreamCallbackMsg {
+        token: mock_address(REWARD_TOKEN),
+        amount: 500,
+    };
+    let _ = execute_on_fund_stream(
+        &mock_contract_context(REWARD_TOKEN, 100),
+        &mock_callback_context(false),
+        &mut state,
+        &callback_msg,
+    );
+    assert_eq!(state.reward_streams[&mock_address(REWARD_TOKEN)].funded, 0);
+
+    let _ = execute_on_fund_stream(
+        &mock_contract_context(REWARD_TOKEN, 100),
+        &mock_callback_context(true),
+        &mut state,
+        &callback_msg,
+    );
+    assert_eq!(
+        state.reward_streams[&mock_address(REWARD_TOKEN)].funded,
+        500
+    );
+
+    stake(&mut state, ALICE, 100, 100);
+    stake(&mut state, BOB, 300, 100);
+
+    // 5 epochs passed, ALICE share 25%
+    let events = execute_claim_stream(
+        &mock_contract_context(ALICE, 150),
+        &mut state,
+        &ClaimStreamMsg {
+            token: mock_address(REWARD_TOKEN),
+            amount: None,
+        },
+    );
+    let mut eg = EventGroup::builder();
+    TransferMsg {
+        to: mock_address(ALICE),
+        amount: 125,
+    }
+    .as_interaction(&mut eg, &mock_address(REWARD_TOKEN));
+    build_msg_callback(
+        &mut eg,
+        ON_CLAIM_STREAM_CALLBACK,
+        &ClaimStreamCallbackMsg {
+            staker: mock_address(ALICE),
+            token: mock_address(REWARD_TOKEN),
+            amount: 125,
+        },
+    );
+    assert_eq!(events, vec![eg.build()]);
+
+    let stream = &state.reward_streams[&mock_address(REWARD_TOKEN)];
+    assert_eq!(stream.global_index, DecimalRatio::new(125, 2));
+    assert_eq!(stream.distributed, 500);
+    assert_eq!(
+        stream.stakers.get(&mock_address(ALICE)),
+        Some(&StreamStaker {
+            reward_index: DecimalRatio::new(125, 2),
+            pending_reward: 0,
+        })
+    );
+
+    // main reward is not affected by the stream
+    assert_eq!(state.mpc20.total_supply, 0);
+
+    // funding is used up, nothing is distributed anymore
+    let _ = execute_claim_stream(
+        &mock_contract_context(BOB, 300),
+        &mut state,
+        &ClaimStreamMsg {
+            token: mock_address(REWARD_TOKEN),
+            amount: Some(375),
+        },
+    );
+    let stream = &state.reward_streams[&mock_address(REWARD_TOKEN)];
+    assert_eq!(stream.distributed, 500);
+    assert_eq!(stream.last_distributed, 200);
+    assert_eq!(stream.pending_reward(&mock_address(BOB)), 0);
+}
+
+#[test]
+fn reward_stream_stops_at_end_time() {
+    const REWARD_TOKEN: u8 = 3;
+    const ALICE: u8 = 10;
+
+    let mut state = staked_state();
+    add_reward_stream(&mut state, REWARD_TOKEN, 130);
+    let _ = execute_on_fund_stream(
+        &mock_contract_context(REWARD_TOKEN, 100),
+        &mock_callback_context(true),
+        &mut state,
+        &FundStreamCallbackMsg {
+            token: mock_address(REWARD_TOKEN),
+            amount: 10_000,
+        },
+    );
+
+    stake(&mut state, ALICE, 100, 100);
+
+    // unstake checkpoints stream rewards with previous staked amount
+    let _ = execute_unstake(
+        &mock_contract_context(ALICE, 500),
+        &mut state,
+        &UnstakeMsg { amount: 100 },
+    );
+
+    let stream = &state.reward_streams[&mock_address(REWARD_TOKEN)];
+    assert_eq!(stream.distributed, 300);
+    assert_eq!(stream.pending_reward(&mock_address(ALICE)), 300);
+}
+
+#[test]
+fn failed_stream_claim_restores_reward() {
+    const REWARD_TOKEN: u8 = 3;
+    const ALICE: u8 = 10;
+
+    let mut state = staked_state();
+    add_reward_stream(&mut state, REWARD_TOKEN, 200);
+    let _ = execute_on_fund_stream(
+        &mock_contract_context(REWARD_TOKEN, 100),
+        &mock_callback_context(true),
+        &mut state,
+        &FundStreamCallbackMsg {
+            token: mock_address(REWARD_TOKEN),
+            amount: 1_000,
+        },
+    );
+    stake(&mut state, ALICE, 100, 100);
+
+    let _ = execute_claim_stream(
+        &mock_contract_context(ALICE, 120),
+        &mut state,
+        &ClaimStreamMsg {
+            token: mock_address(REWARD_TOKEN),
+            amount: Some(150),
+        },
+    );
+    assert_eq!(
+        state.reward_streams[&mock_address(REWARD_TOKEN)].pending_reward(&mock_address(ALICE)),
+        50
+    );
+
+    let _ = execute_on_claim_stream(
+        &mock_contract_context(REWARD_TOKEN, 120),
+        &mock_callback_context(false),
+        &mut state,
+        &ClaimStreamCallbackMsg {
+            staker: mock_address(ALICE),
+            token: mock_address(REWARD_TOKEN),
+            amount: 150,
+        },
+    );
+    assert_eq!(
+        state.reward_streams[&mock_address(REWARD_TOKEN)].pending_reward(&mock_address(ALICE)),
+        200
+    );
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn add_reward_stream_not_owner() {
+    let mut state = staked_state();
+    let _ = execute_add_reward_stream(
+        &mock_contract_context(10, 100),
+        &mut state,
+        &AddRewardStreamMsg {
+            token: mock_address(3),
+            distribution_amount: 100,
+            distribution_epoch: 10,
+            end_time: 200,
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Reward stream already exists")]
+fn add_existing_reward_stream() {
+    let mut state = staked_state();
+    add_reward_stream(&mut state, 3, 200);
+    add_reward_stream(&mut state, 3, 300);
+}
+
+#[test]
+#[should_panic(expected = "Invalid reward stream")]
+fn add_ended_reward_stream() {
+    let mut state = staked_state();
+    add_reward_stream(&mut state, 3, 100);
+}
+
+#[test]
+#[should_panic(expected = "Invalid reward stream")]
+fn add_deposit_token_reward_stream() {
+    let mut state = staked_state();
+    add_reward_stream(&mut state, 2, 200);
+}
+
+#[test]
+#[should_panic(expected = "Invalid reward stream")]
+fn add_reward_program_token_reward_stream() {
+    let mut state = funded_state();
+    add_reward_stream(&mut state, 4, 200);
+}
+
+#[test]
+#[should_panic(expected = "Reward stream not found")]
+fn fund_unknown_reward_stream() {
+    let mut state = staked_state();
+    let _ = execute_fund_reward_stream(
+        &mock_contract_context(9, 100),
+        &mut state,
+        &FundRewardStreamMsg {
+            token: mock_address(3),
+            amount: 100,
+        },
+    );
+}
+
+fn set_unbonding(
+    state: &mut MPC20StakingContractState,
+    unbonding_period: u64,
+    instant_unstake_penalty: Option<UnstakePenalty>,
+) {
+    let _ = execute_set_unbonding(
+        &mock_contract_context(9, 100),
+        state,
+        &SetUnbondingMsg {
+            unbonding_period,
+            instant_unstake_penalty,
+        },
+    );
+}
+
+#[test]
+fn proper_unbonding_withdrawal() {
+    const DEPOSIT_TOKEN: u8 = 2;
+    const ALICE: u8 = 10;
+
+    let mut state = staked_state();
+    set_unbonding(&mut state, 100, None);
+    stake(&mut state, ALICE, 100, 100);
+
+    let events = execute_unstake(
+        &mock_contract_context(ALICE, 110),
+        &mut state,
+        &UnstakeMsg { amount: 40 },
+    );
+    assert_eq!(events, vec![]);
+
+    let _ = execute_unstake(
+        &mock_contract_context(ALICE, 150),
+        &mut state,
+        &UnstakeMsg { amount: 20 },
+    );
+    assert_eq!(state.total_staked, 40);
+    assert_eq!(state.get_staker(&mock_address(ALICE)).staked_amount, 40);
+    assert_eq!(
+        state.withdrawals.get(&mock_address(ALICE)),
+        Some(&vec![
+            PendingWithdrawal {
+                amount: 40,
+                release_time: 210,
+            },
+            PendingWithdrawal {
+                amount: 20,
+                release_time: 250,
+            },
+        ])
+    );
+
+    let events = execute_withdraw(
+        &mock_contract_context(ALICE, 210),
+        &mut state,
+        &WithdrawMsg {},
+    );
+    let mut eg = EventGroup::builder();
+    TransferMsg {
+        to: mock_address(ALICE),
+        amount: 40,
+    }
+    .as_interaction(&mut eg, &mock_address(DEPOSIT_TOKEN));
+    build_msg_callback(
+        &mut eg,
+        ON_WITHDRAW_CALLBACK,
+        &WithdrawCallbackMsg {
+            staker: mock_address(ALICE),
+            amount: 40,
+        },
+    );
+    assert_eq!(events, vec![eg.build()]);
+    assert_eq!(
+        state.withdrawals.get(&mock_address(ALICE)),
+        Some(&vec![PendingWithdrawal {
+            amount: 20,
+            release_time: 250,
+        }])
+    );
+
+    // failed transfer returns amount to the queue as matured entry
+    let _ = execute_on_withdraw(
+        &mock_contract_context(DEPOSIT_TOKEN, 220),
+        &mock_callback_context(false),
+        &mut state,
+        &WithdrawCallbackMsg {
+            staker: mock_address(ALICE),
+            amount: 40,
+        },
+    );
+    assert_eq!(
+        state.withdrawals.get(&mock_address(ALICE)),
+        Some(&vec![
+            PendingWithdrawal {
+                amount: 20,
+                release_time: 250,
+            },
+            PendingWithdrawal {
+                amount: 40,
+                release_time: 220,
+            },
+        ])
+    );
+
+    let _ = execute_withdraw(
+        &mock_contract_context(ALICE, 250),
+        &mut state,
+        &WithdrawMsg {},
+    );
+    assert!(state.withdrawals.is_empty());
+}
+
+#[test]
+fn unbonding_amount_earns_no_rewards() {
+    const ALICE: u8 = 10;
+    const BOB: u8 = 11;
+
+    let mut state = staked_state();
+    set_unbonding(&mut state, 100, None);
+    stake(&mut state, ALICE, 100, 100);
+    stake(&mut state, BOB, 100, 100);
+
+    let _ = execute_unstake(
+        &mock_contract_context(ALICE, 100),
+        &mut state,
+        &UnstakeMsg { amount: 100 },
+    );
+
+    // whole distribution goes to BOB
+    let _ = execute_claim(
+        &mock_contract_context(BOB, 110),
+        &mut state,
+        &ClaimMsg { amount: None },
+    );
+    assert_eq!(state.mpc20.balance_of(&mock_address(BOB)), 1_000);
+}
+
+#[test]
+#[should_panic(expected = "Nothing to withdraw")]
+fn withdraw_before_release_time() {
+    const ALICE: u8 = 10;
+
+    let mut state = staked_state();
+    set_unbonding(&mut state, 100, None);
+    stake(&mut state, ALICE, 100, 100);
+
+    let _ = execute_unstake(
+        &mock_contract_context(ALICE, 110),
+        &mut state,
+        &UnstakeMsg { amount: 40 },
+    );
+    let _ = execute_withdraw(
+        &mock_contract_context(ALICE, 209),
+        &mut state,
+        &WithdrawMsg {},
+    );
+}
+
+#[test]
+fn proper_instant_unstake() {
+    const DEPOSIT_TOKEN: u8 = 2;
+    const TREASURY: u8 = 5;
+    const ALICE: u8 = 10;
+
+    let mut state = staked_state();
+    set_unbonding(
+        &mut state,
+        100,
+        Some(UnstakePenalty {
+            basis_points: 500,
+            treasury: mock_address(TREASURY),
+        }),
+    );
+    stake(&mut state, ALICE, 100, 100);
+
+    let events = execute_instant_unstake(
+        &mock_contract_context(ALICE, 110),
+        &mut state,
+        &InstantUnstakeMsg { amount: 100 },
+    );
+    let callback_msg = InstantUnstakeCallbackMsg {
+        staker: mock_address(ALICE),
+        amount: 100,
+        penalty: 5,
+        treasury: mock_address(TREASURY),
+    };
+    let mut eg = EventGroup::builder();
+    TransferMsg {
+        to: mock_address(ALICE),
+        amount: 95,
+    }
+    .as_interaction(&mut eg, &mock_address(DEPOSIT_TOKEN));
+    build_msg_callback(&mut eg, ON_INSTANT_UNSTAKE_CALLBACK, &callback_msg);
+    assert_eq!(events, vec![eg.build()]);
+    assert_eq!(state.total_staked, 0);
+    assert!(state.withdrawals.is_empty());
+
+    let events = execute_on_instant_unstake(
+        &mock_contract_context(DEPOSIT_TOKEN, 110),
+        &mock_callback_context(true),
+        &mut state,
+        &callback_msg,
+    );
+    let mut eg = EventGroup::builder();
+    TransferMsg {
+        to: mock_address(TREASURY),
+        amount: 5,
+    }
+    .as_interaction(&mut eg, &mock_address(DEPOSIT_TOKEN));
+    build_msg_callback(
+        &mut eg,
+        ON_PENALTY_CALLBACK,
+        &PenaltyCallbackMsg {
+            treasury: mock_address(TREASURY),
+            amount: 5,
+        },
+    );
+    assert_eq!(events, vec![eg.build()]);
+
+    let events = execute_on_instant_unstake(
+        &mock_contract_context(DEPOSIT_TOKEN, 110),
+        &mock_callback_context(false),
+        &mut state,
+        &callback_msg,
+    );
+    assert_eq!(events, vec![]);
+    assert_eq!(state.total_staked, 100);
+    assert_eq!(state.get_staker(&mock_address(ALICE)).staked_amount, 100);
+}
+
+#[test]
+fn failed_penalty_transfer_is_retried() {
+    const DEPOSIT_TOKEN: u8 = 2;
+    const TREASURY: u8 = 5;
+    const BOB: u8 = 11;
+
+    let mut state = staked_state();
+    let callback_msg = PenaltyCallbackMsg {
+        treasury: mock_address(TREASURY),
+        amount: 5,
+    };
+
+    let events = execute_on_penalty(
+        &mock_contract_context(DEPOSIT_TOKEN, 110),
+        &mock_callback_context(true),
+        &mut state,
+        &callback_msg,
+    );
+    assert_eq!(events, vec![]);
+    assert!(state.pending_penalties.is_empty());
+
+    for _ in 0..2 {
+        let _ = execute_on_penalty(
+            &mock_contract_context(DEPOSIT_TOKEN, 110),
+            &mock_callback_context(false),
+            &mut state,
+            &callback_msg,
+        );
+    }
+    assert_eq!(
+        state.pending_penalties,
+        BTreeMap::from([(mock_address(TREASURY), 10)])
+    );
+
+    let events = execute_pay_penalties(
+        &mock_contract_context(BOB, 120),
+        &mut state,
+        &PayPenaltiesMsg {
+            treasury: mock_address(TREASURY),
+        },
+    );
+    let mut eg = EventGroup::builder();
+    TransferMsg {
+        to: mock_address(TREASURY),
+        amount: 10,
+    }
+    .as_interaction(&mut eg, &mock_address(DEPOSIT_TOKEN));
+    build_msg_callback(
+        &mut eg,
+        ON_PENALTY_CALLBACK,
+        &PenaltyCallbackMsg {
+            treasury: mock_address(TREASURY),
+            amount: 10,
+        },
+    );
+    assert_eq!(events, vec![eg.build()]);
+    assert!(state.pending_penalties.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "Nothing to pay")]
+fn pay_penalties_without_pending_penalties() {
+    const TREASURY: u8 = 5;
+    const BOB: u8 = 11;
+
+    let mut state = staked_state();
+    let _ = execute_pay_penalties(
+        &mock_contract_context(BOB, 120),
+        &mut state,
+        &PayPenaltiesMsg {
+            treasury: mock_address(TREASURY),
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Instant unstake is disabled")]
+fn instant_unstake_disabled() {
+    const ALICE: u8 = 10;
+
+    let mut state = staked_state();
+    stake(&mut state, ALICE, 100, 100);
+
+    let _ = execute_instant_unstake(
+        &mock_contract_context(ALICE, 110),
+        &mut state,
+        &InstantUnstakeMsg { amount: 100 },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn set_unbonding_not_owner() {
+    let mut state = staked_state();
+    let _ = execute_set_unbonding(
+        &mock_contract_context(10, 100),
+        &mut state,
+        &SetUnbondingMsg {
+            unbonding_period: 100,
+            instant_unstake_penalty: None,
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Invalid basis points")]
+fn set_unbonding_invalid_penalty() {
+    let mut state = staked_state();
+    set_unbonding(
+        &mut state,
+        100,
+        Some(UnstakePenalty {
+            basis_points: 10_001,
+            treasury: mock_address(5),
+        }),
+    );
+}
+
+fn funded_state() -> MPC20StakingContractState {
+    const DEPOSIT_TOKEN: u8 = 2;
+    const REWARD_TOKEN: u8 = 4;
+    const OWNER: u8 = 9;
+
+    let msg = Mpc20StakingInitMsg {
+        deposit_token: Some(mock_address(DEPOSIT_TOKEN)),
+        reward_token: Some(mock_address(REWARD_TOKEN)),
+        distribution_amount: 1_000,
+        distribution_epoch: 10,
+        compound_frequency: 100,
+        unbonding_period: 0,
+        info: TokenInfo {
+            name: "Staking Token".to_string(),
+            symbol: "STKN".to_string(),
+            decimals: 18,
+        },
+        initial_balances: vec![],
+        minter: None,
+    };
+    let (state, _) = execute_init(&mock_contract_context(OWNER, 100), &msg);
+    state
+}
+
+fn fund_reward_program(
+    state: &mut MPC20StakingContractState,
+    amount: u128,
+    end_time: u64,
+    block_time: i64,
+) {
+    let _ = execute_on_fund_program(
+        &mock_contract_context(4, block_time),
+        &mock_callback_context(true),
+        state,
+        &FundProgramCallbackMsg { amount, end_time },
+    );
+}
+
+fn pending_reward(state: &MPC20StakingContractState, staker: u8, block_time: u64) -> u128 {
+    let mut state = state.clone();
+    let mut staker = state.get_staker(&mock_address(staker));
+    state.distribute_rewards(block_time);
+    staker.compute_reward(state.global_index);
+    staker.pending_reward
+}
+
+#[test]
+fn proper_funded_reward_program() {
+    const REWARD_TOKEN: u8 = 4;
+    const OWNER: u8 = 9;
+    const ALICE: u8 = 10;
+
+    let mut state = funded_state();
+    assert_eq!(
+        state.reward_program,
+        Some(RewardProgram {
+            token: mock_address(REWARD_TOKEN),
+            budget: 0,
+            distributed: 0,
+            end_time: 100,
+        })
+    );
+
+    let events = execute_fund_reward_program(
+        &mock_contract_context(OWNER, 100),
+        &mut state,
+        &FundRewardProgramMsg {
+            amount: 2_500,
+            end_time: 200,
+        },
+    );
+    let mut eg = EventGroup::builder();
+    TransferFromMsg {
+        from: mock_address(OWNER),
+        to: mock_address(1u8),
+        amount: 2_500,
+    }
+    .as_interaction(&mut eg, &mock_address(REWARD_TOKEN));
+    build_msg_callback(
+        &mut eg,
+        ON_FUND_PROGRAM_CALLBACK,
+        &FundProgramCallbackMsg {
+            amount: 2_500,
+            end_time: 200,
+        },
+    );
+    assert_eq!(events, vec![eg.build()]);
+
+    let _ = execute_on_fund_program(
+        &mock_contract_context(REWARD_TOKEN, 100),
+        &mock_callback_context(false),
+        &mut state,
+        &FundProgramCallbackMsg {
+            amount: 2_500,
+            end_time: 200,
+        },
+    );
+    assert_eq!(state.reward_program.as_ref().unwrap().budget, 0);
+
+    fund_reward_program(&mut state, 2_500, 200, 100);
+    assert_eq!(
+        state.reward_program,
+        Some(RewardProgram {
+            token: mock_address(REWARD_TOKEN),
+            budget: 2_500,
+            distributed: 0,
+            end_time: 200,
+        })
+    );
+
+    stake(&mut state, ALICE, 100, 100);
+
+    // claim transfers from the pool instead of minting
+    let events = execute_claim(
+        &mock_contract_context(ALICE, 120),
+        &mut state,
+        &ClaimMsg { amount: None },
+    );
+    let mut eg = EventGroup::builder();
+    TransferMsg {
+        to: mock_address(ALICE),
+        amount: 2_000,
+    }
+    .as_interaction(&mut eg, &mock_address(REWARD_TOKEN));
+    build_msg_callback(
+        &mut eg,
+        ON_CLAIM_CALLBACK,
+        &ClaimCallbackMsg {
+            staker: mock_address(ALICE),
+            amount: 2_000,
+        },
+    );
+    assert_eq!(events, vec![eg.build()]);
+    assert_eq!(state.mpc20.total_supply, 0);
+    assert_eq!(state.get_staker(&mock_address(ALICE)).pending_reward, 0);
+
+    let _ = execute_on_claim(
+        &mock_contract_context(REWARD_TOKEN, 120),
+        &mock_callback_context(false),
+        &mut state,
+        &ClaimCallbackMsg {
+            staker: mock_address(ALICE),
+            amount: 2_000,
+        },
+    );
+    assert_eq!(state.get_staker(&mock_address(ALICE)).pending_reward, 2_000);
+
+    // distribution stops when budget is used up
+    assert_eq!(pending_reward(&state, ALICE, 190), 2_500);
+    state.distribute_rewards(190);
+    assert_eq!(state.reward_program.as_ref().unwrap().distributed, 2_500);
+}
+
+#[test]
+fn funded_reward_program_stops_at_end_time() {
+    const ALICE: u8 = 10;
+
+    let mut state = funded_state();
+    fund_reward_program(&mut state, 10_000, 130, 100);
+    stake(&mut state, ALICE, 100, 100);
+
+    assert_eq!(pending_reward(&state, ALICE, 200), 3_000);
+
+    // inactive time between program end and next deposit is not distributed
+    state.distribute_rewards(200);
+    fund_reward_program(&mut state, 10_000, 400, 300);
+    assert_eq!(state.last_distributed, 300);
+    assert_eq!(pending_reward(&state, ALICE, 320), 5_000);
+}
+
+#[test]
+fn fund_reward_program_does_not_shorten_end_time() {
+    const ALICE: u8 = 10;
+
+    let mut state = funded_state();
+    fund_reward_program(&mut state, 100_000, 400, 100);
+    stake(&mut state, ALICE, 100, 100);
+
+    fund_reward_program(&mut state, 1_000, 200, 150);
+    assert_eq!(state.reward_program.as_ref().unwrap().end_time, 400);
+    assert_eq!(state.reward_program.as_ref().unwrap().budget, 101_000);
+    assert_eq!(pending_reward(&state, ALICE, 300), 20_000);
+}
+
+#[test]
+fn fund_reward_program_after_requested_end_time() {
+    const ALICE: u8 = 10;
+
+    let mut state = funded_state();
+    fund_reward_program(&mut state, 10_000, 130, 100);
+    stake(&mut state, ALICE, 100, 100);
+
+    // deposit requested with end time 150 is received only at 200
+    fund_reward_program(&mut state, 10_000, 150, 200);
+    assert_eq!(state.reward_program.as_ref().unwrap().end_time, 150);
+    assert_eq!(state.reward_program.as_ref().unwrap().budget, 20_000);
+    assert_eq!(state.last_distributed, 200);
+    assert_eq!(pending_reward(&state, ALICE, 300), 3_000);
+}
+
+#[test]
+fn set_distribution_amount_updates_index_first() {
+    const OWNER: u8 = 9;
+    const ALICE: u8 = 10;
+
+    let mut state = funded_state();
+    fund_reward_program(&mut state, 10_000, 1_000, 100);
+    stake(&mut state, ALICE, 100, 100);
+
+    let _ = execute_set_distribution_amount(
+        &mock_contract_context(OWNER, 125),
+        &mut state,
+        &SetDistributionAmountMsg {
+            distribution_amount: 500,
+        },
+    );
+    assert_eq!(state.distribution_amount, 500);
+    assert_eq!(state.last_distributed, 120);
+    assert_eq!(state.global_index, DecimalRatio::new(20, 0));
+
+    assert_eq!(pending_reward(&state, ALICE, 140), 3_000);
+}
+
+#[test]
+#[should_panic(expected = "Reward program is disabled")]
+fn fund_reward_program_in_mint_mode() {
+    let mut state = staked_state();
+    let _ = execute_fund_reward_program(
+        &mock_contract_context(9, 100),
+        &mut state,
+        &FundRewardProgramMsg {
+            amount: 1_000,
+            end_time: 200,
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn fund_reward_program_not_owner() {
+    let mut state = funded_state();
+    let _ = execute_fund_reward_program(
+        &mock_contract_context(10, 100),
+        &mut state,
+        &FundRewardProgramMsg {
+            amount: 1_000,
+            end_time: 200,
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Invalid reward program")]
+fn fund_ended_reward_program() {
+    let mut state = funded_state();
+    let _ = execute_fund_reward_program(
+        &mock_contract_context(9, 100),
+        &mut state,
+        &FundRewardProgramMsg {
+            amount: 1_000,
+            end_time: 100,
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Fund amount must be higher then 0")]
+fn fund_reward_program_with_zero_amount() {
+    let mut state = funded_state();
+    let _ = execute_fund_reward_program(
+        &mock_contract_context(9, 100),
+        &mut state,
+        &FundRewardProgramMsg {
+            amount: 0,
+            end_time: 200,
+        },
+    );
+}
+
+#[test]
+fn proper_withdraw_reward_program() {
+    const REWARD_TOKEN: u8 = 4;
+    const OWNER: u8 = 9;
+    const ALICE: u8 = 10;
+
+    let mut state = funded_state();
+    fund_reward_program(&mut state, 10_000, 130, 100);
+    stake(&mut state, ALICE, 100, 100);
+
+    let events = execute_withdraw_reward_program(
+        &mock_contract_context(OWNER, 200),
+        &mut state,
+        &WithdrawRewardProgramMsg {},
+    );
+    let mut eg = EventGroup::builder();
+    TransferMsg {
+        to: mock_address(OWNER),
+        amount: 7_000,
+    }
+    .as_interaction(&mut eg, &mock_address(REWARD_TOKEN));
+    build_msg_callback(
+        &mut eg,
+        ON_WITHDRAW_PROGRAM_CALLBACK,
+        &WithdrawProgramCallbackMsg { amount: 7_000 },
+    );
+    assert_eq!(events, vec![eg.build()]);
+    assert_eq!(
+        state.reward_program,
+        Some(RewardProgram {
+            token: mock_address(REWARD_TOKEN),
+            budget: 3_000,
+            distributed: 3_000,
+            end_time: 130,
+        })
+    );
+    assert_eq!(pending_reward(&state, ALICE, 200), 3_000);
+
+    let _ = execute_on_withdraw_program(
+        &mock_contract_context(REWARD_TOKEN, 200),
+        &mock_callback_context(true),
+        &mut state,
+        &WithdrawProgramCallbackMsg { amount: 7_000 },
+    );
+    assert_eq!(state.reward_program.as_ref().unwrap().budget, 3_000);
+
+    let _ = execute_on_withdraw_program(
+        &mock_contract_context(REWARD_TOKEN, 200),
+        &mock_callback_context(false),
+        &mut state,
+        &WithdrawProgramCallbackMsg { amount: 7_000 },
+    );
+    assert_eq!(state.reward_program.as_ref().unwrap().budget, 10_000);
+}
+
+#[test]
+#[should_panic(expected = "Reward program has not ended")]
+fn withdraw_running_reward_program() {
+    let mut state = funded_state();
+    fund_reward_program(&mut state, 10_000, 130, 100);
+
+    let _ = execute_withdraw_reward_program(
+        &mock_contract_context(9, 120),
+        &mut state,
+        &WithdrawRewardProgramMsg {},
+    );
+}
+
+#[test]
+#[should_panic(expected = "Nothing to withdraw")]
+fn withdraw_distributed_reward_program() {
+    const ALICE: u8 = 10;
+
+    let mut state = funded_state();
+    fund_reward_program(&mut state, 3_000, 130, 100);
+    stake(&mut state, ALICE, 100, 100);
+
+    let _ = execute_withdraw_reward_program(
+        &mock_contract_context(9, 200),
+        &mut state,
+        &WithdrawRewardProgramMsg {},
+    );
+}
+
+#[test]
+#[should_panic(expected = "Distribution amount must be higher then 0")]
+fn set_zero_distribution_amount() {
+    let mut state = funded_state();
+    let _ = execute_set_distribution_amount(
+        &mock_contract_context(9, 100),
+        &mut state,
+        &SetDistributionAmountMsg {
+            distribution_amount: 0,
+        },
+    );
+}
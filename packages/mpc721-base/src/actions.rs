@@ -1,13 +1,32 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
-use pbc_contract_common::{context::ContractContext, events::EventGroup};
+use counter_base::state::CounterBase;
+use mpc20_base::msg::{TransferFromMsg as Mpc20TransferFromMsg, TransferMsg as Mpc20TransferMsg};
+use pbc_contract_common::{
+    address::{Address, AddressType},
+    context::{CallbackContext, ContractContext},
+    events::EventGroup,
+};
+use utils::{
+    events::{build_msg_callback, build_msg_reply, IntoShortnameRPCEvent},
+    merkle::verify_merkle_proof,
+    signature::{sha256_digest, verify_signature},
+};
 
 use crate::{
     msg::{
-        ApproveForAllMsg, ApproveMsg, BurnMsg, CheckOwnerMsg, InitMsg, MintMsg, MultiMintMsg,
-        RevokeForAllMsg, RevokeMsg, SetBaseUriMsg, TransferFromMsg, TransferMsg, UpdateMinterMsg,
+        write_address, ApproveForAllMsg, ApproveMsg, BalanceOfMsg, BalanceOfReplyMsg, BurnMsg,
+        CheckOwnerMsg, FreezeMetadataMsg, InitMsg, MintConsecutiveMsg, MintMsg, MintNextMsg,
+        MintNextNMsg, MintNextReplyMsg, MultiMintMsg, OnMpc721ReceivedMsg, OwnerOfMsg,
+        OwnerOfReplyMsg, PhaseMintCallbackMsg, PublicMintMsg, RedeemVoucherMsg, RevokeForAllMsg,
+        RevokeMsg, RoyaltyInfoMsg, RoyaltyInfoReplyMsg, SafeTransferCallbackMsg,
+        SafeTransferFromMsg, SetBaseUriMsg, SetDefaultRoyaltyMsg, SetMaxSupplyMsg, SetMintPhaseMsg,
+        SetMinterPublicKeyMsg, SetSoulboundMsg, SetTokenAttributesMsg, SetTokenRoyaltyMsg,
+        SetTokenSoulboundMsg, SetTokenUriMsg, SetUserMsg, TotalSupplyMsg, TotalSupplyReplyMsg,
+        TransferFromMsg, TransferMsg, UpdateMinterMsg, VoucherPaymentCallbackMsg,
+        ON_PHASE_MINT_PAYMENT_CALLBACK, ON_SAFE_TRANSFER_CALLBACK, ON_VOUCHER_PAYMENT_CALLBACK,
     },
-    state::MPC721ContractState,
+    state::{MPC721ContractState, TokenUser},
     ContractError,
 };
 
@@ -31,7 +50,27 @@ pub fn execute_init(
         minter: msg.minter,
         supply: 0,
         tokens: BTreeMap::new(),
+        owned_tokens: BTreeMap::new(),
         operator_approvals: BTreeMap::new(),
+        default_royalty: None,
+        token_royalties: BTreeMap::new(),
+        minter_public_key: None,
+        redeemed_vouchers: BTreeSet::new(),
+        token_id_counter: CounterBase::default(),
+        max_supply: None,
+        mint_phases: BTreeMap::new(),
+        phase_mints: BTreeMap::new(),
+        soulbound: false,
+        soulbound_holder_burn: false,
+        soulbound_tokens: BTreeSet::new(),
+        token_attributes: BTreeMap::new(),
+        metadata_frozen: false,
+        frozen_tokens: BTreeSet::new(),
+        consecutive_ranges: BTreeMap::new(),
+        range_balances: BTreeMap::new(),
+        owner_ranges: BTreeMap::new(),
+        token_index: BTreeMap::new(),
+        index_positions: BTreeMap::new(),
     };
 
     (state, vec![])
@@ -90,6 +129,122 @@ pub fn execute_mint(
 
     vec![]
 }
+
+/// ## Description
+/// Mint a new token with next sequential token id. Can only be executed by minter account.
+/// Assigned token id is sent back to the minter if `callback_action` is specified.
+/// Returns [`(MPC721ContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC721ContractState`]
+///
+/// * **msg** is an object of type [`MintNextMsg`]
+pub fn execute_mint_next(
+    ctx: &ContractContext,
+    state: &mut MPC721ContractState,
+    msg: &MintNextMsg,
+) -> Vec<EventGroup> {
+    assert!(
+        state.minter == ctx.sender,
+        "{}",
+        ContractError::Unauthorized
+    );
+
+    let token_id = state.next_token_id();
+    state.mint(token_id, &msg.to, &msg.token_uri);
+    state.increase_supply();
+
+    mint_next_reply(ctx, &msg.to, vec![token_id], msg.callback_action)
+}
+
+/// ## Description
+/// Mint specified amount of new tokens with next sequential token ids.
+/// Can only be executed by minter account.
+/// Assigned token ids are sent back to the minter if `callback_action` is specified.
+/// Returns [`(MPC721ContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC721ContractState`]
+///
+/// * **msg** is an object of type [`MintNextNMsg`]
+pub fn execute_mint_next_n(
+    ctx: &ContractContext,
+    state: &mut MPC721ContractState,
+    msg: &MintNextNMsg,
+) -> Vec<EventGroup> {
+    assert!(
+        state.minter == ctx.sender,
+        "{}",
+        ContractError::Unauthorized
+    );
+    assert!(msg.amount > 0, "{}", ContractError::ZeroAmount);
+
+    let mut token_ids = Vec::with_capacity(msg.amount as usize);
+    for _ in 0..msg.amount {
+        let token_id = state.next_token_id();
+        state.mint(token_id, &msg.to, &None);
+        state.increase_supply();
+        token_ids.push(token_id);
+    }
+
+    mint_next_reply(ctx, &msg.to, token_ids, msg.callback_action)
+}
+
+/// ## Description
+/// Mint specified amount of new tokens with next sequential token ids as a single ownership range.
+/// Ownership of every token is resolved from the range until it is transferred, approved or burned.
+/// Can only be executed by minter account.
+/// Returns [`(MPC721ContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC721ContractState`]
+///
+/// * **msg** is an object of type [`MintConsecutiveMsg`]
+pub fn execute_mint_consecutive(
+    ctx: &ContractContext,
+    state: &mut MPC721ContractState,
+    msg: &MintConsecutiveMsg,
+) -> Vec<EventGroup> {
+    assert!(
+        state.minter == ctx.sender,
+        "{}",
+        ContractError::Unauthorized
+    );
+    assert!(msg.amount > 0, "{}", ContractError::ZeroAmount);
+
+    state.mint_consecutive(&msg.to, msg.amount as u128);
+    state.increase_supply_by(msg.amount as u128);
+
+    vec![]
+}
+
+fn mint_next_reply(
+    ctx: &ContractContext,
+    to: &Address,
+    token_ids: Vec<u128>,
+    callback_action: Option<u32>,
+) -> Vec<EventGroup> {
+    match callback_action {
+        Some(callback_action) => {
+            let mut event_group = EventGroup::builder();
+            build_msg_reply(
+                &mut event_group,
+                &ctx.sender,
+                callback_action,
+                &MintNextReplyMsg { to: *to, token_ids },
+            );
+            vec![event_group.build()]
+        }
+        None => vec![],
+    }
+}
+
 /// ## Description
 /// Updates the minter address checking that the sender is the contract owner address
 /// ## Params
@@ -110,6 +265,7 @@ pub fn execute_update_minter(
     );
 
     state.minter = msg.new_minter;
+    state.minter_public_key = None;
 
     vec![]
 }
@@ -130,6 +286,7 @@ pub fn execute_transfer(
     msg: &TransferMsg,
 ) -> Vec<EventGroup> {
     assert!(state.is_minted(msg.token_id), "{}", ContractError::NotFound);
+    state.assert_transferable(msg.token_id);
 
     state.transfer(&ctx.sender, &msg.to, msg.token_id);
     vec![]
@@ -151,6 +308,7 @@ pub fn execute_transfer_from(
     msg: &TransferFromMsg,
 ) -> Vec<EventGroup> {
     assert!(state.is_minted(msg.token_id), "{}", ContractError::NotFound);
+    state.assert_transferable(msg.token_id);
 
     state.transfer(&msg.from, &msg.to, msg.token_id);
     vec![]
@@ -172,6 +330,11 @@ pub fn execute_approve(
     msg: &ApproveMsg,
 ) -> Vec<EventGroup> {
     assert!(state.is_minted(msg.token_id), "{}", ContractError::NotFound);
+    assert!(
+        !state.is_soulbound(msg.token_id),
+        "{}",
+        ContractError::SoulboundApproval
+    );
 
     state.update_approvals(&ctx.sender, &msg.spender, msg.token_id, true);
     vec![]
@@ -192,6 +355,8 @@ pub fn execute_approve_for_all(
     state: &mut MPC721ContractState,
     msg: &ApproveForAllMsg,
 ) -> Vec<EventGroup> {
+    assert!(!state.soulbound, "{}", ContractError::SoulboundApproval);
+
     state.add_operator(&ctx.sender, &msg.operator);
     vec![]
 }
@@ -238,6 +403,8 @@ pub fn execute_revoke_for_all(
 
 /// ## Description
 /// Destroy your token forever.
+/// Soulbound tokens can be burned by minter account or,
+/// if holder burn is allowed, by the token owner.
 /// Returns [`(MPC721ContractState, Vec<EventGroup>)`] if operation was successful,
 /// otherwise panics with error message defined in [`ContractError`]
 /// ## Params
@@ -253,7 +420,17 @@ pub fn execute_burn(
 ) -> Vec<EventGroup> {
     assert!(state.is_minted(msg.token_id), "{}", ContractError::NotFound);
 
-    state.remove_token(&ctx.sender, msg.token_id);
+    if state.is_soulbound(msg.token_id) {
+        let owner = state.owner_of(msg.token_id);
+        assert!(
+            state.minter == ctx.sender || (state.soulbound_holder_burn && owner == ctx.sender),
+            "{}",
+            ContractError::SoulboundBurn
+        );
+        state.remove_token(&owner, msg.token_id);
+    } else {
+        state.remove_token(&ctx.sender, msg.token_id);
+    }
     state.decrease_supply();
 
     vec![]
@@ -307,3 +484,741 @@ pub fn execute_multi_mint(
 
     vec![]
 }
+
+/// ## Description
+/// Sets or removes collection-wide royalty. Can only be executed by owner account.
+/// Returns [`(MPC721ContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC721ContractState`]
+///
+/// * **msg** is an object of type [`SetDefaultRoyaltyMsg`]
+pub fn execute_set_default_royalty(
+    ctx: &ContractContext,
+    state: &mut MPC721ContractState,
+    msg: &SetDefaultRoyaltyMsg,
+) -> Vec<EventGroup> {
+    assert!(
+        state.is_owner(&ctx.sender),
+        "{}",
+        ContractError::Unauthorized
+    );
+
+    state.set_default_royalty(&msg.royalty);
+    vec![]
+}
+
+/// ## Description
+/// Sets or removes royalty of the minted token. Can only be executed by owner account.
+/// Returns [`(MPC721ContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC721ContractState`]
+///
+/// * **msg** is an object of type [`SetTokenRoyaltyMsg`]
+pub fn execute_set_token_royalty(
+    ctx: &ContractContext,
+    state: &mut MPC721ContractState,
+    msg: &SetTokenRoyaltyMsg,
+) -> Vec<EventGroup> {
+    assert!(
+        state.is_owner(&ctx.sender),
+        "{}",
+        ContractError::Unauthorized
+    );
+    assert!(state.is_minted(msg.token_id), "{}", ContractError::NotFound);
+
+    state.set_token_royalty(msg.token_id, &msg.royalty);
+    vec![]
+}
+
+/// ## Description
+/// Reports royalty receiver and amount for the sale price back to the calling contract.
+/// Returns [`(MPC721ContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC721ContractState`]
+///
+/// * **msg** is an object of type [`RoyaltyInfoMsg`]
+pub fn execute_royalty_info(
+    ctx: &ContractContext,
+    state: &mut MPC721ContractState,
+    msg: &RoyaltyInfoMsg,
+) -> Vec<EventGroup> {
+    assert!(state.is_minted(msg.token_id), "{}", ContractError::NotFound);
+
+    let royalty = state.royalty_info(msg.token_id, msg.sale_price);
+    let reply = RoyaltyInfoReplyMsg {
+        token_id: msg.token_id,
+        sale_price: msg.sale_price,
+        receiver: royalty.map(|(receiver, _)| receiver),
+        royalty_amount: royalty.map(|(_, amount)| amount).unwrap_or(0),
+    };
+
+    let mut event_group = EventGroup::builder();
+    build_msg_reply(&mut event_group, &ctx.sender, msg.callback_action, &reply);
+
+    vec![event_group.build()]
+}
+
+/// ## Description
+/// Reports amount of tokens owned by the holder back to the calling contract.
+/// Returns [`(MPC721ContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC721ContractState`]
+///
+/// * **msg** is an object of type [`BalanceOfMsg`]
+pub fn execute_balance_of(
+    ctx: &ContractContext,
+    state: &mut MPC721ContractState,
+    msg: &BalanceOfMsg,
+) -> Vec<EventGroup> {
+    let reply = BalanceOfReplyMsg {
+        owner: msg.owner,
+        balance: state.balance_of(&msg.owner),
+        context: msg.context.clone(),
+    };
+
+    let mut event_group = EventGroup::builder();
+    build_msg_reply(&mut event_group, &ctx.sender, msg.callback_action, &reply);
+
+    vec![event_group.build()]
+}
+
+/// ## Description
+/// Reports owner of the token back to the calling contract.
+/// Unlike [`execute_ownership_check`] does not fail when token is not minted.
+/// Returns [`(MPC721ContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC721ContractState`]
+///
+/// * **msg** is an object of type [`OwnerOfMsg`]
+pub fn execute_owner_of(
+    ctx: &ContractContext,
+    state: &mut MPC721ContractState,
+    msg: &OwnerOfMsg,
+) -> Vec<EventGroup> {
+    let reply = OwnerOfReplyMsg {
+        token_id: msg.token_id,
+        owner: state.token_info(msg.token_id).map(|info| info.owner),
+        context: msg.context.clone(),
+    };
+
+    let mut event_group = EventGroup::builder();
+    build_msg_reply(&mut event_group, &ctx.sender, msg.callback_action, &reply);
+
+    vec![event_group.build()]
+}
+
+/// ## Description
+/// Reports current and maximum supply back to the calling contract.
+/// Returns [`(MPC721ContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC721ContractState`]
+///
+/// * **msg** is an object of type [`TotalSupplyMsg`]
+pub fn execute_total_supply(
+    ctx: &ContractContext,
+    state: &mut MPC721ContractState,
+    msg: &TotalSupplyMsg,
+) -> Vec<EventGroup> {
+    let reply = TotalSupplyReplyMsg {
+        total_supply: state.supply,
+        max_supply: state.max_supply,
+        context: msg.context.clone(),
+    };
+
+    let mut event_group = EventGroup::builder();
+    build_msg_reply(&mut event_group, &ctx.sender, msg.callback_action, &reply);
+
+    vec![event_group.build()]
+}
+
+/// ## Description
+/// Transfer token from owner to receiver. If receiver is a contract
+/// it is notified with [`OnMpc721ReceivedMsg`] and the transfer is reverted if it fails.
+/// Returns [`(MPC721ContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC721ContractState`]
+///
+/// * **msg** is an object of type [`SafeTransferFromMsg`]
+pub fn execute_safe_transfer_from(
+    ctx: &ContractContext,
+    state: &mut MPC721ContractState,
+    msg: &SafeTransferFromMsg,
+) -> Vec<EventGroup> {
+    assert!(state.is_minted(msg.token_id), "{}", ContractError::NotFound);
+    assert!(
+        state.owner_of(msg.token_id) == msg.from,
+        "{}",
+        ContractError::IncorrectOwner
+    );
+    state.assert_transferable(msg.token_id);
+
+    let (approvals, user) = state
+        .tokens
+        .get(&msg.token_id)
+        .map(|token| (token.approvals.clone(), token.user.clone()))
+        .unwrap_or_default();
+    state.transfer(&ctx.sender, &msg.to, msg.token_id);
+
+    if !is_contract(&msg.to) {
+        return vec![];
+    }
+
+    let mut event_group = EventGroup::builder();
+    OnMpc721ReceivedMsg {
+        operator: ctx.sender,
+        from: msg.from,
+        token_id: msg.token_id,
+        data: msg.data.clone(),
+    }
+    .as_interaction(&mut event_group, &msg.to);
+    build_msg_callback(
+        &mut event_group,
+        ON_SAFE_TRANSFER_CALLBACK,
+        &SafeTransferCallbackMsg {
+            from: msg.from,
+            to: msg.to,
+            token_id: msg.token_id,
+            approvals,
+            user,
+        },
+    );
+
+    vec![event_group.build()]
+}
+
+/// ## Description
+/// Handles receiver contract acknowledgement of the safe transfer.
+/// Reverts the transfer and restores token approvals and user if receiver contract failed to handle the token.
+/// Returns [`(MPC721ContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **callback_ctx** is an object of type [`CallbackContext`]
+///
+/// * **state** is an object of type [`MPC721ContractState`]
+///
+/// * **msg** is an object of type [`SafeTransferCallbackMsg`]
+pub fn execute_on_safe_transfer(
+    ctx: &ContractContext,
+    callback_ctx: &CallbackContext,
+    state: &mut MPC721ContractState,
+    msg: &SafeTransferCallbackMsg,
+) -> Vec<EventGroup> {
+    if !callback_ctx.success {
+        state.revert_transfer(&msg.from, &msg.to, msg.token_id, &msg.approvals, &msg.user);
+    }
+
+    vec![]
+}
+
+/// ## Description
+/// Sets public key used to verify mint vouchers. Can only be executed by minter account.
+/// Returns [`(MPC721ContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC721ContractState`]
+///
+/// * **msg** is an object of type [`SetMinterPublicKeyMsg`]
+pub fn execute_set_minter_public_key(
+    ctx: &ContractContext,
+    state: &mut MPC721ContractState,
+    msg: &SetMinterPublicKeyMsg,
+) -> Vec<EventGroup> {
+    assert!(
+        state.minter == ctx.sender,
+        "{}",
+        ContractError::Unauthorized
+    );
+
+    state.minter_public_key = Some(msg.public_key.clone());
+    vec![]
+}
+
+/// ## Description
+/// Mints a token described by the voucher signed by the minter.
+/// Paid vouchers keep the token on the contract until the payment is received.
+/// Returns [`(MPC721ContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC721ContractState`]
+///
+/// * **msg** is an object of type [`RedeemVoucherMsg`]
+pub fn execute_redeem_voucher(
+    ctx: &ContractContext,
+    state: &mut MPC721ContractState,
+    msg: &RedeemVoucherMsg,
+) -> Vec<EventGroup> {
+    let voucher = &msg.voucher;
+    assert!(
+        voucher.expires_at > ctx.block_production_time as u64,
+        "{}",
+        ContractError::VoucherExpired
+    );
+    if let Some(recipient) = voucher.recipient {
+        assert!(recipient == ctx.sender, "{}", ContractError::Unauthorized);
+    }
+
+    let public_key = state
+        .minter_public_key
+        .as_ref()
+        .unwrap_or_else(|| panic!("{}", ContractError::MinterPublicKeyNotSet));
+    let payload = voucher.signing_payload(&ctx.contract_address);
+    assert!(
+        verify_signature(public_key, &payload, &msg.signature),
+        "{}",
+        ContractError::InvalidSignature
+    );
+
+    let voucher_digest = sha256_digest(&payload);
+    assert!(
+        state.redeemed_vouchers.insert(voucher_digest),
+        "{}",
+        ContractError::VoucherRedeemed
+    );
+    assert!(
+        !state.is_minted(voucher.token_id),
+        "{}",
+        ContractError::Minted
+    );
+
+    if voucher.price == 0 {
+        state.mint(voucher.token_id, &ctx.sender, &voucher.token_uri);
+        state.increase_supply();
+        return vec![];
+    }
+
+    state.mint(voucher.token_id, &ctx.contract_address, &voucher.token_uri);
+    state.increase_supply();
+
+    let mut event_group = EventGroup::builder();
+    Mpc20TransferFromMsg {
+        from: ctx.sender,
+        to: ctx.contract_address,
+        amount: voucher.price,
+    }
+    .as_interaction(&mut event_group, &voucher.currency);
+    build_msg_callback(
+        &mut event_group,
+        ON_VOUCHER_PAYMENT_CALLBACK,
+        &VoucherPaymentCallbackMsg {
+            voucher_digest,
+            token_id: voucher.token_id,
+            redeemer: ctx.sender,
+            currency: voucher.currency,
+            price: voucher.price,
+        },
+    );
+
+    vec![event_group.build()]
+}
+
+/// ## Description
+/// Handles result of the voucher payment.
+/// Transfers token to the redeemer and payment to the minter on success,
+/// otherwise burns the token and allows voucher to be redeemed again.
+/// Returns [`(MPC721ContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **callback_ctx** is an object of type [`CallbackContext`]
+///
+/// * **state** is an object of type [`MPC721ContractState`]
+///
+/// * **msg** is an object of type [`VoucherPaymentCallbackMsg`]
+pub fn execute_on_voucher_payment(
+    ctx: &ContractContext,
+    callback_ctx: &CallbackContext,
+    state: &mut MPC721ContractState,
+    msg: &VoucherPaymentCallbackMsg,
+) -> Vec<EventGroup> {
+    if !callback_ctx.success {
+        state.burn_escrowed_token(&ctx.contract_address, msg.token_id);
+        state.redeemed_vouchers.remove(&msg.voucher_digest);
+        return vec![];
+    }
+
+    state.transfer(&ctx.contract_address, &msg.redeemer, msg.token_id);
+
+    let mut event_group = EventGroup::builder();
+    Mpc20TransferMsg {
+        to: state.minter,
+        amount: msg.price,
+    }
+    .as_interaction(&mut event_group, &msg.currency);
+
+    vec![event_group.build()]
+}
+
+/// ## Description
+/// Sets or removes maximum supply of the collection. Can only be executed by owner account.
+/// Returns [`(MPC721ContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC721ContractState`]
+///
+/// * **msg** is an object of type [`SetMaxSupplyMsg`]
+pub fn execute_set_max_supply(
+    ctx: &ContractContext,
+    state: &mut MPC721ContractState,
+    msg: &SetMaxSupplyMsg,
+) -> Vec<EventGroup> {
+    assert!(
+        state.is_owner(&ctx.sender),
+        "{}",
+        ContractError::Unauthorized
+    );
+
+    state.set_max_supply(msg.max_supply);
+    vec![]
+}
+
+/// ## Description
+/// Sets or removes public mint phase. Can only be executed by minter account.
+/// Returns [`(MPC721ContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC721ContractState`]
+///
+/// * **msg** is an object of type [`SetMintPhaseMsg`]
+pub fn execute_set_mint_phase(
+    ctx: &ContractContext,
+    state: &mut MPC721ContractState,
+    msg: &SetMintPhaseMsg,
+) -> Vec<EventGroup> {
+    assert!(
+        state.minter == ctx.sender,
+        "{}",
+        ContractError::Unauthorized
+    );
+
+    state.set_mint_phase(msg.phase_id, &msg.phase);
+    vec![]
+}
+
+/// ## Description
+/// Mints tokens with sequential token ids during active public mint phase.
+/// Paid tokens are kept on the contract until the payment is received.
+/// Returns [`(MPC721ContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC721ContractState`]
+///
+/// * **msg** is an object of type [`PublicMintMsg`]
+pub fn execute_public_mint(
+    ctx: &ContractContext,
+    state: &mut MPC721ContractState,
+    msg: &PublicMintMsg,
+) -> Vec<EventGroup> {
+    assert!(msg.amount > 0, "{}", ContractError::ZeroAmount);
+
+    let phase = state
+        .mint_phases
+        .get(&msg.phase_id)
+        .cloned()
+        .unwrap_or_else(|| panic!("{}", ContractError::NotFound));
+    let now = ctx.block_production_time as u64;
+    assert!(
+        phase.start_time <= now && now < phase.end_time,
+        "{}",
+        ContractError::MintPhaseNotActive
+    );
+
+    if let Some(merkle_root) = &phase.merkle_root {
+        let mut leaf = vec![];
+        write_address(&mut leaf, &ctx.sender);
+        verify_merkle_proof(merkle_root, hex::encode(leaf).as_bytes(), &msg.merkle_proof);
+    }
+
+    state.add_phase_mints(msg.phase_id, &ctx.sender, msg.amount);
+
+    let total_price = phase.price.checked_mul(msg.amount as u128).unwrap();
+    let receiver = if total_price == 0 {
+        ctx.sender
+    } else {
+        ctx.contract_address
+    };
+
+    let mut token_ids = Vec::with_capacity(msg.amount as usize);
+    for _ in 0..msg.amount {
+        let token_id = state.next_token_id();
+        state.mint(token_id, &receiver, &None);
+        state.increase_supply();
+        token_ids.push(token_id);
+    }
+
+    if total_price == 0 {
+        return vec![];
+    }
+
+    let mut event_group = EventGroup::builder();
+    Mpc20TransferFromMsg {
+        from: ctx.sender,
+        to: ctx.contract_address,
+        amount: total_price,
+    }
+    .as_interaction(&mut event_group, &phase.currency);
+    build_msg_callback(
+        &mut event_group,
+        ON_PHASE_MINT_PAYMENT_CALLBACK,
+        &PhaseMintCallbackMsg {
+            phase_id: msg.phase_id,
+            buyer: ctx.sender,
+            token_ids,
+            currency: phase.currency,
+            total_price,
+        },
+    );
+
+    vec![event_group.build()]
+}
+
+/// ## Description
+/// Handles result of the public mint payment.
+/// Transfers tokens to the buyer and payment to the minter on success,
+/// otherwise burns the tokens and restores buyer phase allowance.
+/// Returns [`(MPC721ContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **callback_ctx** is an object of type [`CallbackContext`]
+///
+/// * **state** is an object of type [`MPC721ContractState`]
+///
+/// * **msg** is an object of type [`PhaseMintCallbackMsg`]
+pub fn execute_on_phase_mint_payment(
+    ctx: &ContractContext,
+    callback_ctx: &CallbackContext,
+    state: &mut MPC721ContractState,
+    msg: &PhaseMintCallbackMsg,
+) -> Vec<EventGroup> {
+    if !callback_ctx.success {
+        for token_id in msg.token_ids.iter() {
+            state.burn_escrowed_token(&ctx.contract_address, *token_id);
+        }
+        state.remove_phase_mints(msg.phase_id, &msg.buyer, msg.token_ids.len() as u32);
+        return vec![];
+    }
+
+    for token_id in msg.token_ids.iter() {
+        state.transfer(&ctx.contract_address, &msg.buyer, *token_id);
+    }
+
+    let mut event_group = EventGroup::builder();
+    Mpc20TransferMsg {
+        to: state.minter,
+        amount: msg.total_price,
+    }
+    .as_interaction(&mut event_group, &msg.currency);
+
+    vec![event_group.build()]
+}
+
+/// ## Description
+/// Enables or disables soulbound mode for the whole collection. Can only be executed by minter account.
+/// Returns [`(MPC721ContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC721ContractState`]
+///
+/// * **msg** is an object of type [`SetSoulboundMsg`]
+pub fn execute_set_soulbound(
+    ctx: &ContractContext,
+    state: &mut MPC721ContractState,
+    msg: &SetSoulboundMsg,
+) -> Vec<EventGroup> {
+    assert!(
+        state.minter == ctx.sender,
+        "{}",
+        ContractError::Unauthorized
+    );
+
+    state.soulbound = msg.soulbound;
+    state.soulbound_holder_burn = msg.holder_burn;
+    vec![]
+}
+
+/// ## Description
+/// Marks or unmarks token id as soulbound. Token id may be marked before it is minted.
+/// Can only be executed by minter account.
+/// Returns [`(MPC721ContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC721ContractState`]
+///
+/// * **msg** is an object of type [`SetTokenSoulboundMsg`]
+pub fn execute_set_token_soulbound(
+    ctx: &ContractContext,
+    state: &mut MPC721ContractState,
+    msg: &SetTokenSoulboundMsg,
+) -> Vec<EventGroup> {
+    assert!(
+        state.minter == ctx.sender,
+        "{}",
+        ContractError::Unauthorized
+    );
+
+    if msg.soulbound {
+        state.soulbound_tokens.insert(msg.token_id);
+    } else {
+        state.soulbound_tokens.remove(&msg.token_id);
+    }
+    vec![]
+}
+
+/// ## Description
+/// Sets or removes uri of the minted token. Can only be executed by owner account.
+/// Returns [`(MPC721ContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC721ContractState`]
+///
+/// * **msg** is an object of type [`SetTokenUriMsg`]
+pub fn execute_set_token_uri(
+    ctx: &ContractContext,
+    state: &mut MPC721ContractState,
+    msg: &SetTokenUriMsg,
+) -> Vec<EventGroup> {
+    assert!(
+        state.is_owner(&ctx.sender),
+        "{}",
+        ContractError::Unauthorized
+    );
+    assert!(state.is_minted(msg.token_id), "{}", ContractError::NotFound);
+
+    state.set_token_uri(msg.token_id, &msg.token_uri);
+    vec![]
+}
+
+/// ## Description
+/// Sets or removes on-chain attributes of the minted token. Can only be executed by owner account.
+/// Returns [`(MPC721ContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC721ContractState`]
+///
+/// * **msg** is an object of type [`SetTokenAttributesMsg`]
+pub fn execute_set_token_attributes(
+    ctx: &ContractContext,
+    state: &mut MPC721ContractState,
+    msg: &SetTokenAttributesMsg,
+) -> Vec<EventGroup> {
+    assert!(
+        state.is_owner(&ctx.sender),
+        "{}",
+        ContractError::Unauthorized
+    );
+    assert!(state.is_minted(msg.token_id), "{}", ContractError::NotFound);
+
+    state.set_token_attributes(msg.token_id, &msg.attributes);
+    vec![]
+}
+
+/// ## Description
+/// Permanently freezes metadata of the whole collection or of the minted token.
+/// Can only be executed by owner account.
+/// Returns [`(MPC721ContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC721ContractState`]
+///
+/// * **msg** is an object of type [`FreezeMetadataMsg`]
+pub fn execute_freeze_metadata(
+    ctx: &ContractContext,
+    state: &mut MPC721ContractState,
+    msg: &FreezeMetadataMsg,
+) -> Vec<EventGroup> {
+    assert!(
+        state.is_owner(&ctx.sender),
+        "{}",
+        ContractError::Unauthorized
+    );
+    if let Some(token_id) = msg.token_id {
+        assert!(state.is_minted(token_id), "{}", ContractError::NotFound);
+    }
+
+    state.freeze_metadata(msg.token_id);
+    vec![]
+}
+
+/// ## Description
+/// Grants usage rights of the token to the user until expiration, or removes them.
+/// Can be executed by token owner, approved account or operator.
+/// Usage rights are cleared when the token is transferred.
+/// Returns [`(MPC721ContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`MPC721ContractState`]
+///
+/// * **msg** is an object of type [`SetUserMsg`]
+pub fn execute_set_user(
+    ctx: &ContractContext,
+    state: &mut MPC721ContractState,
+    msg: &SetUserMsg,
+) -> Vec<EventGroup> {
+    assert!(state.is_minted(msg.token_id), "{}", ContractError::NotFound);
+
+    let user = msg.user.map(|user| {
+        assert!(
+            msg.expires_at > ctx.block_production_time as u64,
+            "{}",
+            ContractError::InvalidExpiration
+        );
+        TokenUser {
+            user,
+            expires_at: msg.expires_at,
+        }
+    });
+
+    state.set_user(&ctx.sender, msg.token_id, user);
+    vec![]
+}
+
+fn is_contract(address: &Address) -> bool {
+    !matches!(address.address_type, AddressType::Account)
+}
@@ -15,4 +15,49 @@ pub enum ContractError {
 
     #[error("Incorrect Owner")]
     IncorrectOwner,
+
+    #[error("Royalty basis points must not exceed 10000")]
+    InvalidRoyalty,
+
+    #[error("Minter public key is not set")]
+    MinterPublicKeyNotSet,
+
+    #[error("Invalid voucher signature")]
+    InvalidSignature,
+
+    #[error("Voucher has expired")]
+    VoucherExpired,
+
+    #[error("Voucher is already redeemed")]
+    VoucherRedeemed,
+
+    #[error("Amount must be greater than zero")]
+    ZeroAmount,
+
+    #[error("Max supply exceeded")]
+    MaxSupplyExceeded,
+
+    #[error("Mint phase must end after it starts")]
+    InvalidMintPhase,
+
+    #[error("Mint phase is not active")]
+    MintPhaseNotActive,
+
+    #[error("Mint phase wallet limit exceeded")]
+    WalletLimitExceeded,
+
+    #[error("Soulbound token can not be transferred")]
+    SoulboundTransfer,
+
+    #[error("Soulbound token can not be approved")]
+    SoulboundApproval,
+
+    #[error("Soulbound token can only be burned by minter or allowed holder")]
+    SoulboundBurn,
+
+    #[error("Metadata is frozen")]
+    MetadataFrozen,
+
+    #[error("Expiration must be in the future")]
+    InvalidExpiration,
 }
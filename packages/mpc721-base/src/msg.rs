@@ -1,10 +1,12 @@
 use create_type_spec_derive::CreateTypeSpec;
-use pbc_contract_common::address::{Address, Shortname};
+use pbc_contract_common::address::{Address, AddressType, Shortname};
 use read_write_rpc_derive::ReadWriteRPC;
 
 use rpc_msg_derive::IntoShortnameRPCEvent;
 use utils::events::IntoShortnameRPCEvent;
 
+use crate::state::{MintPhase, RoyaltyInfo, TokenAttribute, TokenUser};
+
 /// ## Description
 /// This structure describes fields for mpc721 initialize msg
 #[derive(ReadWriteRPC, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
@@ -142,3 +144,441 @@ pub struct MultiMintMsg {
     /// array of MintMsgs to mint multiple nfts
     pub mints: Vec<MintMsg>,
 }
+
+/// ## Description
+/// This structure describes fields for mpc721 set default royalty msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x21)]
+pub struct SetDefaultRoyaltyMsg {
+    /// new collection-wide royalty, None removes it
+    pub royalty: Option<RoyaltyInfo>,
+}
+
+/// ## Description
+/// This structure describes fields for mpc721 set token royalty msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x22)]
+pub struct SetTokenRoyaltyMsg {
+    /// token id
+    pub token_id: u128,
+    /// new token royalty, None removes it
+    pub royalty: Option<RoyaltyInfo>,
+}
+
+/// ## Description
+/// This structure describes fields for mpc721 royalty info msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x23)]
+pub struct RoyaltyInfoMsg {
+    /// token id
+    pub token_id: u128,
+    /// sale price
+    pub sale_price: u128,
+    /// action shortname of the calling contract which receives [`RoyaltyInfoReplyMsg`]
+    pub callback_action: u32,
+}
+
+/// ## Description
+/// This structure describes royalty information sent back to the calling contract
+#[derive(ReadWriteRPC, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct RoyaltyInfoReplyMsg {
+    /// token id
+    pub token_id: u128,
+    /// sale price
+    pub sale_price: u128,
+    /// optional royalty receiver address
+    pub receiver: Option<Address>,
+    /// royalty amount
+    pub royalty_amount: u128,
+}
+
+/// ## Description
+/// This structure describes fields for mpc721 safe transfer from msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x24)]
+pub struct SafeTransferFromMsg {
+    /// owner address
+    pub from: Address,
+    /// receiver address
+    pub to: Address,
+    /// token id
+    pub token_id: u128,
+    /// additional data passed to the receiver contract
+    pub data: Vec<u8>,
+}
+
+/// ## Description
+/// This structure describes fields of the msg sent to the receiver contract on safe transfer.
+/// Contracts accepting mpc721 tokens must implement action with shortname 0x50
+/// taking these fields, and fail if they can not handle received token
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x50)]
+pub struct OnMpc721ReceivedMsg {
+    /// address which initiated the transfer
+    pub operator: Address,
+    /// previous owner address
+    pub from: Address,
+    /// token id
+    pub token_id: u128,
+    /// additional data passed by the operator
+    pub data: Vec<u8>,
+}
+
+/// ## Description
+/// This structure describes safe transfer which is passed to the acknowledgement callback
+#[derive(ReadWriteRPC, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct SafeTransferCallbackMsg {
+    /// previous owner address
+    pub from: Address,
+    /// receiver contract address
+    pub to: Address,
+    /// token id
+    pub token_id: u128,
+    /// token approvals before the transfer
+    pub approvals: Vec<Address>,
+    /// token user before the transfer
+    pub user: Option<TokenUser>,
+}
+
+pub const ON_SAFE_TRANSFER_CALLBACK: u32 = 0x31;
+
+/// ## Description
+/// This structure describes fields for mpc721 set minter public key msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x25)]
+pub struct SetMinterPublicKeyMsg {
+    /// sec1 encoded secp256k1 public key
+    pub public_key: Vec<u8>,
+}
+
+/// ## Description
+/// This structure describes mint voucher signed by the minter off-chain
+#[derive(ReadWriteRPC, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct MintVoucher {
+    /// token id to mint
+    pub token_id: u128,
+    /// optional token uri
+    pub token_uri: Option<String>,
+    /// mpc20 token used for payment
+    pub currency: Address,
+    /// price of the token
+    pub price: u128,
+    /// optional address allowed to redeem the voucher, if None then anyone can redeem it
+    pub recipient: Option<Address>,
+    /// UTC timestamp
+    pub expires_at: u64,
+}
+
+/// ## Description
+/// This structure describes fields for mpc721 redeem voucher msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x26)]
+pub struct RedeemVoucherMsg {
+    /// voucher to redeem
+    pub voucher: MintVoucher,
+    /// 64 bytes ecdsa signature of the voucher payload
+    pub signature: Vec<u8>,
+}
+
+/// ## Description
+/// This structure describes voucher redemption which is passed to the payment callback
+#[derive(ReadWriteRPC, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct VoucherPaymentCallbackMsg {
+    /// digest of the redeemed voucher
+    pub voucher_digest: [u8; 32],
+    /// token id
+    pub token_id: u128,
+    /// voucher redeemer address
+    pub redeemer: Address,
+    /// mpc20 token used for payment
+    pub currency: Address,
+    /// paid price
+    pub price: u128,
+}
+
+pub const ON_VOUCHER_PAYMENT_CALLBACK: u32 = 0x33;
+
+/// ## Description
+/// This structure describes fields for mpc721 mint with sequential token id msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x27)]
+pub struct MintNextMsg {
+    /// receiver address
+    pub to: Address,
+    /// optional token uri
+    pub token_uri: Option<String>,
+    /// optional action shortname of the calling contract which receives [`MintNextReplyMsg`]
+    pub callback_action: Option<u32>,
+}
+
+/// ## Description
+/// This structure describes fields for mpc721 batch mint with sequential token ids msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x28)]
+pub struct MintNextNMsg {
+    /// receiver address
+    pub to: Address,
+    /// amount of tokens to mint
+    pub amount: u32,
+    /// optional action shortname of the calling contract which receives [`MintNextReplyMsg`]
+    pub callback_action: Option<u32>,
+}
+
+/// ## Description
+/// This structure describes token ids assigned by sequential minting sent back to the calling contract
+#[derive(ReadWriteRPC, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct MintNextReplyMsg {
+    /// receiver address
+    pub to: Address,
+    /// minted token ids
+    pub token_ids: Vec<u128>,
+}
+
+/// ## Description
+/// This structure describes fields for mpc721 set max supply msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x29)]
+pub struct SetMaxSupplyMsg {
+    /// optional maximum supply, None removes the limit
+    pub max_supply: Option<u128>,
+}
+
+/// ## Description
+/// This structure describes fields for mpc721 set mint phase msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x2A)]
+pub struct SetMintPhaseMsg {
+    /// phase id
+    pub phase_id: u32,
+    /// optional phase configuration, None removes the phase
+    pub phase: Option<MintPhase>,
+}
+
+/// ## Description
+/// This structure describes fields for mpc721 public mint msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x2B)]
+pub struct PublicMintMsg {
+    /// phase id
+    pub phase_id: u32,
+    /// amount of tokens to mint
+    pub amount: u32,
+    /// merkle proof of the sender address, required when phase has an allowlist
+    pub merkle_proof: Vec<String>,
+}
+
+/// ## Description
+/// This structure describes public mint which is passed to the payment callback
+#[derive(ReadWriteRPC, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct PhaseMintCallbackMsg {
+    /// phase id
+    pub phase_id: u32,
+    /// buyer address
+    pub buyer: Address,
+    /// token ids held by the contract until payment is received
+    pub token_ids: Vec<u128>,
+    /// mpc20 token used for payment
+    pub currency: Address,
+    /// total paid price
+    pub total_price: u128,
+}
+
+pub const ON_PHASE_MINT_PAYMENT_CALLBACK: u32 = 0x35;
+
+/// ## Description
+/// This structure describes fields for mpc721 set soulbound msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x2C)]
+pub struct SetSoulboundMsg {
+    /// whether all tokens of the collection are non-transferable
+    pub soulbound: bool,
+    /// whether holders are allowed to burn their soulbound tokens
+    pub holder_burn: bool,
+}
+
+/// ## Description
+/// This structure describes fields for mpc721 set token soulbound msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x2D)]
+pub struct SetTokenSoulboundMsg {
+    /// token id
+    pub token_id: u128,
+    /// whether token is non-transferable
+    pub soulbound: bool,
+}
+
+/// ## Description
+/// This structure describes fields for mpc721 set token uri msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x2E)]
+pub struct SetTokenUriMsg {
+    /// token id
+    pub token_id: u128,
+    /// new token uri, None falls back to base uri
+    pub token_uri: Option<String>,
+}
+
+/// ## Description
+/// This structure describes fields for mpc721 set token attributes msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x2F)]
+pub struct SetTokenAttributesMsg {
+    /// token id
+    pub token_id: u128,
+    /// attributes to set or remove
+    pub attributes: Vec<TokenAttribute>,
+}
+
+/// ## Description
+/// This structure describes fields for mpc721 freeze metadata msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x30)]
+pub struct FreezeMetadataMsg {
+    /// optional token id, None freezes the whole collection
+    pub token_id: Option<u128>,
+}
+
+/// ## Description
+/// This structure describes fields for mpc721 set user msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x32)]
+pub struct SetUserMsg {
+    /// token id
+    pub token_id: u128,
+    /// new user address, None removes the user
+    pub user: Option<Address>,
+    /// UTC timestamp when usage rights expire
+    pub expires_at: u64,
+}
+
+/// ## Description
+/// This structure describes fields for mpc721 consecutive batch mint msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x34)]
+pub struct MintConsecutiveMsg {
+    /// receiver address
+    pub to: Address,
+    /// amount of tokens to mint
+    pub amount: u32,
+}
+
+impl MintVoucher {
+    /// ## Description
+    /// Returns bytes which must be signed by the minter.
+    /// Payload is bound to the collection contract address
+    /// ## Params
+    /// * **contract** is an object of type [`Address`]
+    pub fn signing_payload(&self, contract: &Address) -> Vec<u8> {
+        let mut payload = vec![];
+        write_address(&mut payload, contract);
+        payload.extend_from_slice(&self.token_id.to_be_bytes());
+        match &self.token_uri {
+            Some(uri) => {
+                payload.push(1);
+                payload.extend_from_slice(&(uri.len() as u32).to_be_bytes());
+                payload.extend_from_slice(uri.as_bytes());
+            }
+            None => payload.push(0),
+        }
+        write_address(&mut payload, &self.currency);
+        payload.extend_from_slice(&self.price.to_be_bytes());
+        match &self.recipient {
+            Some(recipient) => {
+                payload.push(1);
+                write_address(&mut payload, recipient);
+            }
+            None => payload.push(0),
+        }
+        payload.extend_from_slice(&self.expires_at.to_be_bytes());
+
+        payload
+    }
+}
+
+pub(crate) fn write_address(buf: &mut Vec<u8>, address: &Address) {
+    let address_type: u8 = match address.address_type {
+        AddressType::Account => 0,
+        AddressType::SystemContract => 1,
+        AddressType::PublicContract => 2,
+        AddressType::ZkContract => 3,
+    };
+
+    buf.push(address_type);
+    buf.extend_from_slice(&address.identifier);
+}
+
+/// ## Description
+/// This structure describes fields for mpc721 balance of query msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x40)]
+pub struct BalanceOfMsg {
+    /// token holder address
+    pub owner: Address,
+    /// action shortname of the calling contract which receives [`BalanceOfReplyMsg`]
+    pub callback_action: u32,
+    /// opaque data echoed back in the reply, e.g. to match the reply with the query
+    pub context: Vec<u8>,
+}
+
+/// ## Description
+/// This structure describes amount of owned tokens sent back to the calling contract.
+/// Receiver must check that the reply is sent by the token contract
+#[derive(ReadWriteRPC, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct BalanceOfReplyMsg {
+    /// token holder address
+    pub owner: Address,
+    /// amount of tokens owned by the holder
+    pub balance: u128,
+    /// opaque data of the query
+    pub context: Vec<u8>,
+}
+
+/// ## Description
+/// This structure describes fields for mpc721 owner of query msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x41)]
+pub struct OwnerOfMsg {
+    /// token id
+    pub token_id: u128,
+    /// action shortname of the calling contract which receives [`OwnerOfReplyMsg`]
+    pub callback_action: u32,
+    /// opaque data echoed back in the reply, e.g. to match the reply with the query
+    pub context: Vec<u8>,
+}
+
+/// ## Description
+/// This structure describes token owner sent back to the calling contract.
+/// Receiver must check that the reply is sent by the token contract
+#[derive(ReadWriteRPC, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct OwnerOfReplyMsg {
+    /// token id
+    pub token_id: u128,
+    /// owner address, None if token is not minted
+    pub owner: Option<Address>,
+    /// opaque data of the query
+    pub context: Vec<u8>,
+}
+
+/// ## Description
+/// This structure describes fields for mpc721 total supply query msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x42)]
+pub struct TotalSupplyMsg {
+    /// action shortname of the calling contract which receives [`TotalSupplyReplyMsg`]
+    pub callback_action: u32,
+    /// opaque data echoed back in the reply, e.g. to match the reply with the query
+    pub context: Vec<u8>,
+}
+
+/// ## Description
+/// This structure describes total supply sent back to the calling contract.
+/// Receiver must check that the reply is sent by the token contract
+#[derive(ReadWriteRPC, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct TotalSupplyReplyMsg {
+    /// amount of existing tokens
+    pub total_supply: u128,
+    /// optional maximum supply
+    pub max_supply: Option<u128>,
+    /// opaque data of the query
+    pub context: Vec<u8>,
+}
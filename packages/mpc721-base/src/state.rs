@@ -1,10 +1,16 @@
-use std::collections::BTreeMap;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    ops::Bound,
+};
 
+use counter_base::state::CounterBase;
 use create_type_spec_derive::CreateTypeSpec;
 use pbc_contract_common::address::Address;
 use read_write_rpc_derive::ReadWriteRPC;
 use read_write_state_derive::ReadWriteState;
 
+use utils::merkle::validate_merkle_root;
+
 use crate::ContractError;
 
 /// ## Description
@@ -25,8 +31,48 @@ pub struct MPC721ContractState {
     pub supply: u128,
     /// token info by token id
     pub tokens: BTreeMap<u128, TokenInfo>,
+    /// token ids by owner address
+    pub owned_tokens: BTreeMap<Address, BTreeSet<u128>>,
     /// token approvals
     pub operator_approvals: BTreeMap<Address, BTreeMap<Address, bool>>,
+    /// optional collection-wide royalty
+    pub default_royalty: Option<RoyaltyInfo>,
+    /// royalties overriding the default one by token id
+    pub token_royalties: BTreeMap<u128, RoyaltyInfo>,
+    /// optional sec1 encoded public key used by minter to sign vouchers
+    pub minter_public_key: Option<Vec<u8>>,
+    /// digests of redeemed vouchers
+    pub redeemed_vouchers: BTreeSet<[u8; 32]>,
+    /// last token id assigned by sequential minting
+    pub token_id_counter: CounterBase,
+    /// optional maximum amount of tokens in circulation
+    pub max_supply: Option<u128>,
+    /// public mint phases by phase id
+    pub mint_phases: BTreeMap<u32, MintPhase>,
+    /// amount of tokens minted by address for every phase id
+    pub phase_mints: BTreeMap<u32, BTreeMap<Address, u32>>,
+    /// whether all tokens of the collection are non-transferable
+    pub soulbound: bool,
+    /// whether holders are allowed to burn their soulbound tokens
+    pub soulbound_holder_burn: bool,
+    /// non-transferable token ids
+    pub soulbound_tokens: BTreeSet<u128>,
+    /// on-chain attributes by token id
+    pub token_attributes: BTreeMap<u128, BTreeMap<String, String>>,
+    /// whether metadata of the whole collection is permanently frozen
+    pub metadata_frozen: bool,
+    /// token ids with permanently frozen metadata
+    pub frozen_tokens: BTreeSet<u128>,
+    /// ownership ranges of consecutively minted tokens by first token id
+    pub consecutive_ranges: BTreeMap<u128, ConsecutiveRange>,
+    /// amount of tokens held in ownership ranges by owner
+    pub range_balances: BTreeMap<Address, u128>,
+    /// first token ids of ownership ranges by owner
+    pub owner_ranges: BTreeMap<Address, BTreeSet<u128>>,
+    /// positional index of minted tokens as spans of consecutive token ids by first index
+    pub token_index: BTreeMap<u128, IndexSpan>,
+    /// first index of every span in the positional index by its first token id
+    pub index_positions: BTreeMap<u128, u128>,
 }
 
 /// ## Description
@@ -39,19 +85,173 @@ pub struct TokenInfo {
     pub approvals: Vec<Address>,
     /// optional token uri
     pub token_uri: Option<String>,
+    /// optional address allowed to use the token until expiration
+    pub user: Option<TokenUser>,
+}
+
+/// ## Description
+/// This structure describes address allowed to use mpc721 token until expiration
+#[derive(ReadWriteRPC, ReadWriteState, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct TokenUser {
+    /// user address
+    pub user: Address,
+    /// UTC timestamp when usage rights expire
+    pub expires_at: u64,
+}
+
+/// ## Description
+/// This structure describes ownership of consecutively minted tokens
+/// that were not transferred, approved or burned yet
+#[derive(ReadWriteState, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct ConsecutiveRange {
+    /// owner of every token in the range
+    pub owner: Address,
+    /// last token id in the range, inclusive
+    pub last_token_id: u128,
 }
 
+/// ## Description
+/// This structure describes consecutive token ids stored at consecutive indexes
+/// of the positional token index
+#[derive(ReadWriteState, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct IndexSpan {
+    /// token id at the first index of the span
+    pub first_token_id: u128,
+    /// token id at the last index of the span, inclusive
+    pub last_token_id: u128,
+}
+
+/// ## Description
+/// This structure describes royalty receiver and its share of the sale price
+#[derive(ReadWriteRPC, ReadWriteState, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct RoyaltyInfo {
+    /// royalty receiver address
+    pub receiver: Address,
+    /// royalty in basis points
+    pub basis_points: u16,
+}
+
+/// ## Description
+/// This structure describes public mint phase
+#[derive(ReadWriteRPC, ReadWriteState, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct MintPhase {
+    /// UTC timestamp when phase starts
+    pub start_time: u64,
+    /// UTC timestamp when phase ends
+    pub end_time: u64,
+    /// mpc20 token used for payment, e.g. wrapped MPC
+    pub currency: Address,
+    /// price of a single token
+    pub price: u128,
+    /// optional maximum amount of tokens minted by a single address
+    pub wallet_limit: Option<u32>,
+    /// optional hex encoded merkle root of allowed addresses
+    pub merkle_root: Option<String>,
+}
+
+/// ## Description
+/// This structure describes on-chain token attribute update
+#[derive(ReadWriteRPC, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct TokenAttribute {
+    /// attribute key
+    pub key: String,
+    /// attribute value, None removes the attribute
+    pub value: Option<String>,
+}
+
+/// ## Description
+/// Basis points representing 100% of the sale price
+pub const MAX_ROYALTY_BASIS_POINTS: u16 = 10_000;
+
 impl MPC721ContractState {
     /// ## Description
     /// Sets new base uri
     /// ## Params
     /// * **base_uri** is an object of type [`str`]
     pub fn set_base_uri(&mut self, base_uri: &str) {
+        assert!(!self.metadata_frozen, "{}", ContractError::MetadataFrozen);
         self.base_uri = Some(base_uri.to_string())
     }
 
     /// ## Description
-    /// Mints new token id to specified address
+    /// Sets or removes uri of the minted token
+    /// ## Params
+    /// * **token_id** is an object of type [`u128`]
+    ///
+    /// * **token_uri** is an object of type [`Option<String>`]
+    pub fn set_token_uri(&mut self, token_id: u128, token_uri: &Option<String>) {
+        self.assert_metadata_mutable(token_id);
+        self.split_range(token_id);
+        self.tokens
+            .entry(token_id)
+            .and_modify(|t| t.token_uri = token_uri.clone());
+    }
+
+    /// ## Description
+    /// Sets or removes on-chain attributes of the minted token
+    /// ## Params
+    /// * **token_id** is an object of type [`u128`]
+    ///
+    /// * **attributes** is an object of type [`[TokenAttribute]`]
+    pub fn set_token_attributes(&mut self, token_id: u128, attributes: &[TokenAttribute]) {
+        self.assert_metadata_mutable(token_id);
+
+        let token_attributes = self.token_attributes.entry(token_id).or_default();
+        for attribute in attributes {
+            match &attribute.value {
+                Some(value) => {
+                    token_attributes.insert(attribute.key.clone(), value.clone());
+                }
+                None => {
+                    token_attributes.remove(&attribute.key);
+                }
+            }
+        }
+
+        if token_attributes.is_empty() {
+            self.token_attributes.remove(&token_id);
+        }
+    }
+
+    /// ## Description
+    /// Permanently freezes metadata of the whole collection or of the single token
+    /// ## Params
+    /// * **token_id** is an object of type [`Option<u128>`]
+    pub fn freeze_metadata(&mut self, token_id: Option<u128>) {
+        match token_id {
+            Some(token_id) => {
+                self.frozen_tokens.insert(token_id);
+            }
+            None => self.metadata_frozen = true,
+        }
+    }
+
+    /// ## Description
+    /// Returns token uri, or base uri followed by token id if token uri is not set
+    /// ## Params
+    /// * **token_id** is an object of type [`u128`]
+    pub fn token_uri(&self, token_id: u128) -> Option<String> {
+        let token = self.token_info(token_id)?;
+        match &token.token_uri {
+            Some(token_uri) => Some(token_uri.clone()),
+            None => self
+                .base_uri
+                .as_ref()
+                .map(|base_uri| format!("{}{}", base_uri, token_id)),
+        }
+    }
+
+    fn assert_metadata_mutable(&self, token_id: u128) {
+        assert!(
+            !self.metadata_frozen && !self.frozen_tokens.contains(&token_id),
+            "{}",
+            ContractError::MetadataFrozen
+        );
+    }
+
+    /// ## Description
+    /// Mints new token id to specified address.
+    /// Sequential token id counter is moved to the token id if it is ahead of the counter
     /// ## Params
     /// * **token_id** is a field of type [`u128`]
     ///
@@ -59,19 +259,145 @@ impl MPC721ContractState {
     ///
     /// * **token_uri** is an object of type [`Option<String>`]
     pub fn mint(&mut self, token_id: u128, to: &Address, token_uri: &Option<String>) {
-        let token = TokenInfo {
-            owner: *to,
-            approvals: vec![],
-            token_uri: token_uri.clone(),
-        };
+        self.store_token(token_id, to, token_uri);
+        self.index_tokens(token_id, token_id);
+        if token_id > self.token_id_counter.current() {
+            self.token_id_counter.value = token_id;
+        }
+    }
 
-        self.tokens.insert(token_id, token);
+    /// ## Description
+    /// Mints specified amount of next sequential token ids to specified address
+    /// as a single ownership range. Returns first and last minted token ids
+    /// ## Params
+    /// * **to** is an object of type [`Address`]
+    ///
+    /// * **amount** is a field of type [`u128`]
+    pub fn mint_consecutive(&mut self, to: &Address, amount: u128) -> (u128, u128) {
+        let first_token_id = self.token_id_counter.current().checked_add(1).unwrap();
+        let last_token_id = first_token_id.checked_add(amount - 1).unwrap();
+
+        self.insert_range(first_token_id, to, last_token_id);
+        *self.range_balances.entry(*to).or_insert(0) += amount;
+        self.index_tokens(first_token_id, last_token_id);
+        self.token_id_counter.value = last_token_id;
+
+        (first_token_id, last_token_id)
+    }
+
+    /// ## Description
+    /// Returns next sequential token id. Counter is never behind minted token ids,
+    /// so the id is always unused
+    pub fn next_token_id(&mut self) -> u128 {
+        self.token_id_counter.increment();
+        self.token_id_counter.current()
     }
 
     /// ## Description
     /// Increases total supply
     pub fn increase_supply(&mut self) {
-        self.supply = self.supply.checked_add(1).unwrap()
+        self.increase_supply_by(1);
+    }
+
+    /// ## Description
+    /// Increases total supply by specified amount
+    /// ## Params
+    /// * **amount** is a field of type [`u128`]
+    pub fn increase_supply_by(&mut self, amount: u128) {
+        self.supply = self.supply.checked_add(amount).unwrap();
+        if let Some(max_supply) = self.max_supply {
+            assert!(
+                self.supply <= max_supply,
+                "{}",
+                ContractError::MaxSupplyExceeded
+            );
+        }
+    }
+
+    /// ## Description
+    /// Sets or removes maximum supply
+    /// ## Params
+    /// * **max_supply** is an object of type [`Option<u128>`]
+    pub fn set_max_supply(&mut self, max_supply: Option<u128>) {
+        if let Some(max_supply) = max_supply {
+            assert!(
+                max_supply >= self.supply,
+                "{}",
+                ContractError::MaxSupplyExceeded
+            );
+        }
+        self.max_supply = max_supply;
+    }
+
+    /// ## Description
+    /// Sets or removes public mint phase
+    /// ## Params
+    /// * **phase_id** is an object of type [`u32`]
+    ///
+    /// * **phase** is an object of type [`Option<MintPhase>`]
+    pub fn set_mint_phase(&mut self, phase_id: u32, phase: &Option<MintPhase>) {
+        match phase {
+            Some(phase) => {
+                assert!(
+                    phase.start_time < phase.end_time,
+                    "{}",
+                    ContractError::InvalidMintPhase
+                );
+                if let Some(merkle_root) = &phase.merkle_root {
+                    validate_merkle_root(merkle_root);
+                }
+                self.mint_phases.insert(phase_id, phase.clone());
+            }
+            None => {
+                self.mint_phases.remove(&phase_id);
+                self.phase_mints.remove(&phase_id);
+            }
+        }
+    }
+
+    /// ## Description
+    /// Records amount of tokens minted by address during the phase, checking wallet limit
+    /// ## Params
+    /// * **phase_id** is an object of type [`u32`]
+    ///
+    /// * **address** is an object of type [`Address`]
+    ///
+    /// * **amount** is an object of type [`u32`]
+    pub fn add_phase_mints(&mut self, phase_id: u32, address: &Address, amount: u32) {
+        let wallet_limit = self.mint_phases.get(&phase_id).unwrap().wallet_limit;
+        let minted = self
+            .phase_mints
+            .entry(phase_id)
+            .or_default()
+            .entry(*address)
+            .or_insert(0);
+        *minted = minted.checked_add(amount).unwrap();
+
+        if let Some(wallet_limit) = wallet_limit {
+            assert!(
+                *minted <= wallet_limit,
+                "{}",
+                ContractError::WalletLimitExceeded
+            );
+        }
+    }
+
+    /// ## Description
+    /// Reverts amount of tokens minted by address during the phase
+    /// ## Params
+    /// * **phase_id** is an object of type [`u32`]
+    ///
+    /// * **address** is an object of type [`Address`]
+    ///
+    /// * **amount** is an object of type [`u32`]
+    pub fn remove_phase_mints(&mut self, phase_id: u32, address: &Address, amount: u32) {
+        if let Some(minted) = self
+            .phase_mints
+            .get_mut(&phase_id)
+            .and_then(|mints| mints.get_mut(address))
+        {
+            *minted = minted.saturating_sub(amount);
+        }
     }
 
     /// ## Description
@@ -89,6 +415,7 @@ impl MPC721ContractState {
     ///
     /// * **token_id** is an object of type [`u128`]
     pub fn transfer(&mut self, from: &Address, to: &Address, token_id: u128) {
+        self.split_range(token_id);
         let token = self.tokens.get(&token_id).unwrap();
         assert!(
             Self::allowed_to_transfer(from, token, &self.operator_approvals),
@@ -96,10 +423,89 @@ impl MPC721ContractState {
             ContractError::Unauthorized
         );
 
+        let owner = token.owner;
         self.tokens.entry(token_id).and_modify(|t| {
             t.owner = *to;
             t.approvals = vec![];
+            t.user = None;
+        });
+
+        self.remove_owned_token(&owner, token_id);
+        self.add_owned_token(to, token_id);
+    }
+
+    /// ## Description
+    /// Returns token back to the previous owner with its approvals and user
+    /// if it was not moved after the transfer
+    /// ## Params
+    /// * **from** is an object of type [`Address`]
+    ///
+    /// * **to** is an object of type [`Address`]
+    ///
+    /// * **token_id** is an object of type [`u128`]
+    ///
+    /// * **approvals** is an object of type [`[Address]`]
+    ///
+    /// * **user** is an object of type [`Option<TokenUser>`]
+    pub fn revert_transfer(
+        &mut self,
+        from: &Address,
+        to: &Address,
+        token_id: u128,
+        approvals: &[Address],
+        user: &Option<TokenUser>,
+    ) {
+        let moved = self
+            .tokens
+            .get(&token_id)
+            .map(|t| t.owner != *to)
+            .unwrap_or(true);
+        if moved {
+            return;
+        }
+
+        self.tokens.entry(token_id).and_modify(|t| {
+            t.owner = *from;
+            t.approvals = approvals.to_vec();
+            t.user = user.clone();
         });
+
+        self.remove_owned_token(to, token_id);
+        self.add_owned_token(from, token_id);
+    }
+
+    /// ## Description
+    /// Grants or removes usage rights of the token
+    /// ## Params
+    /// * **from** is an object of type [`Address`]
+    ///
+    /// * **token_id** is an object of type [`u128`]
+    ///
+    /// * **user** is an object of type [`Option<TokenUser>`]
+    pub fn set_user(&mut self, from: &Address, token_id: u128, user: Option<TokenUser>) {
+        self.split_range(token_id);
+        let token = self.tokens.get(&token_id).unwrap();
+        assert!(
+            Self::allowed_to_transfer(from, token, &self.operator_approvals),
+            "{}",
+            ContractError::Unauthorized
+        );
+
+        self.tokens.entry(token_id).and_modify(|t| t.user = user);
+    }
+
+    /// ## Description
+    /// Returns address allowed to use the token at specified time
+    /// ## Params
+    /// * **token_id** is an object of type [`u128`]
+    ///
+    /// * **block_time** is an object of type [`u64`]
+    pub fn user_of(&self, token_id: u128, block_time: u64) -> Option<Address> {
+        self.tokens
+            .get(&token_id)
+            .and_then(|t| t.user.as_ref())
+            .filter(|user| user.expires_at > block_time)
+            .map(|user| user.user)
     }
 
     /// ## Description
@@ -119,6 +525,7 @@ impl MPC721ContractState {
         token_id: u128,
         approved: bool,
     ) {
+        self.split_range(token_id);
         let token = self.tokens.get(&token_id).unwrap().to_owned();
         assert!(
             Self::allowed_to_approve(from, &token, &self.operator_approvals),
@@ -182,6 +589,7 @@ impl MPC721ContractState {
     ///
     /// * **token_id** is an object of type [`u128`]
     pub fn remove_token(&mut self, owner: &Address, token_id: u128) {
+        self.split_range(token_id);
         let token = self.tokens.get(&token_id).unwrap();
         assert!(
             Self::allowed_to_transfer(owner, token, &self.operator_approvals),
@@ -189,7 +597,99 @@ impl MPC721ContractState {
             ContractError::Unauthorized
         );
 
+        let owner = token.owner;
         self.tokens.remove(&token_id);
+        self.token_royalties.remove(&token_id);
+        self.soulbound_tokens.remove(&token_id);
+        self.token_attributes.remove(&token_id);
+        self.frozen_tokens.remove(&token_id);
+        self.remove_owned_token(&owner, token_id);
+        self.unindex_token(token_id);
+    }
+
+    /// ## Description
+    /// Burns token held in escrow by the contract, unless it already left the escrow
+    /// ## Params
+    /// * **escrow** is an object of type [`Address`]
+    ///
+    /// * **token_id** is an object of type [`u128`]
+    pub fn burn_escrowed_token(&mut self, escrow: &Address, token_id: u128) {
+        if self.is_minted(token_id) && self.owner_of(token_id) == *escrow {
+            self.remove_token(escrow, token_id);
+            self.decrease_supply();
+        }
+    }
+
+    /// ## Description
+    /// Sets or removes collection-wide royalty
+    /// ## Params
+    /// * **royalty** is an object of type [`Option<RoyaltyInfo>`]
+    pub fn set_default_royalty(&mut self, royalty: &Option<RoyaltyInfo>) {
+        if let Some(royalty) = royalty {
+            Self::assert_valid_royalty(royalty);
+        }
+
+        self.default_royalty = royalty.clone();
+    }
+
+    /// ## Description
+    /// Sets or removes royalty for the specified token id
+    /// ## Params
+    /// * **token_id** is a field of type [`u128`]
+    ///
+    /// * **royalty** is an object of type [`Option<RoyaltyInfo>`]
+    pub fn set_token_royalty(&mut self, token_id: u128, royalty: &Option<RoyaltyInfo>) {
+        match royalty {
+            Some(royalty) => {
+                Self::assert_valid_royalty(royalty);
+                self.token_royalties.insert(token_id, royalty.clone());
+            }
+            None => {
+                self.token_royalties.remove(&token_id);
+            }
+        }
+    }
+
+    /// ## Description
+    /// Returns royalty receiver and royalty amount for the specified token id and sale price.
+    /// Token royalty takes precedence over the collection-wide one
+    /// ## Params
+    /// * **token_id** is a field of type [`u128`]
+    ///
+    /// * **sale_price** is a field of type [`u128`]
+    pub fn royalty_info(&self, token_id: u128, sale_price: u128) -> Option<(Address, u128)> {
+        self.token_royalties
+            .get(&token_id)
+            .or(self.default_royalty.as_ref())
+            .map(|royalty| {
+                let amount = sale_price
+                    .checked_mul(royalty.basis_points as u128)
+                    .unwrap()
+                    .checked_div(MAX_ROYALTY_BASIS_POINTS as u128)
+                    .unwrap();
+
+                (royalty.receiver, amount)
+            })
+    }
+
+    /// ## Description
+    /// Says is token id non-transferable or not
+    /// ## Params
+    /// * **token_id** is an object of type [`u128`]
+    pub fn is_soulbound(&self, token_id: u128) -> bool {
+        self.soulbound || self.soulbound_tokens.contains(&token_id)
+    }
+
+    /// ## Description
+    /// Checks that token id is not soulbound
+    /// ## Params
+    /// * **token_id** is an object of type [`u128`]
+    pub fn assert_transferable(&self, token_id: u128) {
+        assert!(
+            !self.is_soulbound(token_id),
+            "{}",
+            ContractError::SoulboundTransfer
+        );
     }
 
     /// ## Description
@@ -197,7 +697,7 @@ impl MPC721ContractState {
     /// ## Params
     /// * **token_id** is an object of type [`u128`]
     pub fn is_minted(&self, token_id: u128) -> bool {
-        self.tokens.contains_key(&token_id)
+        self.tokens.contains_key(&token_id) || self.range_of(token_id).is_some()
     }
 
     /// ## Description
@@ -216,8 +716,16 @@ impl MPC721ContractState {
     /// Returns token info by token id
     /// ## Params
     /// * **token_id** is an object of type [`u128`]
-    pub fn token_info(&self, token_id: u128) -> Option<&TokenInfo> {
-        self.tokens.get(&token_id)
+    pub fn token_info(&self, token_id: u128) -> Option<TokenInfo> {
+        match self.tokens.get(&token_id) {
+            Some(token) => Some(token.clone()),
+            None => self.range_of(token_id).map(|(_, range)| TokenInfo {
+                owner: range.owner,
+                approvals: vec![],
+                token_uri: None,
+                user: None,
+            }),
+        }
     }
 
     /// ## Description
@@ -225,11 +733,89 @@ impl MPC721ContractState {
     /// ## Params
     /// * **owner** is an object of type [`Address`]
     pub fn balance_of(&self, owner: &Address) -> u128 {
-        self.tokens
-            .values()
+        let owned = self
+            .owned_tokens
+            .get(owner)
+            .map(|token_ids| token_ids.len())
+            .unwrap_or(0) as u128;
+
+        owned + self.range_balances.get(owner).copied().unwrap_or(0)
+    }
+
+    /// ## Description
+    /// Returns token ids of the owner in ascending order
+    /// ## Params
+    /// * **owner** is an object of type [`Address`]
+    ///
+    /// * **start_after** is an object of type [`Option<u128>`]
+    ///
+    /// * **limit** is a field of type [`u32`]
+    pub fn tokens_of_owner(
+        &self,
+        owner: &Address,
+        start_after: Option<u128>,
+        limit: u32,
+    ) -> Vec<u128> {
+        let first_token_id = match start_after {
+            Some(token_id) => match token_id.checked_add(1) {
+                Some(first_token_id) => first_token_id,
+                None => return vec![],
+            },
+            None => 0,
+        };
+
+        let explicit = self
+            .owned_tokens
+            .get(owner)
+            .into_iter()
+            .flat_map(move |token_ids| token_ids.range(first_token_id..).copied());
+        let range_starts = self.owner_ranges.get(owner);
+        let ranges = range_starts
+            .and_then(|starts| starts.range(..first_token_id).next_back())
             .into_iter()
-            .filter(|ti| ti.owner == *owner)
-            .count() as u128
+            .chain(
+                range_starts
+                    .into_iter()
+                    .flat_map(move |starts| starts.range(first_token_id..)),
+            )
+            .map(|start| (*start, self.consecutive_ranges[start].last_token_id))
+            .filter(move |(_, last_token_id)| first_token_id <= *last_token_id)
+            .map(move |(start, last_token_id)| (start.max(first_token_id), last_token_id));
+
+        Self::collect_spans(Self::merge_spans(explicit, ranges), limit)
+    }
+
+    /// ## Description
+    /// Returns token id at the specified index of all minted tokens.
+    /// Burning a token moves the last indexed token to its index
+    /// ## Params
+    /// * **index** is a field of type [`u128`]
+    pub fn token_by_index(&self, index: u128) -> Option<u128> {
+        let (first_index, span) = self.token_index.range(..=index).next_back()?;
+        let token_id = span.first_token_id + (index - first_index);
+        if token_id <= span.last_token_id {
+            Some(token_id)
+        } else {
+            None
+        }
+    }
+
+    /// ## Description
+    /// Returns minted token ids in ascending order
+    /// ## Params
+    /// * **start_after** is an object of type [`Option<u128>`]
+    ///
+    /// * **limit** is a field of type [`u32`]
+    pub fn all_token_ids(&self, start_after: Option<u128>, limit: u32) -> Vec<u128> {
+        let first_token_id = match start_after {
+            Some(token_id) => match token_id.checked_add(1) {
+                Some(first_token_id) => first_token_id,
+                None => return vec![],
+            },
+            None => 0,
+        };
+
+        Self::collect_spans(self.minted_spans_from(first_token_id), limit)
     }
 
     /// ## Description
@@ -237,7 +823,213 @@ impl MPC721ContractState {
     /// ## Params
     /// * **token_id** is an object of type [`u128`]
     pub fn owner_of(&self, token_id: u128) -> Address {
-        self.tokens.get(&token_id).unwrap().owner
+        self.token_info(token_id).unwrap().owner
+    }
+
+    /// ## Description
+    /// Returns first token id and ownership range containing specified token id
+    /// ## Params
+    /// * **token_id** is an object of type [`u128`]
+    pub fn range_of(&self, token_id: u128) -> Option<(u128, &ConsecutiveRange)> {
+        self.consecutive_ranges
+            .range(..=token_id)
+            .next_back()
+            .filter(|(_, range)| token_id <= range.last_token_id)
+            .map(|(first_token_id, range)| (*first_token_id, range))
+    }
+
+    fn minted_spans_from(&self, token_id: u128) -> impl Iterator<Item = (u128, u128)> + '_ {
+        let explicit = self.tokens.range(token_id..).map(|(token_id, _)| *token_id);
+        let ranges = self
+            .range_of(token_id)
+            .map(|(_, range)| (token_id, range.last_token_id))
+            .into_iter()
+            .chain(
+                self.consecutive_ranges
+                    .range((Bound::Excluded(token_id), Bound::Unbounded))
+                    .map(|(first_token_id, range)| (*first_token_id, range.last_token_id)),
+            );
+
+        Self::merge_spans(explicit, ranges)
+    }
+
+    fn merge_spans<'a>(
+        explicit: impl Iterator<Item = u128> + 'a,
+        ranges: impl Iterator<Item = (u128, u128)> + 'a,
+    ) -> impl Iterator<Item = (u128, u128)> + 'a {
+        let mut explicit = explicit.peekable();
+        let mut ranges = ranges.peekable();
+
+        std::iter::from_fn(move || {
+            let next_range = ranges.peek().map(|(first_token_id, _)| *first_token_id);
+            match explicit.peek() {
+                Some(token_id) if next_range.iter().all(|first| token_id < first) => {
+                    explicit.next().map(|token_id| (token_id, token_id))
+                }
+                _ => ranges.next(),
+            }
+        })
+    }
+
+    fn collect_spans(spans: impl Iterator<Item = (u128, u128)>, limit: u32) -> Vec<u128> {
+        let mut token_ids = vec![];
+        for (first_token_id, last_token_id) in spans {
+            if token_ids.len() >= limit as usize {
+                break;
+            }
+            let remaining = limit as usize - token_ids.len();
+            token_ids.extend((first_token_id..=last_token_id).take(remaining));
+        }
+
+        token_ids
+    }
+
+    fn split_range(&mut self, token_id: u128) {
+        let (first_token_id, range) = match self.range_of(token_id) {
+            Some((first_token_id, range)) => (first_token_id, range.clone()),
+            None => return,
+        };
+
+        self.remove_range(first_token_id, &range.owner);
+        let range_balance = self.range_balances.get_mut(&range.owner).unwrap();
+        *range_balance -= 1;
+        if *range_balance == 0 {
+            self.range_balances.remove(&range.owner);
+        }
+        if first_token_id < token_id {
+            self.insert_range(first_token_id, &range.owner, token_id - 1);
+        }
+        if token_id < range.last_token_id {
+            self.insert_range(token_id + 1, &range.owner, range.last_token_id);
+        }
+
+        self.store_token(token_id, &range.owner, &None);
+    }
+
+    fn insert_range(&mut self, first_token_id: u128, owner: &Address, last_token_id: u128) {
+        self.consecutive_ranges.insert(
+            first_token_id,
+            ConsecutiveRange {
+                owner: *owner,
+                last_token_id,
+            },
+        );
+        self.owner_ranges
+            .entry(*owner)
+            .or_default()
+            .insert(first_token_id);
+    }
+
+    fn remove_range(&mut self, first_token_id: u128, owner: &Address) {
+        self.consecutive_ranges.remove(&first_token_id);
+        if let Some(range_starts) = self.owner_ranges.get_mut(owner) {
+            range_starts.remove(&first_token_id);
+
+            if range_starts.is_empty() {
+                self.owner_ranges.remove(owner);
+            }
+        }
+    }
+
+    fn store_token(&mut self, token_id: u128, to: &Address, token_uri: &Option<String>) {
+        let token = TokenInfo {
+            owner: *to,
+            approvals: vec![],
+            token_uri: token_uri.clone(),
+            user: None,
+        };
+
+        self.tokens.insert(token_id, token);
+        self.add_owned_token(to, token_id);
+    }
+
+    fn index_tokens(&mut self, first_token_id: u128, last_token_id: u128) {
+        match self.token_index.iter_mut().next_back() {
+            Some((_, span)) if span.last_token_id.checked_add(1) == Some(first_token_id) => {
+                span.last_token_id = last_token_id;
+            }
+            _ => {
+                let first_index = self.indexed_supply();
+                self.insert_index_span(first_index, first_token_id, last_token_id);
+            }
+        }
+    }
+
+    fn unindex_token(&mut self, token_id: u128) {
+        let (first_token_id, first_index) = self
+            .index_positions
+            .range(..=token_id)
+            .next_back()
+            .map(|(first_token_id, first_index)| (*first_token_id, *first_index))
+            .unwrap();
+        let span = self.remove_index_span(first_index);
+        let index = first_index + (token_id - first_token_id);
+        if first_token_id < token_id {
+            self.insert_index_span(first_index, first_token_id, token_id - 1);
+        }
+        if token_id < span.last_token_id {
+            self.insert_index_span(index + 1, token_id + 1, span.last_token_id);
+        }
+
+        let (last_first_index, last_span) = match self.token_index.iter().next_back() {
+            Some((first_index, span)) => (*first_index, span.clone()),
+            None => return,
+        };
+        if last_first_index + (last_span.last_token_id - last_span.first_token_id) < index {
+            return;
+        }
+
+        self.remove_index_span(last_first_index);
+        if last_span.first_token_id < last_span.last_token_id {
+            self.insert_index_span(
+                last_first_index,
+                last_span.first_token_id,
+                last_span.last_token_id - 1,
+            );
+        }
+        self.insert_index_span(index, last_span.last_token_id, last_span.last_token_id);
+    }
+
+    fn indexed_supply(&self) -> u128 {
+        self.token_index
+            .iter()
+            .next_back()
+            .map(|(first_index, span)| first_index + (span.last_token_id - span.first_token_id) + 1)
+            .unwrap_or(0)
+    }
+
+    fn insert_index_span(&mut self, first_index: u128, first_token_id: u128, last_token_id: u128) {
+        self.token_index.insert(
+            first_index,
+            IndexSpan {
+                first_token_id,
+                last_token_id,
+            },
+        );
+        self.index_positions.insert(first_token_id, first_index);
+    }
+
+    fn remove_index_span(&mut self, first_index: u128) -> IndexSpan {
+        let span = self.token_index.remove(&first_index).unwrap();
+        self.index_positions.remove(&span.first_token_id);
+        span
+    }
+
+    fn add_owned_token(&mut self, owner: &Address, token_id: u128) {
+        self.owned_tokens
+            .entry(*owner)
+            .or_default()
+            .insert(token_id);
+    }
+
+    fn remove_owned_token(&mut self, owner: &Address, token_id: u128) {
+        if let Some(token_ids) = self.owned_tokens.get_mut(owner) {
+            token_ids.remove(&token_id);
+
+            if token_ids.is_empty() {
+                self.owned_tokens.remove(owner);
+            }
+        }
     }
 
     fn allowed_to_transfer(
@@ -279,4 +1071,12 @@ impl MPC721ContractState {
 
         false
     }
+
+    fn assert_valid_royalty(royalty: &RoyaltyInfo) {
+        assert!(
+            royalty.basis_points <= MAX_ROYALTY_BASIS_POINTS,
+            "{}",
+            ContractError::InvalidRoyalty
+        );
+    }
 }
@@ -1,21 +1,48 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
+use counter_base::state::CounterBase;
+use k256::ecdsa::{signature::Signer, Signature, SigningKey};
+use mpc20_base::msg::{TransferFromMsg as Mpc20TransferFromMsg, TransferMsg as Mpc20TransferMsg};
 use pbc_contract_common::{
     address::{Address, AddressType},
-    context::ContractContext,
+    context::{CallbackContext, ContractContext},
+    events::EventGroup,
+};
+use utils::{
+    events::{build_msg_callback, build_msg_reply, IntoShortnameRPCEvent},
+    signature::sha256_digest,
 };
 
 use crate::{
     actions::{
-        execute_approve, execute_approve_for_all, execute_burn, execute_init, execute_mint,
-        execute_multi_mint, execute_ownership_check, execute_revoke, execute_revoke_for_all,
-        execute_set_base_uri, execute_transfer, execute_transfer_from, execute_update_minter,
+        execute_approve, execute_approve_for_all, execute_balance_of, execute_burn,
+        execute_freeze_metadata, execute_init, execute_mint, execute_mint_consecutive,
+        execute_mint_next, execute_mint_next_n, execute_multi_mint, execute_on_phase_mint_payment,
+        execute_on_safe_transfer, execute_on_voucher_payment, execute_owner_of,
+        execute_ownership_check, execute_public_mint, execute_redeem_voucher, execute_revoke,
+        execute_revoke_for_all, execute_royalty_info, execute_safe_transfer_from,
+        execute_set_base_uri, execute_set_default_royalty, execute_set_max_supply,
+        execute_set_mint_phase, execute_set_minter_public_key, execute_set_soulbound,
+        execute_set_token_attributes, execute_set_token_royalty, execute_set_token_soulbound,
+        execute_set_token_uri, execute_set_user, execute_total_supply, execute_transfer,
+        execute_transfer_from, execute_update_minter,
     },
     msg::{
-        ApproveForAllMsg, ApproveMsg, BurnMsg, CheckOwnerMsg, InitMsg, MintMsg, MultiMintMsg,
-        RevokeForAllMsg, RevokeMsg, SetBaseUriMsg, TransferFromMsg, TransferMsg, UpdateMinterMsg,
+        ApproveForAllMsg, ApproveMsg, BalanceOfMsg, BalanceOfReplyMsg, BurnMsg, CheckOwnerMsg,
+        FreezeMetadataMsg, InitMsg, MintConsecutiveMsg, MintMsg, MintNextMsg, MintNextNMsg,
+        MintNextReplyMsg, MintVoucher, MultiMintMsg, OnMpc721ReceivedMsg, OwnerOfMsg,
+        OwnerOfReplyMsg, PhaseMintCallbackMsg, PublicMintMsg, RedeemVoucherMsg, RevokeForAllMsg,
+        RevokeMsg, RoyaltyInfoMsg, RoyaltyInfoReplyMsg, SafeTransferCallbackMsg,
+        SafeTransferFromMsg, SetBaseUriMsg, SetDefaultRoyaltyMsg, SetMaxSupplyMsg, SetMintPhaseMsg,
+        SetMinterPublicKeyMsg, SetSoulboundMsg, SetTokenAttributesMsg, SetTokenRoyaltyMsg,
+        SetTokenSoulboundMsg, SetTokenUriMsg, SetUserMsg, TotalSupplyMsg, TotalSupplyReplyMsg,
+        TransferFromMsg, TransferMsg, UpdateMinterMsg, VoucherPaymentCallbackMsg,
+        ON_PHASE_MINT_PAYMENT_CALLBACK, ON_SAFE_TRANSFER_CALLBACK, ON_VOUCHER_PAYMENT_CALLBACK,
+    },
+    state::{
+        ConsecutiveRange, IndexSpan, MPC721ContractState, MintPhase, RoyaltyInfo, TokenAttribute,
+        TokenInfo, TokenUser,
     },
-    state::{MPC721ContractState, TokenInfo},
 };
 
 fn mock_address(le: u8) -> Address {
@@ -28,6 +55,23 @@ fn mock_address(le: u8) -> Address {
     }
 }
 
+fn mock_contract_address(le: u8) -> Address {
+    Address {
+        address_type: AddressType::PublicContract,
+        identifier: [
+            le, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8,
+        ],
+    }
+}
+
+fn mock_callback_context(success: bool) -> CallbackContext {
+    CallbackContext {
+        success,
+        results: vec![],
+    }
+}
+
 fn mock_contract_context(sender: u8) -> ContractContext {
     ContractContext {
         contract_address: mock_address(1u8),
@@ -67,7 +111,27 @@ fn proper_execute_init() {
             minter: mock_address(1),
             supply: 0,
             tokens: BTreeMap::new(),
+            owned_tokens: BTreeMap::new(),
             operator_approvals: BTreeMap::new(),
+            default_royalty: None,
+            token_royalties: BTreeMap::new(),
+            minter_public_key: None,
+            redeemed_vouchers: BTreeSet::new(),
+            token_id_counter: CounterBase::default(),
+            max_supply: None,
+            mint_phases: BTreeMap::new(),
+            phase_mints: BTreeMap::new(),
+            soulbound: false,
+            soulbound_holder_burn: false,
+            soulbound_tokens: BTreeSet::new(),
+            token_attributes: BTreeMap::new(),
+            metadata_frozen: false,
+            frozen_tokens: BTreeSet::new(),
+            consecutive_ranges: BTreeMap::new(),
+            range_balances: BTreeMap::new(),
+            owner_ranges: BTreeMap::new(),
+            token_index: BTreeMap::new(),
+            index_positions: BTreeMap::new(),
         }
     );
 }
@@ -165,11 +229,12 @@ fn proper_mint() {
 
     let token = state.token_info(1).unwrap();
     assert_eq!(
-        *token,
+        token,
         TokenInfo {
             owner: mock_address(alice),
             approvals: vec![],
             token_uri: None,
+            user: None,
         }
     );
 }
@@ -465,11 +530,12 @@ fn proper_token_owner_approve() {
 
     let _ = execute_approve(&mock_contract_context(alice), &mut state, &approve_msg);
     assert_eq!(
-        *state.token_info(1).unwrap(),
+        state.token_info(1).unwrap(),
         TokenInfo {
             owner: mock_address(alice),
             approvals: vec![mock_address(bob)],
             token_uri: None,
+            user: None,
         }
     );
 }
@@ -511,11 +577,12 @@ fn proper_token_operator_approve() {
 
     let _ = execute_approve(&mock_contract_context(bob), &mut state, &approve_msg);
     assert_eq!(
-        *state.token_info(1).unwrap(),
+        state.token_info(1).unwrap(),
         TokenInfo {
             owner: mock_address(alice),
             approvals: vec![mock_address(jack)],
             token_uri: None,
+            user: None,
         }
     );
 }
@@ -617,11 +684,12 @@ fn proper_revoke() {
 
     let _ = execute_revoke(&mock_contract_context(alice), &mut state, &revoke_msg);
     assert_eq!(
-        *state.token_info(1).unwrap(),
+        state.token_info(1).unwrap(),
         TokenInfo {
             owner: mock_address(alice),
             approvals: vec![],
             token_uri: None,
+            user: None,
         }
     );
 }
@@ -682,11 +750,12 @@ fn proper_owner_transfer() {
 
     let _ = execute_transfer(&mock_contract_context(alice), &mut state, &transfer_msg);
     assert_eq!(
-        *state.token_info(1).unwrap(),
+        state.token_info(1).unwrap(),
         TokenInfo {
             owner: mock_address(bob),
             approvals: vec![],
             token_uri: None,
+            user: None,
         }
     );
 }
@@ -729,11 +798,12 @@ fn proper_approved_transfer() {
 
     let _ = execute_transfer(&mock_contract_context(bob), &mut state, &transfer_msg);
     assert_eq!(
-        *state.token_info(1).unwrap(),
+        state.token_info(1).unwrap(),
         TokenInfo {
             owner: mock_address(bob),
             approvals: vec![],
             token_uri: None,
+            user: None,
         }
     );
 }
@@ -774,11 +844,12 @@ fn proper_operator_transfer() {
 
     let _ = execute_transfer(&mock_contract_context(bob), &mut state, &transfer_msg);
     assert_eq!(
-        *state.token_info(1).unwrap(),
+        state.token_info(1).unwrap(),
         TokenInfo {
             owner: mock_address(bob),
             approvals: vec![],
             token_uri: None,
+            user: None,
         }
     );
 }
@@ -874,11 +945,12 @@ fn proper_transfer_from() {
 
     let _ = execute_transfer_from(&mock_contract_context(alice), &mut state, &transfer_msg);
     assert_eq!(
-        *state.token_info(1).unwrap(),
+        state.token_info(1).unwrap(),
         TokenInfo {
             owner: mock_address(bob),
             approvals: vec![],
             token_uri: None,
+            user: None,
         }
     );
 }
@@ -937,6 +1009,8 @@ fn proper_burn() {
     let _ = execute_burn(&mock_contract_context(alice), &mut state, &burn_msg);
     assert_eq!(state.supply, 0);
     assert_eq!(state.is_minted(1), false);
+    assert_eq!(state.balance_of(&mock_address(alice)), 0);
+    assert_eq!(state.owned_tokens, BTreeMap::new());
 }
 
 #[test]
@@ -977,7 +1051,27 @@ fn test_multi_mint() {
         minter: mock_address(1),
         supply: 0,
         tokens: BTreeMap::new(),
+        owned_tokens: BTreeMap::new(),
         operator_approvals: BTreeMap::new(),
+        default_royalty: None,
+        token_royalties: BTreeMap::new(),
+        minter_public_key: None,
+        redeemed_vouchers: BTreeSet::new(),
+        token_id_counter: CounterBase::default(),
+        max_supply: None,
+        mint_phases: BTreeMap::new(),
+        phase_mints: BTreeMap::new(),
+        soulbound: false,
+        soulbound_holder_burn: false,
+        soulbound_tokens: BTreeSet::new(),
+        token_attributes: BTreeMap::new(),
+        metadata_frozen: false,
+        frozen_tokens: BTreeSet::new(),
+        consecutive_ranges: BTreeMap::new(),
+        range_balances: BTreeMap::new(),
+        owner_ranges: BTreeMap::new(),
+        token_index: BTreeMap::new(),
+        index_positions: BTreeMap::new(),
     };
     test_state.tokens.insert(
         1,
@@ -988,6 +1082,7 @@ fn test_multi_mint() {
             approvals: vec![],
             /// optional token uri
             token_uri: Some(String::from("Token1")),
+            user: None,
         },
     );
     test_state.tokens.insert(
@@ -999,6 +1094,7 @@ fn test_multi_mint() {
             approvals: vec![],
             /// optional token uri
             token_uri: Some(String::from("Token2")),
+            user: None,
         },
     );
     test_state.tokens.insert(
@@ -1010,6 +1106,7 @@ fn test_multi_mint() {
             approvals: vec![],
             /// optional token uri
             token_uri: Some(String::from("Token3")),
+            user: None,
         },
     );
     test_state.tokens.insert(
@@ -1021,6 +1118,7 @@ fn test_multi_mint() {
             approvals: vec![],
             /// optional token uri
             token_uri: Some(String::from("Token4")),
+            user: None,
         },
     );
     test_state.tokens.insert(
@@ -1032,8 +1130,23 @@ fn test_multi_mint() {
             approvals: vec![],
             /// optional token uri
             token_uri: Some(String::from("Token5")),
+            user: None,
         },
     );
+    test_state.owned_tokens = BTreeMap::from([
+        (mock_address(4), BTreeSet::from([1, 2])),
+        (mock_address(5), BTreeSet::from([3, 4])),
+        (mock_address(6), BTreeSet::from([5])),
+    ]);
+    test_state.token_index = BTreeMap::from([(
+        0,
+        IndexSpan {
+            first_token_id: 1,
+            last_token_id: 5,
+        },
+    )]);
+    test_state.index_positions = BTreeMap::from([(1, 0)]);
+    test_state.token_id_counter.value = 5;
     test_state.supply = 5;
     let mut state = state;
     let mint = vec![
@@ -1146,3 +1259,4044 @@ fn update_minter_fails_no_owner() {
         },
     );
 }
+
+#[test]
+fn proper_royalties() {
+    let owner = 1u8;
+    let minter = 2u8;
+    let alice = 10u8;
+    let creator = 11u8;
+    let artist = 12u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(owner)),
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let artist_royalty = RoyaltyInfo {
+        receiver: mock_address(artist),
+        basis_points: 1_000,
+    };
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 1,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+    let _ = execute_set_token_royalty(
+        &mock_contract_context(owner),
+        &mut state,
+        &SetTokenRoyaltyMsg {
+            token_id: 1,
+            royalty: Some(artist_royalty.clone()),
+        },
+    );
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 2,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+    assert_eq!(state.token_royalties, BTreeMap::from([(1, artist_royalty)]));
+    assert_eq!(
+        state.royalty_info(1, 1_000),
+        Some((mock_address(artist), 100))
+    );
+    assert_eq!(state.royalty_info(2, 1_000), None);
+
+    let _ = execute_set_default_royalty(
+        &mock_contract_context(owner),
+        &mut state,
+        &SetDefaultRoyaltyMsg {
+            royalty: Some(RoyaltyInfo {
+                receiver: mock_address(creator),
+                basis_points: 250,
+            }),
+        },
+    );
+    assert_eq!(
+        state.royalty_info(1, 1_000),
+        Some((mock_address(artist), 100))
+    );
+    assert_eq!(
+        state.royalty_info(2, 1_000),
+        Some((mock_address(creator), 25))
+    );
+
+    let _ = execute_set_token_royalty(
+        &mock_contract_context(owner),
+        &mut state,
+        &SetTokenRoyaltyMsg {
+            token_id: 1,
+            royalty: None,
+        },
+    );
+    assert_eq!(state.token_royalties, BTreeMap::new());
+    assert_eq!(
+        state.royalty_info(1, 1_000),
+        Some((mock_address(creator), 25))
+    );
+
+    let _ = execute_set_token_royalty(
+        &mock_contract_context(owner),
+        &mut state,
+        &SetTokenRoyaltyMsg {
+            token_id: 2,
+            royalty: Some(RoyaltyInfo {
+                receiver: mock_address(artist),
+                basis_points: 500,
+            }),
+        },
+    );
+    assert_eq!(
+        state.royalty_info(2, 1_000),
+        Some((mock_address(artist), 50))
+    );
+
+    let _ = execute_burn(
+        &mock_contract_context(alice),
+        &mut state,
+        &BurnMsg { token_id: 2 },
+    );
+    assert_eq!(state.token_royalties, BTreeMap::new());
+
+    let _ = execute_set_default_royalty(
+        &mock_contract_context(owner),
+        &mut state,
+        &SetDefaultRoyaltyMsg { royalty: None },
+    );
+    assert_eq!(state.default_royalty, None);
+    assert_eq!(state.royalty_info(1, 1_000), None);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn set_default_royalty_not_owner() {
+    let owner = 1u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(owner)),
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(1),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_set_default_royalty(
+        &mock_contract_context(alice),
+        &mut state,
+        &SetDefaultRoyaltyMsg {
+            royalty: Some(RoyaltyInfo {
+                receiver: mock_address(alice),
+                basis_points: 500,
+            }),
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Not found")]
+fn set_royalty_for_not_minted_token() {
+    let owner = 1u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(owner)),
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(1),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_set_token_royalty(
+        &mock_contract_context(owner),
+        &mut state,
+        &SetTokenRoyaltyMsg {
+            token_id: 1,
+            royalty: Some(RoyaltyInfo {
+                receiver: mock_address(owner),
+                basis_points: 500,
+            }),
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Royalty basis points must not exceed 10000")]
+fn set_too_high_token_royalty() {
+    let owner = 1u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(owner)),
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(owner),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_mint(
+        &mock_contract_context(owner),
+        &mut state,
+        &MintMsg {
+            token_id: 1,
+            to: mock_address(owner),
+            token_uri: None,
+        },
+    );
+    let _ = execute_set_token_royalty(
+        &mock_contract_context(owner),
+        &mut state,
+        &SetTokenRoyaltyMsg {
+            token_id: 1,
+            royalty: Some(RoyaltyInfo {
+                receiver: mock_address(owner),
+                basis_points: 10_001,
+            }),
+        },
+    );
+}
+
+#[test]
+fn proper_royalty_info_reply() {
+    let owner = 1u8;
+    let marketplace = 20u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(owner)),
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(owner),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_mint(
+        &mock_contract_context(owner),
+        &mut state,
+        &MintMsg {
+            token_id: 1,
+            to: mock_address(owner),
+            token_uri: None,
+        },
+    );
+    let _ = execute_set_token_royalty(
+        &mock_contract_context(owner),
+        &mut state,
+        &SetTokenRoyaltyMsg {
+            token_id: 1,
+            royalty: Some(RoyaltyInfo {
+                receiver: mock_address(owner),
+                basis_points: 750,
+            }),
+        },
+    );
+
+    let events = execute_royalty_info(
+        &mock_contract_context(marketplace),
+        &mut state,
+        &RoyaltyInfoMsg {
+            token_id: 1,
+            sale_price: 2_000,
+            callback_action: 0x41,
+        },
+    );
+
+    let mut event_group = EventGroup::builder();
+    build_msg_reply(
+        &mut event_group,
+        &mock_address(marketplace),
+        0x41,
+        &RoyaltyInfoReplyMsg {
+            token_id: 1,
+            sale_price: 2_000,
+            receiver: Some(mock_address(owner)),
+            royalty_amount: 150,
+        },
+    );
+    assert_eq!(events, vec![event_group.build()]);
+}
+
+#[test]
+fn proper_owner_index() {
+    let minter = 1u8;
+    let alice = 10u8;
+    let bob = 11u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    for token_id in [5, 1, 3, 2, 4] {
+        let _ = execute_mint(
+            &mock_contract_context(minter),
+            &mut state,
+            &MintMsg {
+                token_id,
+                to: mock_address(alice),
+                token_uri: None,
+            },
+        );
+    }
+    assert_eq!(state.balance_of(&mock_address(alice)), 5);
+    assert_eq!(state.balance_of(&mock_address(bob)), 0);
+    assert_eq!(state.all_token_ids(None, 100), vec![1, 2, 3, 4, 5]);
+    assert_eq!(state.token_by_index(0), Some(5));
+    assert_eq!(state.token_by_index(1), Some(1));
+    assert_eq!(state.token_by_index(4), Some(4));
+    assert_eq!(state.token_by_index(5), None);
+
+    assert_eq!(
+        state.tokens_of_owner(&mock_address(alice), None, 2),
+        vec![1, 2]
+    );
+    assert_eq!(
+        state.tokens_of_owner(&mock_address(alice), Some(2), 2),
+        vec![3, 4]
+    );
+    assert_eq!(
+        state.tokens_of_owner(&mock_address(alice), Some(4), 2),
+        vec![5]
+    );
+    assert_eq!(
+        state.tokens_of_owner(&mock_address(bob), None, 10),
+        Vec::<u128>::new()
+    );
+
+    let _ = execute_transfer(
+        &mock_contract_context(alice),
+        &mut state,
+        &TransferMsg {
+            to: mock_address(bob),
+            token_id: 3,
+        },
+    );
+    let _ = execute_approve_for_all(
+        &mock_contract_context(alice),
+        &mut state,
+        &ApproveForAllMsg {
+            operator: mock_address(bob),
+        },
+    );
+    let _ = execute_transfer_from(
+        &mock_contract_context(bob),
+        &mut state,
+        &TransferFromMsg {
+            from: mock_address(bob),
+            to: mock_address(bob),
+            token_id: 1,
+        },
+    );
+    assert_eq!(
+        state.owned_tokens,
+        BTreeMap::from([
+            (mock_address(alice), BTreeSet::from([2, 4, 5])),
+            (mock_address(bob), BTreeSet::from([1, 3])),
+        ])
+    );
+    assert_eq!(state.balance_of(&mock_address(alice)), 3);
+    assert_eq!(state.balance_of(&mock_address(bob)), 2);
+
+    for token_id in [2, 4, 5] {
+        let _ = execute_burn(
+            &mock_contract_context(alice),
+            &mut state,
+            &BurnMsg { token_id },
+        );
+    }
+    assert_eq!(
+        state.owned_tokens,
+        BTreeMap::from([(mock_address(bob), BTreeSet::from([1, 3]))])
+    );
+    assert_eq!(state.balance_of(&mock_address(alice)), 0);
+    assert_eq!(state.all_token_ids(None, 100), vec![1, 3]);
+    assert_eq!(state.token_by_index(0), Some(3));
+    assert_eq!(state.token_by_index(1), Some(1));
+    assert_eq!(state.token_by_index(2), None);
+}
+
+#[test]
+fn proper_safe_transfer_to_account() {
+    let minter = 1u8;
+    let alice = 10u8;
+    let bob = 11u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 1,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+
+    let events = execute_safe_transfer_from(
+        &mock_contract_context(alice),
+        &mut state,
+        &SafeTransferFromMsg {
+            from: mock_address(alice),
+            to: mock_address(bob),
+            token_id: 1,
+            data: vec![],
+        },
+    );
+    assert_eq!(events, vec![]);
+    assert_eq!(state.owner_of(1), mock_address(bob));
+}
+
+#[test]
+fn proper_safe_transfer_to_contract() {
+    let minter = 1u8;
+    let alice = 10u8;
+    let bob = 11u8;
+    let receiver = 20u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 1,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+    let _ = execute_approve(
+        &mock_contract_context(alice),
+        &mut state,
+        &ApproveMsg {
+            spender: mock_address(bob),
+            token_id: 1,
+        },
+    );
+
+    let events = execute_safe_transfer_from(
+        &mock_contract_context(bob),
+        &mut state,
+        &SafeTransferFromMsg {
+            from: mock_address(alice),
+            to: mock_contract_address(receiver),
+            token_id: 1,
+            data: vec![7u8],
+        },
+    );
+
+    let mut event_group = EventGroup::builder();
+    OnMpc721ReceivedMsg {
+        operator: mock_address(bob),
+        from: mock_address(alice),
+        token_id: 1,
+        data: vec![7u8],
+    }
+    .as_interaction(&mut event_group, &mock_contract_address(receiver));
+    build_msg_callback(
+        &mut event_group,
+        ON_SAFE_TRANSFER_CALLBACK,
+        &SafeTransferCallbackMsg {
+            from: mock_address(alice),
+            to: mock_contract_address(receiver),
+            token_id: 1,
+            approvals: vec![mock_address(bob)],
+            user: None,
+        },
+    );
+    assert_eq!(events, vec![event_group.build()]);
+    assert_eq!(state.owner_of(1), mock_contract_address(receiver));
+
+    let _ = execute_on_safe_transfer(
+        &mock_contract_context(1),
+        &mock_callback_context(true),
+        &mut state,
+        &SafeTransferCallbackMsg {
+            from: mock_address(alice),
+            to: mock_contract_address(receiver),
+            token_id: 1,
+            approvals: vec![mock_address(bob)],
+            user: None,
+        },
+    );
+    assert_eq!(state.owner_of(1), mock_contract_address(receiver));
+    assert_eq!(state.balance_of(&mock_contract_address(receiver)), 1);
+}
+
+#[test]
+fn safe_transfer_reverted_on_failed_acknowledgement() {
+    let minter = 1u8;
+    let alice = 10u8;
+    let bob = 11u8;
+    let receiver = 20u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 1,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+
+    let _ = execute_approve(
+        &mock_contract_context(alice),
+        &mut state,
+        &ApproveMsg {
+            spender: mock_address(bob),
+            token_id: 1,
+        },
+    );
+    let _ = execute_set_user(
+        &mock_contract_context(alice),
+        &mut state,
+        &SetUserMsg {
+            token_id: 1,
+            user: Some(mock_address(bob)),
+            expires_at: 200,
+        },
+    );
+
+    let _ = execute_safe_transfer_from(
+        &mock_contract_context(alice),
+        &mut state,
+        &SafeTransferFromMsg {
+            from: mock_address(alice),
+            to: mock_contract_address(receiver),
+            token_id: 1,
+            data: vec![],
+        },
+    );
+    assert_eq!(state.owner_of(1), mock_contract_address(receiver));
+
+    let _ = execute_on_safe_transfer(
+        &mock_contract_context(1),
+        &mock_callback_context(false),
+        &mut state,
+        &SafeTransferCallbackMsg {
+            from: mock_address(alice),
+            to: mock_contract_address(receiver),
+            token_id: 1,
+            approvals: vec![mock_address(bob)],
+            user: Some(TokenUser {
+                user: mock_address(bob),
+                expires_at: 200,
+            }),
+        },
+    );
+    assert_eq!(state.owner_of(1), mock_address(alice));
+    assert_eq!(
+        state.owned_tokens,
+        BTreeMap::from([(mock_address(alice), BTreeSet::from([1]))])
+    );
+    assert_eq!(
+        state.token_info(1).unwrap().approvals,
+        vec![mock_address(bob)]
+    );
+    assert_eq!(state.user_of(1, 100), Some(mock_address(bob)));
+}
+
+#[test]
+fn safe_transfer_not_reverted_when_token_moved() {
+    let minter = 1u8;
+    let alice = 10u8;
+    let bob = 11u8;
+    let receiver = 20u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 1,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+
+    let _ = execute_safe_transfer_from(
+        &mock_contract_context(alice),
+        &mut state,
+        &SafeTransferFromMsg {
+            from: mock_address(alice),
+            to: mock_contract_address(receiver),
+            token_id: 1,
+            data: vec![],
+        },
+    );
+    let mut receiver_ctx = mock_contract_context(receiver);
+    receiver_ctx.sender = mock_contract_address(receiver);
+    let _ = execute_transfer(
+        &receiver_ctx,
+        &mut state,
+        &TransferMsg {
+            to: mock_address(bob),
+            token_id: 1,
+        },
+    );
+    let _ = execute_on_safe_transfer(
+        &mock_contract_context(1),
+        &mock_callback_context(false),
+        &mut state,
+        &SafeTransferCallbackMsg {
+            from: mock_address(alice),
+            to: mock_contract_address(receiver),
+            token_id: 1,
+            approvals: vec![],
+            user: None,
+        },
+    );
+    assert_eq!(state.owner_of(1), mock_address(bob));
+}
+
+#[test]
+#[should_panic(expected = "Incorrect Owner")]
+fn safe_transfer_from_wrong_owner() {
+    let minter = 1u8;
+    let alice = 10u8;
+    let bob = 11u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 1,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+
+    let _ = execute_safe_transfer_from(
+        &mock_contract_context(bob),
+        &mut state,
+        &SafeTransferFromMsg {
+            from: mock_address(bob),
+            to: mock_address(bob),
+            token_id: 1,
+            data: vec![],
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn safe_transfer_from_not_approved() {
+    let minter = 1u8;
+    let alice = 10u8;
+    let bob = 11u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 1,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+
+    let _ = execute_safe_transfer_from(
+        &mock_contract_context(bob),
+        &mut state,
+        &SafeTransferFromMsg {
+            from: mock_address(alice),
+            to: mock_address(bob),
+            token_id: 1,
+            data: vec![],
+        },
+    );
+}
+
+fn mock_signing_key() -> SigningKey {
+    SigningKey::from_bytes(&[7u8; 32]).unwrap()
+}
+
+fn mock_voucher(token_id: u128, price: u128, recipient: Option<Address>) -> MintVoucher {
+    MintVoucher {
+        token_id,
+        token_uri: Some("1.json".to_string()),
+        currency: mock_contract_address(30),
+        price,
+        recipient,
+        expires_at: 200,
+    }
+}
+
+fn sign_voucher(signing_key: &SigningKey, voucher: &MintVoucher) -> RedeemVoucherMsg {
+    let signature: Signature = signing_key.sign(&voucher.signing_payload(&mock_address(1)));
+    RedeemVoucherMsg {
+        voucher: voucher.clone(),
+        signature: signature.as_ref().to_vec(),
+    }
+}
+
+#[test]
+fn proper_set_minter_public_key() {
+    let minter = 1u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_set_minter_public_key(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetMinterPublicKeyMsg {
+            public_key: mock_signing_key().verifying_key().to_bytes().to_vec(),
+        },
+    );
+    assert_eq!(
+        state.minter_public_key,
+        Some(mock_signing_key().verifying_key().to_bytes().to_vec())
+    );
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn set_minter_public_key_not_minter() {
+    let minter = 1u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_set_minter_public_key(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetMinterPublicKeyMsg {
+            public_key: mock_signing_key().verifying_key().to_bytes().to_vec(),
+        },
+    );
+    let _ = execute_set_minter_public_key(
+        &mock_contract_context(alice),
+        &mut state,
+        &SetMinterPublicKeyMsg {
+            public_key: vec![1u8; 33],
+        },
+    );
+}
+
+#[test]
+fn minter_public_key_cleared_on_update_minter() {
+    let minter = 1u8;
+    let owner = 2u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_set_minter_public_key(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetMinterPublicKeyMsg {
+            public_key: mock_signing_key().verifying_key().to_bytes().to_vec(),
+        },
+    );
+    state.owner = Some(mock_address(owner));
+    let _ = execute_update_minter(
+        &mock_contract_context(owner),
+        &mut state,
+        UpdateMinterMsg {
+            new_minter: mock_address(alice),
+        },
+    );
+    assert_eq!(state.minter_public_key, None);
+}
+
+#[test]
+fn proper_redeem_free_voucher() {
+    let minter = 1u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_set_minter_public_key(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetMinterPublicKeyMsg {
+            public_key: mock_signing_key().verifying_key().to_bytes().to_vec(),
+        },
+    );
+    let msg = sign_voucher(&mock_signing_key(), &mock_voucher(1, 0, None));
+
+    let events = execute_redeem_voucher(&mock_contract_context(alice), &mut state, &msg);
+    assert_eq!(events, vec![]);
+    assert_eq!(state.owner_of(1), mock_address(alice));
+    assert_eq!(state.supply, 1);
+    assert_eq!(
+        state.token_info(1).unwrap().token_uri,
+        Some("1.json".to_string())
+    );
+}
+
+#[test]
+fn proper_redeem_paid_voucher() {
+    let minter = 1u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_set_minter_public_key(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetMinterPublicKeyMsg {
+            public_key: mock_signing_key().verifying_key().to_bytes().to_vec(),
+        },
+    );
+    let voucher = mock_voucher(1, 100, Some(mock_address(alice)));
+    let msg = sign_voucher(&mock_signing_key(), &voucher);
+
+    let events = execute_redeem_voucher(&mock_contract_context(alice), &mut state, &msg);
+    assert_eq!(state.owner_of(1), mock_address(1));
+    assert_eq!(state.supply, 1);
+
+    let callback_msg = VoucherPaymentCallbackMsg {
+        voucher_digest: sha256_digest(&voucher.signing_payload(&mock_address(1))),
+        token_id: 1,
+        redeemer: mock_address(alice),
+        currency: mock_contract_address(30),
+        price: 100,
+    };
+
+    let mut event_group = EventGroup::builder();
+    Mpc20TransferFromMsg {
+        from: mock_address(alice),
+        to: mock_address(1),
+        amount: 100,
+    }
+    .as_interaction(&mut event_group, &mock_contract_address(30));
+    build_msg_callback(&mut event_group, ON_VOUCHER_PAYMENT_CALLBACK, &callback_msg);
+    assert_eq!(events, vec![event_group.build()]);
+
+    let events = execute_on_voucher_payment(
+        &mock_contract_context(1),
+        &mock_callback_context(true),
+        &mut state,
+        &callback_msg,
+    );
+    assert_eq!(state.owner_of(1), mock_address(alice));
+    assert_eq!(
+        state.owned_tokens,
+        BTreeMap::from([(mock_address(alice), BTreeSet::from([1]))])
+    );
+
+    let mut event_group = EventGroup::builder();
+    Mpc20TransferMsg {
+        to: mock_address(minter),
+        amount: 100,
+    }
+    .as_interaction(&mut event_group, &mock_contract_address(30));
+    assert_eq!(events, vec![event_group.build()]);
+}
+
+#[test]
+fn voucher_payment_failed() {
+    let minter = 1u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_set_minter_public_key(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetMinterPublicKeyMsg {
+            public_key: mock_signing_key().verifying_key().to_bytes().to_vec(),
+        },
+    );
+    let voucher = mock_voucher(1, 100, None);
+    let msg = sign_voucher(&mock_signing_key(), &voucher);
+
+    let _ = execute_redeem_voucher(&mock_contract_context(alice), &mut state, &msg);
+    let events = execute_on_voucher_payment(
+        &mock_contract_context(1),
+        &mock_callback_context(false),
+        &mut state,
+        &VoucherPaymentCallbackMsg {
+            voucher_digest: sha256_digest(&voucher.signing_payload(&mock_address(1))),
+            token_id: 1,
+            redeemer: mock_address(alice),
+            currency: mock_contract_address(30),
+            price: 100,
+        },
+    );
+    assert_eq!(events, vec![]);
+    assert!(!state.is_minted(1));
+    assert_eq!(state.supply, 0);
+    assert_eq!(state.owned_tokens, BTreeMap::new());
+    assert_eq!(state.redeemed_vouchers, BTreeSet::new());
+
+    let _ = execute_redeem_voucher(&mock_contract_context(alice), &mut state, &msg);
+    assert!(state.is_minted(1));
+}
+
+#[test]
+fn voucher_payment_failed_after_token_burned() {
+    let minter = 1u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_set_minter_public_key(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetMinterPublicKeyMsg {
+            public_key: mock_signing_key().verifying_key().to_bytes().to_vec(),
+        },
+    );
+    let voucher = mock_voucher(1, 100, None);
+    let msg = sign_voucher(&mock_signing_key(), &voucher);
+
+    let _ = execute_redeem_voucher(&mock_contract_context(alice), &mut state, &msg);
+    let _ = execute_burn(
+        &mock_contract_context(minter),
+        &mut state,
+        &BurnMsg { token_id: 1 },
+    );
+    let events = execute_on_voucher_payment(
+        &mock_contract_context(1),
+        &mock_callback_context(false),
+        &mut state,
+        &VoucherPaymentCallbackMsg {
+            voucher_digest: sha256_digest(&voucher.signing_payload(&mock_address(1))),
+            token_id: 1,
+            redeemer: mock_address(alice),
+            currency: mock_contract_address(30),
+            price: 100,
+        },
+    );
+    assert_eq!(events, vec![]);
+    assert!(!state.is_minted(1));
+    assert_eq!(state.supply, 0);
+    assert_eq!(state.redeemed_vouchers, BTreeSet::new());
+}
+
+#[test]
+#[should_panic(expected = "Voucher has expired")]
+fn redeem_expired_voucher() {
+    let minter = 1u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_set_minter_public_key(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetMinterPublicKeyMsg {
+            public_key: mock_signing_key().verifying_key().to_bytes().to_vec(),
+        },
+    );
+    let mut voucher = mock_voucher(1, 0, None);
+    voucher.expires_at = 100;
+    let msg = sign_voucher(&mock_signing_key(), &voucher);
+
+    let _ = execute_redeem_voucher(&mock_contract_context(alice), &mut state, &msg);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn redeem_voucher_wrong_recipient() {
+    let minter = 1u8;
+    let alice = 10u8;
+    let bob = 11u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_set_minter_public_key(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetMinterPublicKeyMsg {
+            public_key: mock_signing_key().verifying_key().to_bytes().to_vec(),
+        },
+    );
+    let msg = sign_voucher(
+        &mock_signing_key(),
+        &mock_voucher(1, 0, Some(mock_address(alice))),
+    );
+
+    let _ = execute_redeem_voucher(&mock_contract_context(bob), &mut state, &msg);
+}
+
+#[test]
+#[should_panic(expected = "Invalid voucher signature")]
+fn redeem_voucher_invalid_signature() {
+    let minter = 1u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_set_minter_public_key(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetMinterPublicKeyMsg {
+            public_key: mock_signing_key().verifying_key().to_bytes().to_vec(),
+        },
+    );
+    let signing_key = SigningKey::from_bytes(&[8u8; 32]).unwrap();
+    let msg = sign_voucher(&signing_key, &mock_voucher(1, 0, None));
+
+    let _ = execute_redeem_voucher(&mock_contract_context(alice), &mut state, &msg);
+}
+
+#[test]
+#[should_panic(expected = "Invalid voucher signature")]
+fn redeem_tampered_voucher() {
+    let minter = 1u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_set_minter_public_key(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetMinterPublicKeyMsg {
+            public_key: mock_signing_key().verifying_key().to_bytes().to_vec(),
+        },
+    );
+    let mut msg = sign_voucher(&mock_signing_key(), &mock_voucher(1, 100, None));
+    msg.voucher.price = 1;
+
+    let _ = execute_redeem_voucher(&mock_contract_context(alice), &mut state, &msg);
+}
+
+#[test]
+#[should_panic(expected = "Voucher is already redeemed")]
+fn redeem_voucher_twice() {
+    let minter = 1u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_set_minter_public_key(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetMinterPublicKeyMsg {
+            public_key: mock_signing_key().verifying_key().to_bytes().to_vec(),
+        },
+    );
+    let msg = sign_voucher(&mock_signing_key(), &mock_voucher(1, 0, None));
+
+    let _ = execute_redeem_voucher(&mock_contract_context(alice), &mut state, &msg);
+    let _ = execute_burn(
+        &mock_contract_context(alice),
+        &mut state,
+        &BurnMsg { token_id: 1 },
+    );
+    let _ = execute_redeem_voucher(&mock_contract_context(alice), &mut state, &msg);
+}
+
+#[test]
+#[should_panic(expected = "Token with specified id is already minted")]
+fn redeem_voucher_for_minted_token() {
+    let minter = 1u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 1,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+    let _ = execute_set_minter_public_key(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetMinterPublicKeyMsg {
+            public_key: mock_signing_key().verifying_key().to_bytes().to_vec(),
+        },
+    );
+    let msg = sign_voucher(&mock_signing_key(), &mock_voucher(1, 0, None));
+
+    let _ = execute_redeem_voucher(&mock_contract_context(alice), &mut state, &msg);
+}
+
+#[test]
+#[should_panic(expected = "Minter public key is not set")]
+fn redeem_voucher_public_key_not_set() {
+    let minter = 1u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 1,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+    let msg = sign_voucher(&mock_signing_key(), &mock_voucher(2, 0, None));
+
+    let _ = execute_redeem_voucher(&mock_contract_context(alice), &mut state, &msg);
+}
+
+#[test]
+fn proper_mint_next() {
+    let minter = 1u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 2,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+
+    let events = execute_mint_next(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintNextMsg {
+            to: mock_address(alice),
+            token_uri: Some("1.json".to_string()),
+            callback_action: None,
+        },
+    );
+    assert_eq!(events, vec![]);
+    assert_eq!(state.owner_of(3), mock_address(alice));
+    assert_eq!(
+        state.token_info(3).unwrap().token_uri,
+        Some("1.json".to_string())
+    );
+
+    let events = execute_mint_next(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintNextMsg {
+            to: mock_address(alice),
+            token_uri: None,
+            callback_action: Some(0x41),
+        },
+    );
+
+    let mut event_group = EventGroup::builder();
+    build_msg_reply(
+        &mut event_group,
+        &mock_address(minter),
+        0x41,
+        &MintNextReplyMsg {
+            to: mock_address(alice),
+            token_ids: vec![4],
+        },
+    );
+    assert_eq!(events, vec![event_group.build()]);
+    assert!(!state.is_minted(1));
+    assert_eq!(state.supply, 3);
+    assert_eq!(state.token_id_counter.current(), 4);
+}
+
+#[test]
+fn proper_mint_next_n() {
+    let minter = 1u8;
+    let alice = 10u8;
+    let bob = 11u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 3,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+
+    let events = execute_mint_next_n(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintNextNMsg {
+            to: mock_address(bob),
+            amount: 3,
+            callback_action: Some(0x41),
+        },
+    );
+
+    let mut event_group = EventGroup::builder();
+    build_msg_reply(
+        &mut event_group,
+        &mock_address(minter),
+        0x41,
+        &MintNextReplyMsg {
+            to: mock_address(bob),
+            token_ids: vec![4, 5, 6],
+        },
+    );
+    assert_eq!(events, vec![event_group.build()]);
+    assert_eq!(state.supply, 4);
+    assert_eq!(state.balance_of(&mock_address(bob)), 3);
+    assert_eq!(state.owner_of(4), mock_address(bob));
+
+    let events = execute_mint_next_n(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintNextNMsg {
+            to: mock_address(alice),
+            amount: 1,
+            callback_action: None,
+        },
+    );
+    assert_eq!(events, vec![]);
+    assert_eq!(state.owner_of(7), mock_address(alice));
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn mint_next_not_minter() {
+    let minter = 1u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 1,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+    let _ = execute_mint_next(
+        &mock_contract_context(alice),
+        &mut state,
+        &MintNextMsg {
+            to: mock_address(alice),
+            token_uri: None,
+            callback_action: None,
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Amount must be greater than zero")]
+fn mint_next_n_zero_amount() {
+    let minter = 1u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 1,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+    let _ = execute_mint_next_n(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintNextNMsg {
+            to: mock_address(alice),
+            amount: 0,
+            callback_action: None,
+        },
+    );
+}
+
+const ALLOWLIST_ROOT: &str = "86a353ec7619810056d9918816248103ecf54c247cef2079e99f04e516f31fca";
+const ALICE_PROOF: &str = "1509946fd11f4d7f7883545aebc7a04e67920dbb07337ec25a754f755b0c1ce2";
+
+fn mock_mint_phase(price: u128, wallet_limit: Option<u32>, allowlist: bool) -> MintPhase {
+    MintPhase {
+        start_time: 50,
+        end_time: 150,
+        currency: mock_contract_address(30),
+        price,
+        wallet_limit,
+        merkle_root: if allowlist {
+            Some(ALLOWLIST_ROOT.to_string())
+        } else {
+            None
+        },
+    }
+}
+
+#[test]
+fn proper_set_mint_phase() {
+    let minter = 1u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(2)),
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_set_mint_phase(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetMintPhaseMsg {
+            phase_id: 1,
+            phase: Some(mock_mint_phase(10, Some(2), true)),
+        },
+    );
+    assert_eq!(
+        state.mint_phases,
+        BTreeMap::from([(1, mock_mint_phase(10, Some(2), true))])
+    );
+
+    let _ = execute_set_mint_phase(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetMintPhaseMsg {
+            phase_id: 1,
+            phase: None,
+        },
+    );
+    assert_eq!(state.mint_phases, BTreeMap::new());
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn set_mint_phase_not_minter() {
+    let minter = 1u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(2)),
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_set_mint_phase(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetMintPhaseMsg {
+            phase_id: 1,
+            phase: Some(mock_mint_phase(0, None, false)),
+        },
+    );
+    let _ = execute_set_mint_phase(
+        &mock_contract_context(alice),
+        &mut state,
+        &SetMintPhaseMsg {
+            phase_id: 2,
+            phase: Some(mock_mint_phase(0, None, false)),
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Mint phase must end after it starts")]
+fn set_invalid_mint_phase() {
+    let minter = 1u8;
+
+    let mut phase = mock_mint_phase(0, None, false);
+    phase.end_time = phase.start_time;
+    let msg = InitMsg {
+        owner: Some(mock_address(2)),
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+    let _ = execute_set_mint_phase(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetMintPhaseMsg {
+            phase_id: 1,
+            phase: Some(phase),
+        },
+    );
+}
+
+#[test]
+fn proper_free_public_mint() {
+    let minter = 1u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(2)),
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_set_mint_phase(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetMintPhaseMsg {
+            phase_id: 1,
+            phase: Some(mock_mint_phase(0, Some(3), false)),
+        },
+    );
+
+    let events = execute_public_mint(
+        &mock_contract_context(alice),
+        &mut state,
+        &PublicMintMsg {
+            phase_id: 1,
+            amount: 2,
+            merkle_proof: vec![],
+        },
+    );
+    assert_eq!(events, vec![]);
+    assert_eq!(state.supply, 2);
+    assert_eq!(state.owner_of(1), mock_address(alice));
+    assert_eq!(state.owner_of(2), mock_address(alice));
+    assert_eq!(
+        state.phase_mints,
+        BTreeMap::from([(1, BTreeMap::from([(mock_address(alice), 2)]))])
+    );
+}
+
+#[test]
+fn proper_paid_public_mint() {
+    let minter = 1u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(2)),
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_set_mint_phase(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetMintPhaseMsg {
+            phase_id: 1,
+            phase: Some(mock_mint_phase(10, None, true)),
+        },
+    );
+
+    let events = execute_public_mint(
+        &mock_contract_context(alice),
+        &mut state,
+        &PublicMintMsg {
+            phase_id: 1,
+            amount: 2,
+            merkle_proof: vec![ALICE_PROOF.to_string()],
+        },
+    );
+    assert_eq!(state.owner_of(1), mock_address(1));
+    assert_eq!(state.owner_of(2), mock_address(1));
+
+    let callback_msg = PhaseMintCallbackMsg {
+        phase_id: 1,
+        buyer: mock_address(alice),
+        token_ids: vec![1, 2],
+        currency: mock_contract_address(30),
+        total_price: 20,
+    };
+
+    let mut event_group = EventGroup::builder();
+    Mpc20TransferFromMsg {
+        from: mock_address(alice),
+        to: mock_address(1),
+        amount: 20,
+    }
+    .as_interaction(&mut event_group, &mock_contract_address(30));
+    build_msg_callback(
+        &mut event_group,
+        ON_PHASE_MINT_PAYMENT_CALLBACK,
+        &callback_msg,
+    );
+    assert_eq!(events, vec![event_group.build()]);
+
+    let events = execute_on_phase_mint_payment(
+        &mock_contract_context(1),
+        &mock_callback_context(true),
+        &mut state,
+        &callback_msg,
+    );
+    assert_eq!(state.owner_of(1), mock_address(alice));
+    assert_eq!(state.owner_of(2), mock_address(alice));
+
+    let mut event_group = EventGroup::builder();
+    Mpc20TransferMsg {
+        to: mock_address(minter),
+        amount: 20,
+    }
+    .as_interaction(&mut event_group, &mock_contract_address(30));
+    assert_eq!(events, vec![event_group.build()]);
+}
+
+#[test]
+fn public_mint_payment_failed() {
+    let minter = 1u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(2)),
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_set_mint_phase(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetMintPhaseMsg {
+            phase_id: 1,
+            phase: Some(mock_mint_phase(10, Some(2), false)),
+        },
+    );
+
+    let _ = execute_public_mint(
+        &mock_contract_context(alice),
+        &mut state,
+        &PublicMintMsg {
+            phase_id: 1,
+            amount: 2,
+            merkle_proof: vec![],
+        },
+    );
+    let events = execute_on_phase_mint_payment(
+        &mock_contract_context(1),
+        &mock_callback_context(false),
+        &mut state,
+        &PhaseMintCallbackMsg {
+            phase_id: 1,
+            buyer: mock_address(alice),
+            token_ids: vec![1, 2],
+            currency: mock_contract_address(30),
+            total_price: 20,
+        },
+    );
+    assert_eq!(events, vec![]);
+    assert_eq!(state.supply, 0);
+    assert_eq!(state.tokens, BTreeMap::new());
+    assert_eq!(
+        state.phase_mints,
+        BTreeMap::from([(1, BTreeMap::from([(mock_address(alice), 0)]))])
+    );
+}
+
+#[test]
+fn public_mint_payment_failed_after_token_burned() {
+    let minter = 1u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(2)),
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_set_mint_phase(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetMintPhaseMsg {
+            phase_id: 1,
+            phase: Some(mock_mint_phase(10, Some(2), false)),
+        },
+    );
+
+    let _ = execute_public_mint(
+        &mock_contract_context(alice),
+        &mut state,
+        &PublicMintMsg {
+            phase_id: 1,
+            amount: 2,
+            merkle_proof: vec![],
+        },
+    );
+    let _ = execute_burn(
+        &mock_contract_context(minter),
+        &mut state,
+        &BurnMsg { token_id: 1 },
+    );
+    let events = execute_on_phase_mint_payment(
+        &mock_contract_context(1),
+        &mock_callback_context(false),
+        &mut state,
+        &PhaseMintCallbackMsg {
+            phase_id: 1,
+            buyer: mock_address(alice),
+            token_ids: vec![1, 2],
+            currency: mock_contract_address(30),
+            total_price: 20,
+        },
+    );
+    assert_eq!(events, vec![]);
+    assert_eq!(state.supply, 0);
+    assert_eq!(state.tokens, BTreeMap::new());
+    assert_eq!(
+        state.phase_mints,
+        BTreeMap::from([(1, BTreeMap::from([(mock_address(alice), 0)]))])
+    );
+}
+
+#[test]
+#[should_panic(expected = "Mint phase is not active")]
+fn public_mint_phase_not_active() {
+    let minter = 1u8;
+    let alice = 10u8;
+
+    let mut phase = mock_mint_phase(0, None, false);
+    phase.start_time = 101;
+    phase.end_time = 200;
+    let msg = InitMsg {
+        owner: Some(mock_address(2)),
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+    let _ = execute_set_mint_phase(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetMintPhaseMsg {
+            phase_id: 1,
+            phase: Some(phase),
+        },
+    );
+
+    let _ = execute_public_mint(
+        &mock_contract_context(alice),
+        &mut state,
+        &PublicMintMsg {
+            phase_id: 1,
+            amount: 1,
+            merkle_proof: vec![],
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Not found")]
+fn public_mint_phase_not_found() {
+    let minter = 1u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(2)),
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_set_mint_phase(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetMintPhaseMsg {
+            phase_id: 1,
+            phase: Some(mock_mint_phase(0, None, false)),
+        },
+    );
+    let _ = execute_public_mint(
+        &mock_contract_context(alice),
+        &mut state,
+        &PublicMintMsg {
+            phase_id: 2,
+            amount: 1,
+            merkle_proof: vec![],
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Mint phase wallet limit exceeded")]
+fn public_mint_wallet_limit_exceeded() {
+    let minter = 1u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(2)),
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_set_mint_phase(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetMintPhaseMsg {
+            phase_id: 1,
+            phase: Some(mock_mint_phase(0, Some(2), false)),
+        },
+    );
+    let _ = execute_public_mint(
+        &mock_contract_context(alice),
+        &mut state,
+        &PublicMintMsg {
+            phase_id: 1,
+            amount: 1,
+            merkle_proof: vec![],
+        },
+    );
+    let _ = execute_public_mint(
+        &mock_contract_context(alice),
+        &mut state,
+        &PublicMintMsg {
+            phase_id: 1,
+            amount: 2,
+            merkle_proof: vec![],
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Merkle verification failed")]
+fn public_mint_not_allowlisted() {
+    let minter = 1u8;
+    let carol = 12u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(2)),
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_set_mint_phase(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetMintPhaseMsg {
+            phase_id: 1,
+            phase: Some(mock_mint_phase(0, None, true)),
+        },
+    );
+    let _ = execute_public_mint(
+        &mock_contract_context(carol),
+        &mut state,
+        &PublicMintMsg {
+            phase_id: 1,
+            amount: 1,
+            merkle_proof: vec![ALICE_PROOF.to_string()],
+        },
+    );
+}
+
+#[test]
+fn proper_set_max_supply() {
+    let minter = 1u8;
+    let owner = 2u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(2)),
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_set_mint_phase(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetMintPhaseMsg {
+            phase_id: 1,
+            phase: Some(mock_mint_phase(0, None, false)),
+        },
+    );
+    let _ = execute_set_max_supply(
+        &mock_contract_context(owner),
+        &mut state,
+        &SetMaxSupplyMsg {
+            max_supply: Some(3),
+        },
+    );
+    assert_eq!(state.max_supply, Some(3));
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn set_max_supply_not_owner() {
+    let minter = 1u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(2)),
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_set_mint_phase(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetMintPhaseMsg {
+            phase_id: 1,
+            phase: Some(mock_mint_phase(0, None, false)),
+        },
+    );
+    let _ = execute_set_max_supply(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetMaxSupplyMsg {
+            max_supply: Some(3),
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Max supply exceeded")]
+fn public_mint_max_supply_exceeded() {
+    let minter = 1u8;
+    let owner = 2u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(2)),
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_set_mint_phase(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetMintPhaseMsg {
+            phase_id: 1,
+            phase: Some(mock_mint_phase(0, None, false)),
+        },
+    );
+    let _ = execute_set_max_supply(
+        &mock_contract_context(owner),
+        &mut state,
+        &SetMaxSupplyMsg {
+            max_supply: Some(3),
+        },
+    );
+    let _ = execute_public_mint(
+        &mock_contract_context(alice),
+        &mut state,
+        &PublicMintMsg {
+            phase_id: 1,
+            amount: 4,
+            merkle_proof: vec![],
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Max supply exceeded")]
+fn mint_max_supply_exceeded() {
+    let minter = 1u8;
+    let owner = 2u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(2)),
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_set_mint_phase(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetMintPhaseMsg {
+            phase_id: 1,
+            phase: Some(mock_mint_phase(0, None, false)),
+        },
+    );
+    let _ = execute_set_max_supply(
+        &mock_contract_context(owner),
+        &mut state,
+        &SetMaxSupplyMsg {
+            max_supply: Some(0),
+        },
+    );
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 1,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+}
+
+#[test]
+fn proper_set_soulbound() {
+    let minter = 1u8;
+    let alice = 10u8;
+    let bob = 11u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 1,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+    let _ = execute_set_soulbound(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetSoulboundMsg {
+            soulbound: true,
+            holder_burn: true,
+        },
+    );
+    assert!(state.soulbound);
+    assert!(state.soulbound_holder_burn);
+    assert!(state.is_soulbound(1));
+    assert!(state.is_soulbound(2));
+
+    let _ = execute_set_soulbound(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetSoulboundMsg {
+            soulbound: false,
+            holder_burn: false,
+        },
+    );
+    let _ = execute_transfer(
+        &mock_contract_context(alice),
+        &mut state,
+        &TransferMsg {
+            to: mock_address(bob),
+            token_id: 1,
+        },
+    );
+    assert_eq!(state.owner_of(1), mock_address(bob));
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn set_soulbound_not_minter() {
+    let minter = 1u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 1,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+    let _ = execute_set_soulbound(
+        &mock_contract_context(alice),
+        &mut state,
+        &SetSoulboundMsg {
+            soulbound: true,
+            holder_burn: false,
+        },
+    );
+}
+
+#[test]
+fn proper_set_token_soulbound() {
+    let minter = 1u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 1,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+
+    let _ = execute_set_token_soulbound(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetTokenSoulboundMsg {
+            token_id: 1,
+            soulbound: true,
+        },
+    );
+    assert_eq!(state.soulbound_tokens, BTreeSet::from([1]));
+    assert!(!state.is_soulbound(2));
+
+    let _ = execute_set_token_soulbound(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetTokenSoulboundMsg {
+            token_id: 1,
+            soulbound: false,
+        },
+    );
+    assert_eq!(state.soulbound_tokens, BTreeSet::new());
+}
+
+#[test]
+#[should_panic(expected = "Soulbound token can not be transferred")]
+fn transfer_soulbound_token() {
+    let minter = 1u8;
+    let alice = 10u8;
+    let bob = 11u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 1,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+
+    let _ = execute_set_token_soulbound(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetTokenSoulboundMsg {
+            token_id: 1,
+            soulbound: true,
+        },
+    );
+    let _ = execute_transfer(
+        &mock_contract_context(alice),
+        &mut state,
+        &TransferMsg {
+            to: mock_address(bob),
+            token_id: 1,
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Soulbound token can not be transferred")]
+fn transfer_from_soulbound_token() {
+    let minter = 1u8;
+    let alice = 10u8;
+    let bob = 11u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 1,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+
+    let _ = execute_set_token_soulbound(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetTokenSoulboundMsg {
+            token_id: 1,
+            soulbound: true,
+        },
+    );
+    let _ = execute_transfer_from(
+        &mock_contract_context(alice),
+        &mut state,
+        &TransferFromMsg {
+            from: mock_address(alice),
+            to: mock_address(bob),
+            token_id: 1,
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Soulbound token can not be transferred")]
+fn safe_transfer_soulbound_token() {
+    let minter = 1u8;
+    let alice = 10u8;
+    let bob = 11u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 1,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+
+    let _ = execute_set_token_soulbound(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetTokenSoulboundMsg {
+            token_id: 1,
+            soulbound: true,
+        },
+    );
+    let _ = execute_safe_transfer_from(
+        &mock_contract_context(alice),
+        &mut state,
+        &SafeTransferFromMsg {
+            from: mock_address(alice),
+            to: mock_address(bob),
+            token_id: 1,
+            data: vec![],
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Soulbound token can not be approved")]
+fn approve_soulbound_token() {
+    let minter = 1u8;
+    let alice = 10u8;
+    let bob = 11u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 1,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+
+    let _ = execute_set_token_soulbound(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetTokenSoulboundMsg {
+            token_id: 1,
+            soulbound: true,
+        },
+    );
+    let _ = execute_approve(
+        &mock_contract_context(alice),
+        &mut state,
+        &ApproveMsg {
+            spender: mock_address(bob),
+            token_id: 1,
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Soulbound token can not be approved")]
+fn approve_for_all_soulbound_collection() {
+    let minter = 1u8;
+    let alice = 10u8;
+    let bob = 11u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 1,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+    let _ = execute_set_soulbound(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetSoulboundMsg {
+            soulbound: true,
+            holder_burn: false,
+        },
+    );
+    let _ = execute_approve_for_all(
+        &mock_contract_context(alice),
+        &mut state,
+        &ApproveForAllMsg {
+            operator: mock_address(bob),
+        },
+    );
+}
+
+#[test]
+fn minter_burns_soulbound_token() {
+    let minter = 1u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 1,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+
+    let _ = execute_set_token_soulbound(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetTokenSoulboundMsg {
+            token_id: 1,
+            soulbound: true,
+        },
+    );
+    let _ = execute_burn(
+        &mock_contract_context(minter),
+        &mut state,
+        &BurnMsg { token_id: 1 },
+    );
+    assert!(!state.is_minted(1));
+    assert_eq!(state.supply, 0);
+    assert_eq!(state.soulbound_tokens, BTreeSet::new());
+    assert_eq!(state.balance_of(&mock_address(alice)), 0);
+}
+
+#[test]
+fn holder_burns_soulbound_token() {
+    let minter = 1u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 1,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+
+    // allow holder burns without making the whole collection soulbound
+    let _ = execute_set_soulbound(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetSoulboundMsg {
+            soulbound: false,
+            holder_burn: true,
+        },
+    );
+
+    let _ = execute_set_token_soulbound(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetTokenSoulboundMsg {
+            token_id: 1,
+            soulbound: true,
+        },
+    );
+    let _ = execute_burn(
+        &mock_contract_context(alice),
+        &mut state,
+        &BurnMsg { token_id: 1 },
+    );
+    assert!(!state.is_minted(1));
+}
+
+#[test]
+#[should_panic(expected = "Soulbound token can only be burned by minter or allowed holder")]
+fn holder_burn_soulbound_token_not_allowed() {
+    let minter = 1u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 1,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+
+    let _ = execute_set_token_soulbound(
+        &mock_contract_context(minter),
+        &mut state,
+        &SetTokenSoulboundMsg {
+            token_id: 1,
+            soulbound: true,
+        },
+    );
+    let _ = execute_burn(
+        &mock_contract_context(alice),
+        &mut state,
+        &BurnMsg { token_id: 1 },
+    );
+}
+
+#[test]
+fn proper_token_uri() {
+    let owner = 2u8;
+    let minter = 1u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(owner)),
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: Some("ipfs://base/".to_string()),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 1,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 2,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+    assert_eq!(state.token_uri(1), Some("ipfs://base/1".to_string()));
+    assert_eq!(state.token_uri(3), None);
+
+    let _ = execute_set_token_uri(
+        &mock_contract_context(owner),
+        &mut state,
+        &SetTokenUriMsg {
+            token_id: 1,
+            token_uri: Some("ipfs://custom/1.json".to_string()),
+        },
+    );
+    assert_eq!(state.token_uri(1), Some("ipfs://custom/1.json".to_string()));
+    assert_eq!(state.token_uri(2), Some("ipfs://base/2".to_string()));
+
+    state.base_uri = None;
+    assert_eq!(state.token_uri(2), None);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn set_token_uri_not_owner() {
+    let owner = 2u8;
+    let minter = 1u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(owner)),
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: Some("ipfs://base/".to_string()),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 1,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 2,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+    let _ = execute_set_token_uri(
+        &mock_contract_context(alice),
+        &mut state,
+        &SetTokenUriMsg {
+            token_id: 1,
+            token_uri: None,
+        },
+    );
+}
+
+#[test]
+fn proper_set_token_attributes() {
+    let owner = 2u8;
+    let minter = 1u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(owner)),
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: Some("ipfs://base/".to_string()),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 1,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 2,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+    let _ = execute_set_token_attributes(
+        &mock_contract_context(owner),
+        &mut state,
+        &SetTokenAttributesMsg {
+            token_id: 1,
+            attributes: vec![
+                TokenAttribute {
+                    key: "color".to_string(),
+                    value: Some("red".to_string()),
+                },
+                TokenAttribute {
+                    key: "level".to_string(),
+                    value: Some("1".to_string()),
+                },
+            ],
+        },
+    );
+    assert_eq!(
+        state.token_attributes,
+        BTreeMap::from([(
+            1,
+            BTreeMap::from([
+                ("color".to_string(), "red".to_string()),
+                ("level".to_string(), "1".to_string()),
+            ])
+        )])
+    );
+
+    let _ = execute_set_token_attributes(
+        &mock_contract_context(owner),
+        &mut state,
+        &SetTokenAttributesMsg {
+            token_id: 1,
+            attributes: vec![
+                TokenAttribute {
+                    key: "color".to_string(),
+                    value: None,
+                },
+                TokenAttribute {
+                    key: "level".to_string(),
+                    value: Some("2".to_string()),
+                },
+            ],
+        },
+    );
+    assert_eq!(
+        state.token_attributes,
+        BTreeMap::from([(1, BTreeMap::from([("level".to_string(), "2".to_string())]))])
+    );
+
+    let _ = execute_burn(
+        &mock_contract_context(alice),
+        &mut state,
+        &BurnMsg { token_id: 1 },
+    );
+    assert_eq!(state.token_attributes, BTreeMap::new());
+}
+
+#[test]
+#[should_panic(expected = "Not found")]
+fn set_attributes_not_minted_token() {
+    let owner = 2u8;
+    let minter = 1u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(owner)),
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: Some("ipfs://base/".to_string()),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 1,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 2,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+    let _ = execute_set_token_attributes(
+        &mock_contract_context(owner),
+        &mut state,
+        &SetTokenAttributesMsg {
+            token_id: 3,
+            attributes: vec![],
+        },
+    );
+}
+
+#[test]
+fn proper_freeze_token_metadata() {
+    let owner = 2u8;
+    let minter = 1u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(owner)),
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: Some("ipfs://base/".to_string()),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 1,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 2,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+    let _ = execute_freeze_metadata(
+        &mock_contract_context(owner),
+        &mut state,
+        &FreezeMetadataMsg { token_id: Some(1) },
+    );
+    assert_eq!(state.frozen_tokens, BTreeSet::from([1]));
+
+    let _ = execute_set_token_uri(
+        &mock_contract_context(owner),
+        &mut state,
+        &SetTokenUriMsg {
+            token_id: 2,
+            token_uri: Some("2.json".to_string()),
+        },
+    );
+    assert_eq!(state.token_uri(2), Some("2.json".to_string()));
+}
+
+#[test]
+#[should_panic(expected = "Metadata is frozen")]
+fn set_token_uri_frozen_token() {
+    let owner = 2u8;
+    let minter = 1u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(owner)),
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: Some("ipfs://base/".to_string()),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 1,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 2,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+    let _ = execute_freeze_metadata(
+        &mock_contract_context(owner),
+        &mut state,
+        &FreezeMetadataMsg { token_id: Some(1) },
+    );
+    let _ = execute_set_token_uri(
+        &mock_contract_context(owner),
+        &mut state,
+        &SetTokenUriMsg {
+            token_id: 1,
+            token_uri: None,
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Metadata is frozen")]
+fn set_attributes_frozen_collection() {
+    let owner = 2u8;
+    let minter = 1u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(owner)),
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: Some("ipfs://base/".to_string()),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 1,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 2,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+    let _ = execute_freeze_metadata(
+        &mock_contract_context(owner),
+        &mut state,
+        &FreezeMetadataMsg { token_id: None },
+    );
+    let _ = execute_set_token_attributes(
+        &mock_contract_context(owner),
+        &mut state,
+        &SetTokenAttributesMsg {
+            token_id: 2,
+            attributes: vec![],
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Metadata is frozen")]
+fn set_base_uri_frozen_collection() {
+    let owner = 2u8;
+    let minter = 1u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(owner)),
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: Some("ipfs://base/".to_string()),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 1,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 2,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+    let _ = execute_freeze_metadata(
+        &mock_contract_context(owner),
+        &mut state,
+        &FreezeMetadataMsg { token_id: None },
+    );
+    let _ = execute_set_base_uri(
+        &mock_contract_context(owner),
+        &mut state,
+        &SetBaseUriMsg {
+            new_base_uri: "ipfs://new/".to_string(),
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn freeze_metadata_not_owner() {
+    let owner = 2u8;
+    let minter = 1u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: Some(mock_address(owner)),
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: Some("ipfs://base/".to_string()),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 1,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 2,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+    let _ = execute_freeze_metadata(
+        &mock_contract_context(alice),
+        &mut state,
+        &FreezeMetadataMsg { token_id: None },
+    );
+}
+
+#[test]
+fn proper_set_user() {
+    let minter = 1u8;
+    let alice = 10u8;
+    let bob = 11u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 1,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+    assert_eq!(state.user_of(1, 100), None);
+
+    let _ = execute_set_user(
+        &mock_contract_context(alice),
+        &mut state,
+        &SetUserMsg {
+            token_id: 1,
+            user: Some(mock_address(bob)),
+            expires_at: 200,
+        },
+    );
+    assert_eq!(
+        state.token_info(1).unwrap().user,
+        Some(TokenUser {
+            user: mock_address(bob),
+            expires_at: 200,
+        })
+    );
+    assert_eq!(state.user_of(1, 199), Some(mock_address(bob)));
+    assert_eq!(state.user_of(1, 200), None);
+    assert_eq!(state.owner_of(1), mock_address(alice));
+
+    let _ = execute_set_user(
+        &mock_contract_context(alice),
+        &mut state,
+        &SetUserMsg {
+            token_id: 1,
+            user: None,
+            expires_at: 0,
+        },
+    );
+    assert_eq!(state.user_of(1, 100), None);
+}
+
+#[test]
+fn approved_set_user() {
+    let minter = 1u8;
+    let alice = 10u8;
+    let bob = 11u8;
+    let carol = 12u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 1,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+    let _ = execute_approve(
+        &mock_contract_context(alice),
+        &mut state,
+        &ApproveMsg {
+            spender: mock_address(bob),
+            token_id: 1,
+        },
+    );
+    let _ = execute_set_user(
+        &mock_contract_context(bob),
+        &mut state,
+        &SetUserMsg {
+            token_id: 1,
+            user: Some(mock_address(carol)),
+            expires_at: 200,
+        },
+    );
+    assert_eq!(state.user_of(1, 100), Some(mock_address(carol)));
+}
+
+#[test]
+fn user_cleared_on_transfer() {
+    let minter = 1u8;
+    let alice = 10u8;
+    let bob = 11u8;
+    let carol = 12u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 1,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+    let _ = execute_set_user(
+        &mock_contract_context(alice),
+        &mut state,
+        &SetUserMsg {
+            token_id: 1,
+            user: Some(mock_address(bob)),
+            expires_at: 200,
+        },
+    );
+    let _ = execute_transfer(
+        &mock_contract_context(alice),
+        &mut state,
+        &TransferMsg {
+            to: mock_address(carol),
+            token_id: 1,
+        },
+    );
+    assert_eq!(state.user_of(1, 100), None);
+    assert_eq!(state.token_info(1).unwrap().user, None);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn set_user_not_owner_or_approved() {
+    let minter = 1u8;
+    let alice = 10u8;
+    let bob = 11u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 1,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+    let _ = execute_set_user(
+        &mock_contract_context(bob),
+        &mut state,
+        &SetUserMsg {
+            token_id: 1,
+            user: Some(mock_address(bob)),
+            expires_at: 200,
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Expiration must be in the future")]
+fn set_user_expired() {
+    let minter = 1u8;
+    let alice = 10u8;
+    let bob = 11u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 1,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+    let _ = execute_set_user(
+        &mock_contract_context(alice),
+        &mut state,
+        &SetUserMsg {
+            token_id: 1,
+            user: Some(mock_address(bob)),
+            expires_at: 100,
+        },
+    );
+}
+
+#[test]
+fn proper_mint_consecutive() {
+    let minter = 1u8;
+    let alice = 10u8;
+    let bob = 11u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: Some("ipfs://".to_string()),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_mint_consecutive(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintConsecutiveMsg {
+            to: mock_address(alice),
+            amount: 10,
+        },
+    );
+    assert_eq!(state.supply, 10);
+    assert!(state.tokens.is_empty());
+    assert!(state.owned_tokens.is_empty());
+    assert_eq!(
+        state.consecutive_ranges,
+        BTreeMap::from([(
+            1,
+            ConsecutiveRange {
+                owner: mock_address(alice),
+                last_token_id: 10,
+            }
+        )])
+    );
+    assert_eq!(state.token_id_counter.current(), 10);
+
+    assert!(state.is_minted(1));
+    assert!(state.is_minted(10));
+    assert!(!state.is_minted(11));
+    assert_eq!(state.owner_of(7), mock_address(alice));
+    assert_eq!(state.token_uri(7), Some("ipfs://7".to_string()));
+    assert_eq!(state.balance_of(&mock_address(alice)), 10);
+    assert_eq!(
+        state.tokens_of_owner(&mock_address(alice), Some(3), 4),
+        vec![4, 5, 6, 7]
+    );
+    assert_eq!(state.token_by_index(9), Some(10));
+    assert_eq!(
+        state.all_token_ids(None, 100),
+        (1..=10).collect::<Vec<u128>>()
+    );
+
+    let _ = execute_mint_next(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintNextMsg {
+            to: mock_address(bob),
+            token_uri: None,
+            callback_action: None,
+        },
+    );
+    assert_eq!(state.owner_of(11), mock_address(bob));
+    assert_eq!(state.supply, 11);
+}
+
+#[test]
+fn consecutive_transfer_splits_range() {
+    let minter = 1u8;
+    let alice = 10u8;
+    let bob = 11u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: Some("ipfs://".to_string()),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_mint_consecutive(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintConsecutiveMsg {
+            to: mock_address(alice),
+            amount: 10,
+        },
+    );
+    let _ = execute_transfer(
+        &mock_contract_context(alice),
+        &mut state,
+        &TransferMsg {
+            to: mock_address(bob),
+            token_id: 5,
+        },
+    );
+
+    assert_eq!(
+        state.consecutive_ranges,
+        BTreeMap::from([
+            (
+                1,
+                ConsecutiveRange {
+                    owner: mock_address(alice),
+                    last_token_id: 4,
+                }
+            ),
+            (
+                6,
+                ConsecutiveRange {
+                    owner: mock_address(alice),
+                    last_token_id: 10,
+                }
+            ),
+        ])
+    );
+    assert_eq!(state.owner_of(5), mock_address(bob));
+    assert_eq!(state.owner_of(4), mock_address(alice));
+    assert_eq!(state.owner_of(6), mock_address(alice));
+    assert_eq!(state.balance_of(&mock_address(alice)), 9);
+    assert_eq!(state.balance_of(&mock_address(bob)), 1);
+    assert_eq!(
+        state.tokens_of_owner(&mock_address(alice), None, 10),
+        vec![1, 2, 3, 4, 6, 7, 8, 9, 10]
+    );
+    assert_eq!(
+        state.all_token_ids(None, 100),
+        (1..=10).collect::<Vec<u128>>()
+    );
+
+    let _ = execute_transfer(
+        &mock_contract_context(alice),
+        &mut state,
+        &TransferMsg {
+            to: mock_address(bob),
+            token_id: 10,
+        },
+    );
+    assert_eq!(
+        state.consecutive_ranges.get(&6),
+        Some(&ConsecutiveRange {
+            owner: mock_address(alice),
+            last_token_id: 9,
+        })
+    );
+    assert_eq!(
+        state.tokens_of_owner(&mock_address(bob), None, 10),
+        vec![5, 10]
+    );
+}
+
+#[test]
+fn consecutive_approve_and_burn() {
+    let minter = 1u8;
+    let alice = 10u8;
+    let bob = 11u8;
+    let carol = 12u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: Some("ipfs://".to_string()),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_mint_consecutive(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintConsecutiveMsg {
+            to: mock_address(alice),
+            amount: 3,
+        },
+    );
+    let _ = execute_approve(
+        &mock_contract_context(alice),
+        &mut state,
+        &ApproveMsg {
+            spender: mock_address(bob),
+            token_id: 2,
+        },
+    );
+    assert_eq!(
+        state.token_info(2).unwrap().approvals,
+        vec![mock_address(bob)]
+    );
+
+    let _ = execute_transfer_from(
+        &mock_contract_context(bob),
+        &mut state,
+        &TransferFromMsg {
+            from: mock_address(alice),
+            to: mock_address(carol),
+            token_id: 2,
+        },
+    );
+    assert_eq!(state.owner_of(2), mock_address(carol));
+
+    let _ = execute_burn(
+        &mock_contract_context(alice),
+        &mut state,
+        &BurnMsg { token_id: 1 },
+    );
+    assert!(!state.is_minted(1));
+    assert_eq!(state.supply, 2);
+    assert_eq!(state.balance_of(&mock_address(alice)), 1);
+    assert_eq!(state.all_token_ids(None, 100), vec![2, 3]);
+    assert_eq!(
+        state.consecutive_ranges,
+        BTreeMap::from([(
+            3,
+            ConsecutiveRange {
+                owner: mock_address(alice),
+                last_token_id: 3,
+            }
+        )])
+    );
+}
+
+#[test]
+fn consecutive_burn_moves_last_indexed_token() {
+    let minter = 1u8;
+    let alice = 10u8;
+    let bob = 11u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_mint_consecutive(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintConsecutiveMsg {
+            to: mock_address(alice),
+            amount: 5,
+        },
+    );
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 10,
+            to: mock_address(bob),
+            token_uri: None,
+        },
+    );
+    assert_eq!(state.token_by_index(4), Some(5));
+    assert_eq!(state.token_by_index(5), Some(10));
+
+    let _ = execute_burn(
+        &mock_contract_context(alice),
+        &mut state,
+        &BurnMsg { token_id: 2 },
+    );
+    assert_eq!(
+        state.token_index,
+        BTreeMap::from([
+            (
+                0,
+                IndexSpan {
+                    first_token_id: 1,
+                    last_token_id: 1,
+                }
+            ),
+            (
+                1,
+                IndexSpan {
+                    first_token_id: 10,
+                    last_token_id: 10,
+                }
+            ),
+            (
+                2,
+                IndexSpan {
+                    first_token_id: 3,
+                    last_token_id: 5,
+                }
+            ),
+        ])
+    );
+    assert_eq!(
+        state.index_positions,
+        BTreeMap::from([(1, 0), (3, 2), (10, 1)])
+    );
+    assert_eq!(state.token_by_index(1), Some(10));
+    assert_eq!(state.token_by_index(3), Some(4));
+    assert_eq!(state.token_by_index(5), None);
+
+    let _ = execute_transfer(
+        &mock_contract_context(alice),
+        &mut state,
+        &TransferMsg {
+            to: mock_address(bob),
+            token_id: 4,
+        },
+    );
+    assert_eq!(
+        state.owner_ranges,
+        BTreeMap::from([(mock_address(alice), BTreeSet::from([1, 3, 5]))])
+    );
+    assert_eq!(
+        state.tokens_of_owner(&mock_address(alice), None, 10),
+        vec![1, 3, 5]
+    );
+    assert_eq!(
+        state.tokens_of_owner(&mock_address(alice), Some(1), 1),
+        vec![3]
+    );
+    assert_eq!(
+        state.tokens_of_owner(&mock_address(bob), None, 10),
+        vec![4, 10]
+    );
+    assert_eq!(state.token_by_index(3), Some(4));
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn consecutive_transfer_not_owner() {
+    let minter = 1u8;
+    let alice = 10u8;
+    let bob = 11u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: Some("ipfs://".to_string()),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_mint_consecutive(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintConsecutiveMsg {
+            to: mock_address(alice),
+            amount: 3,
+        },
+    );
+    let _ = execute_transfer(
+        &mock_contract_context(bob),
+        &mut state,
+        &TransferMsg {
+            to: mock_address(bob),
+            token_id: 2,
+        },
+    );
+}
+
+#[test]
+fn mint_consecutive_skips_explicitly_minted_tokens() {
+    let minter = 1u8;
+    let alice = 10u8;
+    let bob = 11u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: Some("ipfs://".to_string()),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_mint_consecutive(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintConsecutiveMsg {
+            to: mock_address(alice),
+            amount: 2,
+        },
+    );
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 4,
+            to: mock_address(bob),
+            token_uri: None,
+        },
+    );
+    let _ = execute_mint_consecutive(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintConsecutiveMsg {
+            to: mock_address(bob),
+            amount: 3,
+        },
+    );
+
+    assert_eq!(
+        state.consecutive_ranges.get(&5),
+        Some(&ConsecutiveRange {
+            owner: mock_address(bob),
+            last_token_id: 7,
+        })
+    );
+    assert_eq!(state.token_id_counter.current(), 7);
+    assert!(!state.is_minted(3));
+    assert_eq!(state.owner_of(4), mock_address(bob));
+    assert_eq!(state.balance_of(&mock_address(alice)), 2);
+    assert_eq!(state.balance_of(&mock_address(bob)), 4);
+    assert_eq!(
+        state.range_balances,
+        BTreeMap::from([(mock_address(alice), 2), (mock_address(bob), 3)])
+    );
+
+    assert_eq!(state.all_token_ids(None, 100), vec![1, 2, 4, 5, 6, 7]);
+    assert_eq!(state.all_token_ids(Some(1), 3), vec![2, 4, 5]);
+    assert_eq!(state.all_token_ids(Some(5), 100), vec![6, 7]);
+    assert_eq!(state.all_token_ids(Some(u128::MAX), 100), vec![]);
+    assert_eq!(state.token_by_index(2), Some(4));
+    assert_eq!(state.token_by_index(5), Some(7));
+    assert_eq!(state.token_by_index(6), None);
+    assert_eq!(
+        state.tokens_of_owner(&mock_address(bob), Some(4), 10),
+        vec![5, 6, 7]
+    );
+    assert_eq!(
+        state.tokens_of_owner(&mock_address(bob), Some(u128::MAX), 10),
+        vec![]
+    );
+
+    let _ = execute_transfer(
+        &mock_contract_context(alice),
+        &mut state,
+        &TransferMsg {
+            to: mock_address(bob),
+            token_id: 1,
+        },
+    );
+    let _ = execute_transfer(
+        &mock_contract_context(alice),
+        &mut state,
+        &TransferMsg {
+            to: mock_address(bob),
+            token_id: 2,
+        },
+    );
+    assert_eq!(
+        state.range_balances,
+        BTreeMap::from([(mock_address(bob), 3)])
+    );
+    assert_eq!(state.balance_of(&mock_address(alice)), 0);
+    assert_eq!(state.balance_of(&mock_address(bob)), 6);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn mint_consecutive_not_minter() {
+    let minter = 1u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 1,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+    let _ = execute_mint_consecutive(
+        &mock_contract_context(alice),
+        &mut state,
+        &MintConsecutiveMsg {
+            to: mock_address(alice),
+            amount: 5,
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Amount must be greater than zero")]
+fn mint_consecutive_zero_amount() {
+    let minter = 1u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: Some("ipfs://".to_string()),
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_mint_consecutive(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintConsecutiveMsg {
+            to: mock_address(alice),
+            amount: 0,
+        },
+    );
+}
+
+#[test]
+fn mint_consecutive_over_minted_token() {
+    let minter = 1u8;
+    let alice = 10u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 3,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+    let _ = execute_mint_consecutive(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintConsecutiveMsg {
+            to: mock_address(alice),
+            amount: 5,
+        },
+    );
+    assert_eq!(
+        state.consecutive_ranges,
+        BTreeMap::from([(
+            4,
+            ConsecutiveRange {
+                owner: mock_address(alice),
+                last_token_id: 8,
+            }
+        )])
+    );
+    assert_eq!(state.balance_of(&mock_address(alice)), 6);
+    assert_eq!(state.all_token_ids(None, 100), vec![3, 4, 5, 6, 7, 8]);
+}
+
+#[test]
+fn proper_balance_of_and_owner_of_queries() {
+    let minter = 1u8;
+    let alice = 10u8;
+    let caller = 20u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 1,
+            to: mock_address(alice),
+            token_uri: None,
+        },
+    );
+
+    let events = execute_balance_of(
+        &mock_contract_context(caller),
+        &mut state,
+        &BalanceOfMsg {
+            owner: mock_address(alice),
+            callback_action: 0x41,
+            context: vec![1, 2],
+        },
+    );
+
+    let mut event_group = EventGroup::builder();
+    build_msg_reply(
+        &mut event_group,
+        &mock_address(caller),
+        0x41,
+        &BalanceOfReplyMsg {
+            owner: mock_address(alice),
+            balance: 1,
+            context: vec![1, 2],
+        },
+    );
+    assert_eq!(events, vec![event_group.build()]);
+
+    let events = execute_owner_of(
+        &mock_contract_context(caller),
+        &mut state,
+        &OwnerOfMsg {
+            token_id: 1,
+            callback_action: 0x42,
+            context: vec![1, 2],
+        },
+    );
+
+    let mut event_group = EventGroup::builder();
+    build_msg_reply(
+        &mut event_group,
+        &mock_address(caller),
+        0x42,
+        &OwnerOfReplyMsg {
+            token_id: 1,
+            owner: Some(mock_address(alice)),
+            context: vec![1, 2],
+        },
+    );
+    assert_eq!(events, vec![event_group.build()]);
+
+    let events = execute_owner_of(
+        &mock_contract_context(caller),
+        &mut state,
+        &OwnerOfMsg {
+            token_id: 2,
+            callback_action: 0x42,
+            context: vec![1, 2],
+        },
+    );
+
+    let mut event_group = EventGroup::builder();
+    build_msg_reply(
+        &mut event_group,
+        &mock_address(caller),
+        0x42,
+        &OwnerOfReplyMsg {
+            token_id: 2,
+            owner: None,
+            context: vec![1, 2],
+        },
+    );
+    assert_eq!(events, vec![event_group.build()]);
+}
+
+#[test]
+fn proper_total_supply_query() {
+    let minter = 1u8;
+    let caller = 20u8;
+
+    let msg = InitMsg {
+        owner: None,
+        name: "Cool Token".to_string(),
+        symbol: "CTC".to_string(),
+        base_uri: None,
+        minter: mock_address(minter),
+    };
+
+    let (mut state, _) = execute_init(&mock_contract_context(2), &msg);
+
+    let _ = execute_mint(
+        &mock_contract_context(minter),
+        &mut state,
+        &MintMsg {
+            token_id: 1,
+            to: mock_address(10u8),
+            token_uri: None,
+        },
+    );
+
+    let events = execute_total_supply(
+        &mock_contract_context(caller),
+        &mut state,
+        &TotalSupplyMsg {
+            callback_action: 0x43,
+            context: vec![1, 2],
+        },
+    );
+
+    let mut event_group = EventGroup::builder();
+    build_msg_reply(
+        &mut event_group,
+        &mock_address(caller),
+        0x43,
+        &TotalSupplyReplyMsg {
+            total_supply: 1,
+            max_supply: None,
+            context: vec![1, 2],
+        },
+    );
+    assert_eq!(events, vec![event_group.build()]);
+}
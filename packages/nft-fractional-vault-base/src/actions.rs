@@ -0,0 +1,401 @@
+use pbc_contract_common::{
+    context::{CallbackContext, ContractContext},
+    events::EventGroup,
+};
+
+use crate::{
+    msg::{
+        BidCallbackMsg, BidMsg, InitMsg, ON_BID_CALLBACK, ON_DEPOSIT_CALLBACK, ON_SALE_CALLBACK,
+    },
+    state::{
+        Auction, NFTFractionalVaultContractState, ACTIVE_VAULT_STATUS, AUCTION_VAULT_STATUS,
+        PENDING_VAULT_STATUS, REDEEMED_VAULT_STATUS, SOLD_VAULT_STATUS,
+    },
+    ContractError,
+};
+
+use mpc20_base::{
+    actions::{
+        execute_burn as mpc20_execute_burn, execute_burn_from as mpc20_execute_burn_from,
+        execute_init as mpc20_execute_init,
+    },
+    msg::{
+        BurnFromMsg as Mpc20BurnFromMsg, BurnMsg as Mpc20BurnMsg, Mpc20InitMsg,
+        TransferFromMsg as Mpc20TransferFromMsg, TransferMsg as Mpc20TransferMsg,
+    },
+};
+use mpc721_base::msg::{
+    TransferFromMsg as Mpc721TransferFromMsg, TransferMsg as Mpc721TransferMsg,
+};
+use utils::events::{build_msg_callback, IntoShortnameRPCEvent};
+
+/// ## Description
+/// Inits contract state.
+/// Returns [`(NFTFractionalVaultContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **msg** is an object of type [`InitMsg`]
+pub fn execute_init(
+    ctx: &ContractContext,
+    msg: &InitMsg,
+) -> (NFTFractionalVaultContractState, Vec<EventGroup>) {
+    assert!(
+        msg.share_supply > 0 && msg.reserve_price > 0 && msg.auction_duration > 0,
+        "{}",
+        ContractError::InvalidAmount
+    );
+
+    let (mpc20, _) = mpc20_execute_init(
+        ctx,
+        &Mpc20InitMsg {
+            info: msg.share_info.clone(),
+            initial_balances: vec![],
+            minter: None,
+        },
+    );
+
+    let state = NFTFractionalVaultContractState {
+        curator: ctx.sender,
+        collection: msg.collection,
+        token_id: msg.token_id,
+        share_supply: msg.share_supply,
+        currency: msg.currency,
+        reserve_price: msg.reserve_price,
+        auction_duration: msg.auction_duration,
+        status: PENDING_VAULT_STATUS,
+        auction: None,
+        proceeds: 0,
+        mpc20,
+    };
+
+    (state, vec![])
+}
+
+/// ## Description
+/// Escrows the token in the vault. Can only be executed by curator account.
+/// Curator must approve vault to transfer the token, shares are minted to curator once the token is received.
+/// Returns [`(NFTFractionalVaultContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`NFTFractionalVaultContractState`]
+pub fn execute_deposit(
+    ctx: &ContractContext,
+    state: &mut NFTFractionalVaultContractState,
+) -> Vec<EventGroup> {
+    assert!(
+        state.curator == ctx.sender,
+        "{}",
+        ContractError::Unauthorized
+    );
+    state.assert_status(PENDING_VAULT_STATUS);
+
+    let transfer_msg = Mpc721TransferFromMsg {
+        from: state.curator,
+        to: ctx.contract_address,
+        token_id: state.token_id,
+    };
+
+    let mut event_group = EventGroup::builder();
+    transfer_msg.as_interaction(&mut event_group, &state.collection);
+    build_msg_callback(&mut event_group, ON_DEPOSIT_CALLBACK, &transfer_msg);
+
+    vec![event_group.build()]
+}
+
+/// ## Description
+/// Handles result of the token escrow and mints all shares to curator on success.
+/// Returns [`(NFTFractionalVaultContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **callback_ctx** is an object of type [`CallbackContext`]
+///
+/// * **state** is an object of type [`NFTFractionalVaultContractState`]
+///
+/// * **msg** is an object of type [`Mpc721TransferFromMsg`]
+pub fn execute_on_deposit(
+    ctx: &ContractContext,
+    callback_ctx: &CallbackContext,
+    state: &mut NFTFractionalVaultContractState,
+    msg: &Mpc721TransferFromMsg,
+) -> Vec<EventGroup> {
+    if !callback_ctx.success || state.status != PENDING_VAULT_STATUS {
+        return vec![];
+    }
+
+    state.mpc20.mint_to(&state.curator, state.share_supply);
+    state.status = ACTIVE_VAULT_STATUS;
+
+    vec![]
+}
+
+/// ## Description
+/// Places a buyout bid. The first bid must be at least reserve price and starts the auction,
+/// every next bid must be higher than the current one.
+/// Bidder must approve vault to spend bid amount of currency tokens.
+/// Returns [`(NFTFractionalVaultContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`NFTFractionalVaultContractState`]
+///
+/// * **msg** is an object of type [`BidMsg`]
+pub fn execute_bid(
+    ctx: &ContractContext,
+    state: &mut NFTFractionalVaultContractState,
+    msg: &BidMsg,
+) -> Vec<EventGroup> {
+    assert!(
+        state.status == ACTIVE_VAULT_STATUS || state.status == AUCTION_VAULT_STATUS,
+        "{}",
+        ContractError::InvalidVaultStatus
+    );
+
+    let block_time = ctx.block_production_time as u64;
+    if let Some(auction) = &state.auction {
+        assert!(
+            block_time < auction.end_time,
+            "{}",
+            ContractError::AuctionEnded
+        );
+    }
+    assert!(
+        state.is_valid_bid(msg.amount, block_time),
+        "{}",
+        ContractError::BidTooLow
+    );
+
+    let mut event_group = EventGroup::builder();
+    Mpc20TransferFromMsg {
+        from: ctx.sender,
+        to: ctx.contract_address,
+        amount: msg.amount,
+    }
+    .as_interaction(&mut event_group, &state.currency);
+    build_msg_callback(
+        &mut event_group,
+        ON_BID_CALLBACK,
+        &BidCallbackMsg {
+            bidder: ctx.sender,
+            amount: msg.amount,
+        },
+    );
+
+    vec![event_group.build()]
+}
+
+/// ## Description
+/// Handles result of the bid payment.
+/// Records the bid and refunds previous highest bidder if the bid is still the highest one,
+/// otherwise refunds the bidder.
+/// Returns [`(NFTFractionalVaultContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **callback_ctx** is an object of type [`CallbackContext`]
+///
+/// * **state** is an object of type [`NFTFractionalVaultContractState`]
+///
+/// * **msg** is an object of type [`BidCallbackMsg`]
+pub fn execute_on_bid(
+    ctx: &ContractContext,
+    callback_ctx: &CallbackContext,
+    state: &mut NFTFractionalVaultContractState,
+    msg: &BidCallbackMsg,
+) -> Vec<EventGroup> {
+    if !callback_ctx.success {
+        return vec![];
+    }
+
+    let block_time = ctx.block_production_time as u64;
+    let refund = if state.is_valid_bid(msg.amount, block_time) {
+        state
+            .place_bid(&msg.bidder, msg.amount, block_time)
+            .map(|previous| (previous.bidder, previous.bid))
+    } else {
+        Some((msg.bidder, msg.amount))
+    };
+
+    match refund {
+        Some((to, amount)) => {
+            let mut event_group = EventGroup::builder();
+            Mpc20TransferMsg { to, amount }.as_interaction(&mut event_group, &state.currency);
+            vec![event_group.build()]
+        }
+        None => vec![],
+    }
+}
+
+/// ## Description
+/// Settles ended auction by transferring the token to the highest bidder.
+/// Vault is sold once the transfer succeeds, failed transfer can be retried.
+/// Returns [`(NFTFractionalVaultContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`NFTFractionalVaultContractState`]
+pub fn execute_end_auction(
+    ctx: &ContractContext,
+    state: &mut NFTFractionalVaultContractState,
+) -> Vec<EventGroup> {
+    state.assert_status(AUCTION_VAULT_STATUS);
+
+    let auction = state.auction.clone().unwrap();
+    assert!(
+        ctx.block_production_time as u64 >= auction.end_time,
+        "{}",
+        ContractError::AuctionNotEnded
+    );
+
+    let mut event_group = EventGroup::builder();
+    Mpc721TransferMsg {
+        to: auction.bidder,
+        token_id: state.token_id,
+    }
+    .as_interaction(&mut event_group, &state.collection);
+    build_msg_callback(&mut event_group, ON_SALE_CALLBACK, &auction);
+
+    vec![event_group.build()]
+}
+
+/// ## Description
+/// Handles result of the token transfer to the highest bidder.
+/// Highest bid becomes proceeds redeemable by share holders on success,
+/// vault stays in auction with the highest bid on failure.
+/// Returns [`(NFTFractionalVaultContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **callback_ctx** is an object of type [`CallbackContext`]
+///
+/// * **state** is an object of type [`NFTFractionalVaultContractState`]
+///
+/// * **msg** is an object of type [`Auction`]
+pub fn execute_on_sale(
+    ctx: &ContractContext,
+    callback_ctx: &CallbackContext,
+    state: &mut NFTFractionalVaultContractState,
+    msg: &Auction,
+) -> Vec<EventGroup> {
+    if !callback_ctx.success || state.status != AUCTION_VAULT_STATUS {
+        return vec![];
+    }
+
+    state.status = SOLD_VAULT_STATUS;
+    state.proceeds = msg.bid;
+
+    vec![]
+}
+
+/// ## Description
+/// Burns all shares of the sender and pays out pro-rata part of the buyout proceeds.
+/// Returns [`(NFTFractionalVaultContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`NFTFractionalVaultContractState`]
+pub fn execute_redeem_proceeds(
+    ctx: &ContractContext,
+    state: &mut NFTFractionalVaultContractState,
+) -> Vec<EventGroup> {
+    state.assert_status(SOLD_VAULT_STATUS);
+
+    let amount = state.redeem_proceeds(&ctx.sender);
+    if amount == 0 {
+        return vec![];
+    }
+
+    let mut event_group = EventGroup::builder();
+    Mpc20TransferMsg {
+        to: ctx.sender,
+        amount,
+    }
+    .as_interaction(&mut event_group, &state.currency);
+
+    vec![event_group.build()]
+}
+
+/// ## Description
+/// Burns all shares and transfers the token to the sender.
+/// Can only be executed by account holding all shares while there are no bids.
+/// Returns [`(NFTFractionalVaultContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`NFTFractionalVaultContractState`]
+pub fn execute_redeem_nft(
+    ctx: &ContractContext,
+    state: &mut NFTFractionalVaultContractState,
+) -> Vec<EventGroup> {
+    state.assert_status(ACTIVE_VAULT_STATUS);
+
+    let shares = state.mpc20.balance_of(&ctx.sender);
+    assert!(
+        shares > 0 && shares == state.mpc20.total_supply,
+        "{}",
+        ContractError::NotAllShares
+    );
+
+    state.burn_shares(&ctx.sender, shares);
+    state.status = REDEEMED_VAULT_STATUS;
+
+    let mut event_group = EventGroup::builder();
+    Mpc721TransferMsg {
+        to: ctx.sender,
+        token_id: state.token_id,
+    }
+    .as_interaction(&mut event_group, &state.collection);
+
+    vec![event_group.build()]
+}
+
+/// ## Description
+/// Burns shares of the sender. Shares can only be burned while vault is active,
+/// so that share supply stays fixed for the proceeds redemption.
+/// Returns [`(NFTFractionalVaultContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`NFTFractionalVaultContractState`]
+///
+/// * **msg** is an object of type [`Mpc20BurnMsg`]
+pub fn execute_burn_shares(
+    ctx: &ContractContext,
+    state: &mut NFTFractionalVaultContractState,
+    msg: &Mpc20BurnMsg,
+) -> Vec<EventGroup> {
+    state.assert_status(ACTIVE_VAULT_STATUS);
+    mpc20_execute_burn(ctx, &mut state.mpc20, msg)
+}
+
+/// ## Description
+/// Burns approved shares of the owner. Shares can only be burned while vault is active,
+/// so that share supply stays fixed for the proceeds redemption.
+/// Returns [`(NFTFractionalVaultContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`NFTFractionalVaultContractState`]
+///
+/// * **msg** is an object of type [`Mpc20BurnFromMsg`]
+pub fn execute_burn_shares_from(
+    ctx: &ContractContext,
+    state: &mut NFTFractionalVaultContractState,
+    msg: &Mpc20BurnFromMsg,
+) -> Vec<EventGroup> {
+    state.assert_status(ACTIVE_VAULT_STATUS);
+    mpc20_execute_burn_from(ctx, &mut state.mpc20, msg)
+}
@@ -0,0 +1,30 @@
+use thiserror::Error;
+
+/// ## Description
+/// This enum describes nft-fractional-vault contract errors
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("Unauthorized")]
+    Unauthorized,
+
+    #[error("Invalid amount")]
+    InvalidAmount,
+
+    #[error("Action is not allowed in current vault status")]
+    InvalidVaultStatus,
+
+    #[error("Bid is lower than reserve price or current bid")]
+    BidTooLow,
+
+    #[error("Auction has ended")]
+    AuctionEnded,
+
+    #[error("Auction has not ended yet")]
+    AuctionNotEnded,
+
+    #[error("Sender has no shares")]
+    NoShares,
+
+    #[error("Sender must hold all shares")]
+    NotAllShares,
+}
@@ -0,0 +1,11 @@
+#![allow(unused_variables)]
+
+pub mod actions;
+mod error;
+pub mod msg;
+pub mod state;
+
+pub use crate::error::ContractError;
+
+#[cfg(test)]
+mod tests;
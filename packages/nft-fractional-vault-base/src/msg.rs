@@ -0,0 +1,52 @@
+use create_type_spec_derive::CreateTypeSpec;
+use pbc_contract_common::address::{Address, Shortname};
+use read_write_rpc_derive::ReadWriteRPC;
+
+use mpc20_base::state::TokenInfo;
+use rpc_msg_derive::IntoShortnameRPCEvent;
+use utils::events::IntoShortnameRPCEvent;
+
+/// ## Description
+/// This structure describes fields for nft-fractional-vault initialize msg
+#[derive(ReadWriteRPC, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct InitMsg {
+    /// mpc721 collection address
+    pub collection: Address,
+    /// token id to fractionalize
+    pub token_id: u128,
+    /// share token information
+    pub share_info: TokenInfo,
+    /// amount of shares minted to curator on deposit
+    pub share_supply: u128,
+    /// mpc20 token used for buyout bids
+    pub currency: Address,
+    /// minimal buyout bid
+    pub reserve_price: u128,
+    /// auction duration in seconds counted from the first bid
+    pub auction_duration: u64,
+}
+
+/// ## Description
+/// This structure describes fields for nft-fractional-vault bid msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x19)]
+pub struct BidMsg {
+    /// amount of currency tokens to bid
+    pub amount: u128,
+}
+
+/// ## Description
+/// This structure describes a bid which is passed to the payment callback
+#[derive(ReadWriteRPC, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct BidCallbackMsg {
+    /// bidder address
+    pub bidder: Address,
+    /// amount of currency tokens received from the bidder
+    pub amount: u128,
+}
+
+/// ## Description
+/// Callback shortnames used during deposit, bidding and sale
+pub const ON_DEPOSIT_CALLBACK: u32 = 0x31;
+pub const ON_BID_CALLBACK: u32 = 0x33;
+pub const ON_SALE_CALLBACK: u32 = 0x35;
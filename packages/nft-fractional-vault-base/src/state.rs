@@ -0,0 +1,146 @@
+use create_type_spec_derive::CreateTypeSpec;
+use pbc_contract_common::address::Address;
+use read_write_rpc_derive::ReadWriteRPC;
+use read_write_state_derive::ReadWriteState;
+
+use mpc20_base::state::MPC20ContractState;
+
+use crate::ContractError;
+
+/// ## Description
+/// This structure describes main nft-fractional-vault contract state.
+#[derive(ReadWriteState, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct NFTFractionalVaultContractState {
+    /// curator address which deposits the token and receives all shares
+    pub curator: Address,
+    /// mpc721 collection address
+    pub collection: Address,
+    /// fractionalized token id
+    pub token_id: u128,
+    /// amount of shares minted to curator on deposit
+    pub share_supply: u128,
+    /// mpc20 token used for buyout bids
+    pub currency: Address,
+    /// minimal buyout bid
+    pub reserve_price: u128,
+    /// auction duration in seconds counted from the first bid
+    pub auction_duration: u64,
+    /// current vault status
+    pub status: VaultStatus,
+    /// optional buyout auction information
+    pub auction: Option<Auction>,
+    /// buyout proceeds left for share holders
+    pub proceeds: u128,
+    /// mpc20 base state of shares
+    pub mpc20: MPC20ContractState,
+}
+
+/// ## Description
+/// This structure describes buyout auction
+#[derive(ReadWriteRPC, ReadWriteState, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct Auction {
+    /// highest bidder address
+    pub bidder: Address,
+    /// highest bid
+    pub bid: u128,
+    /// UTC timestamp
+    pub end_time: u64,
+}
+
+/// ## Description
+/// Defines a type for the vault status
+pub type VaultStatus = u8;
+pub const PENDING_VAULT_STATUS: VaultStatus = 0;
+pub const ACTIVE_VAULT_STATUS: VaultStatus = 1;
+pub const AUCTION_VAULT_STATUS: VaultStatus = 2;
+pub const SOLD_VAULT_STATUS: VaultStatus = 3;
+pub const REDEEMED_VAULT_STATUS: VaultStatus = 4;
+
+impl NFTFractionalVaultContractState {
+    /// ## Description
+    /// Checks that vault is in the specified status
+    /// ## Params
+    /// * **status** is an object of type [`VaultStatus`]
+    pub fn assert_status(&self, status: VaultStatus) {
+        assert!(
+            self.status == status,
+            "{}",
+            ContractError::InvalidVaultStatus
+        );
+    }
+
+    /// ## Description
+    /// Says is bid high enough to become the highest one and can still be placed at specified time
+    /// ## Params
+    /// * **amount** is a field of type [`u128`]
+    ///
+    /// * **block_time** is a field of type [`u64`]
+    pub fn is_valid_bid(&self, amount: u128, block_time: u64) -> bool {
+        match (self.status, &self.auction) {
+            (ACTIVE_VAULT_STATUS, _) => amount >= self.reserve_price,
+            (AUCTION_VAULT_STATUS, Some(auction)) => {
+                block_time < auction.end_time && amount > auction.bid
+            }
+            _ => false,
+        }
+    }
+
+    /// ## Description
+    /// Records new highest bid, starting the auction on the first one.
+    /// Returns previous highest bid which must be refunded
+    /// ## Params
+    /// * **bidder** is an object of type [`Address`]
+    ///
+    /// * **amount** is a field of type [`u128`]
+    ///
+    /// * **block_time** is a field of type [`u64`]
+    pub fn place_bid(
+        &mut self,
+        bidder: &Address,
+        amount: u128,
+        block_time: u64,
+    ) -> Option<Auction> {
+        let end_time = match &self.auction {
+            Some(auction) => auction.end_time,
+            None => block_time.checked_add(self.auction_duration).unwrap(),
+        };
+
+        self.status = AUCTION_VAULT_STATUS;
+        self.auction.replace(Auction {
+            bidder: *bidder,
+            bid: amount,
+            end_time,
+        })
+    }
+
+    /// ## Description
+    /// Burns all shares of the holder and returns its pro-rata part of the buyout proceeds
+    /// ## Params
+    /// * **holder** is an object of type [`Address`]
+    pub fn redeem_proceeds(&mut self, holder: &Address) -> u128 {
+        let shares = self.mpc20.balance_of(holder);
+        assert!(shares > 0, "{}", ContractError::NoShares);
+
+        let amount = self
+            .proceeds
+            .checked_mul(shares)
+            .unwrap()
+            .checked_div(self.mpc20.total_supply)
+            .unwrap();
+
+        self.burn_shares(holder, shares);
+        self.proceeds -= amount;
+        amount
+    }
+
+    /// ## Description
+    /// Burns specified amount of shares of the holder
+    /// ## Params
+    /// * **holder** is an object of type [`Address`]
+    ///
+    /// * **shares** is a field of type [`u128`]
+    pub fn burn_shares(&mut self, holder: &Address, shares: u128) {
+        self.mpc20.decrease_balance(holder, shares);
+        self.mpc20.decrease_total_supply(shares);
+    }
+}
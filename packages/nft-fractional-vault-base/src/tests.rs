@@ -0,0 +1,648 @@
+use pbc_contract_common::{
+    address::{Address, AddressType},
+    context::{CallbackContext, ContractContext},
+    events::EventGroup,
+};
+
+use crate::{
+    actions::{
+        execute_bid, execute_burn_shares, execute_burn_shares_from, execute_deposit,
+        execute_end_auction, execute_init, execute_on_bid, execute_on_deposit, execute_on_sale,
+        execute_redeem_nft, execute_redeem_proceeds,
+    },
+    msg::{
+        BidCallbackMsg, BidMsg, InitMsg, ON_BID_CALLBACK, ON_DEPOSIT_CALLBACK, ON_SALE_CALLBACK,
+    },
+    state::{
+        Auction, NFTFractionalVaultContractState, ACTIVE_VAULT_STATUS, AUCTION_VAULT_STATUS,
+        PENDING_VAULT_STATUS, REDEEMED_VAULT_STATUS, SOLD_VAULT_STATUS,
+    },
+};
+
+use mpc20_base::{
+    actions::{
+        execute_approve as mpc20_execute_approve, execute_transfer as mpc20_execute_transfer,
+    },
+    msg::{
+        ApproveMsg as Mpc20ApproveMsg, BurnFromMsg as Mpc20BurnFromMsg, BurnMsg as Mpc20BurnMsg,
+        TransferFromMsg as Mpc20TransferFromMsg, TransferMsg as Mpc20TransferMsg,
+    },
+    state::{MPC20ContractState, TokenInfo},
+};
+use mpc721_base::msg::{
+    TransferFromMsg as Mpc721TransferFromMsg, TransferMsg as Mpc721TransferMsg,
+};
+use utils::events::{build_msg_callback, IntoShortnameRPCEvent};
+
+const VAULT: u8 = 1;
+const CURATOR: u8 = 2;
+const COLLECTION: u8 = 4;
+const CURRENCY: u8 = 5;
+const ALICE: u8 = 10;
+const BOB: u8 = 11;
+
+fn mock_address(le: u8) -> Address {
+    Address {
+        address_type: AddressType::Account,
+        identifier: [
+            le, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8,
+        ],
+    }
+}
+
+fn mock_contract_context_at(sender: u8, block_time: i64) -> ContractContext {
+    ContractContext {
+        contract_address: mock_address(VAULT),
+        sender: mock_address(sender),
+        block_time,
+        block_production_time: block_time,
+        current_transaction: [
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+        ],
+        original_transaction: [
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+        ],
+    }
+}
+
+fn mock_contract_context(sender: u8) -> ContractContext {
+    mock_contract_context_at(sender, 100)
+}
+
+fn mock_callback_context(success: bool) -> CallbackContext {
+    CallbackContext {
+        success,
+        results: vec![],
+    }
+}
+
+fn share_info() -> TokenInfo {
+    TokenInfo {
+        name: "Vault Share".to_string(),
+        symbol: "VSH".to_string(),
+        decimals: 0,
+    }
+}
+
+fn init_msg() -> InitMsg {
+    InitMsg {
+        collection: mock_address(COLLECTION),
+        token_id: 1,
+        share_info: share_info(),
+        share_supply: 1_000,
+        currency: mock_address(CURRENCY),
+        reserve_price: 1_000,
+        auction_duration: 3_600,
+    }
+}
+
+fn deposit_msg() -> Mpc721TransferFromMsg {
+    Mpc721TransferFromMsg {
+        from: mock_address(CURATOR),
+        to: mock_address(VAULT),
+        token_id: 1,
+    }
+}
+
+fn init_active_vault() -> NFTFractionalVaultContractState {
+    let (mut state, _) = execute_init(&mock_contract_context(CURATOR), &init_msg());
+    let _ = execute_deposit(&mock_contract_context(CURATOR), &mut state);
+    let _ = execute_on_deposit(
+        &mock_contract_context(VAULT),
+        &mock_callback_context(true),
+        &mut state,
+        &deposit_msg(),
+    );
+
+    state
+}
+
+fn place_bid(state: &mut NFTFractionalVaultContractState, bidder: u8, amount: u128, time: i64) {
+    let _ = execute_bid(
+        &mock_contract_context_at(bidder, time),
+        state,
+        &BidMsg { amount },
+    );
+    let _ = execute_on_bid(
+        &mock_contract_context_at(VAULT, time),
+        &mock_callback_context(true),
+        state,
+        &BidCallbackMsg {
+            bidder: mock_address(bidder),
+            amount,
+        },
+    );
+}
+
+fn refund_event(to: u8, amount: u128) -> EventGroup {
+    let mut event_group = EventGroup::builder();
+    Mpc20TransferMsg {
+        to: mock_address(to),
+        amount,
+    }
+    .as_interaction(&mut event_group, &mock_address(CURRENCY));
+    event_group.build()
+}
+
+fn sell(state: &mut NFTFractionalVaultContractState, time: i64) {
+    let _ = execute_end_auction(&mock_contract_context_at(ALICE, time), state);
+    let auction = state.auction.clone().unwrap();
+    let _ = execute_on_sale(
+        &mock_contract_context_at(VAULT, time),
+        &mock_callback_context(true),
+        state,
+        &auction,
+    );
+}
+
+fn sale_event(bidder: u8, bid: u128) -> EventGroup {
+    let mut event_group = EventGroup::builder();
+    Mpc721TransferMsg {
+        to: mock_address(bidder),
+        token_id: 1,
+    }
+    .as_interaction(&mut event_group, &mock_address(COLLECTION));
+    build_msg_callback(
+        &mut event_group,
+        ON_SALE_CALLBACK,
+        &Auction {
+            bidder: mock_address(bidder),
+            bid,
+            end_time: 3_700,
+        },
+    );
+    event_group.build()
+}
+
+fn nft_transfer_event(to: u8) -> EventGroup {
+    let mut event_group = EventGroup::builder();
+    Mpc721TransferMsg {
+        to: mock_address(to),
+        token_id: 1,
+    }
+    .as_interaction(&mut event_group, &mock_address(COLLECTION));
+    event_group.build()
+}
+
+#[test]
+fn proper_execute_init() {
+    let (state, events) = execute_init(&mock_contract_context(CURATOR), &init_msg());
+    assert_eq!(events.len(), 0);
+    assert_eq!(
+        state,
+        NFTFractionalVaultContractState {
+            curator: mock_address(CURATOR),
+            collection: mock_address(COLLECTION),
+            token_id: 1,
+            share_supply: 1_000,
+            currency: mock_address(CURRENCY),
+            reserve_price: 1_000,
+            auction_duration: 3_600,
+            status: PENDING_VAULT_STATUS,
+            auction: None,
+            proceeds: 0,
+            mpc20: MPC20ContractState::new(&share_info(), &None),
+        }
+    );
+}
+
+#[test]
+#[should_panic(expected = "Invalid amount")]
+fn init_with_zero_shares() {
+    let mut msg = init_msg();
+    msg.share_supply = 0;
+
+    let _ = execute_init(&mock_contract_context(CURATOR), &msg);
+}
+
+#[test]
+fn proper_deposit() {
+    let (mut state, _) = execute_init(&mock_contract_context(CURATOR), &init_msg());
+
+    let events = execute_deposit(&mock_contract_context(CURATOR), &mut state);
+    let mut event_group = EventGroup::builder();
+    deposit_msg().as_interaction(&mut event_group, &mock_address(COLLECTION));
+    build_msg_callback(&mut event_group, ON_DEPOSIT_CALLBACK, &deposit_msg());
+    assert_eq!(events, vec![event_group.build()]);
+    assert_eq!(state.status, PENDING_VAULT_STATUS);
+
+    let _ = execute_on_deposit(
+        &mock_contract_context(VAULT),
+        &mock_callback_context(false),
+        &mut state,
+        &deposit_msg(),
+    );
+    assert_eq!(state.status, PENDING_VAULT_STATUS);
+    assert_eq!(state.mpc20.total_supply, 0);
+
+    let _ = execute_on_deposit(
+        &mock_contract_context(VAULT),
+        &mock_callback_context(true),
+        &mut state,
+        &deposit_msg(),
+    );
+    assert_eq!(state.status, ACTIVE_VAULT_STATUS);
+    assert_eq!(state.mpc20.total_supply, 1_000);
+    assert_eq!(state.mpc20.balance_of(&mock_address(CURATOR)), 1_000);
+
+    let _ = execute_on_deposit(
+        &mock_contract_context(VAULT),
+        &mock_callback_context(true),
+        &mut state,
+        &deposit_msg(),
+    );
+    assert_eq!(state.mpc20.total_supply, 1_000);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn deposit_not_curator() {
+    let (mut state, _) = execute_init(&mock_contract_context(CURATOR), &init_msg());
+    let _ = execute_deposit(&mock_contract_context(ALICE), &mut state);
+}
+
+#[test]
+#[should_panic(expected = "Action is not allowed in current vault status")]
+fn deposit_twice() {
+    let mut state = init_active_vault();
+    let _ = execute_deposit(&mock_contract_context(CURATOR), &mut state);
+}
+
+#[test]
+fn proper_bid() {
+    let mut state = init_active_vault();
+
+    let events = execute_bid(
+        &mock_contract_context(ALICE),
+        &mut state,
+        &BidMsg { amount: 1_000 },
+    );
+    let callback_msg = BidCallbackMsg {
+        bidder: mock_address(ALICE),
+        amount: 1_000,
+    };
+    let mut event_group = EventGroup::builder();
+    Mpc20TransferFromMsg {
+        from: mock_address(ALICE),
+        to: mock_address(VAULT),
+        amount: 1_000,
+    }
+    .as_interaction(&mut event_group, &mock_address(CURRENCY));
+    build_msg_callback(&mut event_group, ON_BID_CALLBACK, &callback_msg);
+    assert_eq!(events, vec![event_group.build()]);
+    assert_eq!(state.auction, None);
+
+    let events = execute_on_bid(
+        &mock_contract_context(VAULT),
+        &mock_callback_context(true),
+        &mut state,
+        &callback_msg,
+    );
+    assert_eq!(events, vec![]);
+    assert_eq!(state.status, AUCTION_VAULT_STATUS);
+    assert_eq!(
+        state.auction,
+        Some(Auction {
+            bidder: mock_address(ALICE),
+            bid: 1_000,
+            end_time: 3_700,
+        })
+    );
+
+    let _ = execute_bid(
+        &mock_contract_context_at(BOB, 200),
+        &mut state,
+        &BidMsg { amount: 1_500 },
+    );
+    let events = execute_on_bid(
+        &mock_contract_context_at(VAULT, 200),
+        &mock_callback_context(true),
+        &mut state,
+        &BidCallbackMsg {
+            bidder: mock_address(BOB),
+            amount: 1_500,
+        },
+    );
+    assert_eq!(events, vec![refund_event(ALICE, 1_000)]);
+    assert_eq!(
+        state.auction,
+        Some(Auction {
+            bidder: mock_address(BOB),
+            bid: 1_500,
+            end_time: 3_700,
+        })
+    );
+}
+
+#[test]
+fn bid_payment_failed() {
+    let mut state = init_active_vault();
+
+    let events = execute_on_bid(
+        &mock_contract_context(VAULT),
+        &mock_callback_context(false),
+        &mut state,
+        &BidCallbackMsg {
+            bidder: mock_address(ALICE),
+            amount: 1_000,
+        },
+    );
+    assert_eq!(events, vec![]);
+    assert_eq!(state.status, ACTIVE_VAULT_STATUS);
+    assert_eq!(state.auction, None);
+}
+
+#[test]
+fn outbid_while_paying_is_refunded() {
+    let mut state = init_active_vault();
+
+    let _ = execute_bid(
+        &mock_contract_context(ALICE),
+        &mut state,
+        &BidMsg { amount: 1_200 },
+    );
+    let _ = execute_bid(
+        &mock_contract_context(BOB),
+        &mut state,
+        &BidMsg { amount: 1_200 },
+    );
+
+    let _ = execute_on_bid(
+        &mock_contract_context(VAULT),
+        &mock_callback_context(true),
+        &mut state,
+        &BidCallbackMsg {
+            bidder: mock_address(ALICE),
+            amount: 1_200,
+        },
+    );
+    let events = execute_on_bid(
+        &mock_contract_context(VAULT),
+        &mock_callback_context(true),
+        &mut state,
+        &BidCallbackMsg {
+            bidder: mock_address(BOB),
+            amount: 1_200,
+        },
+    );
+    assert_eq!(events, vec![refund_event(BOB, 1_200)]);
+    assert_eq!(state.auction.unwrap().bidder, mock_address(ALICE));
+}
+
+#[test]
+#[should_panic(expected = "Bid is lower than reserve price or current bid")]
+fn bid_below_reserve_price() {
+    let mut state = init_active_vault();
+    let _ = execute_bid(
+        &mock_contract_context(ALICE),
+        &mut state,
+        &BidMsg { amount: 999 },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Bid is lower than reserve price or current bid")]
+fn bid_not_higher_than_current() {
+    let mut state = init_active_vault();
+    place_bid(&mut state, ALICE, 1_500, 100);
+
+    let _ = execute_bid(
+        &mock_contract_context(BOB),
+        &mut state,
+        &BidMsg { amount: 1_500 },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Auction has ended")]
+fn bid_after_auction_end() {
+    let mut state = init_active_vault();
+    place_bid(&mut state, ALICE, 1_000, 100);
+
+    let _ = execute_bid(
+        &mock_contract_context_at(BOB, 3_700),
+        &mut state,
+        &BidMsg { amount: 2_000 },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Action is not allowed in current vault status")]
+fn bid_before_deposit() {
+    let (mut state, _) = execute_init(&mock_contract_context(CURATOR), &init_msg());
+    let _ = execute_bid(
+        &mock_contract_context(ALICE),
+        &mut state,
+        &BidMsg { amount: 1_000 },
+    );
+}
+
+#[test]
+fn proper_end_auction_and_redeem_proceeds() {
+    let mut state = init_active_vault();
+    let _ = mpc20_execute_transfer(
+        &mock_contract_context(CURATOR),
+        &mut state.mpc20,
+        &Mpc20TransferMsg {
+            to: mock_address(ALICE),
+            amount: 250,
+        },
+    );
+    place_bid(&mut state, BOB, 1_001, 100);
+
+    let events = execute_end_auction(&mock_contract_context_at(ALICE, 3_700), &mut state);
+    assert_eq!(events, vec![sale_event(BOB, 1_001)]);
+    assert_eq!(state.status, AUCTION_VAULT_STATUS);
+    assert_eq!(state.proceeds, 0);
+
+    let auction = state.auction.clone().unwrap();
+
+    let events = execute_on_sale(
+        &mock_contract_context_at(VAULT, 3_700),
+        &mock_callback_context(true),
+        &mut state,
+        &auction,
+    );
+    assert_eq!(events, vec![]);
+    assert_eq!(state.status, SOLD_VAULT_STATUS);
+    assert_eq!(state.proceeds, 1_001);
+
+    let events = execute_redeem_proceeds(&mock_contract_context(ALICE), &mut state);
+    assert_eq!(events, vec![refund_event(ALICE, 250)]);
+    assert_eq!(state.proceeds, 751);
+    assert_eq!(state.mpc20.total_supply, 750);
+    assert_eq!(state.mpc20.balance_of(&mock_address(ALICE)), 0);
+
+    let events = execute_redeem_proceeds(&mock_contract_context(CURATOR), &mut state);
+    assert_eq!(events, vec![refund_event(CURATOR, 751)]);
+    assert_eq!(state.proceeds, 0);
+    assert_eq!(state.mpc20.total_supply, 0);
+}
+
+#[test]
+fn failed_sale_transfer_keeps_auction() {
+    let mut state = init_active_vault();
+    place_bid(&mut state, BOB, 1_000, 100);
+
+    let _ = execute_end_auction(&mock_contract_context_at(ALICE, 3_700), &mut state);
+    let auction = state.auction.clone().unwrap();
+    let events = execute_on_sale(
+        &mock_contract_context_at(VAULT, 3_700),
+        &mock_callback_context(false),
+        &mut state,
+        &auction,
+    );
+    assert_eq!(events, vec![]);
+    assert_eq!(state.status, AUCTION_VAULT_STATUS);
+    assert_eq!(state.proceeds, 0);
+    assert_eq!(
+        state.auction,
+        Some(Auction {
+            bidder: mock_address(BOB),
+            bid: 1_000,
+            end_time: 3_700,
+        })
+    );
+
+    let events = execute_end_auction(&mock_contract_context_at(ALICE, 3_800), &mut state);
+    assert_eq!(events, vec![sale_event(BOB, 1_000)]);
+}
+
+#[test]
+#[should_panic(expected = "Auction has not ended yet")]
+fn end_auction_too_early() {
+    let mut state = init_active_vault();
+    place_bid(&mut state, ALICE, 1_000, 100);
+
+    let _ = execute_end_auction(&mock_contract_context_at(ALICE, 3_699), &mut state);
+}
+
+#[test]
+#[should_panic(expected = "Action is not allowed in current vault status")]
+fn end_auction_without_bids() {
+    let mut state = init_active_vault();
+    let _ = execute_end_auction(&mock_contract_context_at(ALICE, 3_700), &mut state);
+}
+
+#[test]
+#[should_panic(expected = "Sender has no shares")]
+fn redeem_proceeds_without_shares() {
+    let mut state = init_active_vault();
+    place_bid(&mut state, BOB, 1_000, 100);
+    sell(&mut state, 3_700);
+
+    let _ = execute_redeem_proceeds(&mock_contract_context(ALICE), &mut state);
+}
+
+#[test]
+#[should_panic(expected = "Action is not allowed in current vault status")]
+fn redeem_proceeds_before_sale() {
+    let mut state = init_active_vault();
+    let _ = execute_redeem_proceeds(&mock_contract_context(CURATOR), &mut state);
+}
+
+#[test]
+fn proper_redeem_nft() {
+    let mut state = init_active_vault();
+
+    let events = execute_redeem_nft(&mock_contract_context(CURATOR), &mut state);
+    assert_eq!(events, vec![nft_transfer_event(CURATOR)]);
+    assert_eq!(state.status, REDEEMED_VAULT_STATUS);
+    assert_eq!(state.mpc20.total_supply, 0);
+    assert_eq!(state.mpc20.balance_of(&mock_address(CURATOR)), 0);
+}
+
+#[test]
+#[should_panic(expected = "Sender must hold all shares")]
+fn redeem_nft_without_all_shares() {
+    let mut state = init_active_vault();
+    let _ = mpc20_execute_transfer(
+        &mock_contract_context(CURATOR),
+        &mut state.mpc20,
+        &Mpc20TransferMsg {
+            to: mock_address(ALICE),
+            amount: 1,
+        },
+    );
+
+    let _ = execute_redeem_nft(&mock_contract_context(CURATOR), &mut state);
+}
+
+#[test]
+#[should_panic(expected = "Action is not allowed in current vault status")]
+fn redeem_nft_during_auction() {
+    let mut state = init_active_vault();
+    place_bid(&mut state, ALICE, 1_000, 100);
+
+    let _ = execute_redeem_nft(&mock_contract_context(CURATOR), &mut state);
+}
+
+#[test]
+fn proper_burn_shares() {
+    let mut state = init_active_vault();
+    let _ = mpc20_execute_approve(
+        &mock_contract_context(CURATOR),
+        &mut state.mpc20,
+        &Mpc20ApproveMsg {
+            spender: mock_address(ALICE),
+            amount: 100,
+        },
+    );
+
+    let _ = execute_burn_shares(
+        &mock_contract_context(CURATOR),
+        &mut state,
+        &Mpc20BurnMsg { amount: 100 },
+    );
+    let _ = execute_burn_shares_from(
+        &mock_contract_context(ALICE),
+        &mut state,
+        &Mpc20BurnFromMsg {
+            owner: mock_address(CURATOR),
+            amount: 100,
+        },
+    );
+    assert_eq!(state.mpc20.total_supply, 800);
+    assert_eq!(state.mpc20.balance_of(&mock_address(CURATOR)), 800);
+}
+
+#[test]
+#[should_panic(expected = "Action is not allowed in current vault status")]
+fn burn_shares_after_sale() {
+    let mut state = init_active_vault();
+    place_bid(&mut state, BOB, 1_000, 100);
+    sell(&mut state, 3_700);
+
+    let _ = execute_burn_shares(
+        &mock_contract_context(CURATOR),
+        &mut state,
+        &Mpc20BurnMsg { amount: 1_000 },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Action is not allowed in current vault status")]
+fn burn_shares_from_during_auction() {
+    let mut state = init_active_vault();
+    let _ = mpc20_execute_approve(
+        &mock_contract_context(CURATOR),
+        &mut state.mpc20,
+        &Mpc20ApproveMsg {
+            spender: mock_address(ALICE),
+            amount: 100,
+        },
+    );
+    place_bid(&mut state, BOB, 1_000, 100);
+
+    let _ = execute_burn_shares_from(
+        &mock_contract_context(ALICE),
+        &mut state,
+        &Mpc20BurnFromMsg {
+            owner: mock_address(CURATOR),
+            amount: 100,
+        },
+    );
+}
@@ -0,0 +1,531 @@
+use std::collections::BTreeMap;
+
+use pbc_contract_common::{
+    context::{CallbackContext, ContractContext},
+    events::EventGroup,
+};
+
+use crate::{
+    msg::{
+        AcceptOfferMsg, BuyMsg, CancelListingMsg, CancelOfferMsg, CreateListingMsg, CreateOfferMsg,
+        FillMsg, InitMsg, RemoveRoyaltyMsg, SetRoyaltyMsg, UpdateFeeMsg,
+        ON_OWNERSHIP_CHECKED_CALLBACK, ON_PAYMENT_RECEIVED_CALLBACK, ON_TOKEN_TRANSFERRED_CALLBACK,
+    },
+    state::{
+        Listing, NFTMarketplaceContractState, Offer, Royalty, TokenType, LISTING_ORDER_TYPE,
+        MPC1155_TOKEN_TYPE, MPC721_TOKEN_TYPE, OFFER_ORDER_TYPE,
+    },
+    ContractError,
+};
+
+use mpc1155_base::msg::{
+    CheckBalancesMsg, TokenTransferInfoMsg, TransferFromMsg as Mpc1155TransferFromMsg,
+};
+use mpc20_base::msg::{TransferFromMsg as Mpc20TransferFromMsg, TransferMsg as Mpc20TransferMsg};
+use mpc721_base::msg::{CheckOwnerMsg, TransferFromMsg as Mpc721TransferFromMsg};
+use utils::events::{build_msg_callback, IntoShortnameRPCEvent};
+
+/// ## Description
+/// Inits contract state.
+/// Returns [`(NFTMarketplaceContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **msg** is an object of type [`InitMsg`]
+pub fn execute_init(
+    ctx: &ContractContext,
+    msg: &InitMsg,
+) -> (NFTMarketplaceContractState, Vec<EventGroup>) {
+    let mut state = NFTMarketplaceContractState {
+        owner: ctx.sender,
+        fee_receiver: msg.fee_receiver,
+        fee_basis_points: 0,
+        royalties: BTreeMap::new(),
+        listings_count: 0,
+        listings: BTreeMap::new(),
+        offers_count: 0,
+        offers: BTreeMap::new(),
+    };
+    state.set_fee(&msg.fee_receiver, msg.fee_basis_points);
+
+    (state, vec![])
+}
+
+/// ## Description
+/// Lists tokens for sale at a fixed price.
+/// Tokens are not escrowed, seller must approve marketplace as an operator.
+/// Returns [`(NFTMarketplaceContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`NFTMarketplaceContractState`]
+///
+/// * **msg** is an object of type [`CreateListingMsg`]
+pub fn execute_create_listing(
+    ctx: &ContractContext,
+    state: &mut NFTMarketplaceContractState,
+    msg: &CreateListingMsg,
+) -> Vec<EventGroup> {
+    validate_order(msg.token_type, msg.amount, msg.unit_price);
+
+    state.save_listing(&Listing {
+        seller: ctx.sender,
+        token_type: msg.token_type,
+        collection: msg.collection,
+        token_id: msg.token_id,
+        amount: msg.amount,
+        currency: msg.currency,
+        unit_price: msg.unit_price,
+    });
+
+    vec![]
+}
+
+/// ## Description
+/// Removes listing. Can be executed by seller or marketplace owner.
+/// Returns [`(NFTMarketplaceContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`NFTMarketplaceContractState`]
+///
+/// * **msg** is an object of type [`CancelListingMsg`]
+pub fn execute_cancel_listing(
+    ctx: &ContractContext,
+    state: &mut NFTMarketplaceContractState,
+    msg: &CancelListingMsg,
+) -> Vec<EventGroup> {
+    let listing = state
+        .listings
+        .get(&msg.listing_id)
+        .unwrap_or_else(|| panic!("{}", ContractError::NotFound));
+
+    assert!(
+        listing.seller == ctx.sender || state.is_owner(&ctx.sender),
+        "{}",
+        ContractError::Unauthorized
+    );
+
+    state.listings.remove(&msg.listing_id);
+    vec![]
+}
+
+/// ## Description
+/// Buys specified amount of listed tokens.
+/// Seller ownership is re-checked on the token contract before payment is taken.
+/// Returns [`(NFTMarketplaceContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`NFTMarketplaceContractState`]
+///
+/// * **msg** is an object of type [`BuyMsg`]
+pub fn execute_buy(
+    ctx: &ContractContext,
+    state: &mut NFTMarketplaceContractState,
+    msg: &BuyMsg,
+) -> Vec<EventGroup> {
+    let listing = state
+        .listings
+        .get(&msg.listing_id)
+        .unwrap_or_else(|| panic!("{}", ContractError::NotFound))
+        .clone();
+
+    assert!(
+        listing.seller != ctx.sender,
+        "{}",
+        ContractError::CannotFillOwnOrder
+    );
+
+    state.reserve(LISTING_ORDER_TYPE, msg.listing_id, msg.amount);
+
+    let fill = FillMsg {
+        order_type: LISTING_ORDER_TYPE,
+        order_id: msg.listing_id,
+        seller: listing.seller,
+        buyer: ctx.sender,
+        token_type: listing.token_type,
+        collection: listing.collection,
+        token_id: listing.token_id,
+        amount: msg.amount,
+        currency: listing.currency,
+        total_price: listing.unit_price.checked_mul(msg.amount).unwrap(),
+    };
+
+    vec![ownership_check_event(&fill)]
+}
+
+/// ## Description
+/// Creates an offer for a single token or for any token of the collection.
+/// Buyer must approve marketplace to spend offered amount of mpc20 tokens.
+/// Returns [`(NFTMarketplaceContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`NFTMarketplaceContractState`]
+///
+/// * **msg** is an object of type [`CreateOfferMsg`]
+pub fn execute_create_offer(
+    ctx: &ContractContext,
+    state: &mut NFTMarketplaceContractState,
+    msg: &CreateOfferMsg,
+) -> Vec<EventGroup> {
+    validate_order(msg.token_type, msg.amount, msg.unit_price);
+    assert!(
+        msg.expires_at > ctx.block_production_time as u64,
+        "{}",
+        ContractError::OfferExpired
+    );
+
+    state.save_offer(&Offer {
+        buyer: ctx.sender,
+        token_type: msg.token_type,
+        collection: msg.collection,
+        token_id: msg.token_id,
+        amount: msg.amount,
+        currency: msg.currency,
+        unit_price: msg.unit_price,
+        expires_at: msg.expires_at,
+    });
+
+    vec![]
+}
+
+/// ## Description
+/// Removes offer. Can be executed by buyer or marketplace owner.
+/// Returns [`(NFTMarketplaceContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`NFTMarketplaceContractState`]
+///
+/// * **msg** is an object of type [`CancelOfferMsg`]
+pub fn execute_cancel_offer(
+    ctx: &ContractContext,
+    state: &mut NFTMarketplaceContractState,
+    msg: &CancelOfferMsg,
+) -> Vec<EventGroup> {
+    let offer = state
+        .offers
+        .get(&msg.offer_id)
+        .unwrap_or_else(|| panic!("{}", ContractError::NotFound));
+
+    assert!(
+        offer.buyer == ctx.sender || state.is_owner(&ctx.sender),
+        "{}",
+        ContractError::Unauthorized
+    );
+
+    state.offers.remove(&msg.offer_id);
+    vec![]
+}
+
+/// ## Description
+/// Sells tokens to the offer creator.
+/// Seller ownership is re-checked on the token contract before payment is taken.
+/// Returns [`(NFTMarketplaceContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`NFTMarketplaceContractState`]
+///
+/// * **msg** is an object of type [`AcceptOfferMsg`]
+pub fn execute_accept_offer(
+    ctx: &ContractContext,
+    state: &mut NFTMarketplaceContractState,
+    msg: &AcceptOfferMsg,
+) -> Vec<EventGroup> {
+    let offer = state
+        .offers
+        .get(&msg.offer_id)
+        .unwrap_or_else(|| panic!("{}", ContractError::NotFound))
+        .clone();
+
+    assert!(
+        offer.buyer != ctx.sender,
+        "{}",
+        ContractError::CannotFillOwnOrder
+    );
+    assert!(
+        offer.expires_at > ctx.block_production_time as u64,
+        "{}",
+        ContractError::OfferExpired
+    );
+    if let Some(token_id) = offer.token_id {
+        assert!(
+            token_id == msg.token_id,
+            "{}",
+            ContractError::TokenIdMismatch
+        );
+    }
+
+    state.reserve(OFFER_ORDER_TYPE, msg.offer_id, msg.amount);
+
+    let fill = FillMsg {
+        order_type: OFFER_ORDER_TYPE,
+        order_id: msg.offer_id,
+        seller: ctx.sender,
+        buyer: offer.buyer,
+        token_type: offer.token_type,
+        collection: offer.collection,
+        token_id: msg.token_id,
+        amount: msg.amount,
+        currency: offer.currency,
+        total_price: offer.unit_price.checked_mul(msg.amount).unwrap(),
+    };
+
+    vec![ownership_check_event(&fill)]
+}
+
+/// ## Description
+/// Handles result of the seller ownership check and takes payment from the buyer.
+/// Returns [`(NFTMarketplaceContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **callback_ctx** is an object of type [`CallbackContext`]
+///
+/// * **state** is an object of type [`NFTMarketplaceContractState`]
+///
+/// * **msg** is an object of type [`FillMsg`]
+pub fn execute_on_ownership_checked(
+    ctx: &ContractContext,
+    callback_ctx: &CallbackContext,
+    state: &mut NFTMarketplaceContractState,
+    msg: &FillMsg,
+) -> Vec<EventGroup> {
+    if !callback_ctx.success {
+        state.release(msg);
+        return vec![];
+    }
+
+    let mut event_group = EventGroup::builder();
+    Mpc20TransferFromMsg {
+        from: msg.buyer,
+        to: ctx.contract_address,
+        amount: msg.total_price,
+    }
+    .as_interaction(&mut event_group, &msg.currency);
+    build_msg_callback(&mut event_group, ON_PAYMENT_RECEIVED_CALLBACK, msg);
+
+    vec![event_group.build()]
+}
+
+/// ## Description
+/// Handles result of the payment and transfers tokens from seller to buyer.
+/// Returns [`(NFTMarketplaceContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **callback_ctx** is an object of type [`CallbackContext`]
+///
+/// * **state** is an object of type [`NFTMarketplaceContractState`]
+///
+/// * **msg** is an object of type [`FillMsg`]
+pub fn execute_on_payment_received(
+    ctx: &ContractContext,
+    callback_ctx: &CallbackContext,
+    state: &mut NFTMarketplaceContractState,
+    msg: &FillMsg,
+) -> Vec<EventGroup> {
+    if !callback_ctx.success {
+        state.release(msg);
+        return vec![];
+    }
+
+    let mut event_group = EventGroup::builder();
+    match msg.token_type {
+        MPC721_TOKEN_TYPE => Mpc721TransferFromMsg {
+            from: msg.seller,
+            to: msg.buyer,
+            token_id: msg.token_id,
+        }
+        .as_interaction(&mut event_group, &msg.collection),
+        _ => Mpc1155TransferFromMsg {
+            from: msg.seller,
+            to: msg.buyer,
+            token_info: TokenTransferInfoMsg {
+                token_id: msg.token_id,
+                amount: msg.amount,
+            },
+        }
+        .as_interaction(&mut event_group, &msg.collection),
+    };
+    build_msg_callback(&mut event_group, ON_TOKEN_TRANSFERRED_CALLBACK, msg);
+
+    vec![event_group.build()]
+}
+
+/// ## Description
+/// Handles result of the token transfer.
+/// Pays out marketplace fee, royalty and seller proceeds on success,
+/// otherwise refunds the buyer.
+/// Returns [`(NFTMarketplaceContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **callback_ctx** is an object of type [`CallbackContext`]
+///
+/// * **state** is an object of type [`NFTMarketplaceContractState`]
+///
+/// * **msg** is an object of type [`FillMsg`]
+pub fn execute_on_token_transferred(
+    ctx: &ContractContext,
+    callback_ctx: &CallbackContext,
+    state: &mut NFTMarketplaceContractState,
+    msg: &FillMsg,
+) -> Vec<EventGroup> {
+    let mut event_group = EventGroup::builder();
+
+    if !callback_ctx.success {
+        state.release(msg);
+
+        Mpc20TransferMsg {
+            to: msg.buyer,
+            amount: msg.total_price,
+        }
+        .as_interaction(&mut event_group, &msg.currency);
+
+        return vec![event_group.build()];
+    }
+
+    state.settle(msg);
+
+    let split = state.split_payment(&msg.collection, msg.total_price);
+    let mut payouts = vec![(state.fee_receiver, split.fee)];
+    if let Some(royalty) = split.royalty {
+        payouts.push(royalty);
+    }
+    payouts.push((msg.seller, split.seller));
+
+    for (to, amount) in payouts.into_iter().filter(|(_, amount)| *amount > 0) {
+        Mpc20TransferMsg { to, amount }.as_interaction(&mut event_group, &msg.currency);
+    }
+
+    vec![event_group.build()]
+}
+
+/// ## Description
+/// Sets creator royalty for the collection. Can only be executed by marketplace owner.
+/// Returns [`(NFTMarketplaceContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`NFTMarketplaceContractState`]
+///
+/// * **msg** is an object of type [`SetRoyaltyMsg`]
+pub fn execute_set_royalty(
+    ctx: &ContractContext,
+    state: &mut NFTMarketplaceContractState,
+    msg: &SetRoyaltyMsg,
+) -> Vec<EventGroup> {
+    assert!(
+        state.is_owner(&ctx.sender),
+        "{}",
+        ContractError::Unauthorized
+    );
+
+    state.set_royalty(
+        &msg.collection,
+        &Royalty {
+            receiver: msg.receiver,
+            basis_points: msg.basis_points,
+        },
+    );
+    vec![]
+}
+
+/// ## Description
+/// Removes creator royalty of the collection. Can only be executed by marketplace owner.
+/// Returns [`(NFTMarketplaceContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`NFTMarketplaceContractState`]
+///
+/// * **msg** is an object of type [`RemoveRoyaltyMsg`]
+pub fn execute_remove_royalty(
+    ctx: &ContractContext,
+    state: &mut NFTMarketplaceContractState,
+    msg: &RemoveRoyaltyMsg,
+) -> Vec<EventGroup> {
+    assert!(
+        state.is_owner(&ctx.sender),
+        "{}",
+        ContractError::Unauthorized
+    );
+
+    state.royalties.remove(&msg.collection);
+    vec![]
+}
+
+/// ## Description
+/// Updates marketplace fee. Can only be executed by marketplace owner.
+/// Returns [`(NFTMarketplaceContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`NFTMarketplaceContractState`]
+///
+/// * **msg** is an object of type [`UpdateFeeMsg`]
+pub fn execute_update_fee(
+    ctx: &ContractContext,
+    state: &mut NFTMarketplaceContractState,
+    msg: &UpdateFeeMsg,
+) -> Vec<EventGroup> {
+    assert!(
+        state.is_owner(&ctx.sender),
+        "{}",
+        ContractError::Unauthorized
+    );
+
+    state.set_fee(&msg.fee_receiver, msg.fee_basis_points);
+    vec![]
+}
+
+fn validate_order(token_type: TokenType, amount: u128, unit_price: u128) {
+    match token_type {
+        MPC721_TOKEN_TYPE => assert!(amount == 1, "{}", ContractError::InvalidAmount),
+        MPC1155_TOKEN_TYPE => assert!(amount > 0, "{}", ContractError::InvalidAmount),
+        _ => panic!("{}", ContractError::UnknownTokenType),
+    };
+
+    assert!(
+        unit_price > 0,
+        "{}",
+        ContractError::PriceMustBeHigherThenZero
+    );
+}
+
+fn ownership_check_event(fill: &FillMsg) -> EventGroup {
+    let mut event_group = EventGroup::builder();
+    match fill.token_type {
+        MPC721_TOKEN_TYPE => CheckOwnerMsg {
+            owner: fill.seller,
+            token_id: fill.token_id,
+        }
+        .as_interaction(&mut event_group, &fill.collection),
+        _ => CheckBalancesMsg {
+            owner: fill.seller,
+            token_ids: vec![fill.token_id],
+            amounts: vec![fill.amount],
+        }
+        .as_interaction(&mut event_group, &fill.collection),
+    };
+    build_msg_callback(&mut event_group, ON_OWNERSHIP_CHECKED_CALLBACK, fill);
+
+    event_group.build()
+}
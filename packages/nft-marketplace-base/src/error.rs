@@ -0,0 +1,33 @@
+use thiserror::Error;
+
+/// ## Description
+/// This enum describes nft-marketplace contract errors
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("Unauthorized")]
+    Unauthorized,
+
+    #[error("Not found")]
+    NotFound,
+
+    #[error("Unknown token type")]
+    UnknownTokenType,
+
+    #[error("Invalid amount")]
+    InvalidAmount,
+
+    #[error("Price must be higher then zero")]
+    PriceMustBeHigherThenZero,
+
+    #[error("Basis points must not exceed 10000")]
+    InvalidBasisPoints,
+
+    #[error("Cannot fill own order")]
+    CannotFillOwnOrder,
+
+    #[error("Offer has expired")]
+    OfferExpired,
+
+    #[error("Token id does not match the offer")]
+    TokenIdMismatch,
+}
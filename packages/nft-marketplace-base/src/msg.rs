@@ -0,0 +1,164 @@
+use create_type_spec_derive::CreateTypeSpec;
+use pbc_contract_common::address::{Address, Shortname};
+use read_write_rpc_derive::ReadWriteRPC;
+
+use crate::state::{OrderType, TokenType};
+use rpc_msg_derive::IntoShortnameRPCEvent;
+use utils::events::IntoShortnameRPCEvent;
+
+/// ## Description
+/// This structure describes fields for nft-marketplace initialize msg
+#[derive(ReadWriteRPC, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct InitMsg {
+    /// marketplace fee receiver address
+    pub fee_receiver: Address,
+    /// marketplace fee in basis points
+    pub fee_basis_points: u16,
+}
+
+/// ## Description
+/// This structure describes fields for nft-marketplace create listing msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x01)]
+pub struct CreateListingMsg {
+    /// token standard of the collection
+    pub token_type: TokenType,
+    /// collection address
+    pub collection: Address,
+    /// token id
+    pub token_id: u128,
+    /// amount of tokens to sell, always 1 for mpc721
+    pub amount: u128,
+    /// mpc20 token used for payment
+    pub currency: Address,
+    /// price for a single token
+    pub unit_price: u128,
+}
+
+/// ## Description
+/// This structure describes fields for nft-marketplace cancel listing msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x03)]
+pub struct CancelListingMsg {
+    /// listing id
+    pub listing_id: u64,
+}
+
+/// ## Description
+/// This structure describes fields for nft-marketplace buy msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x05)]
+pub struct BuyMsg {
+    /// listing id
+    pub listing_id: u64,
+    /// amount of tokens to buy
+    pub amount: u128,
+}
+
+/// ## Description
+/// This structure describes fields for nft-marketplace create offer msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x07)]
+pub struct CreateOfferMsg {
+    /// token standard of the collection
+    pub token_type: TokenType,
+    /// collection address
+    pub collection: Address,
+    /// optional token id, if None then any token of the collection is accepted
+    pub token_id: Option<u128>,
+    /// amount of tokens to buy, always 1 for mpc721
+    pub amount: u128,
+    /// mpc20 token used for payment
+    pub currency: Address,
+    /// price for a single token
+    pub unit_price: u128,
+    /// UTC timestamp
+    pub expires_at: u64,
+}
+
+/// ## Description
+/// This structure describes fields for nft-marketplace cancel offer msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x09)]
+pub struct CancelOfferMsg {
+    /// offer id
+    pub offer_id: u64,
+}
+
+/// ## Description
+/// This structure describes fields for nft-marketplace accept offer msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x11)]
+pub struct AcceptOfferMsg {
+    /// offer id
+    pub offer_id: u64,
+    /// token id to sell
+    pub token_id: u128,
+    /// amount of tokens to sell
+    pub amount: u128,
+}
+
+/// ## Description
+/// This structure describes fields for nft-marketplace set royalty msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x13)]
+pub struct SetRoyaltyMsg {
+    /// collection address
+    pub collection: Address,
+    /// royalty receiver address
+    pub receiver: Address,
+    /// royalty in basis points
+    pub basis_points: u16,
+}
+
+/// ## Description
+/// This structure describes fields for nft-marketplace remove royalty msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x15)]
+pub struct RemoveRoyaltyMsg {
+    /// collection address
+    pub collection: Address,
+}
+
+/// ## Description
+/// This structure describes fields for nft-marketplace update fee msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x17)]
+pub struct UpdateFeeMsg {
+    /// marketplace fee receiver address
+    pub fee_receiver: Address,
+    /// marketplace fee in basis points
+    pub fee_basis_points: u16,
+}
+
+/// ## Description
+/// This structure describes an order fill which is passed through the settlement callbacks
+#[derive(ReadWriteRPC, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct FillMsg {
+    /// type of the filled order
+    pub order_type: OrderType,
+    /// listing or offer id
+    pub order_id: u64,
+    /// token seller address
+    pub seller: Address,
+    /// token buyer address
+    pub buyer: Address,
+    /// token standard of the collection
+    pub token_type: TokenType,
+    /// collection address
+    pub collection: Address,
+    /// token id
+    pub token_id: u128,
+    /// amount of tokens
+    pub amount: u128,
+    /// mpc20 token used for payment
+    pub currency: Address,
+    /// total price paid by the buyer
+    pub total_price: u128,
+}
+
+/// ## Description
+/// Callback shortnames used during order settlement
+pub const ON_OWNERSHIP_CHECKED_CALLBACK: u32 = 0x31;
+pub const ON_PAYMENT_RECEIVED_CALLBACK: u32 = 0x33;
+pub const ON_TOKEN_TRANSFERRED_CALLBACK: u32 = 0x35;
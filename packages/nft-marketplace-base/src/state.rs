@@ -0,0 +1,271 @@
+use std::collections::BTreeMap;
+
+use create_type_spec_derive::CreateTypeSpec;
+use pbc_contract_common::address::Address;
+use read_write_rpc_derive::ReadWriteRPC;
+use read_write_state_derive::ReadWriteState;
+
+use crate::{msg::FillMsg, ContractError};
+
+/// ## Description
+/// This structure describes main nft-marketplace contract state.
+#[derive(ReadWriteState, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct NFTMarketplaceContractState {
+    /// owner address
+    pub owner: Address,
+    /// marketplace fee receiver address
+    pub fee_receiver: Address,
+    /// marketplace fee in basis points
+    pub fee_basis_points: u16,
+    /// creator royalties by collection address
+    pub royalties: BTreeMap<Address, Royalty>,
+    /// listings counter
+    pub listings_count: u64,
+    /// listing information by id
+    pub listings: BTreeMap<u64, Listing>,
+    /// offers counter
+    pub offers_count: u64,
+    /// offer information by id
+    pub offers: BTreeMap<u64, Offer>,
+}
+
+/// ## Description
+/// This structure describes fixed price listing
+#[derive(ReadWriteRPC, ReadWriteState, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct Listing {
+    /// token seller address
+    pub seller: Address,
+    /// token standard of the collection
+    pub token_type: TokenType,
+    /// collection address
+    pub collection: Address,
+    /// token id
+    pub token_id: u128,
+    /// amount of tokens left for sale
+    pub amount: u128,
+    /// mpc20 token used for payment
+    pub currency: Address,
+    /// price for a single token
+    pub unit_price: u128,
+}
+
+/// ## Description
+/// This structure describes collection-wide or token-specific offer
+#[derive(ReadWriteRPC, ReadWriteState, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct Offer {
+    /// token buyer address
+    pub buyer: Address,
+    /// token standard of the collection
+    pub token_type: TokenType,
+    /// collection address
+    pub collection: Address,
+    /// optional token id, if None then any token of the collection is accepted
+    pub token_id: Option<u128>,
+    /// amount of tokens left to buy
+    pub amount: u128,
+    /// mpc20 token used for payment
+    pub currency: Address,
+    /// price for a single token
+    pub unit_price: u128,
+    /// UTC timestamp
+    pub expires_at: u64,
+}
+
+/// ## Description
+/// This structure describes creator royalty information
+#[derive(ReadWriteRPC, ReadWriteState, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct Royalty {
+    /// royalty receiver address
+    pub receiver: Address,
+    /// royalty in basis points
+    pub basis_points: u16,
+}
+
+/// ## Description
+/// This structure describes how a sale price is split between participants
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct PaymentSplit {
+    /// marketplace fee amount
+    pub fee: u128,
+    /// optional royalty receiver and amount
+    pub royalty: Option<(Address, u128)>,
+    /// amount left for the seller
+    pub seller: u128,
+}
+
+/// ## Description
+/// Defines a type for the traded token standard
+pub type TokenType = u8;
+pub const MPC721_TOKEN_TYPE: TokenType = 1;
+pub const MPC1155_TOKEN_TYPE: TokenType = 2;
+
+/// ## Description
+/// Defines a type for the filled order
+pub type OrderType = u8;
+pub const LISTING_ORDER_TYPE: OrderType = 1;
+pub const OFFER_ORDER_TYPE: OrderType = 2;
+
+/// ## Description
+/// Basis points representing 100%
+pub const MAX_BASIS_POINTS: u16 = 10_000;
+
+impl NFTMarketplaceContractState {
+    /// ## Description
+    /// Stores newly created listing and returns its id
+    /// ## Params
+    /// * **listing** is an object of type [`Listing`]
+    pub fn save_listing(&mut self, listing: &Listing) -> u64 {
+        self.listings_count += 1;
+        self.listings.insert(self.listings_count, listing.clone());
+        self.listings_count
+    }
+
+    /// ## Description
+    /// Stores newly created offer and returns its id
+    /// ## Params
+    /// * **offer** is an object of type [`Offer`]
+    pub fn save_offer(&mut self, offer: &Offer) -> u64 {
+        self.offers_count += 1;
+        self.offers.insert(self.offers_count, offer.clone());
+        self.offers_count
+    }
+
+    /// ## Description
+    /// Reserves specified amount of tokens of the order until the fill is settled
+    /// ## Params
+    /// * **order_type** is an object of type [`OrderType`]
+    ///
+    /// * **order_id** is a field of type [`u64`]
+    ///
+    /// * **amount** is a field of type [`u128`]
+    pub fn reserve(&mut self, order_type: OrderType, order_id: u64, amount: u128) {
+        let available = self
+            .order_amount_mut(order_type, order_id)
+            .unwrap_or_else(|| panic!("{}", ContractError::NotFound));
+
+        assert!(
+            amount > 0 && amount <= *available,
+            "{}",
+            ContractError::InvalidAmount
+        );
+        *available -= amount;
+    }
+
+    /// ## Description
+    /// Returns reserved amount back to the order if it still exists
+    /// ## Params
+    /// * **fill** is an object of type [`FillMsg`]
+    pub fn release(&mut self, fill: &FillMsg) {
+        if let Some(available) = self.order_amount_mut(fill.order_type, fill.order_id) {
+            *available = available.checked_add(fill.amount).unwrap();
+        }
+    }
+
+    /// ## Description
+    /// Removes the order after successful fill if nothing is left in it
+    /// ## Params
+    /// * **fill** is an object of type [`FillMsg`]
+    pub fn settle(&mut self, fill: &FillMsg) {
+        match fill.order_type {
+            LISTING_ORDER_TYPE
+                if self.listings.get(&fill.order_id).map(|l| l.amount) == Some(0) =>
+            {
+                self.listings.remove(&fill.order_id);
+            }
+            OFFER_ORDER_TYPE if self.offers.get(&fill.order_id).map(|o| o.amount) == Some(0) => {
+                self.offers.remove(&fill.order_id);
+            }
+            _ => {}
+        }
+    }
+
+    /// ## Description
+    /// Sets royalty for the specified collection
+    /// ## Params
+    /// * **collection** is an object of type [`Address`]
+    ///
+    /// * **royalty** is an object of type [`Royalty`]
+    pub fn set_royalty(&mut self, collection: &Address, royalty: &Royalty) {
+        self.royalties.insert(*collection, royalty.clone());
+        self.assert_valid_basis_points();
+    }
+
+    /// ## Description
+    /// Updates marketplace fee
+    /// ## Params
+    /// * **fee_receiver** is an object of type [`Address`]
+    ///
+    /// * **fee_basis_points** is a field of type [`u16`]
+    pub fn set_fee(&mut self, fee_receiver: &Address, fee_basis_points: u16) {
+        self.fee_receiver = *fee_receiver;
+        self.fee_basis_points = fee_basis_points;
+        self.assert_valid_basis_points();
+    }
+
+    /// ## Description
+    /// Splits total price between marketplace, royalty receiver and seller
+    /// ## Params
+    /// * **collection** is an object of type [`Address`]
+    ///
+    /// * **total_price** is a field of type [`u128`]
+    pub fn split_payment(&self, collection: &Address, total_price: u128) -> PaymentSplit {
+        let fee = Self::basis_points_of(total_price, self.fee_basis_points);
+        let royalty = self.royalties.get(collection).map(|r| {
+            (
+                r.receiver,
+                Self::basis_points_of(total_price, r.basis_points),
+            )
+        });
+
+        let royalty_amount = royalty.map(|(_, amount)| amount).unwrap_or(0);
+        let seller = total_price
+            .checked_sub(fee)
+            .and_then(|rest| rest.checked_sub(royalty_amount))
+            .unwrap();
+
+        PaymentSplit {
+            fee,
+            royalty,
+            seller,
+        }
+    }
+
+    /// ## Description
+    /// Checks that address is owner or not
+    /// ## Params
+    /// * **address** is an object of type [`Address`]
+    pub fn is_owner(&self, address: &Address) -> bool {
+        self.owner == *address
+    }
+
+    fn order_amount_mut(&mut self, order_type: OrderType, order_id: u64) -> Option<&mut u128> {
+        match order_type {
+            LISTING_ORDER_TYPE => self.listings.get_mut(&order_id).map(|l| &mut l.amount),
+            OFFER_ORDER_TYPE => self.offers.get_mut(&order_id).map(|o| &mut o.amount),
+            _ => None,
+        }
+    }
+
+    fn assert_valid_basis_points(&self) {
+        let max_royalty = self
+            .royalties
+            .values()
+            .map(|r| r.basis_points)
+            .max()
+            .unwrap_or(0);
+
+        assert!(
+            (self.fee_basis_points as u32) + (max_royalty as u32) <= MAX_BASIS_POINTS as u32,
+            "{}",
+            ContractError::InvalidBasisPoints
+        );
+    }
+
+    fn basis_points_of(amount: u128, basis_points: u16) -> u128 {
+        amount
+            .checked_mul(basis_points as u128)
+            .unwrap()
+            .checked_div(MAX_BASIS_POINTS as u128)
+            .unwrap()
+    }
+}
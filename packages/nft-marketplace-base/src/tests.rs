@@ -0,0 +1,934 @@
+use std::collections::BTreeMap;
+
+use pbc_contract_common::{
+    address::{Address, AddressType},
+    context::{CallbackContext, ContractContext},
+    events::EventGroup,
+};
+
+use crate::{
+    actions::{
+        execute_accept_offer, execute_buy, execute_cancel_listing, execute_cancel_offer,
+        execute_create_listing, execute_create_offer, execute_init, execute_on_ownership_checked,
+        execute_on_payment_received, execute_on_token_transferred, execute_remove_royalty,
+        execute_set_royalty, execute_update_fee,
+    },
+    msg::{
+        AcceptOfferMsg, BuyMsg, CancelListingMsg, CancelOfferMsg, CreateListingMsg, CreateOfferMsg,
+        FillMsg, InitMsg, RemoveRoyaltyMsg, SetRoyaltyMsg, UpdateFeeMsg,
+        ON_OWNERSHIP_CHECKED_CALLBACK, ON_PAYMENT_RECEIVED_CALLBACK, ON_TOKEN_TRANSFERRED_CALLBACK,
+    },
+    state::{
+        Listing, NFTMarketplaceContractState, Offer, PaymentSplit, Royalty, LISTING_ORDER_TYPE,
+        MPC1155_TOKEN_TYPE, MPC721_TOKEN_TYPE, OFFER_ORDER_TYPE,
+    },
+};
+
+use mpc1155_base::msg::{
+    CheckBalancesMsg, TokenTransferInfoMsg, TransferFromMsg as Mpc1155TransferFromMsg,
+};
+use mpc20_base::msg::{TransferFromMsg as Mpc20TransferFromMsg, TransferMsg as Mpc20TransferMsg};
+use mpc721_base::msg::{CheckOwnerMsg, TransferFromMsg as Mpc721TransferFromMsg};
+use utils::events::{build_msg_callback, IntoShortnameRPCEvent};
+
+const MARKETPLACE: u8 = 1;
+const OWNER: u8 = 2;
+const FEE_RECEIVER: u8 = 3;
+const COLLECTION: u8 = 4;
+const CURRENCY: u8 = 5;
+const CREATOR: u8 = 6;
+const ALICE: u8 = 10;
+const BOB: u8 = 11;
+
+fn mock_address(le: u8) -> Address {
+    Address {
+        address_type: AddressType::Account,
+        identifier: [
+            le, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8,
+        ],
+    }
+}
+
+fn mock_contract_context(sender: u8) -> ContractContext {
+    ContractContext {
+        contract_address: mock_address(MARKETPLACE),
+        sender: mock_address(sender),
+        block_time: 100,
+        block_production_time: 100,
+        current_transaction: [
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+        ],
+        original_transaction: [
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+        ],
+    }
+}
+
+fn mock_callback_context(success: bool) -> CallbackContext {
+    CallbackContext {
+        success,
+        results: vec![],
+    }
+}
+
+fn init_marketplace() -> NFTMarketplaceContractState {
+    let msg = InitMsg {
+        fee_receiver: mock_address(FEE_RECEIVER),
+        fee_basis_points: 250,
+    };
+
+    let (state, _) = execute_init(&mock_contract_context(OWNER), &msg);
+    state
+}
+
+fn mpc721_listing_msg() -> CreateListingMsg {
+    CreateListingMsg {
+        token_type: MPC721_TOKEN_TYPE,
+        collection: mock_address(COLLECTION),
+        token_id: 1,
+        amount: 1,
+        currency: mock_address(CURRENCY),
+        unit_price: 1_000,
+    }
+}
+
+fn mpc1155_listing_msg() -> CreateListingMsg {
+    CreateListingMsg {
+        token_type: MPC1155_TOKEN_TYPE,
+        collection: mock_address(COLLECTION),
+        token_id: 7,
+        amount: 10,
+        currency: mock_address(CURRENCY),
+        unit_price: 100,
+    }
+}
+
+#[test]
+fn proper_execute_init() {
+    let msg = InitMsg {
+        fee_receiver: mock_address(FEE_RECEIVER),
+        fee_basis_points: 250,
+    };
+
+    let (state, events) = execute_init(&mock_contract_context(OWNER), &msg);
+    assert_eq!(events.len(), 0);
+    assert_eq!(
+        state,
+        NFTMarketplaceContractState {
+            owner: mock_address(OWNER),
+            fee_receiver: mock_address(FEE_RECEIVER),
+            fee_basis_points: 250,
+            royalties: BTreeMap::new(),
+            listings_count: 0,
+            listings: BTreeMap::new(),
+            offers_count: 0,
+            offers: BTreeMap::new(),
+        }
+    );
+}
+
+#[test]
+#[should_panic(expected = "Basis points must not exceed 10000")]
+fn fee_too_high_on_init() {
+    let msg = InitMsg {
+        fee_receiver: mock_address(FEE_RECEIVER),
+        fee_basis_points: 10_001,
+    };
+
+    let _ = execute_init(&mock_contract_context(OWNER), &msg);
+}
+
+#[test]
+fn proper_create_and_cancel_listing() {
+    let mut state = init_marketplace();
+
+    let _ = execute_create_listing(
+        &mock_contract_context(ALICE),
+        &mut state,
+        &mpc721_listing_msg(),
+    );
+    let _ = execute_create_listing(
+        &mock_contract_context(ALICE),
+        &mut state,
+        &mpc1155_listing_msg(),
+    );
+
+    assert_eq!(state.listings_count, 2);
+    assert_eq!(
+        state.listings,
+        BTreeMap::from([
+            (
+                1,
+                Listing {
+                    seller: mock_address(ALICE),
+                    token_type: MPC721_TOKEN_TYPE,
+                    collection: mock_address(COLLECTION),
+                    token_id: 1,
+                    amount: 1,
+                    currency: mock_address(CURRENCY),
+                    unit_price: 1_000,
+                }
+            ),
+            (
+                2,
+                Listing {
+                    seller: mock_address(ALICE),
+                    token_type: MPC1155_TOKEN_TYPE,
+                    collection: mock_address(COLLECTION),
+                    token_id: 7,
+                    amount: 10,
+                    currency: mock_address(CURRENCY),
+                    unit_price: 100,
+                }
+            )
+        ])
+    );
+
+    let _ = execute_cancel_listing(
+        &mock_contract_context(ALICE),
+        &mut state,
+        &CancelListingMsg { listing_id: 1 },
+    );
+    let _ = execute_cancel_listing(
+        &mock_contract_context(OWNER),
+        &mut state,
+        &CancelListingMsg { listing_id: 2 },
+    );
+    assert_eq!(state.listings, BTreeMap::new());
+    assert_eq!(state.listings_count, 2);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn cancel_not_own_listing() {
+    let mut state = init_marketplace();
+
+    let _ = execute_create_listing(
+        &mock_contract_context(ALICE),
+        &mut state,
+        &mpc721_listing_msg(),
+    );
+    let _ = execute_cancel_listing(
+        &mock_contract_context(BOB),
+        &mut state,
+        &CancelListingMsg { listing_id: 1 },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Invalid amount")]
+fn list_multiple_mpc721_tokens() {
+    let mut state = init_marketplace();
+
+    let mut msg = mpc721_listing_msg();
+    msg.amount = 2;
+    let _ = execute_create_listing(&mock_contract_context(ALICE), &mut state, &msg);
+}
+
+#[test]
+#[should_panic(expected = "Unknown token type")]
+fn list_unknown_token_type() {
+    let mut state = init_marketplace();
+
+    let mut msg = mpc721_listing_msg();
+    msg.token_type = 3;
+    let _ = execute_create_listing(&mock_contract_context(ALICE), &mut state, &msg);
+}
+
+#[test]
+#[should_panic(expected = "Price must be higher then zero")]
+fn list_with_zero_price() {
+    let mut state = init_marketplace();
+
+    let mut msg = mpc721_listing_msg();
+    msg.unit_price = 0;
+    let _ = execute_create_listing(&mock_contract_context(ALICE), &mut state, &msg);
+}
+
+#[test]
+fn proper_mpc721_buy() {
+    let mut state = init_marketplace();
+    let _ = execute_set_royalty(
+        &mock_contract_context(OWNER),
+        &mut state,
+        &SetRoyaltyMsg {
+            collection: mock_address(COLLECTION),
+            receiver: mock_address(CREATOR),
+            basis_points: 500,
+        },
+    );
+    let _ = execute_create_listing(
+        &mock_contract_context(ALICE),
+        &mut state,
+        &mpc721_listing_msg(),
+    );
+
+    let events = execute_buy(
+        &mock_contract_context(BOB),
+        &mut state,
+        &BuyMsg {
+            listing_id: 1,
+            amount: 1,
+        },
+    );
+
+    let fill = FillMsg {
+        order_type: LISTING_ORDER_TYPE,
+        order_id: 1,
+        seller: mock_address(ALICE),
+        buyer: mock_address(BOB),
+        token_type: MPC721_TOKEN_TYPE,
+        collection: mock_address(COLLECTION),
+        token_id: 1,
+        amount: 1,
+        currency: mock_address(CURRENCY),
+        total_price: 1_000,
+    };
+
+    let mut eg = EventGroup::builder();
+    CheckOwnerMsg {
+        owner: mock_address(ALICE),
+        token_id: 1,
+    }
+    .as_interaction(&mut eg, &mock_address(COLLECTION));
+    build_msg_callback(&mut eg, ON_OWNERSHIP_CHECKED_CALLBACK, &fill);
+    assert_eq!(events, vec![eg.build()]);
+    assert_eq!(state.listings.get(&1).unwrap().amount, 0);
+
+    let events = execute_on_ownership_checked(
+        &mock_contract_context(MARKETPLACE),
+        &mock_callback_context(true),
+        &mut state,
+        &fill,
+    );
+
+    let mut eg = EventGroup::builder();
+    Mpc20TransferFromMsg {
+        from: mock_address(BOB),
+        to: mock_address(MARKETPLACE),
+        amount: 1_000,
+    }
+    .as_interaction(&mut eg, &mock_address(CURRENCY));
+    build_msg_callback(&mut eg, ON_PAYMENT_RECEIVED_CALLBACK, &fill);
+    assert_eq!(events, vec![eg.build()]);
+
+    let events = execute_on_payment_received(
+        &mock_contract_context(MARKETPLACE),
+        &mock_callback_context(true),
+        &mut state,
+        &fill,
+    );
+
+    let mut eg = EventGroup::builder();
+    Mpc721TransferFromMsg {
+        from: mock_address(ALICE),
+        to: mock_address(BOB),
+        token_id: 1,
+    }
+    .as_interaction(&mut eg, &mock_address(COLLECTION));
+    build_msg_callback(&mut eg, ON_TOKEN_TRANSFERRED_CALLBACK, &fill);
+    assert_eq!(events, vec![eg.build()]);
+
+    let events = execute_on_token_transferred(
+        &mock_contract_context(MARKETPLACE),
+        &mock_callback_context(true),
+        &mut state,
+        &fill,
+    );
+
+    let mut eg = EventGroup::builder();
+    Mpc20TransferMsg {
+        to: mock_address(FEE_RECEIVER),
+        amount: 25,
+    }
+    .as_interaction(&mut eg, &mock_address(CURRENCY));
+    Mpc20TransferMsg {
+        to: mock_address(CREATOR),
+        amount: 50,
+    }
+    .as_interaction(&mut eg, &mock_address(CURRENCY));
+    Mpc20TransferMsg {
+        to: mock_address(ALICE),
+        amount: 925,
+    }
+    .as_interaction(&mut eg, &mock_address(CURRENCY));
+    assert_eq!(events, vec![eg.build()]);
+    assert_eq!(state.listings, BTreeMap::new());
+}
+
+#[test]
+fn proper_mpc1155_partial_buy() {
+    let mut state = init_marketplace();
+    let _ = execute_create_listing(
+        &mock_contract_context(ALICE),
+        &mut state,
+        &mpc1155_listing_msg(),
+    );
+
+    let events = execute_buy(
+        &mock_contract_context(BOB),
+        &mut state,
+        &BuyMsg {
+            listing_id: 1,
+            amount: 4,
+        },
+    );
+
+    let fill = FillMsg {
+        order_type: LISTING_ORDER_TYPE,
+        order_id: 1,
+        seller: mock_address(ALICE),
+        buyer: mock_address(BOB),
+        token_type: MPC1155_TOKEN_TYPE,
+        collection: mock_address(COLLECTION),
+        token_id: 7,
+        amount: 4,
+        currency: mock_address(CURRENCY),
+        total_price: 400,
+    };
+
+    let mut eg = EventGroup::builder();
+    CheckBalancesMsg {
+        owner: mock_address(ALICE),
+        token_ids: vec![7],
+        amounts: vec![4],
+    }
+    .as_interaction(&mut eg, &mock_address(COLLECTION));
+    build_msg_callback(&mut eg, ON_OWNERSHIP_CHECKED_CALLBACK, &fill);
+    assert_eq!(events, vec![eg.build()]);
+    assert_eq!(state.listings.get(&1).unwrap().amount, 6);
+
+    let _ = execute_on_ownership_checked(
+        &mock_contract_context(MARKETPLACE),
+        &mock_callback_context(true),
+        &mut state,
+        &fill,
+    );
+    let events = execute_on_payment_received(
+        &mock_contract_context(MARKETPLACE),
+        &mock_callback_context(true),
+        &mut state,
+        &fill,
+    );
+
+    let mut eg = EventGroup::builder();
+    Mpc1155TransferFromMsg {
+        from: mock_address(ALICE),
+        to: mock_address(BOB),
+        token_info: TokenTransferInfoMsg {
+            token_id: 7,
+            amount: 4,
+        },
+    }
+    .as_interaction(&mut eg, &mock_address(COLLECTION));
+    build_msg_callback(&mut eg, ON_TOKEN_TRANSFERRED_CALLBACK, &fill);
+    assert_eq!(events, vec![eg.build()]);
+
+    let events = execute_on_token_transferred(
+        &mock_contract_context(MARKETPLACE),
+        &mock_callback_context(true),
+        &mut state,
+        &fill,
+    );
+
+    let mut eg = EventGroup::builder();
+    Mpc20TransferMsg {
+        to: mock_address(FEE_RECEIVER),
+        amount: 10,
+    }
+    .as_interaction(&mut eg, &mock_address(CURRENCY));
+    Mpc20TransferMsg {
+        to: mock_address(ALICE),
+        amount: 390,
+    }
+    .as_interaction(&mut eg, &mock_address(CURRENCY));
+    assert_eq!(events, vec![eg.build()]);
+    assert_eq!(state.listings.get(&1).unwrap().amount, 6);
+}
+
+#[test]
+#[should_panic(expected = "Cannot fill own order")]
+fn buy_own_listing() {
+    let mut state = init_marketplace();
+    let _ = execute_create_listing(
+        &mock_contract_context(ALICE),
+        &mut state,
+        &mpc721_listing_msg(),
+    );
+
+    let _ = execute_buy(
+        &mock_contract_context(ALICE),
+        &mut state,
+        &BuyMsg {
+            listing_id: 1,
+            amount: 1,
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Invalid amount")]
+fn buy_more_than_listed() {
+    let mut state = init_marketplace();
+    let _ = execute_create_listing(
+        &mock_contract_context(ALICE),
+        &mut state,
+        &mpc1155_listing_msg(),
+    );
+
+    let _ = execute_buy(
+        &mock_contract_context(BOB),
+        &mut state,
+        &BuyMsg {
+            listing_id: 1,
+            amount: 11,
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Not found")]
+fn buy_not_existing_listing() {
+    let mut state = init_marketplace();
+
+    let _ = execute_buy(
+        &mock_contract_context(BOB),
+        &mut state,
+        &BuyMsg {
+            listing_id: 1,
+            amount: 1,
+        },
+    );
+}
+
+#[test]
+fn failed_ownership_check_releases_listing() {
+    let mut state = init_marketplace();
+    let _ = execute_create_listing(
+        &mock_contract_context(ALICE),
+        &mut state,
+        &mpc1155_listing_msg(),
+    );
+
+    let _ = execute_buy(
+        &mock_contract_context(BOB),
+        &mut state,
+        &BuyMsg {
+            listing_id: 1,
+            amount: 10,
+        },
+    );
+    assert_eq!(state.listings.get(&1).unwrap().amount, 0);
+
+    let fill = FillMsg {
+        order_type: LISTING_ORDER_TYPE,
+        order_id: 1,
+        seller: mock_address(ALICE),
+        buyer: mock_address(BOB),
+        token_type: MPC1155_TOKEN_TYPE,
+        collection: mock_address(COLLECTION),
+        token_id: 7,
+        amount: 10,
+        currency: mock_address(CURRENCY),
+        total_price: 1_000,
+    };
+
+    let events = execute_on_ownership_checked(
+        &mock_contract_context(MARKETPLACE),
+        &mock_callback_context(false),
+        &mut state,
+        &fill,
+    );
+    assert_eq!(events, vec![]);
+    assert_eq!(state.listings.get(&1).unwrap().amount, 10);
+}
+
+#[test]
+fn failed_payment_releases_listing() {
+    let mut state = init_marketplace();
+    let _ = execute_create_listing(
+        &mock_contract_context(ALICE),
+        &mut state,
+        &mpc721_listing_msg(),
+    );
+
+    let _ = execute_buy(
+        &mock_contract_context(BOB),
+        &mut state,
+        &BuyMsg {
+            listing_id: 1,
+            amount: 1,
+        },
+    );
+
+    let fill = FillMsg {
+        order_type: LISTING_ORDER_TYPE,
+        order_id: 1,
+        seller: mock_address(ALICE),
+        buyer: mock_address(BOB),
+        token_type: MPC721_TOKEN_TYPE,
+        collection: mock_address(COLLECTION),
+        token_id: 1,
+        amount: 1,
+        currency: mock_address(CURRENCY),
+        total_price: 1_000,
+    };
+
+    let events = execute_on_payment_received(
+        &mock_contract_context(MARKETPLACE),
+        &mock_callback_context(false),
+        &mut state,
+        &fill,
+    );
+    assert_eq!(events, vec![]);
+    assert_eq!(state.listings.get(&1).unwrap().amount, 1);
+}
+
+#[test]
+fn failed_token_transfer_refunds_buyer() {
+    let mut state = init_marketplace();
+    let _ = execute_create_listing(
+        &mock_contract_context(ALICE),
+        &mut state,
+        &mpc721_listing_msg(),
+    );
+
+    let _ = execute_buy(
+        &mock_contract_context(BOB),
+        &mut state,
+        &BuyMsg {
+            listing_id: 1,
+            amount: 1,
+        },
+    );
+
+    let fill = FillMsg {
+        order_type: LISTING_ORDER_TYPE,
+        order_id: 1,
+        seller: mock_address(ALICE),
+        buyer: mock_address(BOB),
+        token_type: MPC721_TOKEN_TYPE,
+        collection: mock_address(COLLECTION),
+        token_id: 1,
+        amount: 1,
+        currency: mock_address(CURRENCY),
+        total_price: 1_000,
+    };
+
+    let events = execute_on_token_transferred(
+        &mock_contract_context(MARKETPLACE),
+        &mock_callback_context(false),
+        &mut state,
+        &fill,
+    );
+
+    let mut eg = EventGroup::builder();
+    Mpc20TransferMsg {
+        to: mock_address(BOB),
+        amount: 1_000,
+    }
+    .as_interaction(&mut eg, &mock_address(CURRENCY));
+    assert_eq!(events, vec![eg.build()]);
+    assert_eq!(state.listings.get(&1).unwrap().amount, 1);
+}
+
+#[test]
+fn cancelled_listing_is_not_restored() {
+    let mut state = init_marketplace();
+    let _ = execute_create_listing(
+        &mock_contract_context(ALICE),
+        &mut state,
+        &mpc721_listing_msg(),
+    );
+
+    let _ = execute_buy(
+        &mock_contract_context(BOB),
+        &mut state,
+        &BuyMsg {
+            listing_id: 1,
+            amount: 1,
+        },
+    );
+    let _ = execute_cancel_listing(
+        &mock_contract_context(ALICE),
+        &mut state,
+        &CancelListingMsg { listing_id: 1 },
+    );
+
+    let fill = FillMsg {
+        order_type: LISTING_ORDER_TYPE,
+        order_id: 1,
+        seller: mock_address(ALICE),
+        buyer: mock_address(BOB),
+        token_type: MPC721_TOKEN_TYPE,
+        collection: mock_address(COLLECTION),
+        token_id: 1,
+        amount: 1,
+        currency: mock_address(CURRENCY),
+        total_price: 1_000,
+    };
+
+    let _ = execute_on_ownership_checked(
+        &mock_contract_context(MARKETPLACE),
+        &mock_callback_context(false),
+        &mut state,
+        &fill,
+    );
+    assert_eq!(state.listings, BTreeMap::new());
+}
+
+#[test]
+fn proper_collection_offer() {
+    let mut state = init_marketplace();
+
+    let _ = execute_create_offer(
+        &mock_contract_context(BOB),
+        &mut state,
+        &CreateOfferMsg {
+            token_type: MPC721_TOKEN_TYPE,
+            collection: mock_address(COLLECTION),
+            token_id: None,
+            amount: 1,
+            currency: mock_address(CURRENCY),
+            unit_price: 500,
+            expires_at: 200,
+        },
+    );
+    assert_eq!(
+        state.offers,
+        BTreeMap::from([(
+            1,
+            Offer {
+                buyer: mock_address(BOB),
+                token_type: MPC721_TOKEN_TYPE,
+                collection: mock_address(COLLECTION),
+                token_id: None,
+                amount: 1,
+                currency: mock_address(CURRENCY),
+                unit_price: 500,
+                expires_at: 200,
+            }
+        )])
+    );
+
+    let events = execute_accept_offer(
+        &mock_contract_context(ALICE),
+        &mut state,
+        &AcceptOfferMsg {
+            offer_id: 1,
+            token_id: 42,
+            amount: 1,
+        },
+    );
+
+    let fill = FillMsg {
+        order_type: OFFER_ORDER_TYPE,
+        order_id: 1,
+        seller: mock_address(ALICE),
+        buyer: mock_address(BOB),
+        token_type: MPC721_TOKEN_TYPE,
+        collection: mock_address(COLLECTION),
+        token_id: 42,
+        amount: 1,
+        currency: mock_address(CURRENCY),
+        total_price: 500,
+    };
+
+    let mut eg = EventGroup::builder();
+    CheckOwnerMsg {
+        owner: mock_address(ALICE),
+        token_id: 42,
+    }
+    .as_interaction(&mut eg, &mock_address(COLLECTION));
+    build_msg_callback(&mut eg, ON_OWNERSHIP_CHECKED_CALLBACK, &fill);
+    assert_eq!(events, vec![eg.build()]);
+
+    let _ = execute_on_ownership_checked(
+        &mock_contract_context(MARKETPLACE),
+        &mock_callback_context(true),
+        &mut state,
+        &fill,
+    );
+    let _ = execute_on_payment_received(
+        &mock_contract_context(MARKETPLACE),
+        &mock_callback_context(true),
+        &mut state,
+        &fill,
+    );
+    let _ = execute_on_token_transferred(
+        &mock_contract_context(MARKETPLACE),
+        &mock_callback_context(true),
+        &mut state,
+        &fill,
+    );
+    assert_eq!(state.offers, BTreeMap::new());
+}
+
+#[test]
+#[should_panic(expected = "Token id does not match the offer")]
+fn accept_offer_with_wrong_token_id() {
+    let mut state = init_marketplace();
+
+    let _ = execute_create_offer(
+        &mock_contract_context(BOB),
+        &mut state,
+        &CreateOfferMsg {
+            token_type: MPC721_TOKEN_TYPE,
+            collection: mock_address(COLLECTION),
+            token_id: Some(1),
+            amount: 1,
+            currency: mock_address(CURRENCY),
+            unit_price: 500,
+            expires_at: 200,
+        },
+    );
+
+    let _ = execute_accept_offer(
+        &mock_contract_context(ALICE),
+        &mut state,
+        &AcceptOfferMsg {
+            offer_id: 1,
+            token_id: 2,
+            amount: 1,
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Offer has expired")]
+fn create_already_expired_offer() {
+    let mut state = init_marketplace();
+
+    let _ = execute_create_offer(
+        &mock_contract_context(BOB),
+        &mut state,
+        &CreateOfferMsg {
+            token_type: MPC721_TOKEN_TYPE,
+            collection: mock_address(COLLECTION),
+            token_id: Some(1),
+            amount: 1,
+            currency: mock_address(CURRENCY),
+            unit_price: 500,
+            expires_at: 100,
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn cancel_not_own_offer() {
+    let mut state = init_marketplace();
+
+    let _ = execute_create_offer(
+        &mock_contract_context(BOB),
+        &mut state,
+        &CreateOfferMsg {
+            token_type: MPC1155_TOKEN_TYPE,
+            collection: mock_address(COLLECTION),
+            token_id: Some(1),
+            amount: 5,
+            currency: mock_address(CURRENCY),
+            unit_price: 500,
+            expires_at: 200,
+        },
+    );
+
+    let _ = execute_cancel_offer(
+        &mock_contract_context(ALICE),
+        &mut state,
+        &CancelOfferMsg { offer_id: 1 },
+    );
+}
+
+#[test]
+fn proper_royalty_and_fee_management() {
+    let mut state = init_marketplace();
+
+    let _ = execute_set_royalty(
+        &mock_contract_context(OWNER),
+        &mut state,
+        &SetRoyaltyMsg {
+            collection: mock_address(COLLECTION),
+            receiver: mock_address(CREATOR),
+            basis_points: 1_000,
+        },
+    );
+    assert_eq!(
+        state.royalties,
+        BTreeMap::from([(
+            mock_address(COLLECTION),
+            Royalty {
+                receiver: mock_address(CREATOR),
+                basis_points: 1_000,
+            }
+        )])
+    );
+    assert_eq!(
+        state.split_payment(&mock_address(COLLECTION), 999),
+        PaymentSplit {
+            fee: 24,
+            royalty: Some((mock_address(CREATOR), 99)),
+            seller: 876,
+        }
+    );
+
+    let _ = execute_update_fee(
+        &mock_contract_context(OWNER),
+        &mut state,
+        &UpdateFeeMsg {
+            fee_receiver: mock_address(OWNER),
+            fee_basis_points: 0,
+        },
+    );
+    let _ = execute_remove_royalty(
+        &mock_contract_context(OWNER),
+        &mut state,
+        &RemoveRoyaltyMsg {
+            collection: mock_address(COLLECTION),
+        },
+    );
+    assert_eq!(state.fee_receiver, mock_address(OWNER));
+    assert_eq!(
+        state.split_payment(&mock_address(COLLECTION), 999),
+        PaymentSplit {
+            fee: 0,
+            royalty: None,
+            seller: 999,
+        }
+    );
+}
+
+#[test]
+#[should_panic(expected = "Basis points must not exceed 10000")]
+fn royalty_and_fee_exceed_price() {
+    let mut state = init_marketplace();
+
+    let _ = execute_set_royalty(
+        &mock_contract_context(OWNER),
+        &mut state,
+        &SetRoyaltyMsg {
+            collection: mock_address(COLLECTION),
+            receiver: mock_address(CREATOR),
+            basis_points: 9_800,
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn not_owner_sets_royalty() {
+    let mut state = init_marketplace();
+
+    let _ = execute_set_royalty(
+        &mock_contract_context(ALICE),
+        &mut state,
+        &SetRoyaltyMsg {
+            collection: mock_address(COLLECTION),
+            receiver: mock_address(ALICE),
+            basis_points: 100,
+        },
+    );
+}
@@ -0,0 +1,438 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use pbc_contract_common::{
+    context::{CallbackContext, ContractContext},
+    events::EventGroup,
+};
+
+use crate::{
+    msg::{
+        ClaimCallbackMsg, ClaimMsg, FundRewardsCallbackMsg, FundRewardsMsg, InitMsg,
+        SetCollectionMsg, StakeCallbackMsg, StakeMsg, UnstakeMsg, ON_CLAIM_CALLBACK,
+        ON_FUND_REWARDS_CALLBACK, ON_STAKE_CALLBACK, ON_UNSTAKE_CALLBACK,
+    },
+    state::{NFTStakingContractState, RewardProgram},
+    ContractError,
+};
+
+use mpc20_base::{
+    actions::execute_init as mpc20_execute_init,
+    msg::{Mpc20InitMsg, TransferFromMsg as Mpc20TransferFromMsg, TransferMsg as Mpc20TransferMsg},
+    state::Minter as Mpc20Minter,
+};
+use mpc721_base::msg::{
+    TransferFromMsg as Mpc721TransferFromMsg, TransferMsg as Mpc721TransferMsg,
+};
+use utils::{
+    decimal::DecimalRatio,
+    events::{build_msg_callback, IntoShortnameRPCEvent},
+};
+
+/// ## Description
+/// Inits contract state.
+/// Returns [`(NFTStakingContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **msg** is an object of type [`InitMsg`]
+pub fn execute_init(
+    ctx: &ContractContext,
+    msg: &InitMsg,
+) -> (NFTStakingContractState, Vec<EventGroup>) {
+    msg.validate();
+
+    let reward_token = if let Some(token) = msg.reward_token {
+        token
+    } else {
+        ctx.contract_address
+    };
+    let reward_program = msg.reward_token.map(|_| RewardProgram::default());
+
+    let minter = msg.minter.map(|minter_addr| Mpc20Minter {
+        minter: minter_addr,
+        capacity: None,
+    });
+
+    let (mpc20, _) = mpc20_execute_init(
+        ctx,
+        &Mpc20InitMsg {
+            info: msg.info.clone(),
+            initial_balances: msg.initial_balances.clone(),
+            minter,
+        },
+    );
+
+    let state = NFTStakingContractState {
+        owner: ctx.sender,
+        collections: msg.collections.iter().copied().collect::<BTreeSet<_>>(),
+        reward_token,
+        distribution_amount: msg.distribution_amount,
+        distribution_epoch: msg.distribution_epoch,
+        reward_program,
+        global_index: DecimalRatio::zero(),
+        total_staked: 0,
+        last_distributed: ctx.block_production_time as u64,
+        stakers: BTreeMap::new(),
+        staked_tokens: BTreeMap::new(),
+        mpc20,
+    };
+
+    (state, vec![])
+}
+
+/// ## Description
+/// Stake specified token to earn rewards.
+/// Staker must approve contract to transfer the token, stake is recorded once the token is received.
+/// Returns [`(NFTStakingContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`NFTStakingContractState`]
+///
+/// * **msg** is an object of type [`StakeMsg`]
+pub fn execute_stake(
+    ctx: &ContractContext,
+    state: &mut NFTStakingContractState,
+    msg: &StakeMsg,
+) -> Vec<EventGroup> {
+    assert!(
+        state.collections.contains(&msg.collection),
+        "{}",
+        ContractError::CollectionNotAllowed
+    );
+
+    let mut event_group = EventGroup::builder();
+    Mpc721TransferFromMsg {
+        from: ctx.sender,
+        to: ctx.contract_address,
+        token_id: msg.token_id,
+    }
+    .as_interaction(&mut event_group, &msg.collection);
+    build_msg_callback(
+        &mut event_group,
+        ON_STAKE_CALLBACK,
+        &StakeCallbackMsg {
+            staker: ctx.sender,
+            collection: msg.collection,
+            token_id: msg.token_id,
+        },
+    );
+
+    vec![event_group.build()]
+}
+
+/// ## Description
+/// Handles result of the token escrow and records the stake on success.
+/// Token is returned to the staker if its collection was disallowed while the escrow was in flight.
+/// Returns [`(NFTStakingContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **callback_ctx** is an object of type [`CallbackContext`]
+///
+/// * **state** is an object of type [`NFTStakingContractState`]
+///
+/// * **msg** is an object of type [`StakeCallbackMsg`]
+pub fn execute_on_stake(
+    ctx: &ContractContext,
+    callback_ctx: &CallbackContext,
+    state: &mut NFTStakingContractState,
+    msg: &StakeCallbackMsg,
+) -> Vec<EventGroup> {
+    if !callback_ctx.success {
+        return vec![];
+    }
+
+    if !state.collections.contains(&msg.collection) {
+        let mut event_group = EventGroup::builder();
+        Mpc721TransferMsg {
+            to: msg.staker,
+            token_id: msg.token_id,
+        }
+        .as_interaction(&mut event_group, &msg.collection);
+        build_msg_callback(&mut event_group, ON_UNSTAKE_CALLBACK, msg);
+
+        return vec![event_group.build()];
+    }
+
+    let mut staker = state.update_staker(&msg.staker, ctx.block_production_time as u64);
+    state.add_staked_token(&msg.staker, &mut staker, &msg.collection, msg.token_id);
+
+    vec![]
+}
+
+/// ## Description
+/// Withdraw staked tokens of the collection. Returns exactly the specified token ids.
+/// Every token is returned with its own transfer and restored as staked if the transfer fails.
+/// Returns [`(NFTStakingContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`NFTStakingContractState`]
+///
+/// * **msg** is an object of type [`UnstakeMsg`]
+pub fn execute_unstake(
+    ctx: &ContractContext,
+    state: &mut NFTStakingContractState,
+    msg: &UnstakeMsg,
+) -> Vec<EventGroup> {
+    assert!(
+        !msg.token_ids.is_empty(),
+        "{}",
+        ContractError::NothingToUnstake
+    );
+
+    let mut staker = state.update_staker(&ctx.sender, ctx.block_production_time as u64);
+
+    let mut events = vec![];
+    for token_id in msg.token_ids.iter() {
+        state.remove_staked_token(&ctx.sender, &mut staker, &msg.collection, *token_id);
+
+        let mut event_group = EventGroup::builder();
+        Mpc721TransferMsg {
+            to: ctx.sender,
+            token_id: *token_id,
+        }
+        .as_interaction(&mut event_group, &msg.collection);
+        build_msg_callback(
+            &mut event_group,
+            ON_UNSTAKE_CALLBACK,
+            &StakeCallbackMsg {
+                staker: ctx.sender,
+                collection: msg.collection,
+                token_id: *token_id,
+            },
+        );
+        events.push(event_group.build());
+    }
+
+    events
+}
+
+/// ## Description
+/// Handles result of the token return and restores the stake on failure.
+/// Returns [`(NFTStakingContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **callback_ctx** is an object of type [`CallbackContext`]
+///
+/// * **state** is an object of type [`NFTStakingContractState`]
+///
+/// * **msg** is an object of type [`StakeCallbackMsg`]
+pub fn execute_on_unstake(
+    ctx: &ContractContext,
+    callback_ctx: &CallbackContext,
+    state: &mut NFTStakingContractState,
+    msg: &StakeCallbackMsg,
+) -> Vec<EventGroup> {
+    if callback_ctx.success {
+        return vec![];
+    }
+
+    let mut staker = state.update_staker(&msg.staker, ctx.block_production_time as u64);
+    state.add_staked_token(&msg.staker, &mut staker, &msg.collection, msg.token_id);
+
+    vec![]
+}
+
+/// ## Description
+/// Claim earned rewards.
+/// Rewards are minted when contract is the reward token, otherwise they are transferred from the funded budget.
+/// Returns [`(NFTStakingContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`NFTStakingContractState`]
+///
+/// * **msg** is an object of type [`ClaimMsg`]
+pub fn execute_claim(
+    ctx: &ContractContext,
+    state: &mut NFTStakingContractState,
+    msg: &ClaimMsg,
+) -> Vec<EventGroup> {
+    let mut staker = state.update_staker(&ctx.sender, ctx.block_production_time as u64);
+
+    assert!(
+        staker.pending_reward > 0,
+        "{}",
+        ContractError::NothingToClaim
+    );
+
+    let claim_amount = if let Some(amount) = msg.amount {
+        assert!(
+            amount <= staker.pending_reward && amount > 0,
+            "{}",
+            ContractError::CannotClaimMoreThenRewarded
+        );
+        amount
+    } else {
+        staker.pending_reward
+    };
+
+    staker.pending_reward = staker.pending_reward.checked_sub(claim_amount).unwrap();
+    state.store_staker(&ctx.sender, &staker);
+
+    if state.mints_rewards(&ctx.contract_address) {
+        state.mpc20.mint_to(&ctx.sender, claim_amount);
+        return vec![];
+    }
+
+    let mut event_group = EventGroup::builder();
+    Mpc20TransferMsg {
+        to: ctx.sender,
+        amount: claim_amount,
+    }
+    .as_interaction(&mut event_group, &state.reward_token);
+    build_msg_callback(
+        &mut event_group,
+        ON_CLAIM_CALLBACK,
+        &ClaimCallbackMsg {
+            staker: ctx.sender,
+            amount: claim_amount,
+        },
+    );
+
+    vec![event_group.build()]
+}
+
+/// ## Description
+/// Handles result of the reward payout and restores pending reward on failure.
+/// Returns [`(NFTStakingContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **callback_ctx** is an object of type [`CallbackContext`]
+///
+/// * **state** is an object of type [`NFTStakingContractState`]
+///
+/// * **msg** is an object of type [`ClaimCallbackMsg`]
+pub fn execute_on_claim(
+    ctx: &ContractContext,
+    callback_ctx: &CallbackContext,
+    state: &mut NFTStakingContractState,
+    msg: &ClaimCallbackMsg,
+) -> Vec<EventGroup> {
+    if callback_ctx.success {
+        return vec![];
+    }
+
+    let mut staker = state.get_staker(&msg.staker);
+    staker.pending_reward = staker.pending_reward.checked_add(msg.amount).unwrap();
+    state.store_staker(&msg.staker, &staker);
+
+    vec![]
+}
+
+/// ## Description
+/// Allows or disallows new stakes of the collection. Can only be executed by owner account.
+/// Already staked tokens can always be unstaked.
+/// Returns [`(NFTStakingContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`NFTStakingContractState`]
+///
+/// * **msg** is an object of type [`SetCollectionMsg`]
+pub fn execute_set_collection(
+    ctx: &ContractContext,
+    state: &mut NFTStakingContractState,
+    msg: &SetCollectionMsg,
+) -> Vec<EventGroup> {
+    assert!(
+        state.is_owner(&ctx.sender),
+        "{}",
+        ContractError::Unauthorized
+    );
+
+    if msg.allowed {
+        state.collections.insert(msg.collection);
+    } else {
+        state.collections.remove(&msg.collection);
+    }
+
+    vec![]
+}
+
+/// ## Description
+/// Deposit external reward tokens to the reward budget. Can only be executed by owner account.
+/// Owner must approve contract to transfer the tokens, budget is increased once the tokens are received.
+/// Returns [`(NFTStakingContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **state** is an object of type [`NFTStakingContractState`]
+///
+/// * **msg** is an object of type [`FundRewardsMsg`]
+pub fn execute_fund_rewards(
+    ctx: &ContractContext,
+    state: &mut NFTStakingContractState,
+    msg: &FundRewardsMsg,
+) -> Vec<EventGroup> {
+    assert!(
+        state.is_owner(&ctx.sender),
+        "{}",
+        ContractError::Unauthorized
+    );
+    assert!(
+        state.reward_program.is_some(),
+        "{}",
+        ContractError::RewardProgramDisabled
+    );
+    assert!(msg.amount > 0, "{}", ContractError::InvalidFundAmount);
+
+    let mut event_group = EventGroup::builder();
+    Mpc20TransferFromMsg {
+        from: ctx.sender,
+        to: ctx.contract_address,
+        amount: msg.amount,
+    }
+    .as_interaction(&mut event_group, &state.reward_token);
+    build_msg_callback(
+        &mut event_group,
+        ON_FUND_REWARDS_CALLBACK,
+        &FundRewardsCallbackMsg { amount: msg.amount },
+    );
+
+    vec![event_group.build()]
+}
+
+/// ## Description
+/// Handles result of the reward deposit and increases the budget on success.
+/// Epochs which passed while the budget was exhausted are not distributed afterwards.
+/// Returns [`(NFTStakingContractState, Vec<EventGroup>)`] if operation was successful,
+/// otherwise panics with error message defined in [`ContractError`]
+/// ## Params
+/// * **ctx** is an object of type [`ContractContext`]
+///
+/// * **callback_ctx** is an object of type [`CallbackContext`]
+///
+/// * **state** is an object of type [`NFTStakingContractState`]
+///
+/// * **msg** is an object of type [`FundRewardsCallbackMsg`]
+pub fn execute_on_fund_rewards(
+    ctx: &ContractContext,
+    callback_ctx: &CallbackContext,
+    state: &mut NFTStakingContractState,
+    msg: &FundRewardsCallbackMsg,
+) -> Vec<EventGroup> {
+    if !callback_ctx.success {
+        return vec![];
+    }
+
+    state.distribute_rewards(ctx.block_production_time as u64);
+
+    let program = state.reward_program.as_mut().unwrap();
+    program.budget = program.budget.checked_add(msg.amount).unwrap();
+
+    vec![]
+}
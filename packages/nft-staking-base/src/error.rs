@@ -0,0 +1,30 @@
+use thiserror::Error;
+
+/// ## Description
+/// This enum describes nft-staking contract errors
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("Unauthorized")]
+    Unauthorized,
+
+    #[error("Collection is not allowed for staking")]
+    CollectionNotAllowed,
+
+    #[error("Token is not staked by sender")]
+    NotStaked,
+
+    #[error("Nothing to unstake")]
+    NothingToUnstake,
+
+    #[error("Cannot claim more then rewarded")]
+    CannotClaimMoreThenRewarded,
+
+    #[error("Nothing to claim")]
+    NothingToClaim,
+
+    #[error("Reward program is disabled")]
+    RewardProgramDisabled,
+
+    #[error("Fund amount must be higher then 0")]
+    InvalidFundAmount,
+}
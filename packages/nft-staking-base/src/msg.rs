@@ -0,0 +1,128 @@
+use create_type_spec_derive::CreateTypeSpec;
+use pbc_contract_common::address::{Address, Shortname};
+use read_write_rpc_derive::ReadWriteRPC;
+
+use mpc20_base::{msg::InitialBalance, state::TokenInfo};
+use rpc_msg_derive::IntoShortnameRPCEvent;
+use utils::events::IntoShortnameRPCEvent;
+
+/// ## Description
+/// This structure describes fields for nft-staking initialize msg
+#[derive(ReadWriteRPC, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct InitMsg {
+    /// mpc721 collections allowed for staking
+    pub collections: Vec<Address>,
+    /// reward token address, if None then rewards are minted by this contract
+    pub reward_token: Option<Address>,
+    /// per epoch distribution amount
+    pub distribution_amount: u128,
+    /// UTC timestamp
+    pub distribution_epoch: u64,
+    /// mpc20 base token info
+    pub info: TokenInfo,
+    /// mpc20 base initial balances
+    pub initial_balances: Vec<InitialBalance>,
+    /// mpc20 base optional minter address
+    pub minter: Option<Address>,
+}
+
+impl InitMsg {
+    pub fn validate(&self) {
+        assert!(
+            self.distribution_epoch > 0,
+            "Distribution epoch must be higher then 0"
+        );
+        assert!(
+            self.distribution_amount > 0,
+            "Distribution amount must be higher then 0"
+        )
+    }
+}
+
+/// ## Description
+/// This structure describes fields for nft-staking stake msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x17)]
+pub struct StakeMsg {
+    /// mpc721 collection address
+    pub collection: Address,
+    /// token id to stake
+    pub token_id: u128,
+}
+
+/// ## Description
+/// This structure describes fields for nft-staking unstake msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x19)]
+pub struct UnstakeMsg {
+    /// mpc721 collection address
+    pub collection: Address,
+    /// staked token ids to return
+    pub token_ids: Vec<u128>,
+}
+
+/// ## Description
+/// This structure describes fields for nft-staking claim msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x21)]
+pub struct ClaimMsg {
+    /// optional amount to claim, if None everything will be claimed
+    pub amount: Option<u128>,
+}
+
+/// ## Description
+/// This structure describes fields for nft-staking set collection msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x23)]
+pub struct SetCollectionMsg {
+    /// mpc721 collection address
+    pub collection: Address,
+    /// whether new stakes of the collection are allowed
+    pub allowed: bool,
+}
+
+/// ## Description
+/// This structure describes fields for nft-staking fund rewards msg
+#[derive(ReadWriteRPC, CreateTypeSpec, IntoShortnameRPCEvent, Clone, PartialEq, Eq, Debug)]
+#[rpc_msg(action = 0x25)]
+pub struct FundRewardsMsg {
+    /// amount of reward tokens to deposit
+    pub amount: u128,
+}
+
+/// ## Description
+/// This structure describes a staked token which is passed to the escrow and return callbacks
+#[derive(ReadWriteRPC, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct StakeCallbackMsg {
+    /// staker address
+    pub staker: Address,
+    /// mpc721 collection address
+    pub collection: Address,
+    /// staked token id
+    pub token_id: u128,
+}
+
+/// ## Description
+/// This structure describes a reward payout which is passed to the transfer callback
+#[derive(ReadWriteRPC, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct ClaimCallbackMsg {
+    /// staker address
+    pub staker: Address,
+    /// claimed amount
+    pub amount: u128,
+}
+
+/// ## Description
+/// This structure describes a reward deposit which is passed to the transfer callback
+#[derive(ReadWriteRPC, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct FundRewardsCallbackMsg {
+    /// deposited amount
+    pub amount: u128,
+}
+
+/// ## Description
+/// Callback shortnames used during staking, reward payouts, unstaking and reward deposits
+pub const ON_STAKE_CALLBACK: u32 = 0x31;
+pub const ON_CLAIM_CALLBACK: u32 = 0x33;
+pub const ON_UNSTAKE_CALLBACK: u32 = 0x35;
+pub const ON_FUND_REWARDS_CALLBACK: u32 = 0x37;
@@ -0,0 +1,258 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use create_type_spec_derive::CreateTypeSpec;
+use pbc_contract_common::address::Address;
+use read_write_rpc_derive::ReadWriteRPC;
+use read_write_state_derive::ReadWriteState;
+
+use mpc20_base::state::MPC20ContractState;
+use utils::decimal::DecimalRatio;
+
+use crate::ContractError;
+
+/// ## Description
+/// This structure describes main nft-staking contract state.
+#[derive(ReadWriteState, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+pub struct NFTStakingContractState {
+    /// owner address
+    pub owner: Address,
+    /// mpc721 collections allowed for staking
+    pub collections: BTreeSet<Address>,
+    /// reward token address, rewards are minted when it is contract address
+    pub reward_token: Address,
+    /// per epoch distribution amount
+    pub distribution_amount: u128,
+    /// UTC timestamp
+    pub distribution_epoch: u64,
+    /// funded budget of external reward token, None when rewards are minted
+    pub reward_program: Option<RewardProgram>,
+
+    /// global index for calculating reward per staked token
+    pub global_index: DecimalRatio,
+    /// total amount of tokens staked
+    pub total_staked: u128,
+    /// UTC timestamp of last distribution
+    pub last_distributed: u64,
+
+    /// information about stakers
+    pub stakers: BTreeMap<Address, Staker>,
+    /// staker address by token id for every collection
+    pub staked_tokens: BTreeMap<Address, BTreeMap<u128, Address>>,
+    /// mpc20 base state
+    pub mpc20: MPC20ContractState,
+}
+
+/// ## Description
+/// This structure describes information about staker
+#[derive(ReadWriteRPC, ReadWriteState, CreateTypeSpec, Clone, PartialEq, Eq, Debug, Default)]
+pub struct Staker {
+    /// personal reward index
+    pub reward_index: DecimalRatio,
+    /// amount of staked tokens
+    pub staked_amount: u128,
+    /// pending rewards
+    pub pending_reward: u128,
+}
+
+/// ## Description
+/// This structure describes funded budget of external reward token
+#[derive(ReadWriteState, CreateTypeSpec, Clone, PartialEq, Eq, Debug, Default)]
+pub struct RewardProgram {
+    /// total amount of deposited reward tokens
+    pub budget: u128,
+    /// total amount of distributed reward tokens
+    pub distributed: u128,
+}
+
+impl NFTStakingContractState {
+    /// ## Description
+    /// Distributes rewards by recalculting global index.
+    /// External reward token is limited by funded budget
+    /// ## Params
+    /// * **block_time** is an object of type [`u64`]
+    pub fn distribute_rewards(&mut self, block_time: u64) {
+        if self.total_staked == 0 {
+            self.last_distributed = block_time;
+            return;
+        }
+
+        let passed_distributions = (block_time - self.last_distributed) / self.distribution_epoch;
+        if passed_distributions == 0 {
+            return;
+        }
+
+        let mut distributed_amount = self.distribution_amount * (passed_distributions as u128);
+        if let Some(program) = self.reward_program.as_mut() {
+            distributed_amount = distributed_amount.min(program.budget - program.distributed);
+            program.distributed += distributed_amount;
+        }
+
+        if distributed_amount > 0 {
+            self.global_index =
+                self.global_index + DecimalRatio::from_ratio(distributed_amount, self.total_staked);
+        }
+        self.last_distributed += self.distribution_epoch * passed_distributions;
+    }
+
+    /// ## Description
+    /// Distributes rewards and returns staker with up to date pending reward
+    /// ## Params
+    /// * **address** is an object of type [`Address`]
+    ///
+    /// * **block_time** is an object of type [`u64`]
+    pub fn update_staker(&mut self, address: &Address, block_time: u64) -> Staker {
+        self.distribute_rewards(block_time);
+
+        let mut staker = self.get_staker(address);
+        staker.compute_reward(self.global_index);
+        staker
+    }
+
+    /// ## Description
+    /// Records escrowed token as staked by the staker
+    /// ## Params
+    /// * **address** is an object of type [`Address`]
+    ///
+    /// * **staker** is an object of type [`Staker`]
+    ///
+    /// * **collection** is an object of type [`Address`]
+    ///
+    /// * **token_id** is a field of type [`u128`]
+    pub fn add_staked_token(
+        &mut self,
+        address: &Address,
+        staker: &mut Staker,
+        collection: &Address,
+        token_id: u128,
+    ) {
+        self.staked_tokens
+            .entry(*collection)
+            .or_default()
+            .insert(token_id, *address);
+
+        self.total_staked = self.total_staked.checked_add(1).unwrap();
+        staker.staked_amount = staker.staked_amount.checked_add(1).unwrap();
+        self.store_staker(address, staker);
+    }
+
+    /// ## Description
+    /// Removes token staked by the staker
+    /// ## Params
+    /// * **address** is an object of type [`Address`]
+    ///
+    /// * **staker** is an object of type [`Staker`]
+    ///
+    /// * **collection** is an object of type [`Address`]
+    ///
+    /// * **token_id** is a field of type [`u128`]
+    pub fn remove_staked_token(
+        &mut self,
+        address: &Address,
+        staker: &mut Staker,
+        collection: &Address,
+        token_id: u128,
+    ) {
+        assert!(
+            self.staker_of(collection, token_id) == Some(*address),
+            "{}",
+            ContractError::NotStaked
+        );
+
+        let token_ids = self.staked_tokens.get_mut(collection).unwrap();
+        token_ids.remove(&token_id);
+        if token_ids.is_empty() {
+            self.staked_tokens.remove(collection);
+        }
+
+        self.total_staked = self.total_staked.checked_sub(1).unwrap();
+        staker.staked_amount = staker.staked_amount.checked_sub(1).unwrap();
+        self.store_staker(address, staker);
+    }
+
+    /// ## Description
+    /// Saves updated information about staker, removing stakers without stake and rewards
+    /// ## Params
+    /// * **address** is an object of type [`Address`]
+    ///
+    /// * **staker** is an object of type [`Staker`]
+    pub fn store_staker(&mut self, address: &Address, staker: &Staker) {
+        if staker.staked_amount == 0 && staker.pending_reward == 0 {
+            self.stakers.remove(address);
+        } else {
+            self.stakers.insert(*address, staker.clone());
+        }
+    }
+
+    /// ## Description
+    /// Returns information about staker
+    /// ## Params
+    /// * **address** is an object of type [`Address`]
+    pub fn get_staker(&self, address: &Address) -> Staker {
+        self.stakers.get(address).cloned().unwrap_or_default()
+    }
+
+    /// ## Description
+    /// Returns address which staked specified token
+    /// ## Params
+    /// * **collection** is an object of type [`Address`]
+    ///
+    /// * **token_id** is a field of type [`u128`]
+    pub fn staker_of(&self, collection: &Address, token_id: u128) -> Option<Address> {
+        self.staked_tokens
+            .get(collection)
+            .and_then(|token_ids| token_ids.get(&token_id))
+            .copied()
+    }
+
+    /// ## Description
+    /// Returns token ids of the collection staked by the staker in ascending order
+    /// ## Params
+    /// * **address** is an object of type [`Address`]
+    ///
+    /// * **collection** is an object of type [`Address`]
+    pub fn staked_token_ids(&self, address: &Address, collection: &Address) -> Vec<u128> {
+        self.staked_tokens
+            .get(collection)
+            .map(|token_ids| {
+                token_ids
+                    .iter()
+                    .filter(|(_, staker)| *staker == address)
+                    .map(|(token_id, _)| *token_id)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// ## Description
+    /// Says are rewards minted by this contract or transferred from the funded pool
+    /// ## Params
+    /// * **contract_address** is an object of type [`Address`]
+    pub fn mints_rewards(&self, contract_address: &Address) -> bool {
+        self.reward_token == *contract_address
+    }
+
+    /// ## Description
+    /// Checks that address is owner or not
+    /// ## Params
+    /// * **address** is an object of type [`Address`]
+    pub fn is_owner(&self, address: &Address) -> bool {
+        self.owner == *address
+    }
+}
+
+impl Staker {
+    /// ## Description
+    /// Computes current staker reward
+    /// ## Params
+    /// * **global_index** is an object of type [`DecimalRatio`]
+    pub fn compute_reward(&mut self, global_index: DecimalRatio) {
+        let staked_amount = DecimalRatio::new(self.staked_amount, 0);
+        let pending_reward = (staked_amount * global_index) - (staked_amount * self.reward_index);
+
+        self.reward_index = global_index;
+        self.pending_reward = self
+            .pending_reward
+            .checked_add(pending_reward.to_u128())
+            .unwrap();
+    }
+}
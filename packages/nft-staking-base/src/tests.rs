@@ -0,0 +1,758 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use pbc_contract_common::{
+    address::{Address, AddressType},
+    context::{CallbackContext, ContractContext},
+    events::EventGroup,
+};
+
+use crate::{
+    actions::{
+        execute_claim, execute_fund_rewards, execute_init, execute_on_claim,
+        execute_on_fund_rewards, execute_on_stake, execute_on_unstake, execute_set_collection,
+        execute_stake, execute_unstake,
+    },
+    msg::{
+        ClaimCallbackMsg, ClaimMsg, FundRewardsCallbackMsg, FundRewardsMsg, InitMsg,
+        SetCollectionMsg, StakeCallbackMsg, StakeMsg, UnstakeMsg, ON_CLAIM_CALLBACK,
+        ON_FUND_REWARDS_CALLBACK, ON_STAKE_CALLBACK, ON_UNSTAKE_CALLBACK,
+    },
+    state::{NFTStakingContractState, RewardProgram},
+};
+
+use mpc20_base::{
+    msg::{TransferFromMsg as Mpc20TransferFromMsg, TransferMsg as Mpc20TransferMsg},
+    state::{MPC20ContractState, TokenInfo},
+};
+use mpc721_base::msg::{
+    TransferFromMsg as Mpc721TransferFromMsg, TransferMsg as Mpc721TransferMsg,
+};
+use utils::{
+    decimal::DecimalRatio,
+    events::{build_msg_callback, IntoShortnameRPCEvent},
+};
+
+const STAKING: u8 = 1;
+const OWNER: u8 = 2;
+const COLLECTION: u8 = 4;
+const OTHER_COLLECTION: u8 = 5;
+const REWARD_TOKEN: u8 = 6;
+const ALICE: u8 = 10;
+const BOB: u8 = 11;
+
+fn mock_address(le: u8) -> Address {
+    Address {
+        address_type: AddressType::Account,
+        identifier: [
+            le, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8,
+        ],
+    }
+}
+
+fn mock_contract_context(sender: u8, block_time: i64) -> ContractContext {
+    ContractContext {
+        contract_address: mock_address(STAKING),
+        sender: mock_address(sender),
+        block_time,
+        block_production_time: block_time,
+        current_transaction: [
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+        ],
+        original_transaction: [
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+            0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+        ],
+    }
+}
+
+fn mock_callback_context(success: bool) -> CallbackContext {
+    CallbackContext {
+        success,
+        results: vec![],
+    }
+}
+
+fn token_info() -> TokenInfo {
+    TokenInfo {
+        name: "Reward Token".to_string(),
+        symbol: "RWD".to_string(),
+        decimals: 18,
+    }
+}
+
+fn init_msg(reward_token: Option<u8>) -> InitMsg {
+    InitMsg {
+        collections: vec![mock_address(COLLECTION), mock_address(OTHER_COLLECTION)],
+        reward_token: reward_token.map(mock_address),
+        distribution_amount: 1_000,
+        distribution_epoch: 10,
+        info: token_info(),
+        initial_balances: vec![],
+        minter: None,
+    }
+}
+
+fn stake_token(
+    state: &mut NFTStakingContractState,
+    staker: u8,
+    collection: u8,
+    token_id: u128,
+    block_time: i64,
+) {
+    let _ = execute_stake(
+        &mock_contract_context(staker, block_time),
+        state,
+        &StakeMsg {
+            collection: mock_address(collection),
+            token_id,
+        },
+    );
+    let _ = execute_on_stake(
+        &mock_contract_context(STAKING, block_time),
+        &mock_callback_context(true),
+        state,
+        &StakeCallbackMsg {
+            staker: mock_address(staker),
+            collection: mock_address(collection),
+            token_id,
+        },
+    );
+}
+
+fn fund_rewards(state: &mut NFTStakingContractState, amount: u128, block_time: i64) {
+    let _ = execute_fund_rewards(
+        &mock_contract_context(OWNER, block_time),
+        state,
+        &FundRewardsMsg { amount },
+    );
+    let _ = execute_on_fund_rewards(
+        &mock_contract_context(STAKING, block_time),
+        &mock_callback_context(true),
+        state,
+        &FundRewardsCallbackMsg { amount },
+    );
+}
+
+#[test]
+fn proper_execute_init() {
+    let (state, events) = execute_init(&mock_contract_context(OWNER, 100), &init_msg(None));
+    assert_eq!(events.len(), 0);
+    assert_eq!(
+        state,
+        NFTStakingContractState {
+            owner: mock_address(OWNER),
+            collections: BTreeSet::from([mock_address(COLLECTION), mock_address(OTHER_COLLECTION)]),
+            reward_token: mock_address(STAKING),
+            distribution_amount: 1_000,
+            distribution_epoch: 10,
+            reward_program: None,
+            global_index: DecimalRatio::zero(),
+            total_staked: 0,
+            last_distributed: 100,
+            stakers: BTreeMap::new(),
+            staked_tokens: BTreeMap::new(),
+            mpc20: MPC20ContractState::new(&token_info(), &None),
+        }
+    );
+
+    let (state, _) = execute_init(
+        &mock_contract_context(OWNER, 100),
+        &init_msg(Some(REWARD_TOKEN)),
+    );
+    assert_eq!(state.reward_token, mock_address(REWARD_TOKEN));
+    assert_eq!(
+        state.reward_program,
+        Some(RewardProgram {
+            budget: 0,
+            distributed: 0,
+        })
+    );
+}
+
+#[test]
+#[should_panic(expected = "Distribution epoch must be higher then 0")]
+fn init_with_zero_epoch() {
+    let mut msg = init_msg(None);
+    msg.distribution_epoch = 0;
+
+    let _ = execute_init(&mock_contract_context(OWNER, 100), &msg);
+}
+
+#[test]
+fn proper_stake() {
+    let (mut state, _) = execute_init(&mock_contract_context(OWNER, 100), &init_msg(None));
+
+    let events = execute_stake(
+        &mock_contract_context(ALICE, 100),
+        &mut state,
+        &StakeMsg {
+            collection: mock_address(COLLECTION),
+            token_id: 7,
+        },
+    );
+    let mut event_group = EventGroup::builder();
+    Mpc721TransferFromMsg {
+        from: mock_address(ALICE),
+        to: mock_address(STAKING),
+        token_id: 7,
+    }
+    .as_interaction(&mut event_group, &mock_address(COLLECTION));
+    build_msg_callback(
+        &mut event_group,
+        ON_STAKE_CALLBACK,
+        &StakeCallbackMsg {
+            staker: mock_address(ALICE),
+            collection: mock_address(COLLECTION),
+            token_id: 7,
+        },
+    );
+    assert_eq!(events, vec![event_group.build()]);
+    assert_eq!(state.total_staked, 0);
+
+    let callback_msg = StakeCallbackMsg {
+        staker: mock_address(ALICE),
+        collection: mock_address(COLLECTION),
+        token_id: 7,
+    };
+    let _ = execute_on_stake(
+        &mock_contract_context(STAKING, 100),
+        &mock_callback_context(false),
+        &mut state,
+        &callback_msg,
+    );
+    assert_eq!(state.total_staked, 0);
+    assert_eq!(state.staker_of(&mock_address(COLLECTION), 7), None);
+    assert!(state.stakers.is_empty());
+
+    let _ = execute_on_stake(
+        &mock_contract_context(STAKING, 100),
+        &mock_callback_context(true),
+        &mut state,
+        &callback_msg,
+    );
+    assert_eq!(state.total_staked, 1);
+    assert_eq!(
+        state.staker_of(&mock_address(COLLECTION), 7),
+        Some(mock_address(ALICE))
+    );
+    assert_eq!(state.get_staker(&mock_address(ALICE)).staked_amount, 1);
+}
+
+#[test]
+#[should_panic(expected = "Collection is not allowed for staking")]
+fn stake_not_allowed_collection() {
+    let (mut state, _) = execute_init(&mock_contract_context(OWNER, 100), &init_msg(None));
+
+    let _ = execute_stake(
+        &mock_contract_context(ALICE, 100),
+        &mut state,
+        &StakeMsg {
+            collection: mock_address(REWARD_TOKEN),
+            token_id: 1,
+        },
+    );
+}
+
+#[test]
+fn stake_of_disallowed_collection_is_returned() {
+    let (mut state, _) = execute_init(&mock_contract_context(OWNER, 100), &init_msg(None));
+
+    let _ = execute_stake(
+        &mock_contract_context(ALICE, 100),
+        &mut state,
+        &StakeMsg {
+            collection: mock_address(COLLECTION),
+            token_id: 7,
+        },
+    );
+    let _ = execute_set_collection(
+        &mock_contract_context(OWNER, 100),
+        &mut state,
+        &SetCollectionMsg {
+            collection: mock_address(COLLECTION),
+            allowed: false,
+        },
+    );
+
+    let callback_msg = StakeCallbackMsg {
+        staker: mock_address(ALICE),
+        collection: mock_address(COLLECTION),
+        token_id: 7,
+    };
+    let events = execute_on_stake(
+        &mock_contract_context(STAKING, 100),
+        &mock_callback_context(true),
+        &mut state,
+        &callback_msg,
+    );
+    let mut event_group = EventGroup::builder();
+    Mpc721TransferMsg {
+        to: mock_address(ALICE),
+        token_id: 7,
+    }
+    .as_interaction(&mut event_group, &mock_address(COLLECTION));
+    build_msg_callback(&mut event_group, ON_UNSTAKE_CALLBACK, &callback_msg);
+    assert_eq!(events, vec![event_group.build()]);
+    assert_eq!(state.total_staked, 0);
+    assert_eq!(state.staker_of(&mock_address(COLLECTION), 7), None);
+    assert!(state.stakers.is_empty());
+}
+
+#[test]
+fn rewards_are_shared_between_stakers() {
+    let (mut state, _) = execute_init(&mock_contract_context(OWNER, 100), &init_msg(None));
+
+    stake_token(&mut state, ALICE, COLLECTION, 1, 100);
+    stake_token(&mut state, BOB, OTHER_COLLECTION, 1, 120);
+    assert_eq!(state.total_staked, 2);
+    assert_eq!(state.get_staker(&mock_address(BOB)).pending_reward, 0);
+
+    let events = execute_claim(
+        &mock_contract_context(ALICE, 145),
+        &mut state,
+        &ClaimMsg { amount: None },
+    );
+    assert_eq!(events, vec![]);
+    assert_eq!(state.last_distributed, 140);
+    assert_eq!(state.mpc20.balance_of(&mock_address(ALICE)), 3_000);
+    assert_eq!(state.get_staker(&mock_address(ALICE)).pending_reward, 0);
+
+    let _ = execute_claim(
+        &mock_contract_context(BOB, 150),
+        &mut state,
+        &ClaimMsg {
+            amount: Some(1_000),
+        },
+    );
+    assert_eq!(state.mpc20.balance_of(&mock_address(BOB)), 1_000);
+    assert_eq!(state.get_staker(&mock_address(BOB)).pending_reward, 500);
+    assert_eq!(state.mpc20.total_supply, 4_000);
+}
+
+#[test]
+fn proper_unstake() {
+    let (mut state, _) = execute_init(&mock_contract_context(OWNER, 100), &init_msg(None));
+
+    stake_token(&mut state, ALICE, COLLECTION, 1, 100);
+    stake_token(&mut state, ALICE, COLLECTION, 2, 100);
+    stake_token(&mut state, ALICE, COLLECTION, 3, 100);
+    stake_token(&mut state, BOB, COLLECTION, 4, 100);
+    assert_eq!(
+        state.staked_token_ids(&mock_address(ALICE), &mock_address(COLLECTION)),
+        vec![1, 2, 3]
+    );
+
+    let events = execute_unstake(
+        &mock_contract_context(ALICE, 120),
+        &mut state,
+        &UnstakeMsg {
+            collection: mock_address(COLLECTION),
+            token_ids: vec![3, 1],
+        },
+    );
+    let mut expected_events = vec![];
+    for token_id in [3, 1] {
+        let mut event_group = EventGroup::builder();
+        Mpc721TransferMsg {
+            to: mock_address(ALICE),
+            token_id,
+        }
+        .as_interaction(&mut event_group, &mock_address(COLLECTION));
+        build_msg_callback(
+            &mut event_group,
+            ON_UNSTAKE_CALLBACK,
+            &StakeCallbackMsg {
+                staker: mock_address(ALICE),
+                collection: mock_address(COLLECTION),
+                token_id,
+            },
+        );
+        expected_events.push(event_group.build());
+    }
+    assert_eq!(events, expected_events);
+    assert_eq!(
+        state.staked_token_ids(&mock_address(ALICE), &mock_address(COLLECTION)),
+        vec![2]
+    );
+    assert_eq!(state.total_staked, 2);
+
+    let alice = state.get_staker(&mock_address(ALICE));
+    assert_eq!(alice.staked_amount, 1);
+    assert_eq!(alice.pending_reward, 1_500);
+
+    let _ = execute_unstake(
+        &mock_contract_context(BOB, 120),
+        &mut state,
+        &UnstakeMsg {
+            collection: mock_address(COLLECTION),
+            token_ids: vec![4],
+        },
+    );
+    assert_eq!(state.get_staker(&mock_address(BOB)).pending_reward, 500);
+    assert_eq!(state.total_staked, 1);
+}
+
+#[test]
+fn failed_unstake_transfer_restores_stake() {
+    let (mut state, _) = execute_init(&mock_contract_context(OWNER, 100), &init_msg(None));
+
+    stake_token(&mut state, ALICE, COLLECTION, 1, 100);
+    stake_token(&mut state, ALICE, COLLECTION, 2, 100);
+    let _ = execute_unstake(
+        &mock_contract_context(ALICE, 120),
+        &mut state,
+        &UnstakeMsg {
+            collection: mock_address(COLLECTION),
+            token_ids: vec![1, 2],
+        },
+    );
+    assert_eq!(state.total_staked, 0);
+
+    let callback_msg = StakeCallbackMsg {
+        staker: mock_address(ALICE),
+        collection: mock_address(COLLECTION),
+        token_id: 2,
+    };
+    let events = execute_on_unstake(
+        &mock_contract_context(STAKING, 120),
+        &mock_callback_context(true),
+        &mut state,
+        &callback_msg,
+    );
+    assert_eq!(events, vec![]);
+    assert_eq!(state.total_staked, 0);
+
+    let _ = execute_on_unstake(
+        &mock_contract_context(STAKING, 130),
+        &mock_callback_context(false),
+        &mut state,
+        &callback_msg,
+    );
+    assert_eq!(
+        state.staked_token_ids(&mock_address(ALICE), &mock_address(COLLECTION)),
+        vec![2]
+    );
+    assert_eq!(state.total_staked, 1);
+
+    let alice = state.get_staker(&mock_address(ALICE));
+    assert_eq!(alice.staked_amount, 1);
+    assert_eq!(alice.pending_reward, 2_000);
+}
+
+#[test]
+#[should_panic(expected = "Token is not staked by sender")]
+fn unstake_token_of_another_staker() {
+    let (mut state, _) = execute_init(&mock_contract_context(OWNER, 100), &init_msg(None));
+
+    stake_token(&mut state, ALICE, COLLECTION, 1, 100);
+    stake_token(&mut state, BOB, COLLECTION, 2, 100);
+
+    let _ = execute_unstake(
+        &mock_contract_context(ALICE, 100),
+        &mut state,
+        &UnstakeMsg {
+            collection: mock_address(COLLECTION),
+            token_ids: vec![1, 2],
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Token is not staked by sender")]
+fn unstake_from_wrong_collection() {
+    let (mut state, _) = execute_init(&mock_contract_context(OWNER, 100), &init_msg(None));
+
+    stake_token(&mut state, ALICE, COLLECTION, 1, 100);
+
+    let _ = execute_unstake(
+        &mock_contract_context(ALICE, 100),
+        &mut state,
+        &UnstakeMsg {
+            collection: mock_address(OTHER_COLLECTION),
+            token_ids: vec![1],
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Nothing to unstake")]
+fn unstake_without_token_ids() {
+    let (mut state, _) = execute_init(&mock_contract_context(OWNER, 100), &init_msg(None));
+
+    let _ = execute_unstake(
+        &mock_contract_context(ALICE, 100),
+        &mut state,
+        &UnstakeMsg {
+            collection: mock_address(COLLECTION),
+            token_ids: vec![],
+        },
+    );
+}
+
+#[test]
+fn proper_claim_from_funded_pool() {
+    let (mut state, _) = execute_init(
+        &mock_contract_context(OWNER, 100),
+        &init_msg(Some(REWARD_TOKEN)),
+    );
+
+    fund_rewards(&mut state, 10_000, 100);
+    stake_token(&mut state, ALICE, COLLECTION, 1, 100);
+
+    let events = execute_claim(
+        &mock_contract_context(ALICE, 120),
+        &mut state,
+        &ClaimMsg { amount: Some(500) },
+    );
+    let mut event_group = EventGroup::builder();
+    Mpc20TransferMsg {
+        to: mock_address(ALICE),
+        amount: 500,
+    }
+    .as_interaction(&mut event_group, &mock_address(REWARD_TOKEN));
+    build_msg_callback(
+        &mut event_group,
+        ON_CLAIM_CALLBACK,
+        &ClaimCallbackMsg {
+            staker: mock_address(ALICE),
+            amount: 500,
+        },
+    );
+    assert_eq!(events, vec![event_group.build()]);
+    assert_eq!(state.get_staker(&mock_address(ALICE)).pending_reward, 1_500);
+    assert_eq!(state.mpc20.total_supply, 0);
+
+    let callback_msg = ClaimCallbackMsg {
+        staker: mock_address(ALICE),
+        amount: 500,
+    };
+    let _ = execute_on_claim(
+        &mock_contract_context(STAKING, 120),
+        &mock_callback_context(true),
+        &mut state,
+        &callback_msg,
+    );
+    assert_eq!(state.get_staker(&mock_address(ALICE)).pending_reward, 1_500);
+
+    let _ = execute_on_claim(
+        &mock_contract_context(STAKING, 120),
+        &mock_callback_context(false),
+        &mut state,
+        &callback_msg,
+    );
+    assert_eq!(state.get_staker(&mock_address(ALICE)).pending_reward, 2_000);
+}
+
+#[test]
+fn failed_payout_restores_reward_after_unstake() {
+    let (mut state, _) = execute_init(
+        &mock_contract_context(OWNER, 100),
+        &init_msg(Some(REWARD_TOKEN)),
+    );
+
+    fund_rewards(&mut state, 10_000, 100);
+    stake_token(&mut state, ALICE, COLLECTION, 1, 100);
+    let _ = execute_unstake(
+        &mock_contract_context(ALICE, 110),
+        &mut state,
+        &UnstakeMsg {
+            collection: mock_address(COLLECTION),
+            token_ids: vec![1],
+        },
+    );
+    let _ = execute_claim(
+        &mock_contract_context(ALICE, 110),
+        &mut state,
+        &ClaimMsg { amount: None },
+    );
+    assert!(state.stakers.is_empty());
+
+    let _ = execute_on_claim(
+        &mock_contract_context(STAKING, 110),
+        &mock_callback_context(false),
+        &mut state,
+        &ClaimCallbackMsg {
+            staker: mock_address(ALICE),
+            amount: 1_000,
+        },
+    );
+    assert_eq!(state.get_staker(&mock_address(ALICE)).pending_reward, 1_000);
+}
+
+#[test]
+fn proper_fund_rewards() {
+    let (mut state, _) = execute_init(
+        &mock_contract_context(OWNER, 100),
+        &init_msg(Some(REWARD_TOKEN)),
+    );
+
+    let events = execute_fund_rewards(
+        &mock_contract_context(OWNER, 100),
+        &mut state,
+        &FundRewardsMsg { amount: 1_500 },
+    );
+    let mut event_group = EventGroup::builder();
+    Mpc20TransferFromMsg {
+        from: mock_address(OWNER),
+        to: mock_address(STAKING),
+        amount: 1_500,
+    }
+    .as_interaction(&mut event_group, &mock_address(REWARD_TOKEN));
+    build_msg_callback(
+        &mut event_group,
+        ON_FUND_REWARDS_CALLBACK,
+        &FundRewardsCallbackMsg { amount: 1_500 },
+    );
+    assert_eq!(events, vec![event_group.build()]);
+
+    let _ = execute_on_fund_rewards(
+        &mock_contract_context(STAKING, 100),
+        &mock_callback_context(false),
+        &mut state,
+        &FundRewardsCallbackMsg { amount: 1_500 },
+    );
+    assert_eq!(state.reward_program, Some(RewardProgram::default()));
+
+    let _ = execute_on_fund_rewards(
+        &mock_contract_context(STAKING, 100),
+        &mock_callback_context(true),
+        &mut state,
+        &FundRewardsCallbackMsg { amount: 1_500 },
+    );
+    stake_token(&mut state, ALICE, COLLECTION, 1, 100);
+
+    let staker = state.update_staker(&mock_address(ALICE), 130);
+    assert_eq!(staker.pending_reward, 1_500);
+    assert_eq!(
+        state.reward_program,
+        Some(RewardProgram {
+            budget: 1_500,
+            distributed: 1_500,
+        })
+    );
+
+    fund_rewards(&mut state, 1_000, 135);
+    let staker = state.update_staker(&mock_address(ALICE), 140);
+    assert_eq!(staker.pending_reward, 2_500);
+    assert_eq!(
+        state.reward_program,
+        Some(RewardProgram {
+            budget: 2_500,
+            distributed: 2_500,
+        })
+    );
+}
+
+#[test]
+#[should_panic(expected = "Reward program is disabled")]
+fn fund_minted_rewards() {
+    let (mut state, _) = execute_init(&mock_contract_context(OWNER, 100), &init_msg(None));
+
+    let _ = execute_fund_rewards(
+        &mock_contract_context(OWNER, 100),
+        &mut state,
+        &FundRewardsMsg { amount: 1_000 },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Fund amount must be higher then 0")]
+fn fund_zero_rewards() {
+    let (mut state, _) = execute_init(
+        &mock_contract_context(OWNER, 100),
+        &init_msg(Some(REWARD_TOKEN)),
+    );
+
+    let _ = execute_fund_rewards(
+        &mock_contract_context(OWNER, 100),
+        &mut state,
+        &FundRewardsMsg { amount: 0 },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Nothing to claim")]
+fn claim_without_rewards() {
+    let (mut state, _) = execute_init(&mock_contract_context(OWNER, 100), &init_msg(None));
+
+    stake_token(&mut state, ALICE, COLLECTION, 1, 100);
+
+    let _ = execute_claim(
+        &mock_contract_context(ALICE, 105),
+        &mut state,
+        &ClaimMsg { amount: None },
+    );
+}
+
+#[test]
+#[should_panic(expected = "Cannot claim more then rewarded")]
+fn claim_more_than_rewarded() {
+    let (mut state, _) = execute_init(&mock_contract_context(OWNER, 100), &init_msg(None));
+
+    stake_token(&mut state, ALICE, COLLECTION, 1, 100);
+
+    let _ = execute_claim(
+        &mock_contract_context(ALICE, 110),
+        &mut state,
+        &ClaimMsg {
+            amount: Some(1_001),
+        },
+    );
+}
+
+#[test]
+fn proper_set_collection() {
+    let (mut state, _) = execute_init(&mock_contract_context(OWNER, 100), &init_msg(None));
+
+    stake_token(&mut state, ALICE, COLLECTION, 1, 100);
+
+    let _ = execute_set_collection(
+        &mock_contract_context(OWNER, 100),
+        &mut state,
+        &SetCollectionMsg {
+            collection: mock_address(COLLECTION),
+            allowed: false,
+        },
+    );
+    assert_eq!(
+        state.collections,
+        BTreeSet::from([mock_address(OTHER_COLLECTION)])
+    );
+
+    let _ = execute_unstake(
+        &mock_contract_context(ALICE, 100),
+        &mut state,
+        &UnstakeMsg {
+            collection: mock_address(COLLECTION),
+            token_ids: vec![1],
+        },
+    );
+    assert_eq!(state.total_staked, 0);
+
+    let _ = execute_set_collection(
+        &mock_contract_context(OWNER, 100),
+        &mut state,
+        &SetCollectionMsg {
+            collection: mock_address(REWARD_TOKEN),
+            allowed: true,
+        },
+    );
+    assert!(state.collections.contains(&mock_address(REWARD_TOKEN)));
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn set_collection_not_owner() {
+    let (mut state, _) = execute_init(&mock_contract_context(OWNER, 100), &init_msg(None));
+
+    let _ = execute_set_collection(
+        &mock_contract_context(ALICE, 100),
+        &mut state,
+        &SetCollectionMsg {
+            collection: mock_address(COLLECTION),
+            allowed: false,
+        },
+    );
+}
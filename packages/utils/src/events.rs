@@ -1,5 +1,5 @@
 use pbc_contract_common::{
-    address::{Address, ShortnameCallback},
+    address::{Address, Shortname, ShortnameCallback},
     events::EventGroupBuilder,
 };
 use pbc_traits::ReadWriteRPC;
@@ -65,6 +65,32 @@ pub fn build_msg_callback_with_cost<T>(
         .done();
 }
 
+/// ## Description
+/// Creates a reply event to the specified action of the calling contract
+/// and adds it to event group builder object
+/// ## Params
+/// * **builder** is an object of type [`EventGroupBuilder`]
+///
+/// * **dest** is an object of type [`Address`]
+///
+/// * **action_byte** is an object of type [`u32`]
+///
+/// * **msg** is an object of type [`T`]
+#[inline]
+pub fn build_msg_reply<T>(
+    builder: &mut EventGroupBuilder,
+    dest: &Address,
+    action_byte: u32,
+    msg: &T,
+) where
+    T: ReadWriteRPC + Clone,
+{
+    builder
+        .call(*dest, Shortname::from_u32(action_byte))
+        .argument(msg.clone())
+        .done();
+}
+
 #[cfg(test)]
 mod rpc_msg_tests {
     use super::*;
@@ -244,3 +270,47 @@ mod rpc_msg_with_cost_tests {
         assert_eq!(eg.build(), derive_eg.build());
     }
 }
+
+#[cfg(test)]
+mod reply_msg_tests {
+    use super::build_msg_reply;
+
+    use create_type_spec_derive::CreateTypeSpec;
+    use pbc_contract_common::{
+        address::{Address, AddressType, Shortname},
+        events::EventGroup,
+    };
+    use read_write_rpc_derive::ReadWriteRPC;
+
+    #[derive(ReadWriteRPC, CreateTypeSpec, Clone, PartialEq, Eq, Debug)]
+    pub struct TestReplyMsg {
+        pub token_id: u128,
+        pub amount: u128,
+    }
+
+    #[test]
+    fn test_build_msg_reply() {
+        let dest = Address {
+            address_type: AddressType::PublicContract,
+            identifier: [
+                10u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8, 0u8,
+                0u8, 0u8, 0u8, 0u8,
+            ],
+        };
+        let msg = TestReplyMsg {
+            token_id: 1,
+            amount: 100,
+        };
+
+        let mut eg = EventGroup::builder();
+        build_msg_reply(&mut eg, &dest, 0x41, &msg);
+
+        let mut test_eg = EventGroup::builder();
+        test_eg
+            .call(dest, Shortname::from_u32(0x41))
+            .argument(msg)
+            .done();
+
+        assert_eq!(eg.build(), test_eg.build());
+    }
+}
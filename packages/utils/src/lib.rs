@@ -3,4 +3,5 @@ pub mod decimal;
 pub mod events;
 pub mod merkle;
 pub mod mpc;
+pub mod signature;
 pub mod zk_contract_deployer;
@@ -0,0 +1,71 @@
+use k256::ecdsa::{signature::Verifier, Signature, VerifyingKey};
+use sha2::{Digest, Sha256};
+
+/// ## Description
+/// Verifies secp256k1 ecdsa signature of the message hashed with sha256
+/// ## Params
+/// * **public_key** is an object of type [`[u8]`], sec1 encoded public key
+///
+/// * **msg** is an object of type [`[u8]`]
+///
+/// * **signature** is an object of type [`[u8]`], 64 bytes of r and s values
+pub fn verify_signature(public_key: &[u8], msg: &[u8], signature: &[u8]) -> bool {
+    let verifying_key = match VerifyingKey::from_sec1_bytes(public_key) {
+        Ok(key) => key,
+        Err(_) => return false,
+    };
+
+    let signature = match Signature::try_from(signature) {
+        Ok(signature) => signature,
+        Err(_) => return false,
+    };
+
+    verifying_key.verify(msg, &signature).is_ok()
+}
+
+/// ## Description
+/// Returns sha256 digest of the message
+/// ## Params
+/// * **msg** is an object of type [`[u8]`]
+pub fn sha256_digest(msg: &[u8]) -> [u8; 32] {
+    Sha256::digest(msg).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use k256::ecdsa::{signature::Signer, SigningKey};
+
+    #[test]
+    fn test_verify_signature() {
+        let signing_key = SigningKey::from_bytes(&[7u8; 32]).unwrap();
+        let public_key = signing_key.verifying_key().to_bytes();
+        let signature: Signature = signing_key.sign(b"voucher");
+
+        assert!(verify_signature(
+            &public_key,
+            b"voucher",
+            signature.as_ref()
+        ));
+        assert!(!verify_signature(
+            &public_key,
+            b"voucher2",
+            signature.as_ref()
+        ));
+        assert!(!verify_signature(
+            &[1u8; 33],
+            b"voucher",
+            signature.as_ref()
+        ));
+        assert!(!verify_signature(&public_key, b"voucher", &[1u8; 10]));
+    }
+
+    #[test]
+    fn test_sha256_digest() {
+        assert_eq!(
+            hex::encode(sha256_digest(b"abc")),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+}